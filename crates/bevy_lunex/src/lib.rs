@@ -103,11 +103,30 @@ pub use structs::*;
 pub mod systems;
 pub use systems::*;
 
+#[cfg(feature = "template")]
+pub mod template;
+#[cfg(feature = "template")]
+pub use template::*;
+
+#[cfg(feature = "template")]
+pub mod snapshot;
+#[cfg(feature = "template")]
+pub use snapshot::*;
+
+#[cfg(any(test, feature = "test_utils"))]
+pub mod test_utils;
+
+#[cfg(feature = "markup")]
+pub mod markup;
+#[cfg(feature = "markup")]
+pub use markup::*;
+
 
 pub mod prelude {
 
     pub use super::Cursor2d;
     pub use super::actions;
+    pub use super::actions::UiCommand;
 
     pub use super::logic::*;
 
@@ -115,10 +134,17 @@ pub mod prelude {
     pub use super::UiGenericPlugins;
     pub use super::UiMinimalPlugins;
     pub use super::UiDefaultPlugins;
-    pub use super::systems::{UiSystems, UiDebugPlugin};
+    pub use super::systems::{UiSystems, UiSchedule, UiQuery, UiUpdateMode, RecomputeUiLayout, UiDebugPlugin, UiDebugOverlay, UiInspector, UiLunexDiagnostics};
     pub use super::structs::*;
 
-    pub use super::PickingPortal;
+    pub use super::{PickingPortal, UiPickingPortal, UiHitTest, UiPickingPolicy, UiRaycast, UiPointerTiming, DoubleClick, LongPress};
+
+    #[cfg(feature = "template")]
+    pub use super::template::{UiTemplate, UiTemplateRoot, UiTemplatePlugin};
+    #[cfg(feature = "template")]
+    pub use super::snapshot::{UiSnapshot, UiSnapshotRequest, UiSnapshotTaken, UiSnapshotPlugin};
+    #[cfg(feature = "markup")]
+    pub use super::markup::UiMarkupError;
 
     // RE-EXPORT BEVY MOD PICKING
     pub use bevy_mod_picking::prelude::*;
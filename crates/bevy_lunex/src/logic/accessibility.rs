@@ -0,0 +1,214 @@
+use crate::*;
+use bevy::a11y::{accesskit::{Action, NodeBuilder, NodeId, Rect, Role, Toggled}, AccessibilityNode, AccessibilitySystem, ActionRequest, Focus};
+use bevy::render::camera::CameraUpdateSystem;
+use bevy::transform::TransformSystem;
+use bevy::window::PrimaryWindow;
+
+
+// #==================#
+// #=== ACCESSIBLE ===#
+
+/// **Ui accessible** - Exposes the node to screen readers and other assistive technologies through AccessKit.
+/// The node is given the role and label, its bounds are computed from [`Dimension`] and the screen reader focus
+/// follows the pressed node and the focused [`UiTextInput`].
+///
+/// Screen readers can focus the node and activate it. Activating sends [`UiClickEvent`], so it works
+/// the same as clicking the node with [`UiClickEmitter::SELF`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu"))).with_children(|ui| {
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Music"),
+///     UiLayout::window().size(Rl((20.0, 6.0))).pack::<Base>(),
+///     UiAccessible::checkbox("Music").checked(true),
+/// ));
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Volume"),
+///     UiLayout::window().y(Rl(10.0)).size(Rl((20.0, 6.0))).pack::<Base>(),
+///     UiAccessible::slider("Volume").value(0.8),
+/// ));
+/// # });
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiAccessible {
+    /// The kind of the widget.
+    pub role: Role,
+    /// Text read by the screen reader.
+    pub label: String,
+    /// State of checkboxes and toggle buttons.
+    pub checked: Option<bool>,
+    /// Value of sliders and progress bars.
+    pub value: Option<f32>,
+    /// Minimum and maximum of the value.
+    pub range: Vec2,
+}
+impl UiAccessible {
+    /// Creates new accessible node with the role and label.
+    pub fn new(role: Role, label: impl Into<String>) -> Self {
+        UiAccessible {
+            role,
+            label: label.into(),
+            checked: None,
+            value: None,
+            range: Vec2::new(0.0, 1.0),
+        }
+    }
+    /// Creates new accessible button.
+    pub fn button(label: impl Into<String>) -> Self {
+        UiAccessible::new(Role::Button, label)
+    }
+    /// Creates new accessible unchecked checkbox.
+    pub fn checkbox(label: impl Into<String>) -> Self {
+        UiAccessible::new(Role::CheckBox, label).checked(false)
+    }
+    /// Creates new accessible slider with value range from `0.0` to `1.0`.
+    pub fn slider(label: impl Into<String>) -> Self {
+        UiAccessible::new(Role::Slider, label).value(0.0)
+    }
+    /// Replaces the checked state with a new value.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+    /// Replaces the value with a new value.
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = Some(value);
+        self
+    }
+    /// Replaces the range with a new value.
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.range = Vec2::new(min, max);
+        self
+    }
+}
+
+/// This system builds [`AccessibilityNode`] from [`UiAccessible`] and the computed node rectangle.
+fn accessible_update(
+    mut commands: Commands,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut removed: RemovedComponents<UiDisabled>,
+    mut query: Query<(Entity, Ref<UiAccessible>, Ref<Dimension>, Ref<GlobalTransform>, Option<Ref<InheritedVisibility>>, Option<Ref<UiTextInput>>, Option<Ref<UiDisabled>>, Option<&mut AccessibilityNode>)>,
+) {
+    let scale_factor = windows.get_single().map_or(1.0, |window| window.resolution.scale_factor());
+    let enabled: Vec<Entity> = removed.read().collect();
+    for (entity, accessible, dimension, transform, visibility, input, disabled, node) in &mut query {
+        let changed = accessible.is_changed() || dimension.is_changed() || transform.is_changed()
+            || visibility.as_ref().is_some_and(|visibility| visibility.is_changed())
+            || input.as_ref().is_some_and(|input| input.is_changed())
+            || disabled.as_ref().is_some_and(|disabled| disabled.is_changed())
+            || enabled.contains(&entity);
+        if node.is_some() && !changed { continue }
+
+        let mut builder = NodeBuilder::new(accessible.role);
+        builder.set_name(accessible.label.as_str());
+        builder.add_action(Action::Focus);
+        builder.add_action(Action::Default);
+        if let Some(checked) = accessible.checked {
+            builder.set_toggled(if checked { Toggled::True } else { Toggled::False });
+        }
+        if let Some(value) = accessible.value {
+            builder.set_numeric_value(value as f64);
+            builder.set_min_numeric_value(accessible.range.x as f64);
+            builder.set_max_numeric_value(accessible.range.y as f64);
+        }
+        if let Some(input) = input {
+            builder.set_value(input.value.as_str());
+        }
+        if visibility.is_some_and(|visibility| !visibility.get()) {
+            builder.set_hidden();
+        }
+        if disabled.is_some() {
+            builder.set_disabled();
+        }
+
+        // Project all corners, so rotated and 3D nodes get their screen space bounds
+        let half = dimension.size / 2.0;
+        let corners = [Vec2::new(-half.x, -half.y), Vec2::new(half.x, -half.y), Vec2::new(half.x, half.y), Vec2::new(-half.x, half.y)];
+        let points: Vec<Vec2> = corners.iter().filter_map(|corner| world_to_window(&cameras, transform.transform_point(corner.extend(0.0)))).collect();
+        if points.len() == corners.len() {
+            let min = points.iter().fold(Vec2::MAX, |min, point| min.min(*point)) * scale_factor;
+            let max = points.iter().fold(Vec2::MIN, |max, point| max.max(*point)) * scale_factor;
+            builder.set_bounds(Rect::new(min.x as f64, min.y as f64, max.x as f64, max.y as f64));
+        }
+
+        match node {
+            Some(mut node) => **node = builder,
+            None => { commands.entity(entity).try_insert(AccessibilityNode::from(builder)); },
+        }
+    }
+}
+
+/// This system removes [`AccessibilityNode`] from nodes that are no longer [`UiAccessible`].
+fn accessible_remove(mut commands: Commands, mut removed: RemovedComponents<UiAccessible>, query: Query<(), With<AccessibilityNode>>) {
+    for entity in removed.read() {
+        if query.contains(entity) {
+            commands.entity(entity).remove::<AccessibilityNode>();
+        }
+    }
+}
+
+/// This system moves the screen reader focus to the pressed [`UiAccessible`] and the focused [`UiTextInput`].
+fn accessible_focus(
+    focus: Option<ResMut<Focus>>,
+    mut events: EventReader<Pointer<Down>>,
+    accessible: Query<(), (With<UiAccessible>, Without<UiDisabled>)>,
+    inputs: Query<(Entity, &UiTextInput), (With<UiAccessible>, Changed<UiTextInput>)>,
+) {
+    let Some(mut focus) = focus else { return };
+    for event in events.read() {
+        let target = accessible.contains(event.target).then_some(event.target);
+        if focus.0 != target { focus.0 = target; }
+    }
+    for (entity, input) in &inputs {
+        if input.focused && focus.0 != Some(entity) { focus.0 = Some(entity); }
+        if !input.focused && focus.0 == Some(entity) { focus.0 = None; }
+    }
+}
+
+/// This system performs the focus and activation actions requested by the screen reader.
+fn accessible_actions(
+    mut focus: Option<ResMut<Focus>>,
+    mut events: EventReader<ActionRequest>,
+    mut clicks: EventWriter<UiClickEvent>,
+    accessible: Query<(), (With<UiAccessible>, Without<UiDisabled>)>,
+    mut inputs: Query<(Entity, &mut UiTextInput)>,
+) {
+    for event in events.read() {
+        let NodeId(bits) = event.target;
+        let Some(target) = Entity::try_from_bits(bits).ok().filter(|target| accessible.contains(*target)) else { continue };
+        match event.action {
+            Action::Focus => {
+                if let Some(focus) = &mut focus { focus.0 = Some(target); }
+                for (entity, mut input) in &mut inputs {
+                    if input.focused != (entity == target) { input.focused = entity == target; }
+                }
+            },
+            Action::Default => { clicks.send(UiClickEvent { target }); },
+            _ => {},
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiAccessible`] logic.
+pub struct AccessiblePlugin;
+impl Plugin for AccessiblePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<ActionRequest>()
+            .add_systems(Update, (accessible_actions, accessible_focus).chain().before(UiSystems::Modify))
+            .add_systems(PostUpdate, (accessible_update, accessible_remove)
+                .after(TransformSystem::TransformPropagate)
+                .after(CameraUpdateSystem)
+                .before(AccessibilitySystem::Update)
+            );
+    }
+}
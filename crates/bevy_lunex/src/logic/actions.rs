@@ -194,28 +194,110 @@ fn apply_event_set_ui_layout(mut events: EventReader<SetUiLayout>, mut query: Qu
     }
 }
 
-/// This event will override sprite/text color of targetted entity
+/// This event will override sprite/text/material color of targetted entity.
+/// Materials are handled by [`crate::UiMaterialSync`] and multi-colored texts by [`crate::UiSpanColor`].
 #[derive(Event, PartialEq, Clone, Copy)]
 pub struct SetColor {
     pub target: Entity,
     pub color: Color,
 }
-fn apply_event_set_color(mut events: EventReader<SetColor>, mut materials: ResMut<Assets<StandardMaterial>>, mut query: Query<(Option<&mut Sprite>, Option<&mut Text>, Option<&Handle<StandardMaterial>>)>) {
+fn apply_event_set_color(mut events: EventReader<SetColor>, mut query: Query<(Option<&mut Sprite>, Option<&mut Text>, Has<UiSpanColor<Base>>)>) {
     for event in events.read() {
-        if let Ok((sprite_option, text_option, material_option)) = query.get_mut(event.target) {
+        if let Ok((sprite_option, text_option, has_span_color)) = query.get_mut(event.target) {
             if let Some(mut sprite) = sprite_option {
                 sprite.color = event.color;
             }
+            // Texts with per-section colors are handled by UiSpanColor
+            if has_span_color { continue }
             if let Some(mut text) = text_option {
                 for section in &mut text.sections {
                     section.style.color = event.color;
                 }
             }
-            if let Some(material_handle) = material_option {
-                if let Some(material) = materials.get_mut(material_handle) {
-                    material.base_color = event.color;
+        }
+    }
+}
+
+
+// #==================#
+// #=== UI COMMAND ===#
+
+/// Commands for tweaking the UI from gameplay code without querying Lunex components.
+/// They are applied in [`UiSystems::Modify`], so the change is computed in the same frame.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// #[derive(Component)]
+/// struct HealthBar;
+///
+/// fn on_damage(mut commands: EventWriter<UiCommand>, health_bar: Query<Entity, With<HealthBar>>) {
+///     for entity in &health_bar {
+///         commands.send(UiCommand::SetColor(entity, Color::srgb(1.0, 0.0, 0.0)));
+///         commands.send(UiCommand::SetState(entity, "danger".into(), 1.0));
+///     }
+/// }
+/// ```
+#[derive(Event, Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum UiCommand {
+    /// Replaces the [`UiLayout<Base>`] of the entity.
+    SetLayout(Entity, UiLayout),
+    /// Replaces the base color of the entity. Updates [`UiColor<Base>`] if present and recolors sprites and texts.
+    SetColor(Entity, Color),
+    /// Sets the transition of a named state in [`UiNamedState`], ranging from `0.0` to `1.0`.
+    /// The component is inserted if the entity doesn't have it.
+    SetState(Entity, String, f32),
+    /// Replaces the [`UiDisplay`] of the entity.
+    SetDisplay(Entity, UiDisplay),
+    /// Shows the entity, same as [`UiCommand::SetDisplay`] with [`UiDisplay::Shown`].
+    Show(Entity),
+    /// Hides the entity, same as [`UiCommand::SetDisplay`] with [`UiDisplay::Hidden`].
+    Hide(Entity),
+}
+fn set_display(commands: &mut Commands, displays: &mut Query<&mut UiDisplay>, entity: Entity, display: UiDisplay) {
+    if let Ok(mut current) = displays.get_mut(entity) {
+        if *current != display { *current = display; }
+    } else if let Some(mut entity) = commands.get_entity(entity) {
+        entity.insert(display);
+    }
+}
+fn apply_ui_commands(
+    mut commands: Commands,
+    mut events: EventReader<UiCommand>,
+    mut set_color: EventWriter<SetColor>,
+    mut layouts: Query<&mut UiLayout>,
+    mut colors: Query<&mut UiColor<Base>>,
+    mut states: Query<&mut UiNamedState>,
+    mut displays: Query<&mut UiDisplay>,
+) {
+    for event in events.read() {
+        #[cfg(feature = "verbose")]
+        info!("{} - Applied command: {:?}", "EVENT".purple().bold(), event);
+        match event {
+            UiCommand::SetLayout(entity, layout) => {
+                if let Ok(mut current) = layouts.get_mut(*entity) {
+                    if *current != *layout { *current = *layout; }
                 }
-            }
+            },
+            UiCommand::SetColor(entity, color) => {
+                if let Ok(mut base) = colors.get_mut(*entity) {
+                    base.color = *color;
+                }
+                set_color.send(SetColor { target: *entity, color: *color });
+            },
+            UiCommand::SetState(entity, name, transition) => {
+                if let Ok(mut state) = states.get_mut(*entity) {
+                    state.set_named(name, *transition);
+                } else if let Some(mut entity) = commands.get_entity(*entity) {
+                    let mut state = UiNamedState::new();
+                    state.set_named(name, *transition);
+                    entity.insert(state);
+                }
+            },
+            UiCommand::SetDisplay(entity, display) => set_display(&mut commands, &mut displays, *entity, *display),
+            UiCommand::Show(entity) => set_display(&mut commands, &mut displays, *entity, UiDisplay::Shown),
+            UiCommand::Hide(entity) => set_display(&mut commands, &mut displays, *entity, UiDisplay::Hidden),
         }
     }
 }
@@ -227,6 +309,7 @@ fn apply_event_set_color(mut events: EventReader<SetColor>, mut materials: ResMu
 pub struct ActionsPlugin;
 impl Plugin for ActionsPlugin {
     fn build(&self, app: &mut App) {
+        let schedule = UiSchedule::of(app);
         app
             .add_event::<SetWindowPresentMode>()
             .add_systems(Update, set_window_present_mode_action.run_if(on_event::<SetWindowPresentMode>()))
@@ -272,9 +355,12 @@ impl Plugin for ActionsPlugin {
             .add_systems(Update, apply_event_confine_cursor_2d.run_if(on_event::<ConfineCursor2d>()))
 
             .add_event::<SetUiLayout>()
-            .add_systems(Update, apply_event_set_ui_layout.run_if(on_event::<SetUiLayout>()))
+            .add_systems(schedule, apply_event_set_ui_layout.run_if(on_event::<SetUiLayout>()).in_set(UiSystems::Modify))
 
             .add_event::<SetColor>()
-            .add_systems(Update, apply_event_set_color.run_if(on_event::<SetColor>()));
+            .add_systems(Update, apply_event_set_color.run_if(on_event::<SetColor>()))
+
+            .add_event::<UiCommand>()
+            .add_systems(schedule, apply_ui_commands.run_if(on_event::<UiCommand>()).in_set(UiSystems::Modify).before(UiSystems::Send).before(apply_event_set_color));
     }
 }
\ No newline at end of file
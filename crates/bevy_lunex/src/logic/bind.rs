@@ -0,0 +1,176 @@
+use crate::*;
+
+
+// #===============#
+// #=== BINDING ===#
+
+/// What the bound value is written into.
+enum UiBindOutput<S> {
+    /// String written into the first [`Text`] section.
+    Text(Box<dyn Fn(&S) -> String + Send + Sync>),
+    /// Fraction from `0.0` to `1.0` written into the width of [`UiLayout::window`].
+    Fill(Box<dyn Fn(&S) -> f32 + Send + Sync>),
+}
+impl <S> UiBindOutput<S> {
+    /// Writes the value into the text or the layout of the node.
+    fn apply(&self, source: &S, text: Option<Mut<Text>>, layout: Option<Mut<UiLayout>>) {
+        match self {
+            UiBindOutput::Text(text_fn) => {
+                let Some(mut text) = text else { return };
+                let value = text_fn(source);
+                match text.sections.first() {
+                    Some(section) if section.value == value => {},
+                    Some(_) => text.sections[0].value = value,
+                    None => text.sections.push(TextSection::new(value, TextStyle::default())),
+                }
+            },
+            UiBindOutput::Fill(fill_fn) => {
+                let Some(mut layout) = layout else { return };
+                let Layout::Window(window) = layout.layout else {
+                    warn!("UiBind fill requires UiLayout::window");
+                    return;
+                };
+                let new = window.width(Rl(fill_fn(source).clamp(0.0, 1.0) * 100.0));
+                if window != new { layout.layout = new.into(); }
+            },
+        }
+    }
+}
+
+/// **Ui bind** - Keeps the node in sync with a component of the source entity. The value is written only when
+/// the component changes, text layouts and their parents reflow on their own once the new text is measured.
+/// Requires [`UiBindPlugin`] for the component type.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// # fn setup(mut commands: Commands) {
+/// let player = commands.spawn(Health(100.0)).id();
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Hud"))).with_children(|ui| {
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Health"),
+///     UiLayout::window().size(Rl((30.0, 5.0))).pack::<Base>(),
+///     UiText2dBundle::default(),
+///     UiBind::<Health>::text(player, |health| format!("{} HP", health.0)),
+/// ));
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Health/Fill"),
+///     UiLayout::window().size(Rl(100.0)).pack::<Base>(),
+///     UiBind::<Health>::fill(player, |health| health.0 / 100.0),
+/// ));
+/// # });
+/// # }
+/// # App::new().add_plugins(UiBindPlugin::<Health>::new());
+/// ```
+#[derive(Component)]
+pub struct UiBind<C: Component> {
+    /// The entity with the bound component.
+    pub source: Entity,
+    /// What the value is written into.
+    output: UiBindOutput<C>,
+}
+impl <C: Component> UiBind<C> {
+    /// Creates new binding writing the string into the first [`Text`] section.
+    pub fn text(source: Entity, text: impl Fn(&C) -> String + Send + Sync + 'static) -> Self {
+        UiBind { source, output: UiBindOutput::Text(Box::new(text)) }
+    }
+    /// Creates new binding writing the fraction from `0.0` to `1.0` into the width of [`UiLayout::window`], for progress bars.
+    pub fn fill(source: Entity, fill: impl Fn(&C) -> f32 + Send + Sync + 'static) -> Self {
+        UiBind { source, output: UiBindOutput::Fill(Box::new(fill)) }
+    }
+}
+
+/// **Ui bind resource** - Keeps the node in sync with a resource, the same way as [`UiBind`] does with components.
+/// Requires [`UiBindResourcePlugin`] for the resource type.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// #[derive(Resource)]
+/// struct Score(u32);
+///
+/// let label = UiBindResource::<Score>::text(|score| format!("Score: {}", score.0));
+/// ```
+#[derive(Component)]
+pub struct UiBindResource<R: Resource> {
+    /// What the value is written into.
+    output: UiBindOutput<R>,
+}
+impl <R: Resource> UiBindResource<R> {
+    /// Creates new binding writing the string into the first [`Text`] section.
+    pub fn text(text: impl Fn(&R) -> String + Send + Sync + 'static) -> Self {
+        UiBindResource { output: UiBindOutput::Text(Box::new(text)) }
+    }
+    /// Creates new binding writing the fraction from `0.0` to `1.0` into the width of [`UiLayout::window`], for progress bars.
+    pub fn fill(fill: impl Fn(&R) -> f32 + Send + Sync + 'static) -> Self {
+        UiBindResource { output: UiBindOutput::Fill(Box::new(fill)) }
+    }
+}
+
+/// This system writes the bound component into nodes with [`UiBind`] when the component or the binding change.
+pub fn bind_component<C: Component>(
+    sources: Query<Ref<C>>,
+    mut query: Query<(Ref<UiBind<C>>, Option<&mut Text>, Option<&mut UiLayout>)>,
+) {
+    for (bind, text, layout) in &mut query {
+        let Ok(source) = sources.get(bind.source) else { continue };
+        if !source.is_changed() && !bind.is_changed() { continue }
+
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Bound component changed", "--".yellow(), "ELEMENT".red());
+        bind.output.apply(&source, text, layout);
+    }
+}
+
+/// This system writes the bound resource into nodes with [`UiBindResource`] when the resource or the binding change.
+pub fn bind_resource<R: Resource>(
+    source: Res<R>,
+    mut query: Query<(Ref<UiBindResource<R>>, Option<&mut Text>, Option<&mut UiLayout>)>,
+) {
+    for (bind, text, layout) in &mut query {
+        if !source.is_changed() && !bind.is_changed() { continue }
+
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Bound resource changed", "--".yellow(), "ELEMENT".red());
+        bind.output.apply(&source, text, layout);
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiBind`] for the specified component.
+/// ## 📦 Types
+/// * Generic `(C)` - Component bound to the nodes
+#[derive(Debug, Default, Clone)]
+pub struct UiBindPlugin <C:Component>(PhantomData<C>);
+impl <C:Component> UiBindPlugin<C> {
+    pub fn new() -> Self {
+        UiBindPlugin::<C>(PhantomData)
+    }
+}
+impl <C:Component> Plugin for UiBindPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, bind_component::<C>.before(UiSystems::Modify));
+    }
+}
+
+/// Plugin adding [`UiBindResource`] for the specified resource.
+/// ## 📦 Types
+/// * Generic `(R)` - Resource bound to the nodes
+#[derive(Debug, Default, Clone)]
+pub struct UiBindResourcePlugin <R:Resource>(PhantomData<R>);
+impl <R:Resource> UiBindResourcePlugin<R> {
+    pub fn new() -> Self {
+        UiBindResourcePlugin::<R>(PhantomData)
+    }
+}
+impl <R:Resource> Plugin for UiBindResourcePlugin<R> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, bind_resource::<R>.run_if(resource_exists::<R>).before(UiSystems::Modify));
+    }
+}
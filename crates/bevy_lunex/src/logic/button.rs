@@ -0,0 +1,280 @@
+use crate::*;
+use bevy::a11y::Focus;
+use bevy::input::gamepad::{GamepadButton, GamepadButtonType};
+use bevy::sprite::Anchor;
+
+
+// #==============#
+// #=== BUTTON ===#
+
+/// **Ui button** - Ready to use button prefab. Spawns a colored node with hover and click animations,
+/// pointer cursor and a centered text subnode. Pressing the button sends [`ButtonPressed`] with the payload.
+///
+/// The button is pressed by clicking it, by pressing `Enter` or `Space` while it is hovered or focused
+/// and by pressing the gamepad `South` button while it is focused. The focus is the [`Focus`] resource,
+/// which follows the last pressed button and the screen reader.
+/// ## 📌 Note
+/// Buttons with a custom payload type need [`UiButtonAppExt::register_ui_button`], the `()` payload is registered by default.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum MenuAction { Play, Quit }
+///
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu"))).with_children(|ui| {
+///     ui.spawn_prefab(UiLink::<MainUi>::path("Play"), UiButton::new("Play").payload(MenuAction::Play)
+///         .layout(UiLayout::window().pos(Rl((40.0, 40.0))).size(Rl((20.0, 8.0))))
+///     );
+///     ui.spawn_prefab(UiLink::<MainUi>::path("Quit"), UiButton::new("Quit").payload(MenuAction::Quit)
+///         .layout(UiLayout::window().pos(Rl((40.0, 52.0))).size(Rl((20.0, 8.0))))
+///         .hover_color(Color::srgb(0.8, 0.2, 0.2))
+///     );
+/// });
+/// # }
+///
+/// fn menu(mut events: EventReader<ButtonPressed<MenuAction>>, mut exit: EventWriter<AppExit>) {
+///     for event in events.read() {
+///         if event.payload == MenuAction::Quit { exit.send(AppExit::Success); }
+///     }
+/// }
+///
+/// # fn build(app: &mut App) {
+/// app.register_ui_button::<MenuAction>().add_systems(Update, menu);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct UiButton<P = ()> {
+    /// Text of the button.
+    pub label: String,
+    /// Value sent with [`ButtonPressed`].
+    pub payload: P,
+    /// Layout of the button.
+    pub layout: Layout,
+    /// Layout the button transitions to on hover.
+    pub hover_layout: Option<Layout>,
+    /// Layout the button transitions to on click.
+    pub clicked_layout: Option<Layout>,
+    /// Color of the button.
+    pub color: Color,
+    /// Color the button transitions to on hover.
+    pub hover_color: Color,
+    /// Color the button transitions to on click.
+    pub clicked_color: Color,
+    /// Color of the text.
+    pub text_color: Color,
+    /// Height of the text in percent of the button height.
+    pub text_size: f32,
+    /// Font of the text.
+    pub font: Handle<Font>,
+    /// Cursor requested on hover.
+    pub cursor: CursorIcon,
+    /// Chord pressing the button through [`UiShortcut`].
+    pub shortcut: Option<KeyChord>,
+}
+impl UiButton {
+    /// Creates new button with the text and the `()` payload.
+    pub fn new(label: impl Into<String>) -> Self {
+        UiButton {
+            label: label.into(),
+            payload: (),
+            layout: UiLayout::window_full().into(),
+            hover_layout: None,
+            clicked_layout: None,
+            color: Color::srgb(0.15, 0.15, 0.18),
+            hover_color: Color::srgb(0.25, 0.25, 0.3),
+            clicked_color: Color::srgb(0.1, 0.1, 0.12),
+            text_color: Color::WHITE,
+            text_size: 50.0,
+            font: Handle::default(),
+            cursor: CursorIcon::Pointer,
+            shortcut: None,
+        }
+    }
+}
+impl <P> UiButton<P> {
+    /// Replaces the payload with a new value, changing the type of [`ButtonPressed`] the button sends.
+    pub fn payload<Q>(self, payload: Q) -> UiButton<Q> {
+        UiButton {
+            label: self.label,
+            payload,
+            layout: self.layout,
+            hover_layout: self.hover_layout,
+            clicked_layout: self.clicked_layout,
+            color: self.color,
+            hover_color: self.hover_color,
+            clicked_color: self.clicked_color,
+            text_color: self.text_color,
+            text_size: self.text_size,
+            font: self.font,
+            cursor: self.cursor,
+            shortcut: self.shortcut,
+        }
+    }
+    /// Replaces the layout with a new value.
+    pub fn layout(mut self, layout: impl Into<Layout>) -> Self {
+        self.layout = layout.into();
+        self
+    }
+    /// Replaces the hover layout with a new value.
+    pub fn hover_layout(mut self, layout: impl Into<Layout>) -> Self {
+        self.hover_layout = Some(layout.into());
+        self
+    }
+    /// Replaces the clicked layout with a new value.
+    pub fn clicked_layout(mut self, layout: impl Into<Layout>) -> Self {
+        self.clicked_layout = Some(layout.into());
+        self
+    }
+    /// Replaces the color with a new value.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = color.into();
+        self
+    }
+    /// Replaces the hover color with a new value.
+    pub fn hover_color(mut self, color: impl Into<Color>) -> Self {
+        self.hover_color = color.into();
+        self
+    }
+    /// Replaces the clicked color with a new value.
+    pub fn clicked_color(mut self, color: impl Into<Color>) -> Self {
+        self.clicked_color = color.into();
+        self
+    }
+    /// Replaces the text color with a new value.
+    pub fn text_color(mut self, color: impl Into<Color>) -> Self {
+        self.text_color = color.into();
+        self
+    }
+    /// Replaces the text size with a new value.
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = size;
+        self
+    }
+    /// Replaces the font with a new value.
+    pub fn font(mut self, font: Handle<Font>) -> Self {
+        self.font = font;
+        self
+    }
+    /// Replaces the cursor with a new value.
+    pub fn cursor(mut self, cursor: CursorIcon) -> Self {
+        self.cursor = cursor;
+        self
+    }
+    /// Replaces the shortcut with a new value.
+    pub fn shortcut(mut self, chord: impl Into<KeyChord>) -> Self {
+        self.shortcut = Some(chord.into());
+        self
+    }
+}
+impl <T: Component, P: Clone + Send + Sync + 'static> UiPrefabTrait<T> for UiButton<P> {
+    fn spawn(self, ui: &mut ChildBuilder, link: UiLink<T>) -> Entity {
+        ui.spawn((
+            link.add("Text"),
+            UiLayout::window().pos(Rl(50.0)).anchor(Anchor::Center).pack::<Base>(),
+            UiText2dBundle {
+                text: Text::from_section(self.label.clone(), TextStyle { font: self.font, font_size: self.text_size, color: self.text_color }),
+                ..default()
+            },
+            Pickable::IGNORE,
+        ));
+
+        let mut button = ui.spawn((
+            link,
+            UiLayout::<Base>::from(self.layout),
+            UiImage2dBundle::default(),
+            PickableBundle::default(),
+            UiColor::<Base>::new(self.color),
+            UiColor::<Hover>::new(self.hover_color),
+            UiColor::<Clicked>::new(self.clicked_color),
+            UiAnimator::<Hover>::new().forward_speed(6.0).backward_speed(4.0),
+            UiAnimator::<Clicked>::new().forward_speed(12.0).play_full(true),
+            OnHoverSetCursor::new(self.cursor),
+            UiClickEmitter::SELF,
+            UiAccessible::button(self.label),
+            UiButtonPayload(self.payload),
+        ));
+        if let Some(layout) = self.hover_layout { button.insert(UiLayout::<Hover>::from(layout)); }
+        if let Some(layout) = self.clicked_layout { button.insert(UiLayout::<Clicked>::from(layout)); }
+        if let Some(chord) = self.shortcut { button.insert(UiShortcut::new(chord)); }
+        button.id()
+    }
+}
+
+/// Payload of the [`UiButton`] node, sent with [`ButtonPressed`] when the button is pressed.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiButtonPayload<P>(pub P);
+
+/// This event is sent when [`UiButton`] with the payload of this type is pressed.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct ButtonPressed<P: Send + Sync + 'static = ()> {
+    /// The pressed button
+    pub target: Entity,
+    /// Payload of the pressed button
+    pub payload: P,
+}
+
+/// This system sends [`ButtonPressed`] for clicked buttons and for buttons activated by the keyboard or the gamepad.
+/// ## 📦 Types
+/// * Generic `(P)` - Payload of the buttons.
+pub fn button_press<P: Clone + Send + Sync + 'static>(
+    focus: Option<Res<Focus>>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    gamepad: Option<Res<ButtonInput<GamepadButton>>>,
+    mut clicks: EventReader<UiClickEvent>,
+    mut pressed: EventWriter<ButtonPressed<P>>,
+    inputs: Query<&UiTextInput>,
+    mut query: Query<(Entity, &UiButtonPayload<P>, Option<&UiAnimator<Hover>>, Option<&mut UiAnimator<Clicked>>), Without<UiDisabled>>,
+) {
+    for event in clicks.read() {
+        let Ok((target, payload, _, _)) = query.get(event.target) else { continue };
+        pressed.send(ButtonPressed { target, payload: payload.0.clone() });
+    }
+
+    // Keys typed into a focused text input don't press buttons
+    let typing = inputs.iter().any(|input| input.focused);
+    let key = !typing && keys.is_some_and(|keys| keys.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space]));
+    let pad = gamepad.is_some_and(|gamepad| gamepad.get_just_pressed().any(|button| button.button_type == GamepadButtonType::South));
+    if !key && !pad { return }
+
+    let focused = focus.and_then(|focus| focus.0);
+    for (target, payload, hover, clicked) in &mut query {
+        // The gamepad cursor already clicks the hovered button, so the gamepad only presses the focused one
+        let hovered = hover.is_some_and(|hover| hover.is_forward());
+        if !(focused == Some(target) || (key && hovered)) { continue }
+
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Pressed by keyboard or gamepad", "--".yellow(), "BUTTON".red());
+        if let Some(mut clicked) = clicked {
+            clicked.play(true);
+            clicked.play(false);
+        }
+        pressed.send(ButtonPressed { target, payload: payload.0.clone() });
+    }
+}
+
+/// Extension trait for registering [`UiButton`] payload types.
+pub trait UiButtonAppExt {
+    /// Adds [`ButtonPressed`] event of the payload type and the system sending it.
+    fn register_ui_button<P: Clone + Send + Sync + 'static>(&mut self) -> &mut Self;
+}
+impl UiButtonAppExt for App {
+    fn register_ui_button<P: Clone + Send + Sync + 'static>(&mut self) -> &mut Self {
+        self
+            .add_event::<ButtonPressed<P>>()
+            .add_systems(Update, button_press::<P>.after(UiSystems::Process))
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiButton`] logic for the `()` payload.
+pub struct ButtonPlugin;
+impl Plugin for ButtonPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_ui_button::<()>();
+    }
+}
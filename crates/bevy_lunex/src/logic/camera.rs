@@ -0,0 +1,110 @@
+use crate::*;
+use bevy::render::view::RenderLayers;
+
+
+// #=================#
+// #=== UI CAMERA ===#
+
+/// Marker for cameras spawned with [`UiCameraBundle`]. Their [`RenderLayers`] are assigned to every entity
+/// of the trees bound to them with [`SourceCameraSlot`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct UiCamera;
+
+/// **Ui camera** - Dedicated 2D camera for the ui, unaffected by the movement and zoom of the game camera.
+/// It renders on top of the game camera without clearing it and only sees its own render layer.
+/// Bind trees to it with [`SourceFromCamera`] and [`SourceCameraSlot`], all their nodes are moved into the layer for you.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// // Game camera free to move and zoom
+/// commands.spawn(Camera2dBundle::default());
+///
+/// // Camera in slot 0 rendering the render layer 1
+/// commands.spawn(UiCameraBundle::new(0, 1));
+/// commands.spawn((
+///     UiTreeBundle::<MainUi>::from(UiTree::new2d("Hud")),
+///     SourceFromCamera,
+///     SourceCameraSlot(0),
+/// ));
+/// # }
+/// ```
+#[derive(Bundle, Clone)]
+pub struct UiCameraBundle {
+    /// The camera rendering the ui.
+    pub camera: Camera2dBundle,
+    /// The slot the trees are bound to.
+    pub source: UiSourceCamera,
+    /// The render layer of the camera and the ui.
+    pub layers: RenderLayers,
+    /// Required to assign the render layer to the ui.
+    pub marker: UiCamera,
+}
+impl UiCameraBundle {
+    /// Creates new camera in the slot, rendering only the render layer.
+    pub fn new(slot: u32, layer: usize) -> Self {
+        UiCameraBundle {
+            camera: Camera2dBundle {
+                camera: Camera {
+                    order: 1,
+                    clear_color: ClearColorConfig::None,
+                    ..default()
+                },
+                transform: Transform::from_xyz(0.0, 0.0, 1000.0),
+                ..default()
+            },
+            source: UiSourceCamera::new(slot),
+            layers: RenderLayers::layer(layer),
+            marker: UiCamera,
+        }
+    }
+    /// Replaces the camera order with a new value.
+    pub fn order(mut self, order: isize) -> Self {
+        self.camera.camera.order = order;
+        self
+    }
+}
+
+/// This system assigns the [`RenderLayers`] of [`UiCamera`] to the trees bound to it and all their descendants.
+pub fn ui_camera_layers(
+    mut commands: Commands,
+    cameras: Query<(&UiSourceCamera, &RenderLayers), With<UiCamera>>,
+    trees: Query<(Entity, &SourceCameraSlot), With<SourceFromCamera>>,
+    descendants: Query<&Children>,
+    layers: Query<Option<&RenderLayers>>,
+) {
+    for (tree, slot) in &trees {
+        let Some((_, layer)) = cameras.iter().find(|(source, _)| source.slot == slot.0) else { continue };
+        for entity in std::iter::once(tree).chain(descendants.iter_descendants(tree)) {
+            if let Ok(current) = layers.get(entity) {
+                if current != Some(layer) {
+                    commands.entity(entity).insert(layer.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Projects the world point into logical window coordinates through the active camera with the highest order.
+pub(crate) fn world_to_window(cameras: &Query<(&Camera, &GlobalTransform)>, point: Vec3) -> Option<Vec2> {
+    let (camera, transform) = cameras.iter().filter(|(camera, _)| camera.is_active).max_by_key(|(camera, _)| camera.order)?;
+    let offset = camera.logical_viewport_rect().map(|rect| rect.min).unwrap_or_default();
+    camera.world_to_viewport(transform, point).map(|position| position + offset)
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiCamera`] logic.
+pub struct CameraPlugin;
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        let schedule = UiSchedule::of(app);
+        app
+            .register_type::<UiCamera>()
+            .add_systems(schedule, ui_camera_layers.after(UiSystems::Compute));
+    }
+}
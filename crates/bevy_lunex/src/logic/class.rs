@@ -0,0 +1,196 @@
+use crate::*;
+use bevy::utils::HashMap;
+
+
+// #==================#
+// #=== STYLESHEET ===#
+
+/// **Ui style class** - A set of optional overrides applied to every entity tagged with the matching [`UiClass`].
+/// Fields that are [`None`] are left untouched on the entity.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// let class = UiStyleClass::new()
+///     .color(Color::srgb(0.2, 0.4, 1.0))
+///     .hover_color(Color::WHITE)
+///     .font_size(40.0);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct UiStyleClass {
+    /// Overrides [`UiColor<Base>`].
+    pub color: Option<Color>,
+    /// Overrides [`UiColor<Hover>`].
+    pub hover_color: Option<Color>,
+    /// Overrides [`UiLayout<Base>`].
+    pub layout: Option<Layout>,
+    /// Overrides [`UiLayout<Hover>`].
+    pub hover_layout: Option<Layout>,
+    /// Overrides the font of all text sections.
+    pub font: Option<Handle<Font>>,
+    /// Overrides the font size of all text sections.
+    pub font_size: Option<f32>,
+}
+impl UiStyleClass {
+    /// Creates new empty class.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Replaces the base color with a new value.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+    /// Replaces the hover color with a new value.
+    pub fn hover_color(mut self, color: impl Into<Color>) -> Self {
+        self.hover_color = Some(color.into());
+        self
+    }
+    /// Replaces the base layout with a new value.
+    pub fn layout(mut self, layout: impl Into<Layout>) -> Self {
+        self.layout = Some(layout.into());
+        self
+    }
+    /// Replaces the hover layout with a new value.
+    pub fn hover_layout(mut self, layout: impl Into<Layout>) -> Self {
+        self.hover_layout = Some(layout.into());
+        self
+    }
+    /// Replaces the font with a new value.
+    pub fn font(mut self, font: Handle<Font>) -> Self {
+        self.font = Some(font);
+        self
+    }
+    /// Replaces the font size with a new value.
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.font_size = Some(font_size);
+        self
+    }
+    /// Overrides fields of this class with the defined fields of the other class.
+    pub fn merge(&mut self, other: &UiStyleClass) {
+        if other.color.is_some() { self.color = other.color; }
+        if other.hover_color.is_some() { self.hover_color = other.hover_color; }
+        if other.layout.is_some() { self.layout = other.layout; }
+        if other.hover_layout.is_some() { self.hover_layout = other.hover_layout; }
+        if other.font.is_some() { self.font.clone_from(&other.font); }
+        if other.font_size.is_some() { self.font_size = other.font_size; }
+    }
+}
+
+/// **Ui stylesheet** - Resource mapping class names to [`UiStyleClass`].
+/// Any change to this resource is reapplied to all entities with [`UiClass`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// fn setup(mut sheet: ResMut<UiStyleSheet>) {
+///     sheet.insert("button-primary", UiStyleClass::new().color(Color::srgb(0.2, 0.4, 1.0)).hover_color(Color::WHITE));
+/// }
+/// ```
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct UiStyleSheet {
+    /// All defined classes.
+    pub classes: HashMap<String, UiStyleClass>,
+}
+impl UiStyleSheet {
+    /// Creates new empty stylesheet.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Adds the class to the stylesheet. Builder variant of [`UiStyleSheet::insert`].
+    pub fn with(mut self, name: impl Into<String>, class: UiStyleClass) -> Self {
+        self.insert(name, class);
+        self
+    }
+    /// Inserts the class into the stylesheet, replacing the previous definition.
+    pub fn insert(&mut self, name: impl Into<String>, class: UiStyleClass) {
+        self.classes.insert(name.into(), class);
+    }
+    /// Returns the class with the given name.
+    pub fn get(&self, name: &str) -> Option<&UiStyleClass> {
+        self.classes.get(name)
+    }
+    /// Merges all listed classes in order. Unknown class names are ignored.
+    pub fn resolve(&self, class: &UiClass) -> UiStyleClass {
+        let mut output = UiStyleClass::new();
+        for name in class.names() {
+            if let Some(style) = self.get(name) {
+                output.merge(style);
+            }
+        }
+        output
+    }
+}
+
+/// **Ui class** - Tags the entity with one or more space separated class names from [`UiStyleSheet`].
+/// Later classes override the earlier ones.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let class = UiClass::new("button-primary large");
+/// ```
+#[derive(Component, Debug, Default, Clone, PartialEq, Eq)]
+pub struct UiClass(pub String);
+impl UiClass {
+    /// Creates new class component from space separated class names.
+    pub fn new(classes: impl Into<String>) -> Self {
+        UiClass(classes.into())
+    }
+    /// Iterates over the class names.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.split_whitespace()
+    }
+    /// Returns `true` if the class name is listed.
+    pub fn contains(&self, name: &str) -> bool {
+        self.names().any(|n| n == name)
+    }
+}
+
+/// This system applies the resolved [`UiStyleSheet`] classes to entities with changed [`UiClass`], or to all of them if the stylesheet changed.
+pub fn apply_style_classes(
+    mut commands: Commands,
+    sheet: Res<UiStyleSheet>,
+    mut query: Query<(Entity, Ref<UiClass>, Option<&mut Text>)>,
+    mut set_color: EventWriter<actions::SetColor>,
+) {
+    for (entity, class, text) in &mut query {
+        if !sheet.is_changed() && !class.is_changed() { continue }
+        let style = sheet.resolve(&class);
+
+        let mut entity_commands = commands.entity(entity);
+        if let Some(color) = style.color {
+            entity_commands.insert(UiColor::<Base>::new(color));
+            set_color.send(actions::SetColor { target: entity, color });
+        }
+        if let Some(color) = style.hover_color {
+            entity_commands.insert(UiColor::<Hover>::new(color));
+        }
+        if let Some(layout) = style.layout {
+            entity_commands.insert(UiLayout::<Base>::from(layout));
+        }
+        if let Some(layout) = style.hover_layout {
+            entity_commands.insert(UiLayout::<Hover>::from(layout));
+        }
+        if let Some(mut text) = text {
+            if style.font.is_none() && style.font_size.is_none() { continue }
+            for section in &mut text.sections {
+                if let Some(font) = &style.font { section.style.font = font.clone(); }
+                if let Some(font_size) = style.font_size { section.style.font_size = font_size; }
+            }
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiStyleSheet`] and [`UiClass`] logic.
+pub struct ClassPlugin;
+impl Plugin for ClassPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<UiStyleSheet>()
+            .add_systems(Update, apply_style_classes.before(UiSystems::Modify));
+    }
+}
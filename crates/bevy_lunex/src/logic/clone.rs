@@ -0,0 +1,361 @@
+use crate::*;
+use lunex_engine::*;
+use std::any::TypeId;
+use std::sync::Arc;
+use bevy::ecs::entity::EntityHashMap;
+use bevy::ecs::system::EntityCommands;
+use bevy::ecs::system::IntoObserverSystem;
+use bevy::sprite::Mesh2dHandle;
+use bevy::utils::HashMap;
+
+
+// #=================#
+// #=== OBSERVERS ===#
+
+type UiObserverFn = Arc<dyn Fn(&mut EntityWorldMut) + Send + Sync>;
+
+/// Observers added with [`UiObserveExt::observe_ui`], kept so they can be added again to the clones of the entity.
+#[derive(Component, Clone, Default)]
+pub struct UiObservers(Vec<UiObserverFn>);
+
+/// Extension trait for adding observers that are copied by [`UiCloneExt::clone_ui`].
+pub trait UiObserveExt {
+    /// Creates an observer watching this entity, same as `observe`. The observer is remembered
+    /// and added to every clone of this entity, so it needs to be [`Clone`].
+    fn observe_ui<E: Event, B: Bundle, M>(&mut self, observer: impl IntoObserverSystem<E, B, M> + Clone + Sync) -> &mut Self;
+}
+impl UiObserveExt for EntityCommands<'_> {
+    fn observe_ui<E: Event, B: Bundle, M>(&mut self, observer: impl IntoObserverSystem<E, B, M> + Clone + Sync) -> &mut Self {
+        let apply: UiObserverFn = Arc::new(move |entity: &mut EntityWorldMut| { entity.observe(observer.clone()); });
+        self.add(move |mut entity: EntityWorldMut| {
+            apply(&mut entity);
+            match entity.get_mut::<UiObservers>() {
+                Some(mut observers) => observers.0.push(apply),
+                None => { entity.insert(UiObservers(vec![apply])); },
+            }
+        })
+    }
+}
+
+
+// #=============#
+// #=== CLONE ===#
+
+type UiCloneFn = Arc<dyn Fn(&mut World, Entity, Entity, &EntityHashMap<Entity>) + Send + Sync>;
+
+/// Components that are cloned by [`UiCloneExt::clone_ui`] without reflection.
+/// Register your own through [`UiCloneAppExt`].
+#[derive(Resource, Clone, Default)]
+pub struct UiCloneRegistry(HashMap<TypeId, UiCloneFn>);
+
+/// Extension trait for registering components that [`UiCloneExt::clone_ui`] should copy.
+/// Components registered for reflection with `#[reflect(Component)]` are copied without this.
+pub trait UiCloneAppExt {
+    /// Copies the component to clones through [`Clone`].
+    fn register_ui_clone<C: Component + Clone>(&mut self) -> &mut Self;
+    /// Copies the component to clones through [`Clone`] and then remaps the entities it stores.
+    /// The map contains the original entities of the cloned subtree and their clones.
+    fn register_ui_clone_with<C: Component + Clone>(&mut self, remap: fn(&mut C, &EntityHashMap<Entity>)) -> &mut Self;
+}
+impl UiCloneAppExt for App {
+    fn register_ui_clone<C: Component + Clone>(&mut self) -> &mut Self {
+        self.register_ui_clone_with::<C>(|_, _| {})
+    }
+    fn register_ui_clone_with<C: Component + Clone>(&mut self, remap: fn(&mut C, &EntityHashMap<Entity>)) -> &mut Self {
+        let cloner: UiCloneFn = Arc::new(move |world: &mut World, source: Entity, target: Entity, map: &EntityHashMap<Entity>| {
+            let Some(mut component) = world.get::<C>(source).cloned() else { return };
+            remap(&mut component, map);
+            world.entity_mut(target).insert(component);
+        });
+        self.init_resource::<UiCloneRegistry>();
+        self.world_mut().resource_mut::<UiCloneRegistry>().0.insert(TypeId::of::<C>(), cloner);
+        self
+    }
+}
+
+/// Replaces the entity with its clone if it is part of the cloned subtree.
+pub fn remap_entity(entity: &mut Entity, map: &EntityHashMap<Entity>) {
+    if let Some(clone) = map.get(entity) { *entity = *clone; }
+}
+
+/// Extension trait for deep-cloning UI subtrees.
+pub trait UiCloneExt {
+    /// Clones the node entity together with all of its subnodes and hierarchy children and returns the new entity.
+    /// The clone is linked to `path` in the same tree and the paths of the subnodes are moved under it.
+    ///
+    /// Reflected components, components registered with [`UiCloneAppExt`] and observers added with
+    /// [`UiObserveExt::observe_ui`] are copied. Entities stored in the components that point inside
+    /// the subtree are remapped to the clones.
+    /// ## 🛠️ Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_lunex::prelude::*;
+    /// # fn setup(mut commands: Commands, query: Query<(Entity, &UiLink<MainUi>)>) {
+    /// for (entity, link) in &query {
+    ///     if link.path == "Menu/Card" {
+    ///         let card = commands.clone_ui::<MainUi>(entity, "Menu/Card2");
+    ///         commands.entity(card).insert(UiLayout::window().x(Rl(50.0)).size(Rl(50.0)).pack::<Base>());
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    fn clone_ui<T: Component>(&mut self, entity: Entity, path: impl Into<String>) -> Entity;
+}
+impl UiCloneExt for Commands<'_, '_> {
+    fn clone_ui<T: Component>(&mut self, entity: Entity, path: impl Into<String>) -> Entity {
+        let target = self.spawn_empty().id();
+        let path = path.into();
+        self.add(move |world: &mut World| clone_subtree::<T>(world, entity, target, path));
+        target
+    }
+}
+
+/// Returns a handle to a copy of the mesh, or the same handle if the mesh is not loaded.
+fn unique_mesh(world: &mut World, handle: &Handle<Mesh>) -> Handle<Mesh> {
+    let Some(mut meshes) = world.get_resource_mut::<Assets<Mesh>>() else { return handle.clone() };
+    match meshes.get(handle.id()).cloned() {
+        Some(mesh) => meshes.add(mesh),
+        None => handle.clone(),
+    }
+}
+
+/// Clones the subtree of the source entity into the reserved target entity.
+fn clone_subtree<T: Component>(world: &mut World, source: Entity, target: Entity, path: String) {
+    if world.get_entity(target).is_none() { return }
+    if world.get_entity(source).is_none() {
+        warn!("Cannot clone {source:?}, the entity does not exist");
+        world.despawn(target);
+        return;
+    }
+
+    // Linked subnodes live next to the node under the tree, not as its children
+    let mut sources = vec![source];
+    let root = world.get::<UiLink<T>>(source).map(|link| link.path.clone());
+    if let (Some(root), Some(parent)) = (&root, world.get::<Parent>(source)) {
+        let prefix = format!("{root}/");
+        if let Some(children) = world.get::<Children>(parent.get()) {
+            sources.extend(children.iter().filter(|child| world.get::<UiLink<T>>(**child).is_some_and(|link| link.path.starts_with(&prefix))));
+        }
+    }
+    let mut index = 0;
+    while index < sources.len() {
+        if let Some(children) = world.get::<Children>(sources[index]) {
+            let children: Vec<Entity> = children.iter().filter(|child| !sources.contains(child)).copied().collect();
+            sources.extend(children);
+        }
+        index += 1;
+    }
+
+    let mut map = EntityHashMap::default();
+    map.insert(source, target);
+    for entity in &sources[1..] {
+        map.insert(*entity, world.spawn_empty().id());
+    }
+
+    let registry = world.get_resource::<AppTypeRegistry>().cloned();
+    let cloners = world.get_resource::<UiCloneRegistry>().cloned().unwrap_or_default();
+    let skip = [TypeId::of::<Parent>(), TypeId::of::<Children>(), TypeId::of::<UiObservers>()];
+    for entity in &sources {
+        let clone = map[entity];
+        let types: Vec<TypeId> = world.inspect_entity(*entity).iter().filter_map(|info| info.type_id()).filter(|id| !skip.contains(id)).collect();
+        for type_id in types {
+            if let Some(cloner) = cloners.0.get(&type_id) {
+                cloner(world, *entity, clone, &map);
+                continue;
+            }
+            let Some(registry) = &registry else { continue };
+            let registry = registry.read();
+            let Some(reflect) = registry.get_type_data::<ReflectComponent>(type_id) else { continue };
+            let Some(value) = reflect.reflect(world.entity(*entity)).map(|value| value.clone_value()) else { continue };
+            reflect.insert(&mut world.entity_mut(clone), value.as_ref(), &registry);
+        }
+
+        // Move the paths of the subnodes under the new path, links into other trees are kept as they are
+        if let (Some(root), Some(link)) = (&root, world.get::<UiLink<T>>(*entity)) {
+            if let Some(rest) = link.path.strip_prefix(root.as_str()).filter(|rest| rest.is_empty() || rest.starts_with('/')) {
+                let link = UiLink::<T>::path(format!("{path}{rest}"));
+                world.entity_mut(clone).insert(link);
+            }
+        }
+
+        // Meshes are resized in place, so the clone needs its own copy
+        if let Some(handle) = world.get::<Handle<Mesh>>(clone).cloned() {
+            let handle = unique_mesh(world, &handle);
+            world.entity_mut(clone).insert(handle);
+        }
+        if let Some(handle) = world.get::<Mesh2dHandle>(clone).map(|handle| handle.0.clone()) {
+            let handle = unique_mesh(world, &handle);
+            world.entity_mut(clone).insert(Mesh2dHandle(handle));
+        }
+
+        if let Some(parent) = world.get::<Parent>(*entity).map(Parent::get) {
+            let parent = map.get(&parent).copied().unwrap_or(parent);
+            world.entity_mut(clone).set_parent(parent);
+        }
+
+        if let Some(observers) = world.get::<UiObservers>(*entity).cloned() {
+            let mut clone = world.entity_mut(clone);
+            for apply in &observers.0 { apply(&mut clone); }
+            clone.insert(observers);
+        }
+    }
+}
+
+
+// #================#
+// #=== REPARENT ===#
+
+/// Inserted by [`UiReparentExt::reparent_ui`]. The node is moved to the tree and path on the next update.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiReparent<T = MainUi> {
+    /// The entity with the [`UiTree`] to move to.
+    pub tree: Entity,
+    /// The new path of the node.
+    pub path: String,
+    marker: PhantomData<T>,
+}
+
+/// Extension trait for moving nodes between trees at runtime.
+pub trait UiReparentExt {
+    /// Moves the node entity together with its subnodes to `path` in the tree of the `tree` entity.
+    /// The tree can be the current one, which renames the node. The node data are moved with the entities,
+    /// so the layouts sent to the old node are kept, and both trees are recomputed.
+    /// ## 🛠️ Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_lunex::prelude::*;
+    /// #[derive(Component)]
+    /// struct RightDock;
+    ///
+    /// fn dock_window(mut commands: Commands, window: Query<Entity, With<UiLink<MainUi>>>, dock: Query<Entity, With<RightDock>>) {
+    ///     let Ok(dock) = dock.get_single() else { return };
+    ///     for entity in &window {
+    ///         commands.entity(entity).reparent_ui::<MainUi>(dock, "Inspector");
+    ///     }
+    /// }
+    /// ```
+    fn reparent_ui<T: Component>(&mut self, tree: Entity, path: impl Into<String>) -> &mut Self;
+}
+impl UiReparentExt for EntityCommands<'_> {
+    fn reparent_ui<T: Component>(&mut self, tree: Entity, path: impl Into<String>) -> &mut Self {
+        self.insert(UiReparent::<T> { tree, path: path.into(), marker: PhantomData })
+    }
+}
+
+/// Inserts the data of the moved node and all its subnodes at the path.
+fn insert_moved_node<T, N: Default + Component>(ui: &mut UiTree<T, N>, path: &str, mut node: UiNode<N>) {
+    let subnodes = std::mem::take(&mut node.nodes);
+    if let Ok(target) = ui.borrow_or_create_ui_node_mut(path) {
+        target.data = node.data;
+    }
+    for (name, subnode) in subnodes {
+        insert_moved_node(ui, &format!("{path}/{name}"), subnode);
+    }
+}
+
+/// This system moves the entities with [`UiReparent`] and their subnodes to the new tree,
+/// together with their node data, and marks both trees to be recomputed.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn reparent_ui<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    query: Query<(Entity, &UiReparent<T>, Option<&Parent>), With<UiLink<T>>>,
+    mut uis: Query<(&mut UiTree<T, N>, Option<&Children>)>,
+    mut links: Query<&mut UiLink<T>>,
+) {
+    for (entity, reparent, parent) in &query {
+        commands.entity(entity).remove::<UiReparent<T>>();
+        if !uis.contains(reparent.tree) {
+            warn!("Cannot reparent {entity:?}, {:?} is not a UiTree", reparent.tree);
+            continue;
+        }
+        let Ok(root) = links.get(entity).map(|link| link.path.clone()) else { continue };
+        if root == reparent.path && parent.is_some_and(|parent| parent.get() == reparent.tree) { continue }
+
+        // Take the node with its subnodes out of the old tree
+        let mut node = None;
+        let mut nodes = vec![entity];
+        if let Some(Ok((mut ui, children))) = parent.map(|parent| uis.get_mut(parent.get())) {
+            let prefix = format!("{root}/");
+            nodes.extend(children.into_iter().flatten().filter(|child| **child != entity && links.get(**child).is_ok_and(|link| link.path.starts_with(&prefix))));
+            node = ui.remove_node(root.as_str()).ok();
+            ui.mark_dirty(root.rsplit_once('/').map(|(parent_path, _)| parent_path).unwrap_or_default());
+        }
+
+        let Ok((mut ui, _)) = uis.get_mut(reparent.tree) else { continue };
+        match node {
+            Some(node) => insert_moved_node(&mut ui, &reparent.path, node),
+            None => { let _ = ui.borrow_or_create_ui_node_mut(reparent.path.as_str()); },
+        }
+        ui.mark_dirty(reparent.path.as_str());
+
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Reparented to {}", "->".blue(), root.yellow().bold(), reparent.path.yellow().bold());
+
+        for node in nodes {
+            if let Ok(mut link) = links.get_mut(node) {
+                link.path = format!("{}{}", reparent.path, &link.path[root.len()..]);
+            }
+            if parent.map(Parent::get) != Some(reparent.tree) {
+                commands.entity(node).set_parent(reparent.tree);
+            }
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Registers the components generic over the state `S`.
+fn register_state_clones<S: UiState + Clone>(app: &mut App) {
+    app
+        .register_ui_clone::<UiColor<S>>()
+        .register_ui_clone::<UiSpanColor<S>>()
+        .register_ui_clone::<UiAnimator<S>>()
+        .register_ui_clone_with::<UiAnimatorPipe<S>>(|pipe, map| pipe.entity.iter_mut().for_each(|entity| remap_entity(entity, map)));
+}
+
+/// Plugin registering the components of this crate for [`UiCloneExt::clone_ui`].
+pub struct ClonePlugin;
+impl Plugin for ClonePlugin {
+    fn build(&self, app: &mut App) {
+        register_state_clones::<Base>(app);
+        register_state_clones::<Hover>(app);
+        register_state_clones::<Clicked>(app);
+        register_state_clones::<Selected>(app);
+        register_state_clones::<Intro>(app);
+        register_state_clones::<Outro>(app);
+
+        app
+            .register_ui_clone::<UiImageAtlas>()
+            .register_ui_clone::<UiNamedState>()
+            .register_ui_clone::<UiNamedLayout>()
+            .register_ui_clone::<UiNamedColor>()
+            .register_ui_clone::<UiIntro>()
+            .register_ui_clone::<UiOutro>()
+            .register_ui_clone::<UiStagger>()
+            .register_ui_clone_with::<UiClickEmitter>(|emitter, map| {
+                if let Some(target) = &mut emitter.target { remap_entity(target, map); }
+            })
+            .register_ui_clone::<UiFlipbook>()
+            .register_ui_clone::<UiClass>()
+            .register_ui_clone::<ThemeColor>()
+            .register_ui_clone::<UiTextKey>()
+            .register_ui_clone::<UiTextAnimator>()
+            .register_ui_clone::<UiTextInput>()
+            .register_ui_clone::<UiKeybindCapture>()
+            .register_ui_clone::<UiAccessible>()
+            .register_ui_clone::<OnHoverSetCursor>()
+            .register_ui_clone::<UiLayoutTween>()
+            .register_ui_clone::<UiScrollArea>()
+            .register_ui_clone_with::<UiScrollbar>(|scrollbar, map| remap_entity(&mut scrollbar.area, map))
+            .register_ui_clone::<UiContextMenu>()
+            .register_ui_clone::<UiHitTest>()
+            .register_ui_clone::<UiSelectGroup>()
+            .register_ui_clone::<UiHoverGroup>()
+            .register_ui_clone::<UiShortcut>()
+            .register_ui_clone::<UiDisabled>();
+    }
+}
@@ -14,6 +14,8 @@ pub struct Cursor2d {
     cursor_request: CursorIcon,
     /// Indicates the priority of the requested cursor.
     cursor_request_priority: f32,
+    /// Atlas index and offset requested directly, overriding the requested cursor icon.
+    cursor_sprite_request: Option<(usize, Vec2)>,
     /// Map which cursor has which atlas index and offset
     cursor_atlas_map: HashMap<CursorIcon, (usize, Vec2)>,
     /// Location of the cursor (same as [`Transform`] without sprite offset).
@@ -29,6 +31,7 @@ impl Cursor2d {
         Cursor2d {
             cursor_request: CursorIcon::Default,
             cursor_request_priority: 0.0,
+            cursor_sprite_request: None,
             cursor_atlas_map: HashMap::new(),
             location: Vec2::ZERO,
             confined: false,
@@ -40,8 +43,22 @@ impl Cursor2d {
         if priority > self.cursor_request_priority {
             self.cursor_request = request;
             self.cursor_request_priority = priority;
+            self.cursor_sprite_request = None;
         }
     }
+    /// A method to request a custom sprite from the cursor texture atlas with the hotspot offset.
+    /// Works only if priority is higher than already set priority this tick.
+    pub fn request_cursor_sprite(&mut self, index: usize, offset: impl Into<Vec2>, priority: f32) {
+        if priority > self.cursor_request_priority {
+            self.cursor_request = CursorIcon::Default;
+            self.cursor_request_priority = priority;
+            self.cursor_sprite_request = Some((index, offset.into()));
+        }
+    }
+    /// Returns the atlas index and offset of the current cursor.
+    fn atlas_entry(&self) -> (usize, Vec2) {
+        self.cursor_sprite_request.or_else(|| self.cursor_atlas_map.get(&self.cursor_request).copied()).unwrap_or((0, Vec2::ZERO))
+    }
     /// This function binds the specific cursor icon to an image index that is used if the entity has texture atlas attached to it.
     pub fn set_index(mut self, icon: CursorIcon, index: usize, offset: impl Into<Vec2>) -> Self {
         self.cursor_atlas_map.insert(icon, (index, offset.into()));
@@ -53,6 +70,7 @@ impl Default for Cursor2d {
         Self {
             cursor_request: Default::default(),
             cursor_request_priority: Default::default(),
+            cursor_sprite_request: Default::default(),
             cursor_atlas_map: Default::default(),
             location: Default::default(),
             confined: Default::default(),
@@ -240,7 +258,7 @@ fn cursor_update_transform(
     mut query: Query<(&Cursor2d, &mut Transform)>
 ) {
     for (cursor, mut transform) in &mut query {
-        let sprite_offset = cursor.cursor_atlas_map.get(&cursor.cursor_request).unwrap_or(&(0, Vec2::ZERO)).1;
+        let sprite_offset = cursor.atlas_entry().1;
         transform.translation.x = cursor.location.x - sprite_offset.x * transform.scale.x;
         transform.translation.y = cursor.location.y + sprite_offset.y * transform.scale.y;
     }
@@ -382,6 +400,7 @@ fn cursor_reset_icon(
     for mut cursor in &mut query {
         cursor.cursor_request = CursorIcon::Default;
         cursor.cursor_request_priority = 0.0;
+        cursor.cursor_sprite_request = None;
     }
 }
 
@@ -390,10 +409,141 @@ fn cursor_update_texture(
     mut query: Query<(&Cursor2d, &mut TextureAtlas)>
 ) {
     for (cursor, mut atlas) in &mut query {
-        atlas.index = cursor.cursor_atlas_map.get(&cursor.cursor_request).unwrap_or(&(0, Vec2::ZERO)).0;
+        let index = cursor.atlas_entry().0;
+        if atlas.index != index { atlas.index = index }
+    }
+}
+
+
+/// Animates the sprite of [`StyledCursorBundle`] while no other cursor icon is requested.
+/// Frames are indexes into the cursor texture atlas and use the offset of [`CursorIcon::Default`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let animation = Cursor2dAnimation::new([0, 3, 4, 3], 8.0).click([5, 6]);
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Cursor2dAnimation {
+    /// Frames played while the cursor is idle
+    pub idle: Vec<usize>,
+    /// Frames played while the primary button is held, falls back to idle frames if empty
+    pub click: Vec<usize>,
+    /// Frames per second
+    pub fps: f32,
+    /// Time since the animation started
+    time: f32,
+}
+impl Cursor2dAnimation {
+    /// Creates new animation from the idle frames and speed.
+    pub fn new(idle: impl IntoIterator<Item = usize>, fps: f32) -> Self {
+        Cursor2dAnimation { idle: idle.into_iter().collect(), click: Vec::new(), fps, time: 0.0 }
+    }
+    /// Replaces the click frames with a new value.
+    pub fn click(mut self, click: impl IntoIterator<Item = usize>) -> Self {
+        self.click = click.into_iter().collect();
+        self
     }
 }
 
+/// This function plays the cursor animation, overriding the atlas index of the idle cursor
+fn cursor_animate(
+    time: Res<Time>,
+    mut query: Query<(&Cursor2d, &mut Cursor2dAnimation, &mut TextureAtlas, Option<&PointerPress>)>,
+) {
+    for (cursor, mut animation, mut atlas, press) in &mut query {
+        if cursor.cursor_request != CursorIcon::Default || cursor.cursor_sprite_request.is_some() { continue }
+        let animation = animation.bypass_change_detection();
+        animation.time += time.delta_seconds();
+
+        let pressed = press.is_some_and(|press| press.is_primary_pressed());
+        let frames = if pressed && !animation.click.is_empty() { &animation.click } else { &animation.idle };
+        if frames.is_empty() { continue }
+        let index = frames[(animation.time * animation.fps) as usize % frames.len()];
+        if atlas.index != index { atlas.index = index }
+    }
+}
+
+/// Leaves a fading trail of copies of the cursor sprite behind the [`StyledCursorBundle`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let trail = Cursor2dTrail::new(6, 0.02);
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Cursor2dTrail {
+    /// Number of the sprites in the trail
+    pub length: usize,
+    /// Seconds between the recorded positions
+    pub interval: f32,
+    /// Recorded positions, newest first
+    positions: Vec<Vec3>,
+    /// Spawned trail sprites
+    sprites: Vec<Entity>,
+    /// Time since the last recorded position
+    time: f32,
+}
+impl Cursor2dTrail {
+    /// Creates new trail from the length and interval between the sprites.
+    pub fn new(length: usize, interval: f32) -> Self {
+        Cursor2dTrail { length, interval, positions: Vec::new(), sprites: Vec::new(), time: 0.0 }
+    }
+}
+
+/// Marks the sprites spawned by [`Cursor2dTrail`].
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Cursor2dTrailSprite;
+
+/// This function records the cursor positions and moves the trail sprites along them
+fn cursor_trail(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(&mut Cursor2dTrail, &Transform, &Sprite, &Handle<Image>, Option<&TextureAtlas>, Option<&Visibility>, Option<&Parent>), With<Cursor2d>>,
+    mut sprites: Query<(&mut Transform, &mut Sprite, Option<&mut TextureAtlas>, &mut Visibility), (With<Cursor2dTrailSprite>, Without<Cursor2d>)>,
+) {
+    for (mut trail, transform, sprite, image, atlas, visibility, parent) in &mut query {
+        let trail = trail.bypass_change_detection();
+
+        // Record the position
+        trail.time += time.delta_seconds();
+        if trail.time >= trail.interval {
+            trail.time = 0.0;
+            trail.positions.insert(0, transform.translation);
+            trail.positions.truncate(trail.length);
+        }
+
+        // Spawn missing sprites
+        while trail.sprites.len() < trail.length {
+            let mut entity = commands.spawn((
+                SpriteBundle { sprite: sprite.clone(), texture: image.clone(), transform: *transform, visibility: Visibility::Hidden, ..default() },
+                Cursor2dTrailSprite,
+                Pickable::IGNORE,
+            ));
+            if let Some(atlas) = atlas { entity.insert(atlas.clone()); }
+            if let Some(parent) = parent { entity.set_parent(parent.get()); }
+            trail.sprites.push(entity.id());
+        }
+        while trail.sprites.len() > trail.length {
+            if let Some(entity) = trail.sprites.pop() { commands.entity(entity).despawn_recursive(); }
+        }
+
+        // Move the sprites along the positions
+        let shown = visibility != Some(&Visibility::Hidden);
+        for (i, entity) in trail.sprites.iter().enumerate() {
+            let Ok((mut sprite_transform, mut trail_sprite, trail_atlas, mut trail_visibility)) = sprites.get_mut(*entity) else { continue };
+            let Some(position) = trail.positions.get(i) else { continue };
+            sprite_transform.translation = position.with_z(position.z - 0.01 * (i + 1) as f32);
+            sprite_transform.scale = transform.scale;
+            let fade = 1.0 - (i + 1) as f32 / (trail.length + 1) as f32;
+            trail_sprite.color = sprite.color.with_alpha(sprite.color.alpha() * fade);
+            trail_sprite.anchor = sprite.anchor;
+            if let (Some(mut trail_atlas), Some(atlas)) = (trail_atlas, atlas) {
+                if trail_atlas.index != atlas.index { trail_atlas.index = atlas.index }
+            }
+            let new_visibility = if shown { Visibility::Inherited } else { Visibility::Hidden };
+            if *trail_visibility != new_visibility { *trail_visibility = new_visibility }
+        }
+    }
+}
 
 /// Requests cursor icon on hover
 #[derive(Component, Debug, Clone, PartialEq)]
@@ -420,6 +570,40 @@ fn on_hover_set_cursor(query: Query<(&UiAnimator<Hover>, &OnHoverSetCursor)>, mu
 }
 
 
+/// Requests custom cursor sprite on hover. The sprite is an index into the texture atlas of [`StyledCursorBundle`],
+/// the offset is the hotspot of the sprite. The previous cursor returns once the entity is no longer hovered.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let cursor = OnHoverSetCursorSprite::new(3, (14.0, 14.0)); // -> Crosshair in the middle of a 28x28 sprite
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct OnHoverSetCursorSprite {
+    /// Index of the sprite in the cursor texture atlas
+    pub index: usize,
+    /// Hotspot of the sprite
+    pub offset: Vec2,
+}
+impl OnHoverSetCursorSprite {
+    /// Creates new struct
+    pub fn new(index: usize, offset: impl Into<Vec2>) -> Self {
+        OnHoverSetCursorSprite {
+            index,
+            offset: offset.into(),
+        }
+    }
+}
+fn on_hover_set_cursor_sprite(query: Query<(&UiAnimator<Hover>, &OnHoverSetCursorSprite)>, mut cursor: Query<&mut Cursor2d>) {
+    for (control, hover_cursor) in &query {
+        if control.is_forward() {
+            if let Ok(mut cursor) = cursor.get_single_mut(){
+                cursor.request_cursor_sprite(hover_cursor.index, hover_cursor.offset, 1.0);
+            }
+        }
+    }
+}
+
+
 // #==============#
 // #=== PLUGIN ===#
 
@@ -437,8 +621,11 @@ impl Plugin for CursorPlugin {
             .add_systems(PostUpdate, cursor_set_visibility)
             .add_systems(PostUpdate, cursor_change_native)
             .add_systems(PostUpdate, cursor_update_texture)
+            .add_systems(PostUpdate, cursor_animate.after(cursor_update_texture))
+            .add_systems(PostUpdate, cursor_trail.after(cursor_animate).after(cursor_set_visibility))
 
             // Other stuff
-            .add_systems(Update, on_hover_set_cursor);
+            .add_systems(Update, on_hover_set_cursor)
+            .add_systems(Update, on_hover_set_cursor_sprite);
     }
 }
\ No newline at end of file
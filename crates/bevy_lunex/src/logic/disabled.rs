@@ -0,0 +1,138 @@
+use crate::*;
+use bevy::a11y::Focus;
+
+
+// #================#
+// #=== DISABLED ===#
+
+/// **Ui disabled** - Disables the interactive node. It stops receiving pointer events, its hover and click animations
+/// play back, buttons, shortcuts and select groups ignore it and it loses the focus. The color of [`UiColor<Base>`]
+/// is desaturated and faded, removing the component restores everything.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu"))).with_children(|ui| {
+/// ui.spawn_prefab(UiLink::<MainUi>::path("Continue"), UiButton::new("Continue"));
+/// # });
+/// # }
+///
+/// fn lock_continue(mut commands: Commands, ui: UiQuery, save: Option<Res<SaveGame>>) {
+///     let Some(button) = ui.find("Continue") else { return };
+///     if save.is_none() { commands.entity(button).insert(UiDisabled::new().alpha(0.3)); }
+/// }
+/// # #[derive(Resource)] struct SaveGame;
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiDisabled {
+    /// How much of the color saturation is removed, from `0.0` to `1.0`.
+    pub desaturate: f32,
+    /// Multiplier of the color alpha.
+    pub alpha: f32,
+}
+impl Default for UiDisabled {
+    fn default() -> Self {
+        UiDisabled { desaturate: 0.8, alpha: 0.5 }
+    }
+}
+impl UiDisabled {
+    /// Creates new struct with the default look.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Replaces the desaturate with a new value.
+    pub fn desaturate(mut self, desaturate: f32) -> Self {
+        self.desaturate = desaturate;
+        self
+    }
+    /// Replaces the alpha with a new value.
+    pub fn alpha(mut self, alpha: f32) -> Self {
+        self.alpha = alpha;
+        self
+    }
+    /// Returns the color as it looks while disabled.
+    pub fn apply(&self, color: Color) -> Color {
+        let mut hsla = Hsla::from(color);
+        hsla.saturation *= 1.0 - self.desaturate.clamp(0.0, 1.0);
+        hsla.alpha *= self.alpha;
+        hsla.into()
+    }
+}
+
+/// [`Pickable`] of the node before it was disabled.
+#[derive(Component, Debug, Clone, Copy)]
+struct UiDisabledPickable(Option<Pickable>);
+
+/// This system stops the newly disabled nodes from being hovered, plays their animations back and takes their focus.
+fn disabled_start(
+    mut commands: Commands,
+    mut focus: Option<ResMut<Focus>>,
+    mut query: Query<(Entity, Option<&Pickable>, Option<&mut UiAnimator<Hover>>, Option<&mut UiAnimator<Clicked>>), Added<UiDisabled>>,
+) {
+    for (entity, pickable, hover, clicked) in &mut query {
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Disabled", "--".yellow(), "ELEMENT".red());
+        let should_block_lower = pickable.is_none_or(|pickable| pickable.should_block_lower);
+        commands.entity(entity).insert((UiDisabledPickable(pickable.copied()), Pickable { should_block_lower, is_hoverable: false }));
+        if let Some(mut hover) = hover { if hover.is_forward() { hover.play(false); } }
+        if let Some(mut clicked) = clicked { if clicked.is_forward() { clicked.play(false); } }
+        if let Some(focus) = &mut focus {
+            if focus.0 == Some(entity) { focus.0 = None; }
+        }
+    }
+}
+
+/// This system makes the enabled nodes pickable again and restores their color.
+fn disabled_finish(
+    mut commands: Commands,
+    mut removed: RemovedComponents<UiDisabled>,
+    mut set_color: EventWriter<actions::SetColor>,
+    query: Query<(&UiDisabledPickable, Option<&UiColor<Base>>), Without<UiDisabled>>,
+) {
+    for entity in removed.read() {
+        let Ok((pickable, color)) = query.get(entity) else { continue };
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.remove::<UiDisabledPickable>();
+        match pickable.0 {
+            Some(pickable) => { entity_commands.insert(pickable); },
+            None => { entity_commands.remove::<Pickable>(); },
+        }
+        if let Some(color) = color {
+            set_color.send(actions::SetColor { target: entity, color: color.color });
+        }
+    }
+}
+
+/// This system applies the disabled look on top of any color set by the states this frame.
+fn disabled_color(
+    mut query: Query<(&UiDisabled, &UiColor<Base>, Option<&mut Sprite>, Option<&mut Text>, Has<UiSpanColor<Base>>), Or<(Changed<UiDisabled>, Changed<Sprite>, Changed<Text>)>>,
+) {
+    for (disabled, base, sprite, text, has_span_color) in &mut query {
+        let color = disabled.apply(base.color);
+        if let Some(mut sprite) = sprite {
+            if sprite.color != color { sprite.color = color; }
+        }
+        if has_span_color { continue }
+        if let Some(mut text) = text {
+            if text.sections.iter().any(|section| section.style.color != color) {
+                text.sections.iter_mut().for_each(|section| section.style.color = color);
+            }
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiDisabled`] logic.
+pub struct DisabledPlugin;
+impl Plugin for DisabledPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, (disabled_start, disabled_finish).before(UiSystems::Modify))
+            .add_systems(PostUpdate, disabled_color);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::*;
+
+
+// #================#
+// #=== FLIPBOOK ===#
+
+/// **Ui flipbook** - Plays the frames of a sprite sheet by writing them into the [`TextureAtlas`] index of the entity.
+/// The animation is paused while the entity is hidden and starts from the first frame again when [`UiIntro`] is inserted.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands, assets: Res<AssetServer>, mut layouts: ResMut<Assets<TextureAtlasLayout>>) {
+/// let layout = layouts.add(TextureAtlasLayout::from_grid(UVec2::splat(32), 8, 1, None, None));
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu"))).with_children(|ui| {
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Spinner"),
+///     UiLayout::window().size(Ab(32.0)).pack::<Base>(),
+///     UiImage2dBundle::from(assets.load("spinner.png")),
+///     TextureAtlas { layout, index: 0 },
+///     UiFlipbook::new(0..8, 12.0),
+/// ));
+/// # });
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiFlipbook {
+    /// Atlas indexes of the frames in the order they are played
+    pub frames: Vec<usize>,
+    /// Frames per second
+    pub fps: f32,
+    /// If the animation starts over after the last frame, otherwise it stops on it
+    pub looping: bool,
+    /// Time since the animation started
+    time: f32,
+}
+impl UiFlipbook {
+    /// Creates new looping animation from the frames and speed.
+    pub fn new(frames: impl IntoIterator<Item = usize>, fps: f32) -> Self {
+        UiFlipbook { frames: frames.into_iter().collect(), fps, looping: true, time: 0.0 }
+    }
+    /// Replaces the looping with a new value.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+    /// Starts the animation from the first frame.
+    pub fn restart(&mut self) {
+        self.time = 0.0;
+    }
+    /// Checks if the animation stopped on the last frame. Looping animations never finish.
+    pub fn is_finished(&self) -> bool {
+        !self.looping && self.frame() + 1 >= self.frames.len()
+    }
+    /// Returns the position of the current frame in the frames.
+    fn frame(&self) -> usize {
+        let frame = (self.time * self.fps) as usize;
+        if self.looping { frame % self.frames.len().max(1) } else { frame.min(self.frames.len().saturating_sub(1)) }
+    }
+}
+
+/// This system restarts [`UiFlipbook`] when [`UiIntro`] is inserted.
+fn flipbook_restart(mut query: Query<&mut UiFlipbook, Added<UiIntro>>) {
+    for mut flipbook in &mut query {
+        flipbook.restart();
+    }
+}
+
+/// This system advances [`UiFlipbook`] of visible entities and writes the frame into [`TextureAtlas`].
+fn flipbook_animate(
+    time: Res<Time>,
+    mut query: Query<(&mut UiFlipbook, &mut TextureAtlas, Option<&InheritedVisibility>)>,
+) {
+    for (mut flipbook, mut atlas, visibility) in &mut query {
+        if flipbook.frames.is_empty() || visibility.is_some_and(|visibility| !visibility.get()) { continue }
+        let flipbook = flipbook.bypass_change_detection();
+        if !flipbook.is_finished() { flipbook.time += time.delta_seconds() }
+
+        let index = flipbook.frames[flipbook.frame()];
+        if atlas.index != index { atlas.index = index }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiFlipbook`] logic.
+pub struct FlipbookPlugin;
+impl Plugin for FlipbookPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (flipbook_restart, flipbook_animate).chain().after(UiSystems::Process));
+    }
+}
@@ -0,0 +1,179 @@
+use crate::*;
+use bevy::utils::HashMap;
+
+
+// #=================#
+// #=== SELECTION ===#
+
+/// **Ui selected** - Marks the node as selected and plays its [`UiAnimator<Selected>`] forward.
+/// Removing the component plays the animation back. Inside [`UiSelectGroup`] only one member can be selected at a time.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UiSelected;
+
+/// **Ui select group** - Groups nodes so at most one of them is [`UiSelected`], like tabs or radio buttons.
+/// Clicking a member selects it, selecting a member in any way deselects the rest of the group.
+/// Every selection and deselection done by the group sends [`UiSelectionChanged`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu"))).with_children(|ui| {
+/// for (i, tab) in ["Video", "Audio", "Controls"].into_iter().enumerate() {
+///     let mut node = ui.spawn((
+///         UiLink::<MainUi>::path(format!("Tabs/{tab}")),
+///         UiLayout::window().x(Rl(i as f32 * 33.3)).size(Rl((33.3, 100.0))).pack::<Base>(),
+///         UiZoneBundle::default(),
+///         UiColor::<Base>::new(Color::srgb(0.2, 0.2, 0.2)),
+///         UiColor::<Selected>::new(Color::srgb(0.9, 0.6, 0.1)),
+///         UiAnimator::<Selected>::new(),
+///         UiClickEmitter::SELF,
+///         UiSelectGroup::new("Tabs"),
+///     ));
+///     if i == 0 { node.insert(UiSelected); }
+/// }
+/// # });
+/// # }
+///
+/// fn open_tab(mut events: EventReader<UiSelectionChanged>) {
+///     for event in events.read().filter(|event| event.selected) {
+///         info!("Opened tab {:?}", event.target);
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct UiSelectGroup(pub String);
+impl UiSelectGroup {
+    /// Creates new group member with the group name.
+    pub fn new(group: impl Into<String>) -> Self {
+        UiSelectGroup(group.into())
+    }
+}
+
+/// This event is sent when a member of [`UiSelectGroup`] gets selected or deselected.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct UiSelectionChanged {
+    /// The selected or deselected member
+    pub target: Entity,
+    /// The name of the group
+    pub group: String,
+    /// If the member was selected or deselected
+    pub selected: bool,
+}
+
+/// This system selects the clicked [`UiSelectGroup`] members.
+fn select_group_click(mut commands: Commands, mut clicks: EventReader<UiClickEvent>, query: Query<Has<UiSelected>, (With<UiSelectGroup>, Without<UiDisabled>)>) {
+    for event in clicks.read() {
+        if query.get(event.target).is_ok_and(|selected| !selected) {
+            commands.entity(event.target).insert(UiSelected);
+        }
+    }
+}
+
+/// This system deselects the rest of the group when [`UiSelectGroup`] member gets selected.
+fn select_group_update(
+    mut commands: Commands,
+    mut changed: EventWriter<UiSelectionChanged>,
+    added: Query<(Entity, &UiSelectGroup), Added<UiSelected>>,
+    query: Query<(Entity, &UiSelectGroup), With<UiSelected>>,
+) {
+    // The last selected member of the group wins
+    let mut selections: HashMap<&str, Entity> = HashMap::new();
+    for (entity, group) in &added {
+        selections.insert(group.0.as_str(), entity);
+    }
+    for (entity, group) in &query {
+        let Some(winner) = selections.get(group.0.as_str()) else { continue };
+        if *winner == entity {
+            changed.send(UiSelectionChanged { target: entity, group: group.0.clone(), selected: true });
+        } else {
+            commands.entity(entity).remove::<UiSelected>();
+            changed.send(UiSelectionChanged { target: entity, group: group.0.clone(), selected: false });
+        }
+    }
+}
+
+/// This system plays [`UiAnimator<Selected>`] forward on [`UiSelected`] nodes and back on the rest.
+fn selected_animation(mut query: Query<(&mut UiAnimator<Selected>, Has<UiSelected>)>) {
+    for (mut animator, selected) in &mut query {
+        if animator.receiver || animator.is_forward() == selected { continue }
+        animator.play(selected);
+    }
+}
+
+
+// #===================#
+// #=== HOVER GROUP ===#
+
+/// **Ui hover group** - Groups nodes so hovering any of them plays the [`Hover`] animation of all of them,
+/// for example to highlight a whole table row made of several cells.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Table"))).with_children(|ui| {
+/// for (i, cell) in ["Name", "Level", "Class"].into_iter().enumerate() {
+///     ui.spawn((
+///         UiLink::<MainUi>::path(format!("Row1/{cell}")),
+///         UiLayout::window().x(Rl(i as f32 * 33.3)).size(Rl((33.3, 100.0))).pack::<Base>(),
+///         UiZoneBundle::default(),
+///         UiColor::<Base>::new(Color::srgb(0.2, 0.2, 0.2)),
+///         UiColor::<Hover>::new(Color::srgb(0.3, 0.3, 0.4)),
+///         UiAnimator::<Hover>::new(),
+///         UiHoverGroup::new("Row1"),
+///     ));
+/// }
+/// # });
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct UiHoverGroup(pub String);
+impl UiHoverGroup {
+    /// Creates new group member with the group name.
+    pub fn new(group: impl Into<String>) -> Self {
+        UiHoverGroup(group.into())
+    }
+}
+
+/// This system plays the [`Hover`] animation of the whole group while any of its members is hovered.
+/// Tracks every pointer separately, same as the hover of single nodes.
+fn hover_group_update(
+    mut over: EventReader<Pointer<Over>>,
+    mut out: EventReader<Pointer<Out>>,
+    mut pointers: Local<HashMap<Entity, Vec<PointerId>>>,
+    mut query: Query<(Entity, &UiHoverGroup, &mut UiAnimator<Hover>)>,
+) {
+    for event in out.read() {
+        let Some(hovered) = pointers.get_mut(&event.target) else { continue };
+        hovered.retain(|pointer| *pointer != event.pointer_id);
+        if hovered.is_empty() { pointers.remove(&event.target); }
+    }
+    for event in over.read() {
+        if !query.contains(event.target) { continue }
+        let hovered = pointers.entry(event.target).or_default();
+        if !hovered.contains(&event.pointer_id) { hovered.push(event.pointer_id) }
+    }
+    pointers.retain(|entity, _| query.contains(*entity));
+
+    let groups: Vec<String> = pointers.keys().filter_map(|entity| query.get(*entity).ok().map(|(_, group, _)| group.0.clone())).collect();
+    for (_, group, mut animator) in &mut query {
+        let hovered = groups.contains(&group.0);
+        if animator.is_forward() != hovered { animator.play(hovered); }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiSelectGroup`] and [`UiHoverGroup`] logic.
+pub struct GroupPlugin;
+impl Plugin for GroupPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<UiSelectionChanged>()
+            .add_systems(Update, (select_group_click, select_group_update, selected_animation).chain().before(UiSystems::Modify))
+            .add_systems(Update, hover_group_update.after(hover_system));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::*;
+use bevy::input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
+use std::time::Duration;
+
+
+// #===============#
+// #=== HAPTICS ===#
+
+/// Single rumble played by [`UiHaptics`]. Magnitudes range from `0.0` to `1.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct UiRumble {
+    /// Magnitude of the weak, high frequency motor.
+    pub weak: f32,
+    /// Magnitude of the strong, low frequency motor.
+    pub strong: f32,
+    /// Duration in seconds.
+    pub duration: f32,
+}
+impl UiRumble {
+    /// Creates new rumble from the motor magnitudes and duration.
+    pub fn new(weak: f32, strong: f32, duration: f32) -> Self {
+        UiRumble { weak, strong, duration }
+    }
+}
+
+/// **Ui haptics** - Rumbles the gamepad controlling [`GamepadCursor`] when the cursor moves onto the entity or presses it.
+/// Pointers not driven by a gamepad are ignored. The rumble is sent as [`GamepadRumbleRequest`],
+/// so it needs a gamepad backend, enable the `haptics` feature to add `bevy_gilrs`.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// let haptics = UiHaptics::new()
+///     .hover(UiRumble::new(0.2, 0.0, 0.05))
+///     .press(UiRumble::new(0.4, 0.6, 0.1));
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiHaptics {
+    /// Rumble played when the cursor moves onto the entity.
+    pub hover: Option<UiRumble>,
+    /// Rumble played when the entity is pressed.
+    pub press: Option<UiRumble>,
+}
+impl UiHaptics {
+    /// Creates new struct without any rumble.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Replaces the hover with a new value.
+    pub fn hover(mut self, rumble: UiRumble) -> Self {
+        self.hover = Some(rumble);
+        self
+    }
+    /// Replaces the press with a new value.
+    pub fn press(mut self, rumble: UiRumble) -> Self {
+        self.press = Some(rumble);
+        self
+    }
+}
+
+/// This system sends [`GamepadRumbleRequest`] for pointer events on entities with [`UiHaptics`].
+fn haptics_system(
+    mut over: EventReader<Pointer<Over>>,
+    mut down: EventReader<Pointer<Down>>,
+    cursors: Query<(&PointerId, &GamepadCursor)>,
+    query: Query<&UiHaptics>,
+    mut rumble: EventWriter<GamepadRumbleRequest>,
+) {
+    let hovers = over.read().filter_map(|event| Some((event.target, event.pointer_id, query.get(event.target).ok()?.hover?)));
+    let presses = down.read().filter(|event| event.button == PointerButton::Primary).filter_map(|event| Some((event.target, event.pointer_id, query.get(event.target).ok()?.press?)));
+    for (_, pointer_id, effect) in hovers.chain(presses).collect::<Vec<_>>() {
+        let Some((_, cursor)) = cursors.iter().find(|(id, _)| **id == pointer_id) else { continue };
+        rumble.send(GamepadRumbleRequest::Add {
+            gamepad: Gamepad::new(cursor.id),
+            intensity: GamepadRumbleIntensity { weak_motor: effect.weak.clamp(0.0, 1.0), strong_motor: effect.strong.clamp(0.0, 1.0) },
+            duration: Duration::from_secs_f32(effect.duration.max(0.0)),
+        });
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiHaptics`] logic.
+pub struct HapticsPlugin;
+impl Plugin for HapticsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<GamepadRumbleRequest>()
+            .add_systems(Update, haptics_system.run_if(on_event::<Pointer<Over>>().or_else(on_event::<Pointer<Down>>())));
+    }
+}
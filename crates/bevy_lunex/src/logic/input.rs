@@ -0,0 +1,610 @@
+use crate::*;
+use std::ops::Range;
+use bevy::input::{gamepad::{GamepadButton, GamepadButtonType}, keyboard::{Key, KeyboardInput}, ButtonState, InputSystem};
+use bevy::sprite::Anchor;
+use bevy::text::TextLayoutInfo;
+use bevy::window::{Ime, PrimaryWindow};
+
+
+// #==================#
+// #=== TEXT INPUT ===#
+
+/// Index of the text section showing the caret. The sections are: text before the selection, selection before the caret,
+/// IME composition, caret, selection after the caret, text after the selection.
+const SECTION_CARET: usize = 3;
+
+/// **Ui text input** - Editable text field. Attach it to a node with [`UiText2dBundle`], the style of the first text section is used
+/// for the whole field. The field is focused by pressing it and typed into with the keyboard or the IME of the system.
+/// While composing, the IME candidate window is placed at the caret.
+///
+/// Text is selected with *Shift* and the arrows, copied, cut and pasted with the usual *Ctrl*/*Cmd* shortcuts through [`UiClipboard`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu"))).with_children(|ui| {
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Name"),
+///     UiLayout::window().size(Rl((40.0, 6.0))).pack::<Base>(),
+///     UiText2dBundle {
+///         text: Text::from_section("", TextStyle { font_size: 40.0, ..default() }),
+///         ..default()
+///     },
+///     UiTextInput::new().max_length(16),
+/// ));
+/// # });
+/// # }
+///
+/// fn greet(mut events: EventReader<UiTextInputSubmitted>) {
+///     for event in events.read() {
+///         info!("Hello {}!", event.value);
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiTextInput {
+    /// The current text.
+    pub value: String,
+    /// If *Enter* inserts a new line instead of submitting the text.
+    pub multiline: bool,
+    /// Maximum number of characters.
+    pub max_length: Option<usize>,
+    /// If the field receives the keyboard input.
+    pub focused: bool,
+    /// Color of the selected text.
+    pub selection_color: Color,
+    /// Byte index of the caret in the value.
+    caret: usize,
+    /// Byte index of the other end of the selection.
+    anchor: Option<usize>,
+    /// Text being composed by the IME, not yet part of the value.
+    preedit: String,
+    /// Time since the caret last moved, for blinking.
+    blink: f32,
+}
+impl Default for UiTextInput {
+    fn default() -> Self {
+        UiTextInput {
+            value: String::new(),
+            multiline: false,
+            max_length: None,
+            focused: false,
+            selection_color: Color::srgb(0.4, 0.7, 1.0),
+            caret: 0,
+            anchor: None,
+            preedit: String::new(),
+            blink: 0.0,
+        }
+    }
+}
+impl UiTextInput {
+    /// Creates new empty single-line field.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Replaces the value with a new value and moves the caret to the end.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.set_value(value);
+        self
+    }
+    /// Replaces the multiline with a new value.
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+    /// Replaces the maximum length with a new value.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+    /// Replaces the selection color with a new value.
+    pub fn selection_color(mut self, color: impl Into<Color>) -> Self {
+        self.selection_color = color.into();
+        self
+    }
+    /// Sets the value and moves the caret to the end.
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.value = value.into();
+        self.caret = self.value.len();
+        self.anchor = None;
+        self.blink = 0.0;
+    }
+    /// Returns the byte index of the caret in the value.
+    pub fn caret(&self) -> usize {
+        self.caret.min(self.value.len())
+    }
+    /// Returns the byte range of the selected text, if any.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let caret = self.caret();
+        let anchor = self.anchor?.min(self.value.len());
+        if anchor == caret { return None }
+        Some(anchor.min(caret)..anchor.max(caret))
+    }
+    /// Returns the selected text.
+    pub fn selected_text(&self) -> &str {
+        self.selection().map_or("", |range| &self.value[range])
+    }
+    /// Selects the whole value.
+    pub fn select_all(&mut self) {
+        self.anchor = Some(0);
+        self.caret = self.value.len();
+    }
+    /// Removes the selected text. Returns `true` if anything was removed.
+    pub fn delete_selection(&mut self) -> bool {
+        let Some(range) = self.selection() else { return false };
+        self.caret = range.start;
+        self.anchor = None;
+        self.value.replace_range(range, "");
+        self.blink = 0.0;
+        true
+    }
+    /// Returns the text being composed by the IME.
+    pub fn preedit(&self) -> &str {
+        &self.preedit
+    }
+    /// Inserts the text at the caret, replacing the selection. Newlines are stripped from single-line fields
+    /// and the text is cut to fit the maximum length. Returns `true` if the value changed.
+    pub fn insert(&mut self, text: &str) -> bool {
+        let deleted = self.delete_selection();
+        let free = self.max_length.map_or(usize::MAX, |max| max.saturating_sub(self.value.chars().count()));
+        let text: String = text.chars()
+            .filter(|char| if *char == '\n' { self.multiline } else { !char.is_control() })
+            .take(free)
+            .collect();
+        if text.is_empty() { return deleted }
+        let caret = self.caret();
+        self.value.insert_str(caret, &text);
+        self.caret = caret + text.len();
+        self.blink = 0.0;
+        true
+    }
+    /// Moves the caret by the number of characters, negative values move left. Clears the selection.
+    pub fn move_caret(&mut self, characters: isize) {
+        self.anchor = None;
+        self.shift_caret(characters);
+    }
+    /// Moves the caret, keeping the selection.
+    fn shift_caret(&mut self, characters: isize) {
+        let mut caret = self.caret();
+        for _ in 0..characters.unsigned_abs() {
+            let next = if characters < 0 {
+                self.value[..caret].chars().next_back().map(|char| caret - char.len_utf8())
+            } else {
+                self.value[caret..].chars().next().map(|char| caret + char.len_utf8())
+            };
+            let Some(next) = next else { break };
+            caret = next;
+        }
+        self.caret = caret;
+        self.blink = 0.0;
+    }
+    /// Removes the selection, or the character before the caret, or after it if `forward`. Returns `true` if anything was removed.
+    pub fn delete(&mut self, forward: bool) -> bool {
+        if self.delete_selection() { return true }
+        let caret = self.caret();
+        let range = if forward {
+            let Some(char) = self.value[caret..].chars().next() else { return false };
+            caret..caret + char.len_utf8()
+        } else {
+            let Some(char) = self.value[..caret].chars().next_back() else { return false };
+            caret - char.len_utf8()..caret
+        };
+        self.caret = range.start;
+        self.value.replace_range(range, "");
+        self.blink = 0.0;
+        true
+    }
+    /// Starts, keeps or clears the selection before the caret is moved.
+    fn extend_selection(&mut self, extend: bool) {
+        if !extend {
+            self.anchor = None;
+        } else if self.anchor.is_none() {
+            self.anchor = Some(self.caret());
+        }
+    }
+    /// Returns the byte index of the start of the line with the caret.
+    fn line_start(&self) -> usize {
+        self.value[..self.caret()].rfind('\n').map_or(0, |index| index + 1)
+    }
+    /// Returns the byte index of the end of the line with the caret.
+    fn line_end(&self) -> usize {
+        let caret = self.caret();
+        self.value[caret..].find('\n').map_or(self.value.len(), |index| caret + index)
+    }
+}
+
+/// This event is sent when the value of [`UiTextInput`] is edited.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct UiTextInputChanged {
+    /// The text input entity.
+    pub target: Entity,
+    /// The new value.
+    pub value: String,
+}
+
+/// This event is sent when *Enter* is pressed in a single-line [`UiTextInput`].
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct UiTextInputSubmitted {
+    /// The text input entity.
+    pub target: Entity,
+    /// The submitted value.
+    pub value: String,
+}
+
+/// This system focuses the pressed [`UiTextInput`] and unfocuses all others.
+fn text_input_focus(mut events: EventReader<Pointer<Down>>, mut query: Query<(Entity, &mut UiTextInput)>) {
+    for event in events.read() {
+        for (entity, mut input) in &mut query {
+            let focused = entity == event.target;
+            if input.focused != focused {
+                input.focused = focused;
+                input.preedit.clear();
+                input.blink = 0.0;
+            }
+        }
+    }
+}
+
+/// This system types the keyboard and IME input into the focused [`UiTextInput`].
+pub(crate) fn text_input_typing(
+    mut keyboard: EventReader<KeyboardInput>,
+    mut ime: EventReader<Ime>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mut clipboard: ResMut<UiClipboard>,
+    mut changed: EventWriter<UiTextInputChanged>,
+    mut submitted: EventWriter<UiTextInputSubmitted>,
+    mut query: Query<(Entity, &mut UiTextInput)>,
+) {
+    let Some((entity, mut input)) = query.iter_mut().find(|(_, input)| input.focused) else {
+        keyboard.clear();
+        ime.clear();
+        return;
+    };
+    let pressed = |codes: [KeyCode; 2]| keys.as_ref().is_some_and(|keys| keys.any_pressed(codes));
+    let shortcut = pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) || pressed([KeyCode::SuperLeft, KeyCode::SuperRight]);
+    let shift = pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let mut edited = false;
+
+    for event in ime.read() {
+        match event {
+            Ime::Preedit { value, .. } => {
+                input.preedit.clone_from(value);
+                input.blink = 0.0;
+            },
+            Ime::Commit { value, .. } => {
+                input.preedit.clear();
+                edited |= input.insert(value);
+            },
+            Ime::Enabled { .. } | Ime::Disabled { .. } => input.preedit.clear(),
+        }
+    }
+
+    for event in keyboard.read() {
+        // Keys belong to the IME while composing
+        if event.state != ButtonState::Pressed || !input.preedit.is_empty() { continue }
+        // Shortcuts do the same as the dedicated keys
+        let key = match &event.logical_key {
+            Key::Character(text) if shortcut => match text.to_lowercase().as_str() {
+                "a" => { input.select_all(); continue },
+                "c" => Key::Copy,
+                "x" => Key::Cut,
+                "v" => Key::Paste,
+                _ => continue,
+            },
+            key => key.clone(),
+        };
+        match &key {
+            Key::Character(text) => edited |= input.insert(text),
+            Key::Copy | Key::Cut if input.selection().is_some() => {
+                clipboard.set(input.selected_text());
+                if key == Key::Cut { edited |= input.delete_selection(); }
+            },
+            Key::Paste => edited |= clipboard.get().is_some_and(|text| input.insert(&text)),
+            Key::Space => edited |= input.insert(" "),
+            Key::Backspace => edited |= input.delete(false),
+            Key::Delete => edited |= input.delete(true),
+            Key::ArrowLeft => { input.extend_selection(shift); input.shift_caret(-1) },
+            Key::ArrowRight => { input.extend_selection(shift); input.shift_caret(1) },
+            Key::Home => { input.extend_selection(shift); input.caret = input.line_start(); input.blink = 0.0; },
+            Key::End => { input.extend_selection(shift); input.caret = input.line_end(); input.blink = 0.0; },
+            Key::Enter if input.multiline => edited |= input.insert("\n"),
+            Key::Enter => { submitted.send(UiTextInputSubmitted { target: entity, value: input.value.clone() }); },
+            Key::Escape => input.focused = false,
+            _ => {},
+        }
+    }
+
+    if edited {
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Text input edited", "--".yellow(), "ELEMENT".red());
+        changed.send(UiTextInputChanged { target: entity, value: input.value.clone() });
+    }
+}
+
+/// This system writes the value, the IME composition and the blinking caret of [`UiTextInput`] into [`Text`].
+fn text_input_display(time: Res<Time>, mut query: Query<(&mut UiTextInput, &mut Text)>) {
+    for (mut input, mut text) in &mut query {
+        let input = input.bypass_change_detection();
+        input.blink += time.delta_seconds();
+
+        let style = text.sections.first().map(|section| section.style.clone()).unwrap_or_default();
+        let caret = input.caret();
+        let range = input.selection().unwrap_or(caret..caret);
+        let (left, right) = if caret == range.end { (&input.value[range.clone()], "") } else { ("", &input.value[range.clone()]) };
+        let values = [&input.value[..range.start], left, input.preedit.as_str(), "|", right, &input.value[range.end..]];
+
+        // Rebuild the sections only if they differ, so the text is not measured every frame
+        let matches = text.sections.len() == values.len() && text.sections.iter().zip(values).all(|(section, value)| section.value == value);
+        if !matches {
+            text.sections = values.iter().map(|value| TextSection::new(*value, style.clone())).collect();
+        }
+
+        let visible = input.focused && (input.blink % 1.0) < 0.5;
+        let caret = if visible { style.color } else { style.color.with_alpha(0.0) };
+        let colors = [style.color, input.selection_color, style.color, caret, input.selection_color, style.color];
+        if text.sections.iter().zip(colors).any(|(section, color)| section.style.color != color) {
+            for (section, color) in text.sections.iter_mut().zip(colors) {
+                if section.style.color != color { section.style.color = color }
+            }
+        }
+    }
+}
+
+/// This system enables the IME while a [`UiTextInput`] is focused and places its candidate window at the caret.
+fn text_input_ime(
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    query: Query<(&UiTextInput, &TextLayoutInfo, &Anchor, &GlobalTransform)>,
+) {
+    let Ok(mut window) = windows.get_single_mut() else { return };
+    let focused = query.iter().find(|(input, ..)| input.focused);
+    if window.ime_enabled != focused.is_some() { window.ime_enabled = focused.is_some(); }
+    let Some((_, layout, anchor, transform)) = focused else { return };
+
+    // The caret glyph is rendered the same way as all Text2d glyphs
+    let Some(glyph) = layout.glyphs.iter().find(|glyph| glyph.section_index == SECTION_CARET) else { return };
+    let scale_factor = window.resolution.scale_factor();
+    let alignment = layout.logical_size * -(anchor.as_vec() + 0.5);
+    let local = alignment + (glyph.position - Vec2::new(0.0, glyph.size.y / 2.0)) / scale_factor;
+    let world = transform.transform_point(local.extend(0.0));
+
+    let Some(position) = world_to_window(&cameras, world) else { return };
+    if window.ime_position != position { window.ime_position = position; }
+}
+
+
+// #=======================#
+// #=== KEYBIND CAPTURE ===#
+
+/// Input that can be bound to an action, captured by [`UiKeybindCapture`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum UiKeybind {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButtonType),
+}
+impl std::fmt::Display for UiKeybind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UiKeybind::Key(key) => {
+                let name = format!("{:?}", key);
+                write!(f, "{}", name.strip_prefix("Key").or(name.strip_prefix("Digit")).unwrap_or(&name))
+            },
+            UiKeybind::Mouse(MouseButton::Other(index)) => write!(f, "Mouse {}", index),
+            UiKeybind::Mouse(button) => write!(f, "Mouse {:?}", button),
+            UiKeybind::Gamepad(button) => write!(f, "Gamepad {:?}", button),
+        }
+    }
+}
+
+/// **Ui keybind capture** - Widget for rebinding controls. When clicked, it waits for the next key, mouse or gamepad button,
+/// shows it in the first section of its [`Text`] and sends [`KeybindChosen`]. The captured input is consumed, so it does not
+/// trigger anything else in the same frame. Pressing the cancel key stops the capture without a change.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu"))).with_children(|ui| {
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Jump"),
+///     UiLayout::window().size(Rl((20.0, 6.0))).pack::<Base>(),
+///     UiText2dBundle {
+///         text: Text::from_section("", TextStyle { font_size: 40.0, ..default() }),
+///         ..default()
+///     },
+///     UiKeybindCapture::new().bind(UiKeybind::Key(KeyCode::Space)),
+/// ));
+/// # });
+/// # }
+///
+/// fn rebind(mut events: EventReader<KeybindChosen>) {
+///     for event in events.read() {
+///         info!("Jump is now bound to {}", event.bind);
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiKeybindCapture {
+    /// The currently bound input.
+    pub bind: Option<UiKeybind>,
+    /// If the widget is waiting for an input.
+    pub capturing: bool,
+    /// Key that stops the capture, it can't be bound.
+    pub cancel: Option<KeyCode>,
+    /// Text shown while capturing.
+    pub prompt: String,
+}
+impl Default for UiKeybindCapture {
+    fn default() -> Self {
+        UiKeybindCapture {
+            bind: None,
+            capturing: false,
+            cancel: Some(KeyCode::Escape),
+            prompt: "...".to_string(),
+        }
+    }
+}
+impl UiKeybindCapture {
+    /// Creates new widget with no bind.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Replaces the bind with a new value.
+    pub fn bind(mut self, bind: UiKeybind) -> Self {
+        self.bind = Some(bind);
+        self
+    }
+    /// Replaces the cancel key with a new value.
+    pub fn cancel(mut self, cancel: Option<KeyCode>) -> Self {
+        self.cancel = cancel;
+        self
+    }
+    /// Replaces the prompt with a new value.
+    pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+}
+
+/// This event is sent when [`UiKeybindCapture`] captures a new input.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct KeybindChosen {
+    /// The capture widget entity.
+    pub target: Entity,
+    /// The captured input.
+    pub bind: UiKeybind,
+}
+
+/// This system starts the capture of the clicked [`UiKeybindCapture`] and stops all others.
+fn keybind_capture_start(mut events: EventReader<Pointer<Click>>, mut query: Query<(Entity, &mut UiKeybindCapture)>) {
+    for event in events.read() {
+        if !query.contains(event.target) { continue }
+        for (entity, mut capture) in &mut query {
+            let capturing = entity == event.target;
+            if capture.capturing != capturing { capture.capturing = capturing; }
+        }
+    }
+}
+
+/// This system captures the first pressed input for the capturing [`UiKeybindCapture`] and consumes it.
+fn keybind_capture_input(
+    mut keys: Option<ResMut<ButtonInput<KeyCode>>>,
+    mut mouse: Option<ResMut<ButtonInput<MouseButton>>>,
+    mut gamepad: Option<ResMut<ButtonInput<GamepadButton>>>,
+    mut events: EventWriter<KeybindChosen>,
+    mut query: Query<(Entity, &mut UiKeybindCapture)>,
+) {
+    let Some((entity, mut capture)) = query.iter_mut().find(|(_, capture)| capture.capturing) else { return };
+
+    let mut bind = None;
+    if let Some(keys) = &mut keys {
+        let pressed = keys.get_just_pressed().next().copied();
+        if let Some(key) = pressed {
+            keys.reset(key);
+            if Some(key) == capture.cancel {
+                capture.capturing = false;
+                return;
+            }
+            bind = Some(UiKeybind::Key(key));
+        }
+    }
+    if let (None, Some(mouse)) = (bind, &mut mouse) {
+        let pressed = mouse.get_just_pressed().next().copied();
+        if let Some(button) = pressed {
+            mouse.reset(button);
+            bind = Some(UiKeybind::Mouse(button));
+        }
+    }
+    if let (None, Some(gamepad)) = (bind, &mut gamepad) {
+        let pressed = gamepad.get_just_pressed().next().copied();
+        if let Some(button) = pressed {
+            gamepad.reset(button);
+            bind = Some(UiKeybind::Gamepad(button.button_type));
+        }
+    }
+
+    let Some(bind) = bind else { return };
+    #[cfg(feature = "verbose")]
+    info!("{} {} - Keybind captured: {}", "--".yellow(), "ELEMENT".red(), bind);
+    capture.capturing = false;
+    capture.bind = Some(bind);
+    events.send(KeybindChosen { target: entity, bind });
+}
+
+/// This system shows the prompt or the bound input of [`UiKeybindCapture`] in [`Text`].
+fn keybind_capture_display(mut query: Query<(&UiKeybindCapture, &mut Text), Changed<UiKeybindCapture>>) {
+    for (capture, mut text) in &mut query {
+        let value = match (capture.capturing, capture.bind) {
+            (true, _) => capture.prompt.clone(),
+            (false, Some(bind)) => bind.to_string(),
+            (false, None) => String::new(),
+        };
+        match text.sections.first_mut() {
+            Some(section) => if section.value != value { section.value = value },
+            None => text.sections.push(TextSection::new(value, TextStyle::default())),
+        }
+    }
+}
+
+
+// #=================#
+// #=== CLIPBOARD ===#
+
+/// **Ui clipboard** - Resource [`UiTextInput`] copies text into and pastes text from. Without the `clipboard` feature
+/// the text is kept only inside the app, with it the system clipboard is used whenever it is available.
+#[derive(Resource, Default)]
+pub struct UiClipboard {
+    /// Text kept inside the app.
+    text: Option<String>,
+    /// Handle to the system clipboard, opened on first use.
+    #[cfg(feature = "clipboard")]
+    system: Option<arboard::Clipboard>,
+}
+impl UiClipboard {
+    /// Returns the text in the clipboard.
+    pub fn get(&mut self) -> Option<String> {
+        #[cfg(feature = "clipboard")]
+        if let Some(text) = self.system().and_then(|clipboard| clipboard.get_text().ok()) {
+            return Some(text);
+        }
+        self.text.clone()
+    }
+    /// Places the text into the clipboard.
+    pub fn set(&mut self, text: impl Into<String>) {
+        let text = text.into();
+        #[cfg(feature = "clipboard")]
+        if let Some(clipboard) = self.system() {
+            if let Err(error) = clipboard.set_text(text.clone()) {
+                warn!("Failed to copy text to the system clipboard: {}", error);
+            }
+        }
+        self.text = Some(text);
+    }
+    /// Returns the system clipboard, opening it if needed.
+    #[cfg(feature = "clipboard")]
+    fn system(&mut self) -> Option<&mut arboard::Clipboard> {
+        if self.system.is_none() { self.system = arboard::Clipboard::new().ok(); }
+        self.system.as_mut()
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiTextInput`] and [`UiKeybindCapture`] logic.
+pub struct TextInputPlugin;
+impl Plugin for TextInputPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<UiTextInputChanged>()
+            .add_event::<UiTextInputSubmitted>()
+            .init_resource::<UiClipboard>()
+            .add_event::<KeybindChosen>()
+            .add_systems(PreUpdate, (keybind_capture_start, keybind_capture_input).chain().after(InputSystem))
+            .add_systems(Update, (text_input_focus, text_input_typing, text_input_display, text_input_ime).chain().before(UiSystems::Modify))
+            .add_systems(Update, keybind_capture_display.before(UiSystems::Modify));
+    }
+}
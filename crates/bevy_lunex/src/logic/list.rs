@@ -0,0 +1,195 @@
+use crate::*;
+use bevy::ecs::system::EntityCommands;
+use std::ops::Range;
+use std::sync::Arc;
+
+
+// #====================#
+// #=== VIRTUAL LIST ===#
+
+/// **Ui virtual list** - Lays out a large number of equally sized items, but spawns only the ones visible in the viewport.
+/// Entities leaving the viewport are reused for the items entering it, so the node count stays constant while scrolling.
+/// Add it together with [`UiScrollArea`] to a node. Items are spawned as its subnodes.
+/// ## 📌 Note
+/// The builder is called again every time an entity is reused for a different item.
+/// It should insert components, not spawn children, otherwise they would accumulate.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Inventory"))).with_children(|ui| {
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Inventory"),
+///     UiLayout::window_full().pack::<Base>(),
+///     UiScrollArea::vertical(),
+///     UiVirtualList::new(10_000, 40.0, |item, index| {
+///         item.insert(Text2dBundle { text: Text::from_section(format!("Item {index}"), default()), ..default() });
+///     }),
+/// ));
+/// # });
+/// # }
+/// ```
+#[derive(Component, Clone)]
+pub struct UiVirtualList {
+    /// Number of items in the list.
+    count: usize,
+    /// Size of one item along the scroll axis in layout units.
+    pub item_size: f32,
+    /// If the items are placed horizontally instead of vertically.
+    pub horizontal: bool,
+    /// Number of extra items kept spawned on each side of the viewport.
+    pub overdraw: usize,
+    /// Closure inserting the content of an item.
+    builder: Arc<dyn Fn(&mut EntityCommands, usize) + Send + Sync>,
+    /// Spawned entities and the items they show.
+    slots: Vec<(Entity, usize)>,
+    /// Entity stretching the scroll range over all items.
+    extent: Option<Entity>,
+    /// Number of entities spawned so far, used for unique node names.
+    spawned: usize,
+    /// Items shown last update.
+    range: Range<usize>,
+    /// If all items should be rebuilt.
+    dirty: bool,
+}
+impl UiVirtualList {
+    /// Creates new vertical list with the item count, item size and the item builder.
+    pub fn new(count: usize, item_size: f32, builder: impl Fn(&mut EntityCommands, usize) + Send + Sync + 'static) -> Self {
+        UiVirtualList {
+            count,
+            item_size,
+            horizontal: false,
+            overdraw: 2,
+            builder: Arc::new(builder),
+            slots: Vec::new(),
+            extent: None,
+            spawned: 0,
+            range: 0..0,
+            dirty: true,
+        }
+    }
+    /// Replaces the horizontal with a new value.
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.horizontal = horizontal;
+        self
+    }
+    /// Replaces the overdraw with a new value.
+    pub fn overdraw(mut self, overdraw: usize) -> Self {
+        self.overdraw = overdraw;
+        self
+    }
+    /// Returns the number of items.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Sets the number of items and rebuilds the visible ones.
+    pub fn set_count(&mut self, count: usize) {
+        self.count = count;
+        self.dirty = true;
+    }
+    /// Rebuilds the visible items, use it after the underlying data changed.
+    pub fn refresh(&mut self) {
+        self.dirty = true;
+    }
+    /// Returns the range of items currently spawned.
+    pub fn spawned(&self) -> Range<usize> {
+        self.range.clone()
+    }
+    /// Returns the layout of the item at the index.
+    fn item_layout(&self, index: usize) -> UiLayout {
+        let position = Ab(index as f32 * self.item_size);
+        if self.horizontal {
+            UiLayout::window().x(position).width(Ab(self.item_size)).height(Rl(100.0)).pack()
+        } else {
+            UiLayout::window().y(position).width(Rl(100.0)).height(Ab(self.item_size)).pack()
+        }
+    }
+}
+impl std::fmt::Debug for UiVirtualList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UiVirtualList")
+            .field("count", &self.count)
+            .field("item_size", &self.item_size)
+            .field("horizontal", &self.horizontal)
+            .field("overdraw", &self.overdraw)
+            .field("range", &self.range)
+            .finish()
+    }
+}
+
+/// Index of the item shown by an entity spawned by [`UiVirtualList`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiVirtualItem(pub usize);
+
+/// This system spawns, reuses and despawns the items of [`UiVirtualList`] to cover the viewport.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn virtual_list_update<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    mut uis: Query<&mut UiTree<T, N>>,
+    items: Query<&UiLink<T>, With<UiVirtualItem>>,
+    mut query: Query<(&UiLink<T>, &mut UiVirtualList, &Dimension, Option<&UiScrollArea>, &Parent)>,
+) {
+    for (link, mut list, dimension, area, parent) in &mut query {
+        let item_size = list.item_size.max(f32::EPSILON);
+        let offset = area.map(|area| area.offset).unwrap_or_default();
+        let (offset, viewport) = if list.horizontal { (offset.x, dimension.size.x) } else { (offset.y, dimension.size.y) };
+
+        let first = ((offset / item_size).floor().max(0.0) as usize).saturating_sub(list.overdraw).min(list.count);
+        let last = (((offset + viewport) / item_size).ceil().max(0.0) as usize + list.overdraw).min(list.count);
+        let range = first..last;
+        if range == list.range && !list.dirty { continue }
+
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Updated virtual list items {:?}", "--".yellow(), link.path.yellow().bold(), range);
+
+        // Stretch the scroll range over all items
+        let extent = if list.horizontal {
+            UiLayout::window().width(Ab(list.count as f32 * item_size)).height(Rl(100.0)).pack::<Base>()
+        } else {
+            UiLayout::window().width(Rl(100.0)).height(Ab(list.count as f32 * item_size)).pack::<Base>()
+        };
+        match list.extent {
+            Some(entity) => { commands.entity(entity).insert(extent); },
+            None => {
+                let entity = commands.spawn((link.add("extent"), extent)).set_parent(parent.get()).id();
+                list.extent = Some(entity);
+            },
+        }
+
+        // Free the entities showing items out of the viewport
+        let dirty = list.dirty;
+        let (kept, mut free): (Vec<_>, Vec<_>) = list.slots.drain(..).partition(|(_, index)| range.contains(index) && !dirty);
+        let mut slots = kept;
+
+        for index in range.clone() {
+            if slots.iter().any(|(_, i)| *i == index) { continue }
+            let layout = list.item_layout(index);
+            let entity = match free.pop() {
+                Some((entity, _)) => entity,
+                None => {
+                    list.spawned += 1;
+                    commands.spawn(link.add(format!("item{}", list.spawned))).set_parent(parent.get()).id()
+                },
+            };
+            let mut item = commands.entity(entity);
+            item.insert((layout, UiVirtualItem(index)));
+            (list.builder)(&mut item, index);
+            slots.push((entity, index));
+        }
+
+        // The list got shorter
+        for (entity, _) in free {
+            if let (Ok(mut ui), Ok(item)) = (uis.get_mut(parent.get()), items.get(entity)) {
+                let _ = ui.remove_node(item.path.clone());
+            }
+            commands.entity(entity).despawn_recursive();
+        }
+
+        list.slots = slots;
+        list.range = range;
+        list.dirty = false;
+    }
+}
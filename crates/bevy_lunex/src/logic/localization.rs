@@ -0,0 +1,89 @@
+use crate::*;
+
+
+// #====================#
+// #=== LOCALIZATION ===#
+
+/// Trait for a user-provided resource that resolves text keys into strings.
+/// Every time the resource changes (for example when the language is switched), all texts with [`UiTextKey`] are resolved again.
+/// Text layouts driven by the text size reflow automatically once the new strings are measured.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::utils::HashMap;
+/// # use bevy_lunex::prelude::*;
+/// #[derive(Resource)]
+/// struct Language {
+///     strings: HashMap<String, String>,
+/// }
+/// impl UiTextSource for Language {
+///     fn text(&self, key: &str) -> Option<String> {
+///         self.strings.get(key).cloned()
+///     }
+/// }
+/// App::new().add_plugins(UiLocalizationPlugin::<Language>::new());
+/// ```
+pub trait UiTextSource: Resource {
+    /// Returns the string for the key or [`None`] if the key is missing.
+    fn text(&self, key: &str) -> Option<String>;
+}
+
+/// Text keys resolved by [`UiTextSource`]. Each key replaces the value of the text section with the same index.
+/// Empty keys leave the section untouched.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let key = UiTextKey::new("menu.play");
+/// let keys = UiTextKey::sections(["menu.score", ""]);
+/// ```
+#[derive(Component, Debug, Default, Clone, PartialEq, Eq)]
+pub struct UiTextKey(pub Vec<String>);
+impl UiTextKey {
+    /// Creates new key for the first text section.
+    pub fn new(key: impl Into<String>) -> Self {
+        UiTextKey(vec![key.into()])
+    }
+    /// Creates new keys for multiple text sections.
+    pub fn sections(keys: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        UiTextKey(keys.into_iter().map(|key| key.into()).collect())
+    }
+}
+
+/// This system resolves [`UiTextKey`] into [`Text`] sections when the keys or the source change.
+/// Missing keys are logged and the section is left untouched.
+pub fn localize_text<R: UiTextSource>(source: Res<R>, mut query: Query<(Ref<UiTextKey>, &mut Text)>) {
+    for (keys, mut text) in &mut query {
+        if !source.is_changed() && !keys.is_changed() { continue }
+
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Localized text", "--".yellow(), "ELEMENT".red());
+
+        for (section, key) in text.sections.iter_mut().zip(&keys.0) {
+            if key.is_empty() { continue }
+            match source.text(key) {
+                Some(value) => if section.value != value { section.value = value },
+                None => warn!("Missing localization for key '{}'", key),
+            }
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding localization of [`UiTextKey`] texts using the specified [`UiTextSource`] resource.
+/// ## 📦 Types
+/// * Generic `(R)` - Resource implementing [`UiTextSource`]
+#[derive(Debug, Default, Clone)]
+pub struct UiLocalizationPlugin <R:UiTextSource>(PhantomData<R>);
+impl <R:UiTextSource> UiLocalizationPlugin<R> {
+    pub fn new() -> Self {
+        UiLocalizationPlugin::<R>(PhantomData)
+    }
+}
+impl <R:UiTextSource> Plugin for UiLocalizationPlugin<R> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, localize_text::<R>.run_if(resource_exists::<R>).before(UiSystems::Modify));
+    }
+}
@@ -0,0 +1,356 @@
+use crate::*;
+use bevy::asset::load_internal_asset;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef};
+use bevy::sprite::{Material2d, Material2dPlugin, Mesh2dHandle};
+
+
+// #============#
+// #=== MASK ===#
+
+pub(crate) const NODE_MASK_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(0x6c75_6e65_785f_7374_796c_655f_0000_0006);
+pub(crate) const NODE_MASK_2D_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(0x6c75_6e65_785f_7374_796c_655f_0000_0007);
+pub(crate) const NODE_MASK_3D_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(0x6c75_6e65_785f_7374_796c_655f_0000_0008);
+
+/// **Ui mask** - Clips the rendering of all subnodes to the shape of this node, or to the alpha of the mask texture.
+/// The shape is taken from [`UiShape`] and the corner radius of [`UiNodeStyle`], polygons are clipped by their bounding rectangle.
+///
+/// Subnodes rendered with [`Sprite`], [`ColorMaterial`] or [`StandardMaterial`] are moved to an internal masked material,
+/// which keeps their color and texture. Only the closest mask above the subnode is applied.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands, assets: Res<AssetServer>) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Hud"))).with_children(|ui| {
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Minimap"),
+///     UiLayout::window().size(Ab(200.0)).pack::<Base>(),
+///     UiShape::Circle,
+///     UiMask::new(),
+/// ));
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Minimap/Map"),
+///     UiLayout::window().pos(Rl(-50.0)).size(Rl(200.0)).pack::<Base>(),
+///     UiImage2dBundle::from(assets.load("map.png")),
+/// ));
+/// # });
+/// # }
+/// ```
+/// ## 📌 Note
+/// Text and nodes with [`UiNodeStyle`] use their own rendering and are not clipped.
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiMask {
+    /// Texture stretched over the node, whose alpha clips the subnodes together with the shape.
+    pub texture: Option<Handle<Image>>,
+    /// Alpha below which the texture clips. Set to `0.0` to use the alpha as soft edges.
+    pub threshold: f32,
+}
+impl UiMask {
+    /// Creates new mask clipping to the shape of the node.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Replaces the mask texture with a new value.
+    pub fn texture(mut self, texture: Handle<Image>) -> Self {
+        self.texture = Some(texture);
+        self
+    }
+    /// Replaces the threshold with a new value.
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+/// Mask data shared by the masked materials.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct UiMaskData {
+    /// The color multiplying the texture.
+    color: LinearRgba,
+    /// Transformation from the world into the mask node.
+    world_to_mask: Mat4,
+    /// Corner radius of the mask. The order is `top-left`, `top-right`, `bottom-right`, `bottom-left`.
+    corner_radius: Vec4,
+    /// Size of the mask node.
+    size: Vec2,
+    /// Center of the mask node in its local space.
+    center: Vec2,
+    /// `0` - no shape, `1` - rounded rectangle, `2` - ellipse.
+    shape: u32,
+    /// Alpha below which the mask texture clips.
+    threshold: f32,
+    /// `1` - has texture, `2` - has mask texture.
+    flags: u32,
+}
+impl UiMaskData {
+    /// Creates new data for the mask node, keeping the color.
+    fn new(color: LinearRgba, mask: &UiMask, node: (&Dimension, &GlobalTransform, Option<&UiShape>, Option<&UiNodeStyle>, bool)) -> Self {
+        let (dimension, transform, shape, style, is_element) = node;
+        let half = dimension.size / 2.0;
+        let (shape, corner_radius) = match shape.cloned().unwrap_or_default() {
+            UiShape::Circle => (2, Vec4::ZERO),
+            UiShape::Capsule => (1, Vec4::splat(half.min_element())),
+            UiShape::RoundedRectangle(radius) => (1, Vec4::splat(radius)),
+            UiShape::Rectangle | UiShape::Polygon(_) => (1, style.map(|style| style.corner_radius).unwrap_or_default()),
+        };
+        UiMaskData {
+            color,
+            world_to_mask: transform.compute_matrix().inverse(),
+            corner_radius,
+            size: dimension.size,
+            center: if is_element { Vec2::ZERO } else { Vec2::new(dimension.size.x, -dimension.size.y) / 2.0 },
+            shape,
+            threshold: mask.threshold,
+            flags: if mask.texture.is_some() { 2 } else { 0 },
+        }
+    }
+}
+
+/// Internal 2D material used to render the subnodes of [`UiMask`].
+#[derive(Asset, AsBindGroup, Debug, Default, Clone, Reflect)]
+pub struct UiMaskMaterial2d {
+    #[uniform(0)]
+    pub color: LinearRgba,
+    #[uniform(0)]
+    pub world_to_mask: Mat4,
+    #[uniform(0)]
+    pub corner_radius: Vec4,
+    #[uniform(0)]
+    pub size: Vec2,
+    #[uniform(0)]
+    pub center: Vec2,
+    #[uniform(0)]
+    pub shape: u32,
+    #[uniform(0)]
+    pub threshold: f32,
+    #[uniform(0)]
+    pub flags: u32,
+    #[texture(1)]
+    #[sampler(2)]
+    pub texture: Option<Handle<Image>>,
+    #[texture(3)]
+    #[sampler(4)]
+    pub mask_texture: Option<Handle<Image>>,
+}
+impl Material2d for UiMaskMaterial2d {
+    fn fragment_shader() -> ShaderRef {
+        NODE_MASK_2D_SHADER_HANDLE.into()
+    }
+}
+impl UiMaskMaterial2d {
+    /// Creates new material from the color and optional texture, without any mask.
+    pub fn new(color: impl Into<LinearRgba>, texture: Option<Handle<Image>>) -> Self {
+        UiMaskMaterial2d { color: color.into(), flags: texture.is_some() as u32, texture, ..default() }
+    }
+    /// Returns the current mask data.
+    fn data(&self) -> UiMaskData {
+        UiMaskData { color: self.color, world_to_mask: self.world_to_mask, corner_radius: self.corner_radius, size: self.size, center: self.center, shape: self.shape, threshold: self.threshold, flags: self.flags }
+    }
+    /// Replaces the mask data with a new value.
+    fn set_data(&mut self, data: UiMaskData) {
+        self.color = data.color;
+        self.world_to_mask = data.world_to_mask;
+        self.corner_radius = data.corner_radius;
+        self.size = data.size;
+        self.center = data.center;
+        self.shape = data.shape;
+        self.threshold = data.threshold;
+        self.flags = data.flags;
+    }
+}
+impl UiMaterialSync for UiMaskMaterial2d {
+    fn set_color(&mut self, color: Color) {
+        self.color = color.into();
+    }
+}
+
+/// Internal 3D material used to render the subnodes of [`UiMask`].
+#[derive(Asset, AsBindGroup, Debug, Default, Clone, Reflect)]
+pub struct UiMaskMaterial3d {
+    #[uniform(0)]
+    pub color: LinearRgba,
+    #[uniform(0)]
+    pub world_to_mask: Mat4,
+    #[uniform(0)]
+    pub corner_radius: Vec4,
+    #[uniform(0)]
+    pub size: Vec2,
+    #[uniform(0)]
+    pub center: Vec2,
+    #[uniform(0)]
+    pub shape: u32,
+    #[uniform(0)]
+    pub threshold: f32,
+    #[uniform(0)]
+    pub flags: u32,
+    #[texture(1)]
+    #[sampler(2)]
+    pub texture: Option<Handle<Image>>,
+    #[texture(3)]
+    #[sampler(4)]
+    pub mask_texture: Option<Handle<Image>>,
+}
+impl Material for UiMaskMaterial3d {
+    fn fragment_shader() -> ShaderRef {
+        NODE_MASK_3D_SHADER_HANDLE.into()
+    }
+    fn alpha_mode(&self) -> AlphaMode {
+        AlphaMode::Blend
+    }
+}
+impl UiMaskMaterial3d {
+    /// Creates new material from the color and optional texture, without any mask.
+    pub fn new(color: impl Into<LinearRgba>, texture: Option<Handle<Image>>) -> Self {
+        UiMaskMaterial3d { color: color.into(), flags: texture.is_some() as u32, texture, ..default() }
+    }
+    /// Returns the current mask data.
+    fn data(&self) -> UiMaskData {
+        UiMaskData { color: self.color, world_to_mask: self.world_to_mask, corner_radius: self.corner_radius, size: self.size, center: self.center, shape: self.shape, threshold: self.threshold, flags: self.flags }
+    }
+    /// Replaces the mask data with a new value.
+    fn set_data(&mut self, data: UiMaskData) {
+        self.color = data.color;
+        self.world_to_mask = data.world_to_mask;
+        self.corner_radius = data.corner_radius;
+        self.size = data.size;
+        self.center = data.center;
+        self.shape = data.shape;
+        self.threshold = data.threshold;
+        self.flags = data.flags;
+    }
+}
+impl UiMaterialSync for UiMaskMaterial3d {
+    fn set_color(&mut self, color: Color) {
+        self.color = color.into();
+    }
+}
+
+/// Returns the path of the closest mask node above the path.
+fn closest_mask<'a>(masks: &'a [(String, Entity)], path: &str) -> Option<&'a (String, Entity)> {
+    masks.iter()
+        .filter(|(mask, _)| path.len() > mask.len() && path.starts_with(mask.as_str()) && path.as_bytes()[mask.len()] == b'/')
+        .max_by_key(|(mask, _)| mask.len())
+}
+
+/// This system moves the subnodes of [`UiMask`] nodes rendered with [`Sprite`], [`ColorMaterial`] or [`StandardMaterial`] to the masked materials.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn mask_insert_material<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    mut materials_2d: ResMut<Assets<UiMaskMaterial2d>>,
+    mut materials_3d: ResMut<Assets<UiMaskMaterial3d>>,
+    color_materials: Res<Assets<ColorMaterial>>,
+    standard_materials: Res<Assets<StandardMaterial>>,
+    uis: Query<&Children, With<UiTree<T, N>>>,
+    masks: Query<&UiLink<T>, With<UiMask>>,
+    query: Query<(&UiLink<T>, Option<&Sprite>, Option<&Handle<Image>>, Option<&Handle<ColorMaterial>>, Option<&Handle<StandardMaterial>>), (With<Element>, Without<UiNodeStyle>)>,
+) {
+    for children in &uis {
+        let mask_paths: Vec<(String, Entity)> = children.iter().filter_map(|child| masks.get(*child).ok().map(|link| (link.path.clone(), *child))).collect();
+        if mask_paths.is_empty() { continue }
+
+        for child in children {
+            let Ok((link, sprite, image, color_material, standard_material)) = query.get(*child) else { continue };
+            if closest_mask(&mask_paths, &link.path).is_none() { continue }
+
+            let mut entity = commands.entity(*child);
+            if let (Some(sprite), Some(image)) = (sprite, image) {
+                entity.remove::<Sprite>().insert((
+                    Mesh2dHandle::default(),
+                    materials_2d.add(UiMaskMaterial2d::new(sprite.color, Some(image.clone()))),
+                ));
+            } else if let Some(material) = color_material.and_then(|handle| color_materials.get(handle)) {
+                entity.remove::<Handle<ColorMaterial>>().insert(
+                    materials_2d.add(UiMaskMaterial2d::new(material.color, material.texture.clone())),
+                );
+            } else if let Some(material) = standard_material.and_then(|handle| standard_materials.get(handle)) {
+                entity.remove::<Handle<StandardMaterial>>().insert(
+                    materials_3d.add(UiMaskMaterial3d::new(material.base_color, material.base_color_texture.clone())),
+                );
+            } else {
+                continue;
+            }
+
+            #[cfg(feature = "verbose")]
+            info!("{} {} - Moved to masked material", "--".yellow(), link.path.yellow().bold());
+        }
+    }
+}
+
+/// This system updates the masked materials of the subnodes from the closest [`UiMask`] node.
+/// It runs after the transforms are propagated, so the mask never lags behind the nodes.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn mask_update_material<T:Component, N:Default + Component>(
+    mut materials_2d: ResMut<Assets<UiMaskMaterial2d>>,
+    mut materials_3d: ResMut<Assets<UiMaskMaterial3d>>,
+    uis: Query<&Children, With<UiTree<T, N>>>,
+    masks: Query<(&UiLink<T>, &UiMask, &Dimension, &GlobalTransform, Option<&UiShape>, Option<&UiNodeStyle>, Has<Element>)>,
+    query: Query<(&UiLink<T>, Option<&Handle<UiMaskMaterial2d>>, Option<&Handle<UiMaskMaterial3d>>)>,
+) {
+    for children in &uis {
+        let mask_paths: Vec<(String, Entity)> = children.iter().filter_map(|child| masks.get(*child).ok().map(|(link, ..)| (link.path.clone(), *child))).collect();
+
+        for child in children {
+            let Ok((link, material_2d, material_3d)) = query.get(*child) else { continue };
+            if material_2d.is_none() && material_3d.is_none() { continue }
+
+            let mask = closest_mask(&mask_paths, &link.path).and_then(|(_, mask)| masks.get(*mask).ok());
+            let data = |current: &UiMaskData| match mask {
+                Some((_, mask, dimension, transform, shape, style, is_element)) => {
+                    let mut data = UiMaskData::new(current.color, mask, (dimension, transform, shape, style, is_element));
+                    data.flags |= current.flags & 1;
+                    data
+                },
+                None => UiMaskData { color: current.color, flags: current.flags & 1, ..default() },
+            };
+            let mask_texture = mask.and_then(|(_, mask, ..)| mask.texture.clone());
+
+            if let Some(handle) = material_2d {
+                if let Some(new) = materials_2d.get(handle).map(|material| data(&material.data())) {
+                    if materials_2d.get(handle).is_some_and(|material| material.data() != new || material.mask_texture != mask_texture) {
+                        if let Some(material) = materials_2d.get_mut(handle) {
+                            material.set_data(new);
+                            material.mask_texture = mask_texture.clone();
+                        }
+                    }
+                }
+            }
+            if let Some(handle) = material_3d {
+                if let Some(new) = materials_3d.get(handle).map(|material| data(&material.data())) {
+                    if materials_3d.get(handle).is_some_and(|material| material.data() != new || material.mask_texture != mask_texture) {
+                        if let Some(material) = materials_3d.get_mut(handle) {
+                            material.set_data(new);
+                            material.mask_texture = mask_texture;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding the [`UiMask`] materials.
+pub struct MaskPlugin;
+impl Plugin for MaskPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(app, NODE_MASK_SHADER_HANDLE, "../shaders/node_mask.wgsl", Shader::from_wgsl);
+        load_internal_asset!(app, NODE_MASK_2D_SHADER_HANDLE, "../shaders/node_mask_2d.wgsl", Shader::from_wgsl);
+        load_internal_asset!(app, NODE_MASK_3D_SHADER_HANDLE, "../shaders/node_mask_3d.wgsl", Shader::from_wgsl);
+
+        app
+            .register_type::<UiMask>()
+            .register_ui_material::<UiMaskMaterial2d>()
+            .register_ui_material::<UiMaskMaterial3d>()
+            .add_plugins(Material2dPlugin::<UiMaskMaterial2d>::default())
+            .add_plugins(MaterialPlugin::<UiMaskMaterial3d>::default());
+    }
+}
+
@@ -0,0 +1,201 @@
+use crate::*;
+use lunex_engine::*;
+
+
+// #====================#
+// #=== CONTEXT MENU ===#
+
+/// Where [`UiContextMenu`] is opened.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UiContextMenuAnchor {
+    /// At the cursor position when the menu is spawned.
+    Cursor,
+    /// At the point in the coordinates of the tree, with Y pointing down.
+    Point(Vec2),
+    /// Below the bottom-left corner of the node entity, which can be in any tree.
+    Node(Entity),
+}
+
+/// **Ui context menu** - Places the node next to the anchor and keeps it fully inside the tree. If the menu does not fit,
+/// it is flipped to the other side of the anchor and clamped to the tree edges. The menu and all its subnodes are
+/// despawned when clicking outside of it or pressing *Escape*.
+///
+/// Spawn the menu as a top-level node of an overlay tree with [`UiLayout::window`] of any size, the position is overwritten.
+/// The menu stays hidden until it is placed.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// #[derive(Component)]
+/// struct Overlay;
+///
+/// fn open_menu(_: Trigger<Pointer<Click>>, mut commands: Commands, overlay: Query<Entity, With<Overlay>>) {
+///     let Ok(overlay) = overlay.get_single() else { return };
+///     commands.entity(overlay).with_children(|ui| {
+///         ui.spawn((
+///             UiLink::<MainUi>::path("Menu"),
+///             UiLayout::window().size((200.0, 120.0)).pack::<Base>(),
+///             UiContextMenu::at_cursor(),
+///         ));
+///         ui.spawn((
+///             UiLink::<MainUi>::path("Menu/Copy"),
+///             UiLayout::window().size(Rl((100.0, 50.0))).pack::<Base>(),
+///         ));
+///     });
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiContextMenu {
+    /// Where the menu is opened.
+    pub anchor: UiContextMenuAnchor,
+    /// The anchor area in the coordinates of the tree, resolved once.
+    area: Option<Rect>,
+    /// If the menu was placed and is visible.
+    placed: bool,
+}
+impl UiContextMenu {
+    /// Creates new menu opened at the anchor.
+    pub fn new(anchor: UiContextMenuAnchor) -> Self {
+        UiContextMenu { anchor, area: None, placed: false }
+    }
+    /// Creates new menu opened at the cursor.
+    pub fn at_cursor() -> Self {
+        UiContextMenu::new(UiContextMenuAnchor::Cursor)
+    }
+    /// Creates new menu opened at the point in the coordinates of the tree.
+    pub fn at(point: impl Into<Vec2>) -> Self {
+        UiContextMenu::new(UiContextMenuAnchor::Point(point.into()))
+    }
+    /// Creates new menu opened below the node entity.
+    pub fn below(node: Entity) -> Self {
+        UiContextMenu::new(UiContextMenuAnchor::Node(node))
+    }
+    /// Returns the position of the menu with the size, keeping it inside the bounds.
+    pub fn position(area: Rect, size: Vec2, bounds: Vec2) -> Vec2 {
+        let place = |edge: f32, flipped_edge: f32, size: f32, bound: f32| {
+            let position = if edge + size > bound { flipped_edge - size } else { edge };
+            position.clamp(0.0, (bound - size).max(0.0))
+        };
+        // The menu starts at the left edge and below the anchor area
+        Vec2::new(
+            place(area.min.x, area.max.x, size.x, bounds.x),
+            place(area.max.y, area.min.y, size.y, bounds.y),
+        )
+    }
+}
+
+/// Returns the linked entities of the node and its subnodes with their depth in the tree.
+pub(crate) fn subtree_entities<T: Component>(children: &Children, links: &Query<&UiLink<T>>, path: &str) -> Vec<(Entity, usize)> {
+    let prefix = format!("{path}/");
+    children.iter().filter_map(|child| {
+        let link = links.get(*child).ok()?;
+        (link.path == path || link.path.starts_with(&prefix)).then(|| (*child, link.path.matches('/').count()))
+    }).collect()
+}
+
+/// Returns the rectangle of the node entity in the coordinates of the tree, with Y pointing down.
+fn node_area(node: (&Dimension, &GlobalTransform, bool), tree: &GlobalTransform) -> Rect {
+    let (dimension, transform, is_element) = node;
+    let top_left = if is_element { dimension.size.invert_y() / -2.0 } else { Vec2::ZERO };
+    let bottom_right = top_left + dimension.size.invert_y();
+    let inverse = tree.affine().inverse();
+    let corner = |point: Vec2| inverse.transform_point3(transform.transform_point(point.extend(0.0))).truncate().invert_y();
+    Rect::from_corners(corner(top_left), corner(bottom_right))
+}
+
+/// This system places [`UiContextMenu`] nodes inside their tree once their size is computed.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn context_menu_place<T:Component, N:Default + Component>(
+    raycast: UiRaycast,
+    cameras: Query<(Entity, &Camera)>,
+    uis: Query<(Entity, &Dimension, &GlobalTransform, &Children), With<UiTree<T, N>>>,
+    nodes: Query<(&Dimension, &GlobalTransform, Has<Element>), Without<UiContextMenu>>,
+    links: Query<&UiLink<T>>,
+    mut menus: Query<(&UiLink<T>, &mut UiContextMenu, &mut UiLayout, &Dimension), Without<UiTree<T, N>>>,
+    mut visibility: Query<&mut Visibility>,
+) {
+    for (tree, tree_dimension, tree_transform, children) in &uis {
+        for child in children {
+            let Ok((link, mut menu, mut layout, dimension)) = menus.get_mut(*child) else { continue };
+
+            // Resolve the anchor
+            if menu.area.is_none() {
+                menu.area = match menu.anchor {
+                    UiContextMenuAnchor::Point(point) => Some(Rect::from_corners(point, point)),
+                    UiContextMenuAnchor::Node(node) => nodes.get(node).ok().map(|node| node_area(node, tree_transform)),
+                    UiContextMenuAnchor::Cursor => {
+                        let mut cameras: Vec<_> = cameras.iter().filter(|(_, camera)| camera.is_active).collect();
+                        cameras.sort_by_key(|(_, camera)| -camera.order);
+                        cameras.iter().find_map(|(camera, _)| raycast.cursor_to_root(*camera, tree)).map(|point| Rect::from_corners(point, point))
+                    },
+                };
+            }
+            let Some(area) = menu.area else { continue };
+            if tree_dimension.size.x <= 0.0 || tree_dimension.size.y <= 0.0 { continue }
+
+            let position = UiContextMenu::position(area, dimension.size, tree_dimension.size);
+            let pos = Rl(position / tree_dimension.size * 100.0);
+            let Layout::Window(window) = &layout.layout else {
+                warn!("UiContextMenu {} requires UiLayout::window", link.path);
+                continue;
+            };
+
+            let moved = window.pos != pos.into();
+            if moved { layout.layout = (*window).pos(pos).into(); }
+
+            // Keep the menu hidden until it is computed in place
+            let visible = !moved && dimension.size != Vec2::ZERO;
+            if menu.placed != visible || !visible {
+                menu.placed = visible;
+                for (entity, _) in subtree_entities(children, &links, &link.path) {
+                    if let Ok(mut visibility) = visibility.get_mut(entity) {
+                        let target = if visible { Visibility::Inherited } else { Visibility::Hidden };
+                        if *visibility != target { *visibility = target; }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// This system despawns [`UiContextMenu`] nodes with their subnodes when clicking outside of them or pressing *Escape*.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn context_menu_dismiss<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+    raycast: UiRaycast,
+    cameras: Query<(Entity, &Camera)>,
+    uis: Query<(Entity, &UiTree<T, N>, &Children)>,
+    links: Query<&UiLink<T>>,
+    menus: Query<(&UiLink<T>, &UiContextMenu)>,
+) {
+    let escape = keys.is_some_and(|keys| keys.just_pressed(KeyCode::Escape));
+    let click = mouse.is_some_and(|mouse| mouse.get_just_pressed().next().is_some());
+    if !escape && !click { return }
+
+    for (tree, ui, children) in &uis {
+        let cursor = if click { cameras.iter().filter(|(_, camera)| camera.is_active).find_map(|(camera, _)| raycast.cursor_to_root(camera, tree)) } else { None };
+        for child in children {
+            let Ok((link, menu)) = menus.get(*child) else { continue };
+            if !menu.placed { continue }
+
+            if !escape {
+                let Some(cursor) = cursor else { continue };
+                let Some(rectangle) = ui.borrow_node(link.path.clone()).ok().and_then(|node| node.obtain_data()).map(|container| container.rectangle) else { continue };
+                if Rect::from_corners(rectangle.pos.truncate(), rectangle.pos.truncate() + rectangle.size).contains(cursor) { continue }
+            }
+
+            // Despawn the subnodes first, so their nodes are removed from the tree before the menu node
+            let mut entities = subtree_entities(children, &links, &link.path);
+            entities.sort_by_key(|(_, depth)| std::cmp::Reverse(*depth));
+            for (entity, _) in entities {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+}
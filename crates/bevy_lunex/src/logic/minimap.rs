@@ -0,0 +1,232 @@
+use crate::*;
+
+
+// #===============#
+// #=== MINIMAP ===#
+
+/// The plane of the world shown by [`UiMinimap`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum UiMinimapPlane {
+    /// The `X` and `Y` axes, used by 2D games. The world `Y` points up on the map.
+    #[default]
+    XY,
+    /// The `X` and `Z` axes, used by 3D games viewed from the top. The world `-Z` points up on the map.
+    XZ,
+}
+impl UiMinimapPlane {
+    /// Returns the world position flattened onto the plane.
+    pub fn project(&self, position: Vec3) -> Vec2 {
+        match self {
+            UiMinimapPlane::XY => position.xy(),
+            UiMinimapPlane::XZ => position.xz(),
+        }
+    }
+    /// Returns the world position of the point on the plane, with the remaining axis set to the height.
+    pub fn unproject(&self, point: Vec2, height: f32) -> Vec3 {
+        match self {
+            UiMinimapPlane::XY => point.extend(height),
+            UiMinimapPlane::XZ => Vec3::new(point.x, height, point.y),
+        }
+    }
+}
+
+/// **Ui minimap** - Maps a rectangle of the world onto the node. Nodes with [`UiMinimapMarker`] are moved
+/// to the positions of their targets and clicking the node sends [`MinimapClicked`] with the world position under the pointer.
+///
+/// The bounds are the world rectangle shown by the map. Set them yourself or make the map [`follow`](UiMinimap::follow)
+/// an entity, which keeps the bounds centered on it.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::sprite::Anchor;
+/// # use bevy_lunex::prelude::*;
+/// # #[derive(Component)] struct Player;
+/// # fn setup(mut commands: Commands, player: Query<Entity, With<Player>>) {
+/// # let player = player.single();
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Hud"))).with_children(|ui| {
+/// let map = ui.spawn((
+///     UiLink::<MainUi>::path("Map"),
+///     UiLayout::window().pos(Rl(75.0)).size(Rl(20.0)).pack::<Base>(),
+///     UiImage2dBundle::from(Handle::<Image>::default()),
+///     UiMinimap::new(Rect::new(-500.0, -500.0, 500.0, 500.0)),
+/// )).id();
+///
+/// // Marker must be linked under the map, so its relative position is relative to the map
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Map/Player"),
+///     UiLayout::window().anchor(Anchor::Center).size(Ab(8.0)).pack::<Base>(),
+///     UiImage2dBundle::from(Handle::<Image>::default()),
+///     UiMinimapMarker::new(map, player),
+/// ));
+/// # });
+/// # }
+///
+/// fn teleport(mut events: EventReader<MinimapClicked>, mut player: Query<&mut Transform, With<Player>>) {
+///     for event in events.read() {
+///         let mut transform = player.single_mut();
+///         transform.translation = UiMinimapPlane::XY.unproject(event.position, transform.translation.z);
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiMinimap {
+    /// The world rectangle shown by the map.
+    pub bounds: Rect,
+    /// The plane of the world shown by the map.
+    pub plane: UiMinimapPlane,
+    /// Entity the bounds are centered on every frame.
+    pub follow: Option<Entity>,
+}
+impl UiMinimap {
+    /// Creates new minimap showing the world rectangle.
+    pub fn new(bounds: Rect) -> Self {
+        UiMinimap { bounds, plane: UiMinimapPlane::XY, follow: None }
+    }
+    /// Replaces the bounds with a new value.
+    pub fn bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = bounds;
+        self
+    }
+    /// Replaces the plane with a new value.
+    pub fn plane(mut self, plane: UiMinimapPlane) -> Self {
+        self.plane = plane;
+        self
+    }
+    /// Keeps the bounds centered on the entity, the size of the bounds stays the same.
+    pub fn follow(mut self, entity: Entity) -> Self {
+        self.follow = Some(entity);
+        self
+    }
+    /// Returns the position on the map for the world position, from `(0, 0)` at the top-left to `(1, 1)` at the bottom-right.
+    /// Positions outside of the bounds are outside of this range.
+    pub fn world_to_map(&self, position: Vec3) -> Vec2 {
+        let point = self.plane.project(position);
+        let size = self.bounds.size().max(Vec2::splat(f32::EPSILON));
+        let x = (point.x - self.bounds.min.x) / size.x;
+        let y = match self.plane {
+            UiMinimapPlane::XY => (self.bounds.max.y - point.y) / size.y,
+            UiMinimapPlane::XZ => (point.y - self.bounds.min.y) / size.y,
+        };
+        Vec2::new(x, y)
+    }
+    /// Returns the point on the plane for the position on the map, the inverse of [`UiMinimap::world_to_map`].
+    pub fn map_to_world(&self, point: Vec2) -> Vec2 {
+        let size = self.bounds.size();
+        let x = self.bounds.min.x + point.x * size.x;
+        let y = match self.plane {
+            UiMinimapPlane::XY => self.bounds.max.y - point.y * size.y,
+            UiMinimapPlane::XZ => self.bounds.min.y + point.y * size.y,
+        };
+        Vec2::new(x, y)
+    }
+}
+
+/// **Ui minimap marker** - Moves the node to the position of the target entity on [`UiMinimap`].
+/// The node must have a window layout and be linked under the map, its position is replaced with the relative position on the map.
+/// Markers of targets outside of the bounds are hidden, unless they are clamped to the edge.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiMinimapMarker {
+    /// The minimap entity.
+    pub minimap: Entity,
+    /// The entity shown by the marker.
+    pub target: Entity,
+    /// If the marker stays on the edge of the map when the target leaves the bounds.
+    pub clamp: bool,
+}
+impl UiMinimapMarker {
+    /// Creates new marker showing the target on the minimap.
+    pub fn new(minimap: Entity, target: Entity) -> Self {
+        UiMinimapMarker { minimap, target, clamp: false }
+    }
+    /// Replaces the clamp with a new value.
+    pub fn clamp(mut self, clamp: bool) -> Self {
+        self.clamp = clamp;
+        self
+    }
+}
+
+/// This event is sent when [`UiMinimap`] or one of its markers is clicked.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct MinimapClicked {
+    /// The minimap entity
+    pub target: Entity,
+    /// The clicked point on the plane of the minimap in world coordinates
+    pub position: Vec2,
+}
+
+/// This system centers the bounds of [`UiMinimap`] on the followed entity.
+fn minimap_follow(mut query: Query<&mut UiMinimap>, transforms: Query<&GlobalTransform>) {
+    for mut minimap in &mut query {
+        let Some(follow) = minimap.follow else { continue };
+        let Ok(transform) = transforms.get(follow) else { continue };
+        let bounds = Rect::from_center_size(minimap.plane.project(transform.translation()), minimap.bounds.size());
+        if minimap.bounds != bounds { minimap.bounds = bounds; }
+    }
+}
+
+/// This system moves [`UiMinimapMarker`] nodes to the positions of their targets.
+fn minimap_markers(
+    minimaps: Query<&UiMinimap>,
+    transforms: Query<&GlobalTransform>,
+    mut query: Query<(&UiMinimapMarker, &mut UiLayout, Option<&mut Visibility>)>,
+) {
+    for (marker, mut layout, visibility) in &mut query {
+        let Ok(minimap) = minimaps.get(marker.minimap) else { continue };
+        let Ok(transform) = transforms.get(marker.target) else { continue };
+        let point = minimap.world_to_map(transform.translation());
+        let inside = point.cmpge(Vec2::ZERO).all() && point.cmple(Vec2::ONE).all();
+
+        if let Some(mut visibility) = visibility {
+            let new = if inside || marker.clamp { Visibility::Inherited } else { Visibility::Hidden };
+            if *visibility != new { *visibility = new; }
+        }
+
+        let Layout::Window(window) = layout.layout else {
+            warn!("UiMinimapMarker requires UiLayout::window");
+            continue;
+        };
+        let new = window.pos(Rl(point.clamp(Vec2::ZERO, Vec2::ONE) * 100.0));
+        if window != new { layout.layout = new.into(); }
+    }
+}
+
+/// This system sends [`MinimapClicked`] with the world position of the clicked point on [`UiMinimap`].
+fn minimap_input(
+    mut clicks: EventReader<Pointer<Click>>,
+    raycast: UiRaycast,
+    minimaps: Query<(&UiMinimap, &Dimension, Has<Element>), Without<UiDisabled>>,
+    markers: Query<&UiMinimapMarker>,
+    mut clicked: EventWriter<MinimapClicked>,
+) {
+    for event in clicks.read() {
+        if event.button != PointerButton::Primary { continue }
+        // Clicks on the markers are clicks on the map below them
+        let target = markers.get(event.target).map_or(event.target, |marker| marker.minimap);
+        let Ok((minimap, dimension, is_element)) = minimaps.get(target) else { continue };
+        let Some(local) = raycast.viewport_to_root(event.hit.camera, event.pointer_location.position, target) else { continue };
+        if dimension.size.cmple(Vec2::ZERO).any() { continue }
+
+        // Elements are centered on their transform
+        let offset = if is_element { Vec2::splat(0.5) } else { Vec2::ZERO };
+        let point = (local / dimension.size + offset).clamp(Vec2::ZERO, Vec2::ONE);
+        let position = minimap.map_to_world(point);
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Minimap clicked", "--".yellow(), "ELEMENT".red());
+        clicked.send(MinimapClicked { target, position });
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiMinimap`] logic.
+pub struct MinimapPlugin;
+impl Plugin for MinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<MinimapClicked>()
+            .add_systems(Update, (minimap_input, minimap_follow, minimap_markers).chain().before(UiSystems::Send));
+    }
+}
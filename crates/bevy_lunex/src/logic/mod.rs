@@ -1,18 +1,104 @@
+pub mod accessibility;
+pub use accessibility::*;
+
 pub mod actions;
 use actions::ActionsPlugin;
 
+pub mod bind;
+pub use bind::*;
+
+pub mod button;
+pub use button::*;
+
+pub mod camera;
+pub use camera::*;
+
+pub mod class;
+pub use class::*;
+
+pub mod clone;
+pub use clone::*;
+
 pub mod core;
 pub use core::*;
 
 pub mod cursor;
 pub use cursor::*;
 
+pub mod disabled;
+pub use disabled::*;
+
+pub mod flipbook;
+pub use flipbook::*;
+
+pub mod group;
+pub use group::*;
+
+pub mod haptics;
+pub use haptics::*;
+
+pub mod input;
+pub use input::*;
+
+pub mod list;
+pub use list::*;
+
+pub mod localization;
+pub use localization::*;
+
+pub mod mask;
+pub use mask::*;
+
+pub mod menu;
+pub use menu::*;
+
+pub mod minimap;
+pub use minimap::*;
+
+pub mod picker;
+pub use picker::*;
+
+pub mod prefab;
+pub use prefab::*;
+
+pub mod router;
+pub use router::*;
+
+#[cfg(feature = "states")]
+pub mod scoped;
+#[cfg(feature = "states")]
+pub use scoped::*;
+
+pub mod scroll;
+pub use scroll::*;
+
+pub mod shortcut;
+pub use shortcut::*;
+
 pub mod states;
 pub use states::*;
 
 pub mod style;
 pub use style::*;
 
+pub mod table;
+pub use table::*;
+
+pub mod text;
+pub use text::*;
+
+pub mod theme;
+pub use theme::*;
+
+pub mod touch;
+pub use touch::*;
+
+pub mod tween;
+pub use tween::*;
+
+pub mod world;
+pub use world::*;
+
 
 // #====================#
 // #=== LOGIC PLUGIN ===#
@@ -25,11 +111,34 @@ pub struct UiLogicPlugin;
 impl Plugin for UiLogicPlugin {
     fn build(&self, app: &mut App) {
         app
+            .add_plugins(AccessiblePlugin)
             .add_plugins(ActionsPlugin)
+            .add_plugins(ButtonPlugin)
+            .add_plugins(CameraPlugin)
+            .add_plugins(ClassPlugin)
+            .add_plugins(ClonePlugin)
+            .add_plugins(ColorPickerPlugin)
             .add_plugins(CorePlugin)
             .add_plugins(CursorPlugin)
             .add_plugins(DefaultStatesPlugin)
-            .add_plugins(StylePlugin);
+            .add_plugins(DisabledPlugin)
+            .add_plugins(FlipbookPlugin)
+            .add_plugins(GroupPlugin)
+            .add_plugins(HapticsPlugin)
+            .add_plugins(MaskPlugin)
+            .add_plugins(MinimapPlugin)
+            .add_plugins(ScrollPlugin)
+            .add_plugins(ShortcutPlugin)
+            .add_plugins(StylePlugin)
+            .add_plugins(TablePlugin)
+            .add_plugins(TextAnimatorPlugin)
+            .add_plugins(TextInputPlugin)
+            .add_plugins(ThemePlugin)
+            .add_plugins(TouchPlugin)
+            .add_plugins(WorldPlugin);
+
+        // Also added by UiStateScopePlugin
+        if !app.is_plugin_added::<RouterPlugin>() { app.add_plugins(RouterPlugin); }
     }
 }
 
@@ -43,12 +152,35 @@ impl <T:Component, N:Default + Component> UiStateLogicPlugin<T, N> {
 }
 impl <T:Component, N:Default + Component> Plugin for UiStateLogicPlugin<T, N> {
     fn build(&self, app: &mut App) {
-        app            
+        let schedule = UiSchedule::of(app);
+        app
             //.add_plugins(StatePlugin::<T, N, Base>::new())
             .add_plugins(StatePlugin::<T, N, Hover>::new())
             .add_plugins(StatePlugin::<T, N, Clicked>::new())
             .add_plugins(StatePlugin::<T, N, Selected>::new())
             .add_plugins(StatePlugin::<T, N, Intro>::new())
-            .add_plugins(StatePlugin::<T, N, Outro>::new());
+            .add_plugins(StatePlugin::<T, N, Outro>::new())
+
+            .init_resource::<UiStateNames>()
+            .add_systems(schedule, send_named_layout_to_node::<T, N>.in_set(UiSystems::Send).before(send_content_size_to_node::<T, N>))
+            .add_systems(schedule, stagger_intro::<T, N>.before(lifecycle_start))
+            .add_systems(schedule, despawn_ui_start::<T, N>.in_set(UiSystems::Modify))
+            .add_systems(schedule, reparent_ui::<T, N>.in_set(UiSystems::Modify).before(UiSystems::Send))
+            .add_systems(schedule, shortcut_text::<T>.after(UiSystems::Modify))
+            .add_systems(Update, scroll_area_input::<T>.before(scroll_area_physics))
+            .add_systems(Update, scrollbar_input::<T, N>.before(scroll_area_physics))
+            .add_systems(schedule, scrollbar_update::<T, N>.after(scroll_area_physics).before(UiSystems::Send))
+            .add_systems(schedule, virtual_list_update::<T, N>.after(scroll_area_physics).before(UiSystems::Send))
+            .add_systems(schedule, table_update::<T>.after(virtual_list_update::<T, N>).before(UiSystems::Send))
+            .add_systems(schedule, send_scroll_to_node::<T, N>.in_set(UiSystems::Send).before(UiSystems::Compute))
+            .add_systems(schedule, fetch_scroll_from_node::<T, N>.in_set(UiSystems::Fetch).after(UiSystems::Compute))
+            .add_systems(schedule, context_menu_dismiss::<T, N>.in_set(UiSystems::Modify).before(UiSystems::Send))
+            .add_systems(schedule, context_menu_place::<T, N>.in_set(UiSystems::Fetch).after(fetch_transform_from_node::<T, N>).after(fetch_dimension_from_node::<T, N>))
+            .add_systems(schedule, node_backdrop_update::<T, N>.in_set(UiSystems::Process).after(UiSystems::Fetch))
+            .add_systems(schedule, (node_shadow_spawn::<T, N>, node_shadow_update::<T, N>).chain().in_set(UiSystems::Process).after(UiSystems::Fetch).after(node_shadow_remove))
+            .add_systems(schedule, mask_insert_material::<T, N>.in_set(UiSystems::Process).after(UiSystems::Fetch))
+            .add_systems(PostUpdate, mask_update_material::<T, N>.after(bevy::transform::TransformSystem::TransformPropagate))
+            .add_systems(schedule, world_anchor_update::<T, N>.in_set(UiSystems::Modify).before(UiSystems::Send))
+            .add_systems(schedule, layout_tween::<T, N>.in_set(UiSystems::Fetch).after(fetch_transform_from_node::<T, N>).after(fetch_dimension_from_node::<T, N>).after(element_fetch_transform_from_node::<T, N>));
     }
 }
\ No newline at end of file
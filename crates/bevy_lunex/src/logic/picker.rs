@@ -0,0 +1,431 @@
+use crate::*;
+use bevy::asset::load_internal_asset;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef};
+use bevy::sprite::{Anchor, Material2d, Material2dPlugin, Mesh2dHandle};
+use bevy::utils::HashMap;
+
+
+// #====================#
+// #=== COLOR PICKER ===#
+
+pub(crate) const COLOR_PICKER_2D_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(0x6c75_6e65_785f_7374_796c_655f_0000_000b);
+
+/// **Ui color picker** - Ready to use color picker prefab. Spawns a saturation and value field, a hue strip,
+/// an alpha slider and a hex text input, rendered with gradient materials. Dragging over the parts or submitting
+/// a hex code sends [`ColorPicked`]. The current color is stored in [`UiColorPickerValue`] of the prefab node,
+/// changing it moves the markers without sending the event.
+/// ## 📌 Note
+/// The gradients are rendered with 2D materials, so the picker is meant for 2D trees.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Character"))).with_children(|ui| {
+///     ui.spawn_prefab(UiLink::<MainUi>::path("Hair"), UiColorPicker::new(Color::srgb(0.4, 0.25, 0.1))
+///         .layout(UiLayout::window().pos(Rl((60.0, 20.0))).size(Ab((300.0, 260.0))))
+///         .alpha(false)
+///     );
+/// });
+/// # }
+///
+/// fn hair_color(mut events: EventReader<ColorPicked>) {
+///     for event in events.read() {
+///         info!("Hair color {:?}", event.color);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct UiColorPicker {
+    /// The initial color.
+    pub color: Color,
+    /// If the alpha slider is spawned. Without it the initial color is made opaque.
+    pub alpha: bool,
+    /// If the hex text input is spawned.
+    pub hex: bool,
+    /// Layout of the picker.
+    pub layout: Layout,
+    /// Color of the hex text input.
+    pub background: Color,
+    /// Color of the hex text.
+    pub text_color: Color,
+    /// Height of the hex text in percent of the text input height.
+    pub text_size: f32,
+    /// Font of the hex text.
+    pub font: Handle<Font>,
+}
+impl UiColorPicker {
+    /// Creates new picker with the initial color.
+    pub fn new(color: impl Into<Color>) -> Self {
+        UiColorPicker {
+            color: color.into(),
+            alpha: true,
+            hex: true,
+            layout: UiLayout::window_full().into(),
+            background: Color::srgb(0.15, 0.15, 0.18),
+            text_color: Color::WHITE,
+            text_size: 60.0,
+            font: Handle::default(),
+        }
+    }
+    /// Replaces the alpha with a new value.
+    pub fn alpha(mut self, alpha: bool) -> Self {
+        self.alpha = alpha;
+        self
+    }
+    /// Replaces the hex with a new value.
+    pub fn hex(mut self, hex: bool) -> Self {
+        self.hex = hex;
+        self
+    }
+    /// Replaces the layout with a new value.
+    pub fn layout(mut self, layout: impl Into<Layout>) -> Self {
+        self.layout = layout.into();
+        self
+    }
+    /// Replaces the background with a new value.
+    pub fn background(mut self, color: impl Into<Color>) -> Self {
+        self.background = color.into();
+        self
+    }
+    /// Replaces the text color with a new value.
+    pub fn text_color(mut self, color: impl Into<Color>) -> Self {
+        self.text_color = color.into();
+        self
+    }
+    /// Replaces the text size with a new value.
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = size;
+        self
+    }
+    /// Replaces the font with a new value.
+    pub fn font(mut self, font: Handle<Font>) -> Self {
+        self.font = font;
+        self
+    }
+}
+impl <T: Component> UiPrefabTrait<T> for UiColorPicker {
+    fn spawn(self, ui: &mut ChildBuilder, link: UiLink<T>) -> Entity {
+        let mut value = UiColorPickerValue::new(self.color);
+        if !self.alpha { value.color.alpha = 1.0; }
+
+        // The parts are spawned after the prefab node, so they can point to its entity
+        let picker = ui.spawn((
+            UiLink::<T>::path(link.path.as_str()),
+            UiLayout::<Base>::from(self.layout),
+            UiSpatialBundle::default(),
+            value,
+        )).id();
+
+        // The bottom row holds the alpha slider and the hex input, whichever is present takes the whole row
+        let top = if self.alpha || self.hex { 80.0 } else { 100.0 };
+        let mut parts = vec![
+            (UiColorPickerPart::Field(picker), UiLayout::window().size(Rl((85.0, top)))),
+            (UiColorPickerPart::Hue(picker), UiLayout::window().x(Rl(88.0)).size(Rl((12.0, top)))),
+        ];
+        if self.alpha {
+            let width = if self.hex { 58.0 } else { 100.0 };
+            parts.push((UiColorPickerPart::Alpha(picker), UiLayout::window().y(Rl(84.0)).size(Rl((width, 16.0)))));
+        }
+
+        for (part, layout) in parts {
+            let path = part.name();
+            ui.spawn((
+                link.add(path),
+                layout.pack::<Base>(),
+                UiElementBundle::default(),
+                part,
+            ));
+            ui.spawn((
+                link.add(format!("{path}/Marker")),
+                part.marker_layout(&value),
+                UiElementBundle::default(),
+                UiNodeStyle::new().color(Color::NONE).radius(6.0).border(2.0, Color::WHITE),
+                UiColorPickerMarker(part),
+                Pickable::IGNORE,
+            ));
+        }
+
+        if self.hex {
+            let x = if self.alpha { 62.0 } else { 0.0 };
+            ui.spawn((
+                link.add("Hex"),
+                UiLayout::window().pos(Rl((x, 84.0))).size(Rl((100.0 - x, 16.0))).pack::<Base>(),
+                UiElementBundle::default(),
+                UiNodeStyle::new().color(self.background).radius(4.0),
+            ));
+            ui.spawn((
+                link.add("Hex/Text"),
+                UiLayout::window().pos(Rl((8.0, 50.0))).anchor(Anchor::CenterLeft).pack::<Base>(),
+                UiText2dBundle {
+                    text: Text::from_section("", TextStyle { font: self.font, font_size: self.text_size, color: self.text_color }),
+                    ..default()
+                },
+                UiTextInput::new().value(value.hex()).max_length(9),
+                UiColorPickerPart::Hex(picker),
+            ));
+        }
+
+        picker
+    }
+}
+
+/// **Ui color picker value** - The color of [`UiColorPicker`], stored in HSV so the hue is kept for gray colors.
+/// Change it to move the picker to another color.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiColorPickerValue {
+    /// The picked color.
+    pub color: Hsva,
+}
+impl UiColorPickerValue {
+    /// Creates new value from the color.
+    pub fn new(color: impl Into<Color>) -> Self {
+        UiColorPickerValue { color: Hsva::from(color.into()) }
+    }
+    /// Returns the picked color.
+    pub fn get(&self) -> Color {
+        self.color.into()
+    }
+    /// Replaces the picked color with a new value.
+    pub fn set(&mut self, color: impl Into<Color>) {
+        self.color = Hsva::from(color.into());
+    }
+    /// Returns the color as a hex code, with the alpha only if it is not opaque.
+    pub fn hex(&self) -> String {
+        Srgba::from(self.color).to_hex()
+    }
+}
+
+/// Part of [`UiColorPicker`], holding the entity of the prefab node.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiColorPickerPart {
+    /// Field picking the saturation horizontally and the value vertically.
+    Field(Entity),
+    /// Vertical strip picking the hue.
+    Hue(Entity),
+    /// Horizontal slider picking the alpha.
+    Alpha(Entity),
+    /// Text input with the hex code.
+    Hex(Entity),
+}
+impl UiColorPickerPart {
+    /// Returns the entity of the prefab node.
+    pub fn picker(&self) -> Entity {
+        match self {
+            UiColorPickerPart::Field(picker) | UiColorPickerPart::Hue(picker) | UiColorPickerPart::Alpha(picker) | UiColorPickerPart::Hex(picker) => *picker,
+        }
+    }
+    /// Returns the name of the part subnode.
+    fn name(&self) -> &'static str {
+        match self {
+            UiColorPickerPart::Field(_) => "Field",
+            UiColorPickerPart::Hue(_) => "Hue",
+            UiColorPickerPart::Alpha(_) => "Alpha",
+            UiColorPickerPart::Hex(_) => "Hex",
+        }
+    }
+    /// Returns the color after picking the point of the part, normalized from `0.0` to `1.0` with `y` pointing down.
+    fn pick(&self, mut color: Hsva, point: Vec2) -> Hsva {
+        match self {
+            UiColorPickerPart::Field(_) => { color.saturation = point.x; color.value = 1.0 - point.y; },
+            UiColorPickerPart::Hue(_) => color.hue = point.y * 360.0,
+            UiColorPickerPart::Alpha(_) => color.alpha = point.x,
+            UiColorPickerPart::Hex(_) => {},
+        }
+        color
+    }
+    /// Returns the layout of the marker showing the value on the part.
+    fn marker_layout(&self, value: &UiColorPickerValue) -> UiLayout {
+        let color = value.color;
+        match self {
+            UiColorPickerPart::Field(_) => UiLayout::window().pos(Rl((color.saturation * 100.0, (1.0 - color.value) * 100.0))).anchor(Anchor::Center).size(Ab(12.0)).pack::<Base>(),
+            UiColorPickerPart::Hue(_) => UiLayout::window().pos(Rl((50.0, color.hue / 3.6))).anchor(Anchor::Center).width(Rl(100.0)).height(Ab(4.0)).pack::<Base>(),
+            UiColorPickerPart::Alpha(_) | UiColorPickerPart::Hex(_) => UiLayout::window().pos(Rl((color.alpha * 100.0, 50.0))).anchor(Anchor::Center).width(Ab(4.0)).height(Rl(100.0)).pack::<Base>(),
+        }
+    }
+}
+
+/// Marker node showing the value on [`UiColorPickerPart`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiColorPickerMarker(pub UiColorPickerPart);
+
+/// This event is sent when the color of [`UiColorPicker`] is picked by the user.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct ColorPicked {
+    /// The prefab node of the picker
+    pub target: Entity,
+    /// The picked color
+    pub color: Color,
+}
+
+/// Internal 2D material used to render the gradients of [`UiColorPicker`].
+#[derive(Asset, AsBindGroup, Debug, Default, Clone, Reflect)]
+pub struct UiColorPickerMaterial2d {
+    /// Hue from `0.0` to `1.0`, saturation, value and alpha.
+    #[uniform(0)]
+    pub hsva: Vec4,
+    #[uniform(0)]
+    pub size: Vec2,
+    /// `0` - saturation and value field, `1` - hue strip, `2` - alpha slider.
+    #[uniform(0)]
+    pub mode: u32,
+}
+impl UiColorPickerMaterial2d {
+    /// Creates new material for the part showing the color.
+    pub fn new(part: &UiColorPickerPart, color: Hsva, size: Vec2) -> Self {
+        let mode = match part {
+            UiColorPickerPart::Field(_) => 0,
+            UiColorPickerPart::Hue(_) => 1,
+            UiColorPickerPart::Alpha(_) | UiColorPickerPart::Hex(_) => 2,
+        };
+        UiColorPickerMaterial2d { hsva: Vec4::new(color.hue / 360.0, color.saturation, color.value, color.alpha), size, mode }
+    }
+}
+impl Material2d for UiColorPickerMaterial2d {
+    fn fragment_shader() -> ShaderRef {
+        COLOR_PICKER_2D_SHADER_HANDLE.into()
+    }
+}
+impl UiMaterialSync for UiColorPickerMaterial2d {
+    fn set_dimension(&mut self, size: Vec2) {
+        self.size = size;
+    }
+}
+
+/// This system creates the gradient materials of newly spawned [`UiColorPickerPart`] nodes.
+fn color_picker_insert_material(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<UiColorPickerMaterial2d>>,
+    pickers: Query<&UiColorPickerValue>,
+    query: Query<(Entity, &UiColorPickerPart, &Dimension), Added<UiColorPickerPart>>,
+) {
+    for (entity, part, dimension) in &query {
+        if matches!(part, UiColorPickerPart::Hex(_)) { continue }
+        let Ok(value) = pickers.get(part.picker()) else { continue };
+        // The mesh is reconstructed from dimension once added
+        commands.entity(entity).insert((
+            Mesh2dHandle::default(),
+            materials.add(UiColorPickerMaterial2d::new(part, value.color, dimension.size)),
+        ));
+    }
+}
+
+/// This system picks the color of [`UiColorPicker`] from the pointer pressing or dragging over its parts.
+fn color_picker_input(
+    mut down: EventReader<Pointer<Down>>,
+    mut drag: EventReader<Pointer<Drag>>,
+    mut cameras: Local<HashMap<PointerId, Entity>>,
+    raycast: UiRaycast,
+    parts: Query<(&UiColorPickerPart, &Dimension, Has<Element>)>,
+    mut pickers: Query<&mut UiColorPickerValue, Without<UiDisabled>>,
+    mut picked: EventWriter<ColorPicked>,
+) {
+    // Drag events don't carry the camera, so it is remembered from the press
+    let mut points = Vec::new();
+    for event in down.read() {
+        if event.button != PointerButton::Primary || !parts.contains(event.target) { continue }
+        cameras.insert(event.pointer_id, event.hit.camera);
+        points.push((event.target, event.hit.camera, event.pointer_location.position));
+    }
+    for event in drag.read() {
+        if event.button != PointerButton::Primary { continue }
+        let Some(camera) = cameras.get(&event.pointer_id) else { continue };
+        points.push((event.target, *camera, event.pointer_location.position));
+    }
+
+    for (target, camera, position) in points {
+        let Ok((part, dimension, is_element)) = parts.get(target) else { continue };
+        let Ok(mut value) = pickers.get_mut(part.picker()) else { continue };
+        let Some(local) = raycast.viewport_to_root(camera, position, target) else { continue };
+        if dimension.size.cmple(Vec2::ZERO).any() { continue }
+
+        // Elements are centered on their transform
+        let offset = if is_element { Vec2::splat(0.5) } else { Vec2::ZERO };
+        let point = (local / dimension.size + offset).clamp(Vec2::ZERO, Vec2::ONE);
+        let color = part.pick(value.color, point);
+        if value.color != color {
+            #[cfg(feature = "verbose")]
+            info!("{} {} - Color picked", "--".yellow(), "ELEMENT".red());
+            value.color = color;
+            picked.send(ColorPicked { target: part.picker(), color: color.into() });
+        }
+    }
+}
+
+/// This system picks the color of [`UiColorPicker`] from the submitted hex codes and keeps the hex input in sync with the color.
+fn color_picker_hex(
+    mut submitted: EventReader<UiTextInputSubmitted>,
+    mut picked: EventWriter<ColorPicked>,
+    mut inputs: Query<(&UiColorPickerPart, &mut UiTextInput)>,
+    mut pickers: Query<(&mut UiColorPickerValue, Has<UiDisabled>)>,
+) {
+    for event in submitted.read() {
+        let Ok((part, mut input)) = inputs.get_mut(event.target) else { continue };
+        let Ok((mut value, disabled)) = pickers.get_mut(part.picker()) else { continue };
+        let Ok(srgba) = Srgba::hex(event.value.trim()) else {
+            // Invalid codes are replaced by the current color
+            input.set_value(value.hex());
+            continue;
+        };
+        if disabled { continue }
+        let mut color = Hsva::from(srgba);
+        // Gray colors keep the hue, so the field does not jump back to red
+        if color.saturation == 0.0 || color.value == 0.0 { color.hue = value.color.hue; }
+        if value.color != color {
+            value.color = color;
+            picked.send(ColorPicked { target: part.picker(), color: color.into() });
+        }
+    }
+
+    for (part, mut input) in &mut inputs {
+        if input.focused { continue }
+        let Ok((value, _)) = pickers.get(part.picker()) else { continue };
+        let hex = value.hex();
+        if input.value != hex { input.set_value(hex); }
+    }
+}
+
+/// This system moves the [`UiColorPickerMarker`] nodes and updates the gradient materials when the color changes.
+fn color_picker_update(
+    mut materials: ResMut<Assets<UiColorPickerMaterial2d>>,
+    pickers: Query<Ref<UiColorPickerValue>>,
+    parts: Query<(&UiColorPickerPart, &Handle<UiColorPickerMaterial2d>)>,
+    mut markers: Query<(Ref<UiColorPickerMarker>, &mut UiLayout)>,
+) {
+    for (marker, mut layout) in &mut markers {
+        let Ok(value) = pickers.get(marker.0.picker()) else { continue };
+        if !value.is_changed() && !marker.is_added() { continue }
+        let new = marker.0.marker_layout(&value);
+        if layout.layout != new.layout { layout.layout = new.layout; }
+    }
+
+    for (part, handle) in &parts {
+        let Ok(value) = pickers.get(part.picker()) else { continue };
+        if !value.is_changed() { continue }
+        let hsva = Vec4::new(value.color.hue / 360.0, value.color.saturation, value.color.value, value.color.alpha);
+        if materials.get(handle).is_some_and(|material| material.hsva != hsva) {
+            if let Some(material) = materials.get_mut(handle) { material.hsva = hsva }
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiColorPicker`] logic and materials.
+pub struct ColorPickerPlugin;
+impl Plugin for ColorPickerPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(app, COLOR_PICKER_2D_SHADER_HANDLE, "../shaders/color_picker_2d.wgsl", Shader::from_wgsl);
+
+        let schedule = UiSchedule::of(app);
+        app
+            .add_event::<ColorPicked>()
+            .register_ui_material::<UiColorPickerMaterial2d>()
+            .add_plugins(Material2dPlugin::<UiColorPickerMaterial2d>::default())
+            .add_systems(Update, (color_picker_input, color_picker_hex, color_picker_update).chain().after(text_input_typing).before(UiSystems::Modify))
+            .add_systems(schedule, color_picker_insert_material.in_set(UiSystems::Process).after(UiSystems::Fetch));
+    }
+}
@@ -0,0 +1,145 @@
+use crate::*;
+
+
+// #==============#
+// #=== PREFAB ===#
+
+/// Trait for reusable subtrees. Implement it once for a struct holding the parameters of the widget,
+/// then spawn as many instances as you need with [`UiSpawnPrefabExt::spawn_prefab`].
+/// ## 📌 Note
+/// All entities must be spawned through the given [`ChildBuilder`] with paths starting with the given link,
+/// so they become children of the [`UiTree`] entity and subnodes of the prefab node.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// struct MyButton {
+///     label: &'static str,
+/// }
+/// impl UiPrefabTrait for MyButton {
+///     fn spawn(self, ui: &mut ChildBuilder, link: UiLink) -> Entity {
+///         ui.spawn((
+///             link.add("Text"),
+///             UiLayout::window().center().pack::<Base>(),
+///             UiText2dBundle { text: Text::from_section(self.label, default()), ..default() },
+///         ));
+///         ui.spawn((
+///             link,
+///             UiLayout::window_full().pack::<Base>(),
+///             UiZoneBundle::default(),
+///             UiAnimator::<Hover>::new().receiver(true),
+///         )).id()
+///     }
+/// }
+///
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu"))).with_children(|ui| {
+///     ui.spawn_prefab(UiLink::path("Play"), MyButton { label: "Play" });
+///     ui.spawn_prefab(UiLink::path("Quit"), MyButton { label: "Quit" });
+/// });
+/// # }
+/// ```
+pub trait UiPrefabTrait<T: Component = MainUi> {
+    /// Spawns the prefab at the link and returns the entity of the prefab node.
+    fn spawn(self, ui: &mut ChildBuilder, link: UiLink<T>) -> Entity;
+}
+
+/// **Ui prefab** - Prefab defined by a function, for widgets that don't need their own struct.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// fn separator() -> UiPrefab {
+///     UiPrefab::new(|ui, link| {
+///         ui.spawn((link, UiLayout::window().width(Rl(100.0)).height(Ab(2.0)).pack::<Base>())).id()
+///     })
+/// }
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu"))).with_children(|ui| {
+/// ui.spawn_prefab(UiLink::path("Separator"), separator());
+/// # });
+/// # }
+/// ```
+pub struct UiPrefab<T: Component = MainUi> {
+    builder: Box<dyn FnOnce(&mut ChildBuilder, UiLink<T>) -> Entity + Send + Sync>,
+}
+impl <T: Component> UiPrefab<T> {
+    /// Creates new prefab from the function spawning it.
+    pub fn new(builder: impl FnOnce(&mut ChildBuilder, UiLink<T>) -> Entity + Send + Sync + 'static) -> Self {
+        UiPrefab { builder: Box::new(builder) }
+    }
+}
+impl <T: Component> UiPrefabTrait<T> for UiPrefab<T> {
+    fn spawn(self, ui: &mut ChildBuilder, link: UiLink<T>) -> Entity {
+        (self.builder)(ui, link)
+    }
+}
+
+/// Extension trait for spawning [`UiPrefabTrait`] instances.
+pub trait UiSpawnPrefabExt {
+    /// Spawns the prefab at the link and returns the entity of the prefab node.
+    fn spawn_prefab<T: Component>(&mut self, link: UiLink<T>, prefab: impl UiPrefabTrait<T>) -> Entity;
+}
+impl UiSpawnPrefabExt for ChildBuilder<'_> {
+    fn spawn_prefab<T: Component>(&mut self, link: UiLink<T>, prefab: impl UiPrefabTrait<T>) -> Entity {
+        prefab.spawn(self, link)
+    }
+}
+
+
+// #=============#
+// #=== MACRO ===#
+
+/// Declarative tree building. Expands nested node definitions into spawn calls on a [`ChildBuilder`],
+/// nesting the paths of the links for you.
+///
+/// Every node is written as `"Name": (components...)`, optionally followed by `[observers...]`
+/// and `{ subnodes... }`. Nodes are separated by commas. The marker of the links defaults to [`MainUi`],
+/// use `ui!(builder, MyUi => { ... })` for other trees.
+///
+/// Observers are added through [`UiObserveExt::observe_ui`], so they are kept when the node is cloned.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// use bevy_lunex::ui;
+///
+/// fn on_click(trigger: Trigger<Pointer<Click>>) {
+///     info!("Clicked {:?}", trigger.entity());
+/// }
+///
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu"))).with_children(|ui| {
+///     ui!(ui => {
+///         "Root": (UiLayout::window_full().pack::<Base>()) {
+///             "Play": (UiLayout::window().size(Rl(50.0)).pack::<Base>(), UiZoneBundle::default()) [on_click],
+///             "Quit": (UiLayout::window().y(Rl(50.0)).size(Rl(50.0)).pack::<Base>(), UiZoneBundle::default()) [on_click],
+///         }
+///     });
+/// });
+/// # }
+/// ```
+#[macro_export]
+macro_rules! ui {
+    ($ui:ident => { $($body:tt)* }) => {
+        $crate::ui!(@nodes $ui, $crate::prelude::MainUi, None; $($body)*)
+    };
+    ($ui:ident, $marker:ty => { $($body:tt)* }) => {
+        $crate::ui!(@nodes $ui, $marker, None; $($body)*)
+    };
+    (@nodes $ui:ident, $marker:ty, $parent:expr; ) => {};
+    (@nodes $ui:ident, $marker:ty, $parent:expr; $name:literal : $bundle:tt $([ $($observer:expr),* $(,)? ])? $({ $($children:tt)* })? $(, $($rest:tt)*)?) => {
+        {
+            let parent: Option<&$crate::UiLink<$marker>> = $parent;
+            let link = match parent {
+                Some(parent) => parent.add($name),
+                None => $crate::UiLink::<$marker>::path($name),
+            };
+            #[allow(unused_mut)]
+            let mut entity = $ui.spawn((link.clone(), $bundle));
+            $($( $crate::UiObserveExt::observe_ui(&mut entity, $observer); )*)?
+            $( $crate::ui!(@nodes $ui, $marker, Some(&link); $($children)*); )?
+        }
+        $( $crate::ui!(@nodes $ui, $marker, $parent; $($rest)*); )?
+    };
+}
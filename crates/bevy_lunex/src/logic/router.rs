@@ -0,0 +1,234 @@
+use crate::*;
+use bevy::utils::HashMap;
+
+
+// #==============#
+// #=== ROUTER ===#
+
+/// How a registered screen is brought to life.
+enum UiScreen {
+    /// Screen spawned by the function when navigated to and despawned when left.
+    Spawn(Box<dyn Fn(&mut Commands) -> Entity + Send + Sync>),
+    /// Screen that already exists and is only shown and hidden.
+    Entity(Entity),
+}
+
+/// Navigation requested by the router methods, applied by [`router_navigate`].
+#[derive(Debug, Clone, PartialEq)]
+enum UiRouteAction {
+    Push(String),
+    Pop,
+    Replace(String),
+}
+
+/// **Ui router** - Navigation stack of named screens for menu flows like *Main menu → Settings → Keybinds*.
+/// Register the screens once, then navigate with [`UiRouter::push`], [`UiRouter::pop`] and [`UiRouter::replace`].
+/// The navigation is applied in the next [`UiSystems::Modify`].
+///
+/// Screens registered with [`UiRouter::register`] are spawned by the function when navigated to. When they
+/// are popped or replaced, [`UiOutro`] is inserted on all their nodes with [`UiLayout<Outro>`] and the screen is despawned
+/// once the animations finish. Screens covered by a push are hidden and get [`UiIntro`] on all nodes with [`UiLayout<Intro>`]
+/// when they are revealed again. Screens registered with [`UiRouter::register_entity`] are never despawned, only hidden.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// #[derive(Component)]
+/// struct MainMenuRoute;
+/// #[derive(Component)]
+/// struct SettingsRoute;
+///
+/// fn setup(mut router: ResMut<UiRouter>) {
+///     router.register("main_menu", |commands: &mut Commands| commands.spawn(MainMenuRoute).id());
+///     router.register("settings", |commands: &mut Commands| commands.spawn(SettingsRoute).id());
+///     router.push("main_menu");
+/// }
+///
+/// fn open_settings(_: Trigger<Pointer<Click>>, mut router: ResMut<UiRouter>) {
+///     router.push("settings");
+/// }
+///
+/// fn go_back(_: Trigger<Pointer<Click>>, mut router: ResMut<UiRouter>) {
+///     router.pop();
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct UiRouter {
+    screens: HashMap<String, UiScreen>,
+    stack: Vec<(String, Entity)>,
+    pending: Vec<UiRouteAction>,
+}
+impl UiRouter {
+    /// Registers a screen spawned by the function. The function returns the root entity of the screen.
+    pub fn register(&mut self, name: impl Into<String>, spawn: impl Fn(&mut Commands) -> Entity + Send + Sync + 'static) -> &mut Self {
+        self.screens.insert(name.into(), UiScreen::Spawn(Box::new(spawn)));
+        self
+    }
+    /// Registers an already spawned screen. It is shown when navigated to and hidden when left.
+    pub fn register_entity(&mut self, name: impl Into<String>, entity: Entity) -> &mut Self {
+        self.screens.insert(name.into(), UiScreen::Entity(entity));
+        self
+    }
+    /// Navigates to the screen, keeping the current one in the history.
+    pub fn push(&mut self, name: impl Into<String>) {
+        self.pending.push(UiRouteAction::Push(name.into()));
+    }
+    /// Leaves the current screen and returns to the previous one.
+    pub fn pop(&mut self) {
+        self.pending.push(UiRouteAction::Pop);
+    }
+    /// Navigates to the screen, replacing the current one in the history.
+    pub fn replace(&mut self, name: impl Into<String>) {
+        self.pending.push(UiRouteAction::Replace(name.into()));
+    }
+    /// Returns the name of the current screen.
+    pub fn current(&self) -> Option<&str> {
+        self.stack.last().map(|(name, _)| name.as_str())
+    }
+    /// Returns the root entity of the current screen.
+    pub fn current_entity(&self) -> Option<Entity> {
+        self.stack.last().map(|(_, entity)| *entity)
+    }
+    /// Returns the names of the screens in the history, the current screen is last.
+    pub fn history(&self) -> impl Iterator<Item = &str> {
+        self.stack.iter().map(|(name, _)| name.as_str())
+    }
+    /// Returns `true` if there is a screen to return to with [`UiRouter::pop`].
+    pub fn can_pop(&self) -> bool {
+        self.stack.len() > 1
+    }
+    /// Returns `true` if the screen is registered.
+    pub fn is_registered(&self, name: &str) -> bool {
+        self.screens.contains_key(name)
+    }
+}
+
+/// This event is sent when [`UiRouter`] changes the current screen.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct UiRouteChanged {
+    /// Screen that was current before the navigation.
+    pub from: Option<String>,
+    /// Screen that is current after the navigation.
+    pub to: Option<String>,
+}
+
+/// Marker for screens that were left and are despawned once their [`UiOutro`] animations finish.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiScreenLeaving;
+
+/// Plays [`UiOutro`] on all nodes of the screen with [`UiLayout<Outro>`] and marks it with [`UiScreenLeaving`].
+pub(crate) fn leave_screen(commands: &mut Commands, descendants: &Query<&Children>, outros: &Query<(), With<UiLayout<Outro>>>, entity: Entity) {
+    let Some(mut entity_commands) = commands.get_entity(entity) else { return };
+    entity_commands.insert(UiScreenLeaving);
+    for node in descendants.iter_descendants(entity) {
+        if outros.contains(node) { commands.entity(node).insert(UiOutro::default()); }
+    }
+}
+
+/// Plays [`UiIntro`] on all nodes of the screen with [`UiLayout<Intro>`].
+pub(crate) fn reveal_screen(commands: &mut Commands, descendants: &Query<&Children>, intros: &Query<(), With<UiLayout<Intro>>>, entity: Entity) {
+    for node in descendants.iter_descendants(entity) {
+        if intros.contains(node) { commands.entity(node).insert(UiIntro::default()); }
+    }
+}
+
+/// This system applies the navigation requested on [`UiRouter`].
+pub fn router_navigate(
+    mut commands: Commands,
+    mut router: ResMut<UiRouter>,
+    descendants: Query<&Children>,
+    outros: Query<(), With<UiLayout<Outro>>>,
+    intros: Query<(), With<UiLayout<Intro>>>,
+    mut visibility: Query<&mut Visibility>,
+    mut changed: EventWriter<UiRouteChanged>,
+) {
+    if router.pending.is_empty() { return }
+    let from = router.current().map(|name| name.to_string());
+    let router = &mut *router;
+
+    for action in std::mem::take(&mut router.pending) {
+        let (leaving, covered, entering) = match action {
+            UiRouteAction::Push(name) => (None, router.stack.last().cloned(), Some(name)),
+            UiRouteAction::Replace(name) => (router.stack.pop(), None, Some(name)),
+            UiRouteAction::Pop => (router.stack.pop(), None, None),
+        };
+
+        if let Some(name) = &entering {
+            if !router.screens.contains_key(name) {
+                warn!("Screen {} is not registered in UiRouter", name);
+                if let Some(leaving) = leaving { router.stack.push(leaving); }
+                continue;
+            }
+        }
+
+        // Leave the screen
+        if let Some((name, entity)) = leaving {
+            match router.screens.get(&name) {
+                Some(UiScreen::Entity(_)) => {
+                    if let Ok(mut visibility) = visibility.get_mut(entity) { *visibility = Visibility::Hidden; }
+                },
+                _ => leave_screen(&mut commands, &descendants, &outros, entity),
+            }
+        }
+
+        // Cover the screen
+        if let Some((_, entity)) = covered {
+            if let Ok(mut visibility) = visibility.get_mut(entity) { *visibility = Visibility::Hidden; }
+        }
+
+        // Enter the screen
+        match entering {
+            Some(name) => {
+                let entity = match &router.screens[&name] {
+                    UiScreen::Spawn(spawn) => spawn(&mut commands),
+                    UiScreen::Entity(entity) => {
+                        if let Ok(mut visibility) = visibility.get_mut(*entity) { *visibility = Visibility::Inherited; }
+                        *entity
+                    },
+                };
+                router.stack.push((name, entity));
+            },
+            // Reveal the previous screen
+            None => if let Some((_, entity)) = router.stack.last() {
+                if let Ok(mut visibility) = visibility.get_mut(*entity) { *visibility = Visibility::Inherited; }
+                reveal_screen(&mut commands, &descendants, &intros, *entity);
+            },
+        }
+    }
+
+    let to = router.current().map(|name| name.to_string());
+    if from != to {
+        #[cfg(feature = "verbose")]
+        info!("{} - Navigated from {:?} to {:?}", "ROUTER".red().bold(), from, to);
+        changed.send(UiRouteChanged { from, to });
+    }
+}
+
+/// This system despawns left screens once none of their nodes is playing [`UiOutro`].
+pub fn router_despawn_left(
+    mut commands: Commands,
+    leaving: Query<Entity, With<UiScreenLeaving>>,
+    descendants: Query<&Children>,
+    outros: Query<(), With<UiOutro>>,
+) {
+    for entity in &leaving {
+        if descendants.iter_descendants(entity).any(|node| outros.contains(node)) { continue }
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiRouter`] and its systems.
+pub struct RouterPlugin;
+impl Plugin for RouterPlugin {
+    fn build(&self, app: &mut App) {
+        let schedule = UiSchedule::of(app);
+        app
+            .init_resource::<UiRouter>()
+            .add_event::<UiRouteChanged>()
+            .add_systems(schedule, (router_despawn_left, router_navigate).chain().in_set(UiSystems::Modify).before(UiSystems::Send));
+    }
+}
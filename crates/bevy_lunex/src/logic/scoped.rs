@@ -0,0 +1,115 @@
+use crate::*;
+
+
+// #====================#
+// #=== STATE SCOPED ===#
+
+/// What happens with [`UiStateScoped`] entities when their state is exited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UiScopeMode {
+    /// Plays [`UiOutro`] on all nodes with [`UiLayout<Outro>`] and despawns the entity once the animations finish.
+    #[default]
+    Despawn,
+    /// Hides the entity and shows it again when the state is re-entered, playing [`UiIntro`] on all nodes with [`UiLayout<Intro>`].
+    Hide,
+}
+
+/// **Ui state scoped** - Ties the entity to a Bevy [`States`] value. Insert it on the [`UiTree`] entity or the route entity
+/// owning it. Unlike [`StateScoped`], the outro animations of the nodes are played before the entity is despawned.
+/// Requires [`UiStateScopePlugin`] for the state type.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+/// enum GameState {
+///     #[default]
+///     MainMenu,
+///     InGame,
+/// }
+///
+/// fn spawn_menu(mut commands: Commands) {
+///     commands.spawn((
+///         UiTreeBundle::<MainUi>::from(UiTree::new2d("MainMenu")),
+///         UiStateScoped::despawn(GameState::MainMenu),
+///     )).with_children(|ui| {
+///         ui.spawn((
+///             UiLink::<MainUi>::path("Panel"),
+///             UiLayout::window_full().pack::<Base>(),
+///             UiLayout::window_full().y(Rl(100.0)).pack::<Outro>(),
+///         ));
+///     });
+/// }
+///
+/// # fn build(app: &mut App) {
+/// app.add_plugins(UiStateScopePlugin::<GameState>::new())
+///     .add_systems(OnEnter(GameState::MainMenu), spawn_menu);
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiStateScoped<S: States> {
+    /// The state the entity belongs to.
+    pub state: S,
+    /// What happens when the state is exited.
+    pub mode: UiScopeMode,
+}
+impl <S: States> UiStateScoped<S> {
+    /// Creates new scope despawning the entity when the state is exited.
+    pub fn despawn(state: S) -> Self {
+        UiStateScoped { state, mode: UiScopeMode::Despawn }
+    }
+    /// Creates new scope hiding the entity when the state is exited.
+    pub fn hide(state: S) -> Self {
+        UiStateScoped { state, mode: UiScopeMode::Hide }
+    }
+}
+
+/// This system leaves and reveals [`UiStateScoped`] entities on state transitions.
+pub fn state_scoped_transition<S: States>(
+    mut commands: Commands,
+    mut transitions: EventReader<StateTransitionEvent<S>>,
+    mut scoped: Query<(Entity, &UiStateScoped<S>, Option<&mut Visibility>), Without<UiScreenLeaving>>,
+    descendants: Query<&Children>,
+    outros: Query<(), With<UiLayout<Outro>>>,
+    intros: Query<(), With<UiLayout<Intro>>>,
+) {
+    for transition in transitions.read() {
+        if transition.exited == transition.entered { continue }
+        for (entity, scope, visibility) in &mut scoped {
+            if transition.exited.as_ref() == Some(&scope.state) {
+                match scope.mode {
+                    UiScopeMode::Despawn => leave_screen(&mut commands, &descendants, &outros, entity),
+                    UiScopeMode::Hide => if let Some(mut visibility) = visibility {
+                        *visibility = Visibility::Hidden;
+                    },
+                }
+            } else if transition.entered.as_ref() == Some(&scope.state) && scope.mode == UiScopeMode::Hide {
+                if let Some(mut visibility) = visibility { *visibility = Visibility::Inherited; }
+                reveal_screen(&mut commands, &descendants, &intros, entity);
+            }
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin handling [`UiStateScoped`] entities for the specified state type.
+pub struct UiStateScopePlugin<S: States>(PhantomData<S>);
+impl <S: States> UiStateScopePlugin<S> {
+    pub fn new() -> Self {
+        UiStateScopePlugin::<S>(PhantomData)
+    }
+}
+impl <S: States> Default for UiStateScopePlugin<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl <S: States> Plugin for UiStateScopePlugin<S> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<RouterPlugin>() { app.add_plugins(RouterPlugin); }
+        app.add_systems(Update, state_scoped_transition::<S>.in_set(UiSystems::Modify).before(router_despawn_left));
+    }
+}
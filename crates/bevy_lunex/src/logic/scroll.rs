@@ -0,0 +1,503 @@
+use crate::*;
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use lunex_engine::*;
+
+
+// #===================#
+// #=== SCROLL AREA ===#
+
+/// **Ui scroll area** - Makes the node scroll its subnodes with the mouse wheel or by dragging.
+/// Released drags keep their momentum and slow down with friction, scrolling past the edges is pulled back like a rubber band.
+/// The scroll range is the bounding box of the direct subnodes.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let area = UiScrollArea::vertical().friction(6.0).snap(true); // -> Snaps to the closest subnode once it slows down
+/// let area = UiScrollArea::new().rubber_band(0.0);              // -> Both axes, hard stop at the edges
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiScrollArea {
+    /// Current scroll offset in layout units.
+    pub offset: Vec2,
+    /// Current scroll speed in layout units per second.
+    pub velocity: Vec2,
+    /// If the content can scroll horizontally.
+    pub horizontal: bool,
+    /// If the content can scroll vertically.
+    pub vertical: bool,
+    /// How quickly the momentum fades, higher values stop sooner.
+    pub friction: f32,
+    /// How strongly overscroll is pulled back. Set to `0.0` to disable overscroll.
+    pub rubber_band: f32,
+    /// Maximum distance the content can be pulled past the edges.
+    pub overscroll: f32,
+    /// If the scroll should settle on the closest subnode.
+    pub snap: bool,
+    /// Distance scrolled by one line of the mouse wheel.
+    pub wheel_speed: f32,
+    /// Maximum scroll offset, computed from the subnodes.
+    max: Vec2,
+    /// Scroll offsets aligning each subnode with the area.
+    snaps: Vec<Vec2>,
+    /// If the content is being dragged.
+    dragging: bool,
+    /// Drag distance received this frame.
+    drag_delta: Vec2,
+    /// Number of hovered nodes inside this area.
+    hovered: usize,
+}
+impl Default for UiScrollArea {
+    fn default() -> Self {
+        UiScrollArea {
+            offset: Vec2::ZERO,
+            velocity: Vec2::ZERO,
+            horizontal: true,
+            vertical: true,
+            friction: 4.0,
+            rubber_band: 12.0,
+            overscroll: 100.0,
+            snap: false,
+            wheel_speed: 40.0,
+            max: Vec2::ZERO,
+            snaps: Vec::new(),
+            dragging: false,
+            drag_delta: Vec2::ZERO,
+            hovered: 0,
+        }
+    }
+}
+impl UiScrollArea {
+    /// Creates new scroll area scrolling on both axes.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Creates new scroll area scrolling only horizontally.
+    pub fn horizontal() -> Self {
+        UiScrollArea { vertical: false, ..Default::default() }
+    }
+    /// Creates new scroll area scrolling only vertically.
+    pub fn vertical() -> Self {
+        UiScrollArea { horizontal: false, ..Default::default() }
+    }
+    /// Replaces the friction with a new value.
+    pub fn friction(mut self, friction: f32) -> Self {
+        self.friction = friction;
+        self
+    }
+    /// Replaces the rubber band strength with a new value.
+    pub fn rubber_band(mut self, rubber_band: f32) -> Self {
+        self.rubber_band = rubber_band;
+        self
+    }
+    /// Replaces the overscroll with a new value.
+    pub fn overscroll(mut self, overscroll: f32) -> Self {
+        self.overscroll = overscroll;
+        self
+    }
+    /// Replaces the snap with a new value.
+    pub fn snap(mut self, snap: bool) -> Self {
+        self.snap = snap;
+        self
+    }
+    /// Replaces the wheel speed with a new value.
+    pub fn wheel_speed(mut self, wheel_speed: f32) -> Self {
+        self.wheel_speed = wheel_speed;
+        self
+    }
+    /// Returns the maximum scroll offset.
+    pub fn max(&self) -> Vec2 {
+        self.max
+    }
+    /// Returns `true` while the content is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+    /// Jumps to the offset and stops the momentum.
+    pub fn scroll_to(&mut self, offset: Vec2) {
+        self.offset = offset.clamp(Vec2::ZERO, self.max);
+        self.velocity = Vec2::ZERO;
+    }
+    /// Zeroes the disabled axes.
+    fn mask(&self, value: Vec2) -> Vec2 {
+        Vec2::new(
+            if self.horizontal { value.x } else { 0.0 },
+            if self.vertical { value.y } else { 0.0 },
+        )
+    }
+}
+
+/// Returns the scroll area containing the node, preferring the innermost one.
+fn find_scroll_area<T: Component>(target: Entity, links: &Query<&UiLink<T>>, areas: &Query<(Entity, &UiLink<T>), With<UiScrollArea>>) -> Option<Entity> {
+    let path = &links.get(target).ok()?.path;
+    areas.iter()
+        .filter(|(_, link)| path == &link.path || path.starts_with(&format!("{}/", link.path)))
+        .max_by_key(|(_, link)| link.path.len())
+        .map(|(entity, _)| entity)
+}
+
+/// This system feeds the pointer input into [`UiScrollArea`].
+pub fn scroll_area_input<T: Component>(
+    mut over: EventReader<Pointer<Over>>,
+    mut out: EventReader<Pointer<Out>>,
+    mut drag_start: EventReader<Pointer<DragStart>>,
+    mut drag: EventReader<Pointer<Drag>>,
+    mut drag_end: EventReader<Pointer<DragEnd>>,
+    mut wheel: EventReader<MouseWheel>,
+    links: Query<&UiLink<T>>,
+    bars: Query<(), With<UiScrollbar>>,
+    areas: Query<(Entity, &UiLink<T>), With<UiScrollArea>>,
+    mut query: Query<&mut UiScrollArea>,
+) {
+    for event in over.read() {
+        let Some(area) = find_scroll_area(event.target, &links, &areas) else { continue };
+        if let Ok(mut area) = query.get_mut(area) { area.hovered += 1 }
+    }
+    for event in out.read() {
+        let Some(area) = find_scroll_area(event.target, &links, &areas) else { continue };
+        if let Ok(mut area) = query.get_mut(area) { area.hovered = area.hovered.saturating_sub(1) }
+    }
+    for event in drag_start.read() {
+        if event.button != PointerButton::Primary { continue }
+        if bars.contains(event.target) { continue }
+        let Some(area) = find_scroll_area(event.target, &links, &areas) else { continue };
+        if let Ok(mut area) = query.get_mut(area) {
+            area.dragging = true;
+            area.velocity = Vec2::ZERO;
+        }
+    }
+    for event in drag.read() {
+        if event.button != PointerButton::Primary { continue }
+        let Some(area) = find_scroll_area(event.target, &links, &areas) else { continue };
+        if let Ok(mut area) = query.get_mut(area) { area.drag_delta += event.delta }
+    }
+    for event in drag_end.read() {
+        if event.button != PointerButton::Primary { continue }
+        if bars.contains(event.target) { continue }
+        let Some(area) = find_scroll_area(event.target, &links, &areas) else { continue };
+        if let Ok(mut area) = query.get_mut(area) { area.dragging = false }
+    }
+    for event in wheel.read() {
+        for mut area in &mut query {
+            if area.hovered == 0 { continue }
+            let mut delta = Vec2::new(event.x, event.y);
+            if event.unit == MouseScrollUnit::Line { delta *= area.wheel_speed }
+            // Vertical wheel scrolls horizontal-only areas
+            if !area.vertical && delta.x == 0.0 { delta = Vec2::new(delta.y, 0.0) }
+            let offset = area.offset - area.mask(delta);
+            area.offset = offset.clamp(Vec2::ZERO, area.max);
+            area.velocity = Vec2::ZERO;
+        }
+    }
+}
+
+/// This system moves [`UiScrollArea`] by the drag and the momentum.
+pub fn scroll_area_physics(time: Res<Time>, mut query: Query<&mut UiScrollArea>) {
+    let dt = time.delta_seconds();
+    if dt <= 0.0 { return }
+
+    for mut area in &mut query {
+        // Avoid triggering the layout recompute when the area is idle
+        let area_ref = area.bypass_change_detection();
+        let previous = area_ref.offset;
+        let (min, max) = (Vec2::ZERO, area_ref.max);
+
+        if area_ref.dragging {
+            let mut delta = -area_ref.mask(area_ref.drag_delta);
+            area_ref.drag_delta = Vec2::ZERO;
+
+            // Resist the drag past the edges
+            let outside = area_ref.offset.cmplt(min) | area_ref.offset.cmpgt(max);
+            if area_ref.rubber_band > 0.0 { delta = Vec2::select(outside, delta * 0.5, delta) }
+            area_ref.offset += delta;
+            area_ref.velocity = area_ref.velocity.lerp(delta / dt, 0.5);
+        } else {
+            let target = area_ref.offset.clamp(min, max);
+            area_ref.offset += area_ref.velocity * dt;
+            area_ref.velocity *= (-area_ref.friction * dt).exp();
+
+            if area_ref.rubber_band <= 0.0 {
+                area_ref.offset = area_ref.offset.clamp(min, max);
+            } else if target != area_ref.offset {
+                // Pull back, stronger than the friction
+                let pull = 1.0 - (-area_ref.rubber_band * dt).exp();
+                area_ref.offset += (target - area_ref.offset) * pull;
+                area_ref.velocity *= 1.0 - pull;
+                if area_ref.offset.distance(target) < 0.5 { area_ref.offset = target }
+            } else if area_ref.snap && area_ref.velocity.length() < 100.0 {
+                // Settle on the closest subnode
+                let offset = area_ref.offset;
+                if let Some(snap) = area_ref.snaps.iter().copied().min_by(|a, b| a.distance(offset).total_cmp(&b.distance(offset))) {
+                    area_ref.offset += (snap - offset) * (1.0 - (-10.0 * dt).exp());
+                    area_ref.velocity = Vec2::ZERO;
+                    if area_ref.offset.distance(snap) < 0.5 { area_ref.offset = snap }
+                }
+            }
+            if area_ref.velocity.length() < 1.0 { area_ref.velocity = Vec2::ZERO }
+        }
+
+        let overscroll = Vec2::splat(area_ref.overscroll.max(0.0));
+        area_ref.offset = area_ref.offset.clamp(min - overscroll, max + overscroll);
+        if area_ref.offset != previous { area.set_changed() }
+    }
+}
+
+/// This system takes [`UiScrollArea`] offset and overwrites coresponding [`UiTree`] data.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn send_scroll_to_node<T:Component, N:Default + Component>(
+    mut uis: Query<(&mut UiTree<T, N>, &Children)>,
+    query: Query<(&UiLink<T>, &UiScrollArea), Changed<UiScrollArea>>,
+) {
+    for (mut ui, children) in &mut uis {
+        for child in children {
+            // If child matches
+            if let Ok((link, area)) = query.get(*child) {
+                // If node exists
+                if let Ok(node) = ui.borrow_node_mut(link.path.clone()) {
+                    //Should always be Some but just in case
+                    if let Some(container) = node.obtain_data_mut() {
+                        if container.scroll == area.offset { continue }
+                        #[cfg(feature = "verbose")]
+                        info!("{} {} - Received Scroll data", "->".blue(), link.path.yellow().bold());
+                        container.scroll = area.offset;
+                    }
+                }
+                ui.mark_dirty(link.path.as_str());
+            }
+        }
+    }
+}
+
+/// This system computes the scroll range and snap points of [`UiScrollArea`] from the computed subnodes.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn fetch_scroll_from_node<T:Component, N:Default + Component>(
+    uis: Query<(&UiTree<T, N>, &Children), Changed<UiTree<T, N>>>,
+    mut query: Query<(&UiLink<T>, &mut UiScrollArea)>,
+) {
+    for (ui, children) in &uis {
+        for child in children {
+            // If child matches
+            if let Ok((link, mut area)) = query.get_mut(*child) {
+                // If node exists
+                let Ok(node) = ui.borrow_node(link.path.clone()) else { continue };
+                let Some(node_data) = node.obtain_data() else { continue };
+
+                // Subnode positions as if the content was not scrolled
+                let origin = node_data.rectangle.pos.truncate() - node_data.scroll;
+                let mut max = Vec2::ZERO;
+                let mut snaps = Vec::new();
+                for (_, subnode) in &node.nodes {
+                    let Some(subnode_data) = &subnode.data else { continue };
+                    if subnode_data.display.is_collapsed() { continue }
+                    let position = subnode_data.rectangle.pos.truncate() - origin;
+                    max = max.max(position + subnode_data.rectangle.size);
+                    snaps.push(position);
+                }
+                let max = area.mask((max - node_data.rectangle.size).max(Vec2::ZERO));
+                let snaps: Vec<Vec2> = snaps.into_iter().map(|snap| area.mask(snap.clamp(Vec2::ZERO, max))).collect();
+
+                if area.max != max || area.snaps != snaps {
+                    let area = area.bypass_change_detection();
+                    area.max = max;
+                    area.snaps = snaps;
+                }
+            }
+        }
+    }
+}
+
+
+// #=================#
+// #=== SCROLLBAR ===#
+
+/// **Ui scrollbar** - Turns the node into the thumb of a scrollbar controlling the linked [`UiScrollArea`].
+/// The parent node of the thumb is the track. The thumb is sized by the visible part of the content and can be dragged,
+/// clicking on the track scrolls by one page. The layout of the thumb is overwritten.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Inventory"))).with_children(|ui| {
+/// let area = ui.spawn((
+///     UiLink::<MainUi>::path("Items"),
+///     UiLayout::window().size(Rl((95.0, 100.0))).pack::<Base>(),
+///     UiScrollArea::vertical(),
+/// )).id();
+///
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Track"),
+///     UiLayout::window().x(Rl(95.0)).size(Rl((5.0, 100.0))).pack::<Base>(),
+///     UiZoneBundle::default(),
+/// ));
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Track/Thumb"),
+///     UiZoneBundle::default(),
+///     UiScrollbar::vertical(area).auto_hide(true),
+/// ));
+/// # });
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiScrollbar {
+    /// The entity with [`UiScrollArea`] controlled by this scrollbar.
+    pub area: Entity,
+    /// If the scrollbar controls the vertical axis.
+    pub vertical: bool,
+    /// Minimal length of the thumb in percent of the track.
+    pub min_thumb: f32,
+    /// If the thumb and the track are hidden when the content fits.
+    pub auto_hide: bool,
+}
+impl UiScrollbar {
+    /// Creates new scrollbar controlling the vertical axis of the area.
+    pub fn vertical(area: Entity) -> Self {
+        UiScrollbar { area, vertical: true, min_thumb: 10.0, auto_hide: false }
+    }
+    /// Creates new scrollbar controlling the horizontal axis of the area.
+    pub fn horizontal(area: Entity) -> Self {
+        UiScrollbar { vertical: false, ..UiScrollbar::vertical(area) }
+    }
+    /// Replaces the minimal thumb length with a new value.
+    pub fn min_thumb(mut self, min_thumb: f32) -> Self {
+        self.min_thumb = min_thumb;
+        self
+    }
+    /// Replaces the auto hide with a new value.
+    pub fn auto_hide(mut self, auto_hide: bool) -> Self {
+        self.auto_hide = auto_hide;
+        self
+    }
+    /// Returns the value of the controlled axis.
+    fn axis(&self, value: Vec2) -> f32 {
+        if self.vertical { value.y } else { value.x }
+    }
+    /// Returns the vector with the value on the controlled axis.
+    fn on_axis(&self, value: f32) -> Vec2 {
+        if self.vertical { Vec2::new(0.0, value) } else { Vec2::new(value, 0.0) }
+    }
+}
+
+/// Returns the path of the parent node.
+fn parent_path(path: &str) -> &str {
+    path.rsplit_once('/').map(|(parent, _)| parent).unwrap_or("")
+}
+
+/// This system scrolls [`UiScrollArea`] by dragging [`UiScrollbar`] thumbs and clicking on their tracks.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn scrollbar_input<T:Component, N:Default + Component>(
+    mut drag: EventReader<Pointer<Drag>>,
+    mut click: EventReader<Pointer<Click>>,
+    raycast: UiRaycast,
+    uis: Query<(Entity, &UiTree<T, N>, &Children)>,
+    nodes: Query<(&UiLink<T>, &Dimension)>,
+    bars: Query<(&UiLink<T>, &UiScrollbar)>,
+    mut areas: Query<&mut UiScrollArea>,
+) {
+    // Returns the length of the track and the thumb on the axis
+    let lengths = |children: &Children, link: &UiLink<T>, bar: &UiScrollbar| {
+        let track = parent_path(&link.path);
+        let track = children.iter().filter_map(|child| nodes.get(*child).ok()).find(|(node, _)| node.path == track)?.1;
+        let thumb = children.iter().filter_map(|child| nodes.get(*child).ok()).find(|(node, _)| node.path == link.path)?.1;
+        Some((bar.axis(track.size), bar.axis(thumb.size)))
+    };
+
+    for event in drag.read() {
+        if event.button != PointerButton::Primary { continue }
+        let Ok((link, bar)) = bars.get(event.target) else { continue };
+        let Some((_, _, children)) = uis.iter().find(|(_, _, children)| children.contains(&event.target)) else { continue };
+        let Some((track, thumb)) = lengths(children, link, bar) else { continue };
+        let Ok(mut area) = areas.get_mut(bar.area) else { continue };
+
+        // Moving the thumb over the free part of the track scrolls over the whole content
+        let free = track - thumb;
+        if free <= 0.0 { continue }
+        let offset = area.offset + bar.on_axis(bar.axis(event.delta) * bar.axis(area.max) / free);
+        area.scroll_to(offset);
+    }
+
+    for event in click.read() {
+        if event.button != PointerButton::Primary { continue }
+        let Some((tree, ui, children)) = uis.iter().find(|(_, _, children)| children.contains(&event.target)) else { continue };
+        let Ok((track, _)) = nodes.get(event.target) else { continue };
+        let Some(cursor) = raycast.viewport_to_root(event.hit.camera, event.pointer_location.position, tree) else { continue };
+
+        for child in children {
+            let Ok((link, bar)) = bars.get(*child) else { continue };
+            if parent_path(&link.path) != track.path { continue }
+            let Some(thumb) = ui.borrow_node(link.path.clone()).ok().and_then(|node| node.obtain_data()).map(|container| container.rectangle) else { continue };
+            let Ok((area_link, _)) = nodes.get(bar.area) else { continue };
+            let Some(page) = ui.borrow_node(area_link.path.clone()).ok().and_then(|node| node.obtain_data()).map(|container| bar.axis(container.rectangle.size)) else { continue };
+            let Ok(mut area) = areas.get_mut(bar.area) else { continue };
+
+            // Page towards the click
+            let direction = if bar.axis(cursor) < bar.axis(thumb.pos.truncate()) { -1.0 } else { 1.0 };
+            let offset = area.offset + bar.on_axis(page * direction);
+            area.scroll_to(offset);
+        }
+    }
+}
+
+/// This system sizes and moves [`UiScrollbar`] thumbs to match their [`UiScrollArea`].
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn scrollbar_update<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    uis: Query<(&UiTree<T, N>, &Children)>,
+    areas: Query<(&UiLink<T>, &UiScrollArea)>,
+    links: Query<&UiLink<T>>,
+    mut bars: Query<(&UiLink<T>, &UiScrollbar, Option<&mut UiLayout>)>,
+    mut visibility: Query<&mut Visibility>,
+) {
+    for (ui, children) in &uis {
+        for child in children {
+            let Ok((link, bar, layout)) = bars.get_mut(*child) else { continue };
+            let Ok((area_link, area)) = areas.get(bar.area) else { continue };
+            let Some(size) = ui.borrow_node(area_link.path.clone()).ok().and_then(|node| node.obtain_data()).map(|container| container.rectangle.size) else { continue };
+
+            let visible = bar.axis(size);
+            let max = bar.axis(area.max());
+            let thumb = if visible + max > 0.0 { visible / (visible + max) * 100.0 } else { 100.0 };
+            let thumb = thumb.max(bar.min_thumb).min(100.0);
+            let progress = if max > 0.0 { (bar.axis(area.offset) / max).clamp(0.0, 1.0) } else { 0.0 };
+            let position = progress * (100.0 - thumb);
+
+            let new = if bar.vertical {
+                UiLayout::window().y(Rl(position)).size(Rl((100.0, thumb))).pack::<Base>()
+            } else {
+                UiLayout::window().x(Rl(position)).size(Rl((thumb, 100.0))).pack::<Base>()
+            };
+            match layout {
+                Some(mut layout) => if layout.layout != new.layout { layout.layout = new.layout; },
+                None => { commands.entity(*child).insert(new); },
+            }
+
+            // Hide the thumb and the track when the content fits
+            let target = if bar.auto_hide && max <= 0.0 { Visibility::Hidden } else { Visibility::Inherited };
+            let track = parent_path(&link.path);
+            for entity in children.iter().filter(|entity| links.get(**entity).is_ok_and(|node| node.path == link.path || node.path == track)) {
+                if let Ok(mut visibility) = visibility.get_mut(*entity) {
+                    if *visibility != target { *visibility = target; }
+                }
+            }
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiScrollArea`] physics.
+pub struct ScrollPlugin;
+impl Plugin for ScrollPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(UiSchedule::of(app), scroll_area_physics.before(UiSystems::Send));
+    }
+}
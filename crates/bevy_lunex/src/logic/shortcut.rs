@@ -0,0 +1,193 @@
+use crate::*;
+
+
+// #=================#
+// #=== KEY CHORD ===#
+
+/// Key with the modifiers that must be held with it. `Ctrl` also matches the `Super` key, so the same chord works on macOS.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// let save = KeyChord::new(KeyCode::KeyS).ctrl();
+/// assert_eq!(save.to_string(), "Ctrl+S");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub struct KeyChord {
+    /// The key that activates the chord.
+    pub key: KeyCode,
+    /// If `Ctrl` or `Super` must be held.
+    pub ctrl: bool,
+    /// If `Shift` must be held.
+    pub shift: bool,
+    /// If `Alt` must be held.
+    pub alt: bool,
+}
+impl KeyChord {
+    /// Creates new chord of the key without modifiers.
+    pub fn new(key: KeyCode) -> Self {
+        KeyChord { key, ctrl: false, shift: false, alt: false }
+    }
+    /// Requires `Ctrl` or `Super` to be held.
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+    /// Requires `Shift` to be held.
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+    /// Requires `Alt` to be held.
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+    /// Returns `true` if the key was just pressed with exactly the modifiers of the chord.
+    pub fn just_pressed(&self, keys: &ButtonInput<KeyCode>) -> bool {
+        let ctrl = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight, KeyCode::SuperLeft, KeyCode::SuperRight]);
+        let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+        let alt = keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+        keys.just_pressed(self.key) && ctrl == self.ctrl && shift == self.shift && alt == self.alt
+    }
+}
+impl From<KeyCode> for KeyChord {
+    fn from(key: KeyCode) -> Self {
+        KeyChord::new(key)
+    }
+}
+impl std::fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.ctrl { write!(f, "Ctrl+")?; }
+        if self.shift { write!(f, "Shift+")?; }
+        if self.alt { write!(f, "Alt+")?; }
+        match self.key {
+            KeyCode::Escape => write!(f, "Esc"),
+            key => write!(f, "{}", UiKeybind::Key(key)),
+        }
+    }
+}
+
+
+// #================#
+// #=== SHORTCUT ===#
+
+/// **Ui shortcut** - Clicks the node when the chord is pressed while the node is visible and not [`UiDisabled`], as if [`UiClickEmitter::SELF`] was clicked.
+/// It sends [`UiClickEvent`] and plays [`UiAnimator<Clicked>`], so buttons, select groups and click commands react to it.
+///
+/// If the same chord belongs to more than one visible node, the press is ignored and a warning is logged,
+/// hide the nodes that should not react, like the buttons of closed menus.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::sprite::Anchor;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu"))).with_children(|ui| {
+/// ui.spawn_prefab(UiLink::<MainUi>::path("Back"), UiButton::new("Back").shortcut(KeyCode::Escape));
+///
+/// // Shows "Ctrl+S" in the "Save/Hint" text node
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Save"),
+///     UiLayout::window().y(Rl(10.0)).size(Rl((20.0, 6.0))).pack::<Base>(),
+///     UiZoneBundle::default(),
+///     UiShortcut::new(KeyChord::new(KeyCode::KeyS).ctrl()).text("Hint"),
+/// ));
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Save/Hint"),
+///     UiLayout::window().pos(Rl((95.0, 50.0))).anchor(Anchor::CenterRight).pack::<Base>(),
+///     UiText2dBundle { text: Text::from_section("", default()), ..default() },
+/// ));
+/// # });
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiShortcut {
+    /// The chord that clicks the node.
+    pub chord: KeyChord,
+    /// Path of the text subnode showing the chord, relative to the node.
+    pub text: Option<String>,
+}
+impl UiShortcut {
+    /// Creates new shortcut of the chord.
+    pub fn new(chord: impl Into<KeyChord>) -> Self {
+        UiShortcut { chord: chord.into(), text: None }
+    }
+    /// Replaces the text path with a new value.
+    pub fn text(mut self, path: impl Into<String>) -> Self {
+        self.text = Some(path.into());
+        self
+    }
+}
+
+/// This system clicks the visible nodes whose [`UiShortcut`] chord was pressed.
+fn shortcut_press(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mut clicks: EventWriter<UiClickEvent>,
+    inputs: Query<&UiTextInput>,
+    shortcuts: Query<(Entity, &UiShortcut, Option<&InheritedVisibility>), Without<UiDisabled>>,
+    mut animators: Query<&mut UiAnimator<Clicked>>,
+) {
+    let Some(keys) = keys else { return };
+    if keys.get_just_pressed().next().is_none() { return }
+    let typing = inputs.iter().any(|input| input.focused);
+
+    let mut pressed: Vec<(KeyChord, Vec<Entity>)> = Vec::new();
+    for (entity, shortcut, visibility) in &shortcuts {
+        if visibility.is_some_and(|visibility| !visibility.get()) { continue }
+        // Keys typed into a focused text input only trigger chords with Ctrl or Alt
+        if typing && !shortcut.chord.ctrl && !shortcut.chord.alt { continue }
+        if !shortcut.chord.just_pressed(&keys) { continue }
+        match pressed.iter_mut().find(|(chord, _)| *chord == shortcut.chord) {
+            Some((_, entities)) => entities.push(entity),
+            None => pressed.push((shortcut.chord, vec![entity])),
+        }
+    }
+
+    for (chord, entities) in pressed {
+        let [entity] = entities[..] else {
+            warn!("Shortcut {} is bound to {} visible nodes {:?}, ignoring the press", chord, entities.len(), entities);
+            continue;
+        };
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Shortcut {} pressed", "--".yellow(), "ELEMENT".red(), chord);
+        if let Ok(mut clicked) = animators.get_mut(entity) {
+            clicked.play(true);
+            clicked.play(false);
+        }
+        clicks.send(UiClickEvent { target: entity });
+    }
+}
+
+/// This system writes the chord of [`UiShortcut`] into the first section of its text subnode.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+pub fn shortcut_text<T: Component>(
+    trees: Query<Ref<UiLinkIndex<T>>>,
+    shortcuts: Query<(Ref<UiShortcut>, Ref<UiLink<T>>, &Parent)>,
+    mut texts: Query<&mut Text>,
+) {
+    for (shortcut, link, parent) in &shortcuts {
+        let Some(path) = &shortcut.text else { continue };
+        // The text subnode can be linked after the shortcut, so changes of the index are checked too
+        let Ok(index) = trees.get(parent.get()) else { continue };
+        if !shortcut.is_changed() && !link.is_changed() && !index.is_changed() { continue }
+        let Some(entity) = index.find(format!("{}/{}", link.path, path)) else { continue };
+        let Ok(mut text) = texts.get_mut(entity) else { continue };
+        let Some(section) = text.sections.first_mut() else { continue };
+        let chord = shortcut.chord.to_string();
+        if section.value != chord { section.value = chord; }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiShortcut`] logic.
+pub struct ShortcutPlugin;
+impl Plugin for ShortcutPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, shortcut_press.before(UiSystems::Modify));
+    }
+}
@@ -2,6 +2,9 @@
 use bevy_kira_audio::prelude::*;
 
 use crate::*;
+use lunex_engine::*;
+use bevy::ecs::system::EntityCommands;
+use bevy::utils::HashMap;
 
 
 // #==============#
@@ -47,6 +50,10 @@ pub struct UiAnimator<S: UiState> {
     pub animation_speed_forward: f32,
     /// Hover animation speed when transitioning back to default
     pub animation_speed_backward: f32,
+    /// If the forward animation should finish before going back, so even a quick click plays the whole animation
+    pub play_full: bool,
+    /// If the animation should go back once the forward animation finishes
+    pub (crate) reverse_pending: bool,
 }
 impl <S: UiState> UiAnimator<S> {
     /// Creates new struct
@@ -68,10 +75,28 @@ impl <S: UiState> UiAnimator<S> {
         self.animation_speed_backward = speed;
         self
     }
+    /// Replaces the play_full with a new value.
+    pub fn play_full(mut self, play_full: bool) -> Self {
+        self.play_full = play_full;
+        self
+    }
     /// Checks if animation is moving forward
     pub fn is_forward(&self) -> bool {
         self.animation_direction == 1.0
     }
+    /// Starts moving the animation forward or backward.
+    /// With [`UiAnimator::play_full`] going backward is delayed until the forward animation finishes.
+    pub fn play(&mut self, forward: bool) {
+        if forward {
+            self.animation_direction = 1.0;
+            self.reverse_pending = false;
+        } else if self.play_full && self.is_forward() && self.animation_transition < 1.0 {
+            self.reverse_pending = true;
+        } else {
+            self.animation_direction = -1.0;
+            self.reverse_pending = false;
+        }
+    }
 }
 impl <S: UiState> Default for UiAnimator<S> {
     fn default() -> Self {
@@ -82,12 +107,18 @@ impl <S: UiState> Default for UiAnimator<S> {
             receiver: false,
             animation_speed_backward: 8.0,
             animation_speed_forward: 8.0,
+            play_full: false,
+            reverse_pending: false,
         }
     }
 }
 fn ui_animation<S: UiState>(time: Res<Time>, mut query: Query<&mut UiAnimator<S>>) {
     for mut control in &mut query {
         if control.receiver { continue }
+        if control.reverse_pending && control.animation_transition == 1.0 {
+            control.animation_direction = -1.0;
+            control.reverse_pending = false;
+        }
         if !(
             (control.animation_transition == 0.0 && control.animation_direction.is_sign_negative()) ||
             (control.animation_transition == 1.0 && control.animation_direction.is_sign_positive())
@@ -101,7 +132,7 @@ fn ui_animation<S: UiState>(time: Res<Time>, mut query: Query<&mut UiAnimator<S>
 }
 fn ui_animation_state<S: UiState>(mut query: Query<(&UiAnimator<S>, &mut UiLayoutController), Changed<UiAnimator<S>>>) {
     for (animator, mut controller) in &mut query {
-        controller.index[1] = Hover::INDEX;
+        controller.index[1] = S::INDEX;
         controller.tween = animator.animation_transition;
     }
 }
@@ -133,6 +164,73 @@ fn ui_state_pipe_system<S: UiState>(query: Query<(&UiAnimator<S>, &UiAnimatorPip
 }
 
 
+/// **Ui state inherit** - Makes the node follow the state animations of its closest parent node that has them,
+/// so the text of a button can be tinted on hover without listening to the pointer itself.
+/// The node is given receiver [`UiAnimator`] for every state its parent has, colors and layouts then work as usual.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu"))).with_children(|ui| {
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Button"),
+///     UiLayout::window().size(Rl((20.0, 8.0))).pack::<Base>(),
+///     UiZoneBundle::default(),
+///     UiAnimator::<Hover>::new(),
+/// ));
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Button/Text"),
+///     UiLayout::window().center().pack::<Base>(),
+///     UiText2dBundle { text: Text::from_section("Play", default()), ..default() },
+///     UiColor::<Base>::new(Color::WHITE),
+///     UiColor::<Hover>::new(Color::srgb(1.0, 0.8, 0.2)),
+///     UiStateInherit,
+///     Pickable::IGNORE,
+/// ));
+/// # });
+/// # }
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UiStateInherit;
+
+/// This system pipes the state animation of the closest parent node to nodes with [`UiStateInherit`].
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(S)` - The state to inherit
+pub fn ui_state_inherit<T: Component, S: UiState>(
+    mut commands: Commands,
+    trees: Query<&UiLinkIndex<T>>,
+    query: Query<(Entity, &UiLink<T>, &Parent, Has<UiAnimator<S>>), With<UiStateInherit>>,
+    animators: Query<Ref<UiAnimator<S>>>,
+    mut event: EventWriter<SetUiStateTransition<S>>,
+) {
+    for (entity, link, parent, has_animator) in &query {
+        let Ok(index) = trees.get(parent.get()) else { continue };
+
+        // Walk up the path until a node with the state is found
+        let mut path = link.path.as_str();
+        let mut source = None;
+        while let Some((parent_path, _)) = path.rsplit_once('/') {
+            path = parent_path;
+            if let Some(animator) = index.find(path).and_then(|node| animators.get(node).ok()) {
+                source = Some(animator);
+                break;
+            }
+        }
+        let Some(source) = source else { continue };
+
+        if !has_animator {
+            let mut animator = UiAnimator::<S>::new().receiver(true);
+            animator.animation_transition = source.animation_transition;
+            commands.entity(entity).insert(animator);
+        } else if source.is_changed() {
+            event.send(SetUiStateTransition::new(entity, source.animation_transition));
+        }
+    }
+}
+
+
 /// Default base color component
 #[derive(Component, Debug, Clone, PartialEq)]
 pub struct UiColor<S: UiState> {
@@ -159,6 +257,341 @@ fn set_ui_color<S: UiState>(query: Query<(&UiAnimator<S>, &UiColor<Base>, &UiCol
     }
 }
 
+/// Per-section text color component. Each entry overrides the color of the text section with the same index.
+/// If present with [`UiColor<Base>`] variant, the sections keep their own colors instead of being tinted by [`actions::SetColor`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// let span_colors = (
+///     UiSpanColor::<Base>::new([Color::WHITE, Color::srgb(1.0, 0.8, 0.0)]),
+///     UiSpanColor::<Hover>::new([Color::BLACK, Color::srgb(1.0, 0.0, 0.0)]),
+/// );
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiSpanColor<S: UiState> {
+    /// The colors of the sections
+    pub colors: Vec<Color>,
+    /// Phantom data
+    phantom: PhantomData<S>
+}
+impl <S: UiState> UiSpanColor<S> {
+    /// Creates new struct
+    pub fn new(colors: impl IntoIterator<Item = Color>) -> Self {
+        UiSpanColor {
+            colors: colors.into_iter().collect(),
+            phantom: PhantomData,
+        }
+    }
+}
+fn set_ui_span_color<S: UiState>(mut query: Query<(&UiAnimator<S>, &UiSpanColor<Base>, &UiSpanColor<S>, &mut Text), Changed<UiAnimator<S>>>) {
+    for (hover, basecolor, hovercolor, mut text) in &mut query {
+        for (section, (base, color)) in text.sections.iter_mut().zip(basecolor.colors.iter().zip(&hovercolor.colors)) {
+            section.style.color = base.mix(color, hover.animation_transition);
+        }
+    }
+}
+fn set_ui_span_base_color(mut query: Query<(&UiSpanColor<Base>, &mut Text), Changed<UiSpanColor<Base>>>) {
+    for (basecolor, mut text) in &mut query {
+        for (section, color) in text.sections.iter_mut().zip(&basecolor.colors) {
+            section.style.color = *color;
+        }
+    }
+}
+
+/// Sprite sheet frames for each state, written into the [`TextureAtlas`] index of the entity.
+/// The frames of a state are shown once its transition crosses the threshold, states with higher [`UiState::INDEX`] win.
+/// States with more than one frame are animated.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let frames = UiImageAtlas::new(0)
+///     .state::<Hover>(1)
+///     .state_frames::<Clicked>([2, 3, 4])
+///     .fps(12.0);
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiImageAtlas {
+    /// Frames of each state indexed by [`UiState::INDEX`], empty for states without frames
+    frames: Vec<Vec<usize>>,
+    /// Frames per second of the animated states
+    pub fps: f32,
+    /// Transition from which the frames of the state are shown
+    pub threshold: f32,
+    /// Current transition of each state
+    weights: Vec<f32>,
+    /// State of the shown frames
+    state: usize,
+    /// Time since the state started
+    time: f32,
+}
+impl UiImageAtlas {
+    /// Creates new struct from the atlas index of the base state.
+    pub fn new(index: usize) -> Self {
+        UiImageAtlas::from_frames([index])
+    }
+    /// Creates new struct from the animated frames of the base state.
+    pub fn from_frames(frames: impl IntoIterator<Item = usize>) -> Self {
+        UiImageAtlas { frames: vec![frames.into_iter().collect()], fps: 12.0, threshold: 0.5, weights: Vec::new(), state: 0, time: 0.0 }
+    }
+    /// Sets the atlas index of the state.
+    pub fn state<S: UiState>(self, index: usize) -> Self {
+        self.state_frames::<S>([index])
+    }
+    /// Sets the animated frames of the state.
+    pub fn state_frames<S: UiState>(mut self, frames: impl IntoIterator<Item = usize>) -> Self {
+        if self.frames.len() <= S::INDEX { self.frames.resize(S::INDEX + 1, Vec::new()) }
+        self.frames[S::INDEX] = frames.into_iter().collect();
+        self
+    }
+    /// Replaces the frames per second with a new value.
+    pub fn fps(mut self, fps: f32) -> Self {
+        self.fps = fps;
+        self
+    }
+    /// Replaces the threshold with a new value.
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+    /// Returns the state whose frames are shown.
+    fn active_state(&self) -> usize {
+        (1..self.frames.len()).rev()
+            .find(|state| !self.frames[*state].is_empty() && self.weights.get(*state).is_some_and(|weight| *weight >= self.threshold))
+            .unwrap_or(0)
+    }
+}
+fn set_ui_image_atlas_weight<S: UiState>(mut query: Query<(&UiAnimator<S>, &mut UiImageAtlas), Changed<UiAnimator<S>>>) {
+    for (animator, mut atlas) in &mut query {
+        if atlas.weights.len() <= S::INDEX { atlas.weights.resize(S::INDEX + 1, 0.0) }
+        atlas.weights[S::INDEX] = animator.animation_transition;
+    }
+}
+fn set_ui_image_atlas_frame(time: Res<Time>, mut query: Query<(&mut UiImageAtlas, &mut TextureAtlas)>) {
+    for (mut frames, mut atlas) in &mut query {
+        let frames = frames.bypass_change_detection();
+        let state = frames.active_state();
+        if frames.state != state {
+            frames.state = state;
+            frames.time = 0.0;
+        }
+
+        let state_frames = &frames.frames[state];
+        if state_frames.is_empty() { continue }
+        if state_frames.len() > 1 { frames.time += time.delta_seconds() }
+        let index = state_frames[(frames.time * frames.fps) as usize % state_frames.len()];
+        if atlas.index != index { atlas.index = index }
+    }
+}
+
+
+// #====================#
+// #=== NAMED STATES ===#
+
+/// Registry assigning layout indexes to string keyed states.
+/// Names are registered the first time they are used, so states can come from data files.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct UiStateNames {
+    names: Vec<String>,
+}
+impl UiStateNames {
+    /// First layout index used for named states, the indexes below are reserved for [`UiState`] types.
+    pub const FIRST_INDEX: usize = 64;
+    /// Returns the layout index of the state, registering it if it is new.
+    pub fn index(&mut self, name: &str) -> usize {
+        match self.get(name) {
+            Some(index) => index,
+            None => {
+                self.names.push(name.to_string());
+                Self::FIRST_INDEX + self.names.len() - 1
+            },
+        }
+    }
+    /// Returns the layout index of the state if it is registered.
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name).map(|index| Self::FIRST_INDEX + index)
+    }
+    /// Returns the name of the state with the layout index.
+    pub fn name(&self, index: usize) -> Option<&str> {
+        self.names.get(index.checked_sub(Self::FIRST_INDEX)?).map(|name| name.as_str())
+    }
+}
+
+/// **Ui named state** - Transitions of string keyed states, the runtime counterpart of [`UiAnimator`].
+/// Unlike [`UiState`] types, the states can be created from data, for example from a markup file.
+/// Their looks are defined by [`UiNamedLayout`] and [`UiNamedColor`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Alert"))).with_children(|ui| {
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Alert"),
+///     UiLayout::window_full().pack::<Base>(),
+///     UiLayoutController::default(),
+///     UiNamedLayout::new().state("collapsed", UiLayout::window().size(Rl((100.0, 10.0))).pack()),
+///     UiColor::<Base>::new(Color::WHITE),
+///     UiNamedColor::new().state("danger", Color::srgb(1.0, 0.0, 0.0)),
+///     UiNamedState::new(),
+/// ));
+/// # });
+/// # }
+///
+/// fn alert(mut query: Query<&mut UiNamedState>) {
+///     for mut state in &mut query {
+///         state.set_named("danger", 0.7);
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct UiNamedState {
+    /// The states and their transitions ranging from `0.0` to `1.0`.
+    states: Vec<(String, f32)>,
+}
+impl UiNamedState {
+    /// Creates new struct
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Sets the transition of the state, ranging from `0.0` to `1.0`.
+    pub fn set_named(&mut self, name: impl AsRef<str>, transition: f32) {
+        let transition = transition.clamp(0.0, 1.0);
+        match self.states.iter_mut().find(|(n, _)| n == name.as_ref()) {
+            Some((_, value)) => *value = transition,
+            None => self.states.push((name.as_ref().to_string(), transition)),
+        }
+    }
+    /// Returns the transition of the state, `0.0` if it was never set.
+    pub fn named(&self, name: &str) -> f32 {
+        self.states.iter().find(|(n, _)| n == name).map(|(_, value)| *value).unwrap_or_default()
+    }
+    /// Returns the states in the order they were first set.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f32)> {
+        self.states.iter().map(|(name, value)| (name.as_str(), *value))
+    }
+}
+
+/// Layouts for string keyed states of [`UiNamedState`]. The layout of the most active state is blended with [`UiLayout<Base>`].
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct UiNamedLayout {
+    /// The states and their layouts.
+    layouts: Vec<(String, Layout)>,
+}
+impl UiNamedLayout {
+    /// Creates new struct
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Adds the layout for the state.
+    pub fn state(mut self, name: impl Into<String>, layout: UiLayout) -> Self {
+        self.set(name, layout);
+        self
+    }
+    /// Sets the layout for the state.
+    pub fn set(&mut self, name: impl Into<String>, layout: UiLayout) {
+        let name = name.into();
+        match self.layouts.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, l)) => *l = layout.layout,
+            None => self.layouts.push((name, layout.layout)),
+        }
+    }
+    /// Returns the layout of the state.
+    pub fn get(&self, name: &str) -> Option<&Layout> {
+        self.layouts.iter().find(|(n, _)| n == name).map(|(_, layout)| layout)
+    }
+}
+
+/// Colors for string keyed states of [`UiNamedState`]. The colors are mixed into [`UiColor<Base>`] in the order the states were first set.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct UiNamedColor {
+    /// The states and their colors.
+    colors: Vec<(String, Color)>,
+}
+impl UiNamedColor {
+    /// Creates new struct
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Adds the color for the state.
+    pub fn state(mut self, name: impl Into<String>, color: Color) -> Self {
+        self.set(name, color);
+        self
+    }
+    /// Sets the color for the state.
+    pub fn set(&mut self, name: impl Into<String>, color: Color) {
+        let name = name.into();
+        match self.colors.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, c)) => *c = color,
+            None => self.colors.push((name, color)),
+        }
+    }
+    /// Returns the color of the state.
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.colors.iter().find(|(n, _)| n == name).map(|(_, color)| *color)
+    }
+}
+
+/// This system takes [`UiNamedLayout`] data and overwrites coresponding [`UiTree`] data.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn send_named_layout_to_node<T:Component, N:Default + Component>(
+    mut names: ResMut<UiStateNames>,
+    mut uis: Query<(&mut UiTree<T, N>, &Children)>,
+    query: Query<(&UiLink<T>, &UiNamedLayout), (Changed<UiNamedLayout>, Without<UiTree<T, N>>)>,
+) {
+    for (mut ui, children) in &mut uis {
+        for child in children {
+            if let Ok((link, layouts)) = query.get(*child) {
+                if let Ok(node) = ui.borrow_or_create_ui_node_mut(link.path.clone()) {
+                    if let Some(container) = node.obtain_data_mut() {
+                        #[cfg(feature = "verbose")]
+                        info!("{} {} - Received named Layout data", "->".blue(), link.path.yellow().bold());
+                        for (name, layout) in &layouts.layouts {
+                            container.layout.insert(names.index(name), *layout);
+                        }
+                    }
+                }
+                ui.mark_dirty(link.path.as_str());
+            }
+        }
+    }
+}
+
+/// This system tweens towards the layout of the most active named state.
+fn named_state_to_controller(
+    mut names: ResMut<UiStateNames>,
+    mut query: Query<(&UiNamedState, &UiNamedLayout, &mut UiLayoutController), Or<(Changed<UiNamedState>, Changed<UiNamedLayout>)>>,
+) {
+    for (state, layouts, mut controller) in &mut query {
+        let active = state.iter()
+            .filter(|(name, _)| layouts.get(name).is_some())
+            .fold(None, |best: Option<(&str, f32)>, (name, value)| match best {
+                Some((_, best_value)) if best_value >= value => best,
+                _ => Some((name, value)),
+            });
+        if let Some((name, value)) = active {
+            controller.index[1] = names.index(name);
+            controller.tween = value;
+        }
+    }
+}
+
+/// This system mixes the colors of the named states into the base color.
+fn set_ui_named_color(
+    query: Query<(Entity, &UiNamedState, &UiNamedColor, &UiColor<Base>), Or<(Changed<UiNamedState>, Changed<UiNamedColor>)>>,
+    mut set_color: EventWriter<actions::SetColor>,
+) {
+    for (entity, state, colors, base) in &query {
+        let color = state.iter().fold(base.color, |color, (name, value)| match colors.get(name) {
+            Some(state_color) => color.mix(&state_color, value),
+            None => color,
+        });
+        set_color.send(actions::SetColor { target: entity, color });
+    }
+}
+
 
 // #=============#
 // #=== HOVER ===#
@@ -191,24 +624,369 @@ fn on_hover_play_sound_system(mut events: EventReader<Pointer<Over>>, audio: Res
     }
 }
 
-/// System that changes animation direction on hover
-fn hover_enter_system(mut events: EventReader<Pointer<Over>>, mut query: Query<&mut UiAnimator<Hover>>) {
-    for event in events.read() {
+/// System that changes animation direction on hover.
+/// Tracks every pointer separately, so the hover ends only once the last touch or cursor leaves.
+pub(crate) fn hover_system(
+    mut over: EventReader<Pointer<Over>>,
+    mut out: EventReader<Pointer<Out>>,
+    mut pointers: Local<HashMap<Entity, Vec<PointerId>>>,
+    mut query: Query<&mut UiAnimator<Hover>>,
+) {
+    for event in out.read() {
+        let Some(hovered) = pointers.get_mut(&event.target) else { continue };
+        hovered.retain(|pointer| *pointer != event.pointer_id);
+        if !hovered.is_empty() { continue }
+        pointers.remove(&event.target);
+        if let Ok(mut hover) = query.get_mut(event.target) {
+            hover.play(false);
+        }
+    }
+    for event in over.read() {
+        let hovered = pointers.entry(event.target).or_default();
+        if !hovered.contains(&event.pointer_id) { hovered.push(event.pointer_id) }
         if let Ok(mut hover) = query.get_mut(event.target) {
-            hover.animation_direction = 1.0;
+            hover.play(true);
         }
     }
 }
 
-/// System that changes animation direction on hover
-fn hover_leave_system(mut events: EventReader<Pointer<Out>>, mut query: Query<&mut UiAnimator<Hover>>) {
-    for event in events.read() {
-        if let Ok(mut hover) = query.get_mut(event.target) {
-            hover.animation_direction = -1.0;
+
+
+// #===============#
+// #=== CLICKED ===#
+
+/// System that plays the [`Clicked`] animation while the entity is pressed.
+/// The animation goes back once every pointer pressing the entity is released, even outside of it.
+/// Use [`UiAnimator::play_full`] to make quick clicks play the whole press animation.
+fn clicked_system(
+    mut down: EventReader<Pointer<Down>>,
+    mut pressed: Local<HashMap<Entity, Vec<PointerId>>>,
+    pointers: Query<(&PointerId, &PointerPress)>,
+    mut query: Query<&mut UiAnimator<Clicked>>,
+) {
+    for event in down.read() {
+        if event.button != PointerButton::Primary { continue }
+        let Ok(mut clicked) = query.get_mut(event.target) else { continue };
+        let pressing = pressed.entry(event.target).or_default();
+        if !pressing.contains(&event.pointer_id) { pressing.push(event.pointer_id) }
+        clicked.play(true);
+    }
+    pressed.retain(|entity, pressing| {
+        pressing.retain(|id| pointers.iter().any(|(pointer, press)| pointer == id && press.is_primary_pressed()));
+        if !pressing.is_empty() { return true }
+        if let Ok(mut clicked) = query.get_mut(*entity) {
+            clicked.play(false);
         }
+        false
+    });
+}
+
+
+// #=================#
+// #=== LIFECYCLE ===#
+
+/// **Ui intro** - Plays the [`Intro`] state animation once after spawn, going from [`UiLayout<Intro>`] and [`UiColor<Intro>`]
+/// to the base look. Once finished, the component removes itself and [`UiIntroFinished`] is sent and triggered on the entity.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu"))).with_children(|ui| {
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Menu"),
+///     UiLayout::window_full().pack::<Base>(),
+///     UiLayout::window_full().x(Rl(-100.0)).pack::<Intro>(),
+///     UiIntro::new(2.0),
+/// ));
+/// # });
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiIntro {
+    /// Speed of the animation, `1.0` takes one second.
+    pub speed: f32,
+    /// Seconds to wait before the animation starts.
+    pub delay: f32,
+}
+impl UiIntro {
+    /// Creates new intro with the animation speed.
+    pub fn new(speed: f32) -> Self {
+        UiIntro { speed, delay: 0.0 }
+    }
+    /// Replaces the delay with a new value.
+    pub fn delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+impl Default for UiIntro {
+    fn default() -> Self {
+        UiIntro { speed: 4.0, delay: 0.0 }
+    }
+}
+
+/// **Ui stagger** - Delays [`UiIntro`] of the subnodes by their index, so list items cascade in one after another.
+/// Attach it to the parent node, or to the [`UiTree`] entity for the top level nodes. The index is the spawn order of the subnodes.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu"))).with_children(|ui| {
+/// let list = UiLink::<MainUi>::path("List");
+/// ui.spawn((list.clone(), UiLayout::window_full().pack::<Base>(), UiStagger::new(0.05)));
+/// for i in 0..10 {
+///     ui.spawn((
+///         list.add(format!("Item {i}")),
+///         UiLayout::window().y(Rl(i as f32 * 10.0)).size(Rl((100.0, 10.0))).pack::<Base>(),
+///         UiLayout::window().x(Rl(-100.0)).y(Rl(i as f32 * 10.0)).size(Rl((100.0, 10.0))).pack::<Intro>(),
+///         UiIntro::default(),
+///     ));
+/// }
+/// # });
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiStagger {
+    /// Seconds added to the delay of each next subnode.
+    pub delay: f32,
+}
+impl UiStagger {
+    /// Creates new stagger with the delay between subnodes.
+    pub fn new(delay: f32) -> Self {
+        UiStagger { delay }
+    }
+}
+
+/// **Ui outro** - Plays the [`Outro`] state animation, going from the base look to [`UiLayout<Outro>`] and [`UiColor<Outro>`],
+/// and then despawns the entity. Insert it when the entity should leave. Before the despawn
+/// [`UiOutroFinished`] is sent and triggered on the entity.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// fn close_menu(mut commands: Commands, query: Query<Entity, With<UiLayout<Outro>>>) {
+///     for entity in &query {
+///         commands.entity(entity).insert(UiOutro::new(2.0));
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiOutro {
+    /// Speed of the animation, `1.0` takes one second.
+    pub speed: f32,
+}
+impl UiOutro {
+    /// Creates new outro with the animation speed.
+    pub fn new(speed: f32) -> Self {
+        UiOutro { speed }
+    }
+}
+impl Default for UiOutro {
+    fn default() -> Self {
+        UiOutro { speed: 4.0 }
     }
 }
 
+/// This event is sent when [`UiIntro`] finishes.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct UiIntroFinished {
+    pub target: Entity,
+}
+
+/// This event is sent when [`UiOutro`] finishes, right before the entity is despawned.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct UiOutroFinished {
+    pub target: Entity,
+}
+
+/// This system delays added [`UiIntro`] by the index of the node under the parent with [`UiStagger`].
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn stagger_intro<T:Component, N:Default + Component>(
+    uis: Query<(Option<&UiStagger>, &Children), With<UiTree<T, N>>>,
+    links: Query<(&UiLink<T>, Option<&UiStagger>)>,
+    mut intros: Query<&mut UiIntro, Added<UiIntro>>,
+) {
+    if intros.is_empty() { return }
+    for (root_stagger, children) in &uis {
+        let staggers: HashMap<&str, &UiStagger> = children.iter()
+            .filter_map(|child| match links.get(*child) { Ok((link, Some(stagger))) => Some((link.path.as_str(), stagger)), _ => None })
+            .collect();
+
+        // Spawn order of the subnodes under each parent
+        let mut indices: HashMap<&str, usize> = HashMap::new();
+        for child in children {
+            let Ok((link, _)) = links.get(*child) else { continue };
+            let parent = link.path.rsplit_once('/').map(|(parent, _)| parent).unwrap_or_default();
+            let index = indices.entry(parent).or_default();
+            *index += 1;
+
+            let Ok(mut intro) = intros.get_mut(*child) else { continue };
+            let stagger = if parent.is_empty() { root_stagger } else { staggers.get(parent).copied() };
+            if let Some(stagger) = stagger {
+                intro.delay += stagger.delay * (*index - 1) as f32;
+            }
+        }
+    }
+}
+
+/// This system starts the animations of added [`UiIntro`] and [`UiOutro`].
+pub fn lifecycle_start(
+    mut commands: Commands,
+    intros: Query<(Entity, &UiIntro, Has<UiLayoutController>), Added<UiIntro>>,
+    outros: Query<(Entity, &UiOutro, Has<UiLayoutController>), Added<UiOutro>>,
+) {
+    for (entity, intro, has_controller) in &intros {
+        let mut animator = UiAnimator::<Intro>::new().backward_speed(intro.speed).receiver(intro.delay > 0.0);
+        animator.animation_transition = 1.0;
+        commands.entity(entity).insert(animator);
+        if !has_controller { commands.entity(entity).insert(UiLayoutController::default()); }
+    }
+    for (entity, outro, has_controller) in &outros {
+        let mut animator = UiAnimator::<Outro>::new().forward_speed(outro.speed);
+        animator.play(true);
+        commands.entity(entity).insert(animator);
+        if !has_controller { commands.entity(entity).insert(UiLayoutController::default()); }
+    }
+}
+
+/// This system finishes [`UiIntro`] and [`UiOutro`] once their animations end.
+fn lifecycle_finish(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut intros: Query<(Entity, &mut UiIntro, &mut UiAnimator<Intro>)>,
+    outros: Query<(Entity, &UiAnimator<Outro>, Has<UiDespawnMember>), With<UiOutro>>,
+    mut intro_finished: EventWriter<UiIntroFinished>,
+    mut outro_finished: EventWriter<UiOutroFinished>,
+) {
+    for (entity, mut intro, mut animator) in &mut intros {
+        if intro.delay > 0.0 {
+            intro.delay -= time.delta_seconds();
+            if intro.delay <= 0.0 { animator.receiver = false }
+            continue;
+        }
+        if animator.animation_transition > 0.0 { continue }
+        let event = UiIntroFinished { target: entity };
+        intro_finished.send(event);
+        commands.trigger_targets(event, entity);
+        commands.entity(entity).remove::<UiIntro>();
+    }
+    for (entity, animator, is_member) in &outros {
+        if animator.animation_transition < 1.0 { continue }
+        let event = UiOutroFinished { target: entity };
+        outro_finished.send(event);
+        commands.trigger_targets(event, entity);
+        // Members of despawn_ui wait for the rest of the subtree
+        if is_member {
+            commands.entity(entity).remove::<UiOutro>();
+        } else {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+
+// #==================#
+// #=== DESPAWN UI ===#
+
+/// Inserted by [`UiDespawnExt::despawn_ui`]. The entity is despawned together with its subnodes once all their [`UiOutro`] animations finish.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct UiDespawn {
+    /// Entities despawned together, collected when the outro starts.
+    nodes: Option<Vec<Entity>>,
+}
+
+/// Marker for entities playing [`UiOutro`] as part of [`UiDespawn`]. They are not despawned by their own outro.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub(crate) struct UiDespawnMember;
+
+/// Extension trait for despawning ui with animations.
+pub trait UiDespawnExt {
+    /// Plays [`UiOutro`] on all nodes with [`UiLayout<Outro>`] in the subtree and despawns the subtree once every outro finishes,
+    /// no matter how long each of them takes. If nothing has an outro, the subtree is despawned right away.
+    /// Works for tree entities, node entities with their subnodes and plain entities with their children.
+    /// ## 🛠️ Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_lunex::prelude::*;
+    /// fn close_menu(mut commands: Commands, query: Query<Entity, With<UiTree<MainUi>>>) {
+    ///     for entity in &query {
+    ///         commands.entity(entity).despawn_ui();
+    ///     }
+    /// }
+    /// ```
+    fn despawn_ui(&mut self) -> &mut Self;
+}
+impl UiDespawnExt for EntityCommands<'_> {
+    fn despawn_ui(&mut self) -> &mut Self {
+        self.insert(UiDespawn::default())
+    }
+}
+
+/// Starts the outro of the entities despawned by [`UiDespawn`] and all their descendants.
+fn despawn_ui_begin(commands: &mut Commands, descendants: &Query<&Children>, outros: &Query<(), (With<UiLayout<Outro>>, Without<UiOutro>)>, despawn: &mut UiDespawn, nodes: Vec<Entity>) {
+    for node in nodes.iter().flat_map(|node| std::iter::once(*node).chain(descendants.iter_descendants(*node))) {
+        if outros.contains(node) { commands.entity(node).insert((UiOutro::default(), UiDespawnMember)); }
+    }
+    despawn.nodes = Some(nodes);
+}
+
+/// This system starts added [`UiDespawn`] on trees and linked nodes, collecting the subnodes of the node.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn despawn_ui_start<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut UiDespawn, Option<&UiLink<T>>, Option<&Parent>, Has<UiTree<T, N>>), Added<UiDespawn>>,
+    links: Query<&UiLink<T>>,
+    children: Query<&Children>,
+    outros: Query<(), (With<UiLayout<Outro>>, Without<UiOutro>)>,
+) {
+    for (entity, mut despawn, link, parent, is_tree) in &mut query {
+        if despawn.nodes.is_some() { continue }
+        let nodes = match (is_tree, link, parent.and_then(|parent| children.get(parent.get()).ok())) {
+            (true, _, _) => vec![entity],
+            (false, Some(link), Some(siblings)) => subtree_entities(siblings, &links, &link.path).into_iter().map(|(node, _)| node).collect(),
+            _ => continue,
+        };
+        despawn_ui_begin(&mut commands, &children, &outros, &mut despawn, nodes);
+    }
+}
+
+/// This system starts added [`UiDespawn`] on entities that are not part of any tree.
+fn despawn_ui_start_unlinked(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut UiDespawn), Added<UiDespawn>>,
+    children: Query<&Children>,
+    outros: Query<(), (With<UiLayout<Outro>>, Without<UiOutro>)>,
+) {
+    for (entity, mut despawn) in &mut query {
+        if despawn.nodes.is_some() { continue }
+        despawn_ui_begin(&mut commands, &children, &outros, &mut despawn, vec![entity]);
+    }
+}
+
+/// This system despawns the entities of [`UiDespawn`] once none of them is playing [`UiOutro`].
+fn despawn_ui_finish(
+    mut commands: Commands,
+    query: Query<&UiDespawn>,
+    descendants: Query<&Children>,
+    outros: Query<(), With<UiOutro>>,
+) {
+    for despawn in &query {
+        let Some(nodes) = &despawn.nodes else { continue };
+        if nodes.iter().flat_map(|node| std::iter::once(*node).chain(descendants.iter_descendants(*node))).any(|node| outros.contains(node)) { continue }
+
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Despawning {} nodes after outro", "--".yellow(), "ELEMENT".red(), nodes.len());
+        for node in nodes {
+            if let Some(node) = commands.get_entity(*node) { node.despawn_recursive(); }
+        }
+    }
+}
 
 // #===============#
 // #=== PLUGINS ===#
@@ -221,17 +999,21 @@ impl <T:Component, N:Default + Component, S: UiState> StatePlugin<T,N,S> {
 }
 impl <T:Component, N:Default + Component, S: UiState> Plugin for StatePlugin<T,N,S> {
     fn build(&self, app: &mut App) {
+        let schedule = UiSchedule::of(app);
         app
             .add_event::<SetUiStateTransition<S>>()
-            .add_systems(Update, set_ui_state_transition::<S>.run_if(on_event::<SetUiStateTransition<S>>()))
+            .add_systems(schedule, set_ui_state_transition::<S>.run_if(on_event::<SetUiStateTransition<S>>()))
 
-            .add_systems(Update, ui_state_pipe_system::<S>)
+            .add_systems(schedule, ui_state_pipe_system::<S>)
+            .add_systems(schedule, ui_state_inherit::<T, S>.after(ui_animation::<S>).before(set_ui_state_transition::<S>))
 
-            .add_systems(Update, ui_animation_state::<S>)
+            .add_systems(schedule, ui_animation_state::<S>)
 
-            .add_systems(Update, (ui_animation::<S>, set_ui_color::<S>.after(UiSystems::Process)).chain())
+            .add_systems(schedule, (ui_animation::<S>, set_ui_color::<S>.after(UiSystems::Process)).chain())
+            .add_systems(schedule, set_ui_span_color::<S>.after(ui_animation::<S>).after(UiSystems::Process))
+            .add_systems(schedule, set_ui_image_atlas_weight::<S>.after(ui_animation::<S>).before(set_ui_image_atlas_frame))
 
-            .add_systems(Update, send_layout_to_node::<T, N, S>.in_set(UiSystems::Send).before(send_content_size_to_node::<T, N>));
+            .add_systems(schedule, send_layout_to_node::<T, N, S>.in_set(UiSystems::Send).before(send_content_size_to_node::<T, N>));
     }
 }
 impl <T:Component, N:Default + Component, S: UiState> Default for StatePlugin<T,N,S> {
@@ -248,8 +1030,18 @@ impl Plugin for DefaultStatesPlugin {
             .add_audio_channel::<UiSoundChannel>()
             .add_systems(Update, on_hover_play_sound_system.run_if(on_event::<Pointer<Over>>()));
 
+        let schedule = UiSchedule::of(app);
         app
-            .add_systems(Update, hover_enter_system.run_if(on_event::<Pointer<Over>>()))
-            .add_systems(Update, hover_leave_system.run_if(on_event::<Pointer<Out>>()));
+            .init_resource::<UiStateNames>()
+            .add_systems(schedule, named_state_to_controller.before(UiSystems::Send))
+            .add_systems(schedule, set_ui_named_color.after(UiSystems::Process))
+            .add_systems(Update, set_ui_span_base_color)
+            .add_systems(schedule, set_ui_image_atlas_frame.after(UiSystems::Process))
+            .add_systems(Update, clicked_system)
+            .add_event::<UiIntroFinished>()
+            .add_event::<UiOutroFinished>()
+            .add_systems(schedule, (lifecycle_start, lifecycle_finish).chain())
+            .add_systems(schedule, (despawn_ui_start_unlinked, despawn_ui_finish).chain().after(UiSystems::Modify).before(lifecycle_start))
+            .add_systems(Update, hover_system.run_if(on_event::<Pointer<Over>>().or_else(on_event::<Pointer<Out>>())));
     }
 }
@@ -1,4 +1,8 @@
 use crate::*;
+use lunex_engine::*;
+use bevy::asset::load_internal_asset;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef};
+use bevy::sprite::{Material2d, Material2dPlugin, MaterialMesh2dBundle, Mesh2dHandle};
 
 
 /// Color lerping functionality
@@ -14,12 +18,847 @@ impl LerpColor for Color {
 }
 
 
+// #=======================#
+// #=== UNIQUE MATERIALS ===#
+
+/// Marks the entity to receive its own copy of its material on insertion.
+/// This is useful when multiple nodes are spawned with the same material handle,
+/// but their color should change independently, for example through [`UiColor`].
+/// Supports [`StandardMaterial`], [`ColorMaterial`] and all materials registered with [`UiMaterialAppExt::register_ui_material`].
+/// ## 📌 Note
+/// The material needs to be present in [`Assets`] at the time of insertion, otherwise it is left shared.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiUniqueMaterial;
+
+/// This system clones the material of entities with [`UiUniqueMaterial`] into a new unique handle.
+/// ## 📦 Types
+/// * Generic `(M)` - Material asset type to clone
+pub fn make_material_unique<M: Asset + Clone>(
+    mut materials: ResMut<Assets<M>>,
+    mut query: Query<&mut Handle<M>, (With<UiUniqueMaterial>, Or<(Added<UiUniqueMaterial>, Added<Handle<M>>)>)>,
+) {
+    for mut handle in &mut query {
+        if let Some(material) = materials.get(handle.id()).cloned() {
+            #[cfg(feature = "verbose")]
+            info!("{} {} - Cloned material into unique handle", "--".yellow(), "ENTITY".blue());
+            *handle = materials.add(material);
+        }
+    }
+}
+
+
+// #=======================#
+// #=== MATERIAL SYNCING ===#
+
+/// Trait for materials that should receive data from the UI node they are attached to.
+/// Implement it for your own [`Material`] or [`Material2d`] type and register it with
+/// [`UiMaterialAppExt::register_ui_material`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::render::render_resource::AsBindGroup;
+/// # use bevy_lunex::prelude::*;
+/// #[derive(Asset, TypePath, AsBindGroup, Clone)]
+/// struct MyMaterial {
+///     #[uniform(0)] color: LinearRgba,
+///     #[uniform(0)] size: Vec2,
+/// }
+/// impl UiMaterialSync for MyMaterial {
+///     fn set_color(&mut self, color: Color) { self.color = color.into(); }
+///     fn set_dimension(&mut self, size: Vec2) { self.size = size; }
+/// }
+/// # fn build(app: &mut App) {
+/// app.register_ui_material::<MyMaterial>();
+/// # }
+/// ```
+pub trait UiMaterialSync: Asset + Clone {
+    /// Receives the blended color from [`UiColor`] state transitions and [`actions::SetColor`] events.
+    fn set_color(&mut self, _color: Color) {}
+    /// Receives the node size every time [`Dimension`] changes.
+    fn set_dimension(&mut self, _size: Vec2) {}
+    /// Receives the node parameters every frame, if the entity has [`UiMaterialParams`].
+    fn set_params(&mut self, _params: &UiMaterialParams) {}
+}
+impl UiMaterialSync for StandardMaterial {
+    fn set_color(&mut self, color: Color) {
+        self.base_color = color;
+    }
+}
+impl UiMaterialSync for ColorMaterial {
+    fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+}
+
+/// This system applies [`actions::SetColor`] events to the material of the targetted entity.
+/// ## 📦 Types
+/// * Generic `(M)` - Material asset type to sync
+pub fn material_sync_color<M: UiMaterialSync>(
+    mut events: EventReader<actions::SetColor>,
+    mut materials: ResMut<Assets<M>>,
+    query: Query<&Handle<M>>,
+) {
+    for event in events.read() {
+        if let Ok(handle) = query.get(event.target) {
+            if let Some(material) = materials.get_mut(handle) {
+                material.set_color(event.color);
+            }
+        }
+    }
+}
+
+/// This system pipes updated [`Dimension`] data into the material.
+/// ## 📦 Types
+/// * Generic `(M)` - Material asset type to sync
+pub fn material_sync_dimension<M: UiMaterialSync>(
+    mut materials: ResMut<Assets<M>>,
+    query: Query<(&Handle<M>, &Dimension), Or<(Changed<Dimension>, Changed<Handle<M>>)>>,
+) {
+    for (handle, dimension) in &query {
+        if let Some(material) = materials.get_mut(handle) {
+            material.set_dimension(dimension.size);
+        }
+    }
+}
+
+/// This component collects the node data custom shaders usually need: the size, the blended state transitions and the time.
+/// It is filled every frame and passed to [`UiMaterialSync::set_params`] of the node material.
+/// Declare the fields in the same order as `#[uniform]` fields of the material to use the `UiMaterialParams`
+/// struct from the `bevy_lunex::material_params` shader import.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::render::render_resource::AsBindGroup;
+/// # use bevy_lunex::prelude::*;
+/// #[derive(Asset, TypePath, AsBindGroup, Clone)]
+/// struct GlowMaterial {
+///     #[uniform(0)] size: Vec2,
+///     #[uniform(0)] hover: f32,
+///     #[uniform(0)] clicked: f32,
+///     #[uniform(0)] selected: f32,
+///     #[uniform(0)] time: f32,
+/// }
+/// impl UiMaterialSync for GlowMaterial {
+///     fn set_params(&mut self, params: &UiMaterialParams) {
+///         let UiMaterialParams { size, hover, clicked, selected, time } = *params;
+///         *self = GlowMaterial { size, hover, clicked, selected, time };
+///     }
+/// }
+/// ```
+/// ```wgsl
+/// #import bevy_lunex::material_params::UiMaterialParams
+///
+/// @group(2) @binding(0) var<uniform> params: UiMaterialParams;
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiMaterialParams {
+    /// The node size from [`Dimension`].
+    pub size: Vec2,
+    /// Transition of the [`Hover`] state from `0.0` to `1.0`.
+    pub hover: f32,
+    /// Transition of the [`Clicked`] state from `0.0` to `1.0`.
+    pub clicked: f32,
+    /// Transition of the [`Selected`] state from `0.0` to `1.0`.
+    pub selected: f32,
+    /// Seconds since the startup, wrapped to keep the precision.
+    pub time: f32,
+}
+
+/// This system fills [`UiMaterialParams`] from the node.
+pub fn material_params_update(
+    time: Res<Time>,
+    mut query: Query<(&mut UiMaterialParams, Option<&Dimension>, Option<&UiAnimator<Hover>>, Option<&UiAnimator<Clicked>>, Option<&UiAnimator<Selected>>)>,
+) {
+    for (mut params, dimension, hover, clicked, selected) in &mut query {
+        *params = UiMaterialParams {
+            size: dimension.map(|dimension| dimension.size).unwrap_or_default(),
+            hover: hover.map(|animator| animator.animation_transition).unwrap_or_default(),
+            clicked: clicked.map(|animator| animator.animation_transition).unwrap_or_default(),
+            selected: selected.map(|animator| animator.animation_transition).unwrap_or_default(),
+            time: time.elapsed_seconds_wrapped(),
+        };
+    }
+}
+
+/// This system pipes [`UiMaterialParams`] into the material.
+/// ## 📦 Types
+/// * Generic `(M)` - Material asset type to sync
+pub fn material_sync_params<M: UiMaterialSync>(
+    mut materials: ResMut<Assets<M>>,
+    query: Query<(&Handle<M>, &UiMaterialParams), Changed<UiMaterialParams>>,
+) {
+    for (handle, params) in &query {
+        if let Some(material) = materials.get_mut(handle) {
+            material.set_params(params);
+        }
+    }
+}
+
+/// Extension trait for registering materials that implement [`UiMaterialSync`].
+pub trait UiMaterialAppExt {
+    /// Adds systems that keep material `M` in sync with the color and size of the node.
+    /// It also enables [`UiUniqueMaterial`] for this material type.
+    fn register_ui_material<M: UiMaterialSync>(&mut self) -> &mut Self;
+}
+impl UiMaterialAppExt for App {
+    fn register_ui_material<M: UiMaterialSync>(&mut self) -> &mut Self {
+        let schedule = UiSchedule::of(self);
+        self
+            .add_event::<actions::SetColor>()
+            .add_systems(schedule, make_material_unique::<M>.before(UiSystems::Process))
+            .add_systems(schedule, (
+                material_sync_color::<M>.run_if(on_event::<actions::SetColor>()),
+                material_sync_dimension::<M>,
+                material_sync_params::<M>.after(material_params_update),
+            ).in_set(UiSystems::Process).after(UiSystems::Fetch))
+    }
+}
+
+
+// #==================#
+// #=== NODE STYLE ===#
+
+pub(crate) const NODE_STYLE_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(0x6c75_6e65_785f_7374_796c_655f_0000_0001);
+pub(crate) const NODE_STYLE_2D_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(0x6c75_6e65_785f_7374_796c_655f_0000_0002);
+pub(crate) const NODE_STYLE_3D_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(0x6c75_6e65_785f_7374_796c_655f_0000_0003);
+pub(crate) const NODE_SHADOW_2D_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(0x6c75_6e65_785f_7374_796c_655f_0000_0004);
+pub(crate) const NODE_SHADOW_3D_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(0x6c75_6e65_785f_7374_796c_655f_0000_0005);
+pub(crate) const MATERIAL_PARAMS_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(0x6c75_6e65_785f_7374_796c_655f_0000_000a);
+
+/// This component styles the node as a rectangle with rounded corners and a border.
+/// It is rendered through an internal SDF material that is sized from [`Dimension`].
+///
+/// Add it to an entity with [`UiElementBundle`] to render it in 2D, or to an entity
+/// with [`UiMaterial3dBundle`] to render it in 3D instead. The material is created automatically.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// let style = UiNodeStyle::new()
+///     .color(Color::srgb(0.1, 0.1, 0.1))
+///     .radius(8.0)
+///     .border(2.0, Color::WHITE);
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiNodeStyle {
+    /// The fill color of the node.
+    pub color: Color,
+    /// Corner radius in pixels. The order is `top-left`, `top-right`, `bottom-right`, `bottom-left`.
+    pub corner_radius: Vec4,
+    /// Width of the border in pixels. The border is drawn inside the node.
+    pub border_width: f32,
+    /// The color of the border.
+    pub border_color: Color,
+}
+impl Default for UiNodeStyle {
+    fn default() -> Self {
+        UiNodeStyle {
+            color: Color::WHITE,
+            corner_radius: Vec4::ZERO,
+            border_width: 0.0,
+            border_color: Color::NONE,
+        }
+    }
+}
+impl UiNodeStyle {
+    /// Creates new style with white fill and no border.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Replaces the fill color with a new value.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = color.into();
+        self
+    }
+    /// Replaces the corner radius of all corners with a new value.
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.corner_radius = Vec4::splat(radius);
+        self
+    }
+    /// Replaces the corner radius with a new value. The order is `top-left`, `top-right`, `bottom-right`, `bottom-left`.
+    pub fn corner_radius(mut self, corner_radius: impl Into<Vec4>) -> Self {
+        self.corner_radius = corner_radius.into();
+        self
+    }
+    /// Replaces the border with a new value.
+    pub fn border(mut self, width: f32, color: impl Into<Color>) -> Self {
+        self.border_width = width;
+        self.border_color = color.into();
+        self
+    }
+}
+
+/// Internal 2D material used to render [`UiNodeStyle`].
+#[derive(Asset, AsBindGroup, Debug, Default, Clone, Reflect)]
+pub struct UiNodeStyleMaterial2d {
+    #[uniform(0)]
+    pub color: LinearRgba,
+    #[uniform(0)]
+    pub border_color: LinearRgba,
+    #[uniform(0)]
+    pub corner_radius: Vec4,
+    #[uniform(0)]
+    pub size: Vec2,
+    #[uniform(0)]
+    pub border_width: f32,
+}
+impl UiNodeStyleMaterial2d {
+    /// Creates new material from the style and the node size.
+    pub fn new(style: &UiNodeStyle, size: Vec2) -> Self {
+        UiNodeStyleMaterial2d {
+            color: style.color.into(),
+            border_color: style.border_color.into(),
+            corner_radius: style.corner_radius,
+            size,
+            border_width: style.border_width,
+        }
+    }
+}
+impl Material2d for UiNodeStyleMaterial2d {
+    fn fragment_shader() -> ShaderRef {
+        NODE_STYLE_2D_SHADER_HANDLE.into()
+    }
+}
+
+/// Internal 3D material used to render [`UiNodeStyle`].
+#[derive(Asset, AsBindGroup, Debug, Default, Clone, Reflect)]
+pub struct UiNodeStyleMaterial3d {
+    #[uniform(0)]
+    pub color: LinearRgba,
+    #[uniform(0)]
+    pub border_color: LinearRgba,
+    #[uniform(0)]
+    pub corner_radius: Vec4,
+    #[uniform(0)]
+    pub size: Vec2,
+    #[uniform(0)]
+    pub border_width: f32,
+}
+impl UiNodeStyleMaterial3d {
+    /// Creates new material from the style and the node size.
+    pub fn new(style: &UiNodeStyle, size: Vec2) -> Self {
+        UiNodeStyleMaterial3d {
+            color: style.color.into(),
+            border_color: style.border_color.into(),
+            corner_radius: style.corner_radius,
+            size,
+            border_width: style.border_width,
+        }
+    }
+}
+impl Material for UiNodeStyleMaterial3d {
+    fn fragment_shader() -> ShaderRef {
+        NODE_STYLE_3D_SHADER_HANDLE.into()
+    }
+    fn alpha_mode(&self) -> AlphaMode {
+        AlphaMode::Blend
+    }
+}
+
+/// This system creates the style material for newly added [`UiNodeStyle`] components.
+/// Entities with [`Handle<Mesh>`] get a 3D material, all other entities get a 2D mesh and material.
+pub fn node_style_insert_material(
+    mut commands: Commands,
+    mut materials_2d: ResMut<Assets<UiNodeStyleMaterial2d>>,
+    mut materials_3d: ResMut<Assets<UiNodeStyleMaterial3d>>,
+    query: Query<(Entity, &UiNodeStyle, &Dimension, Has<Handle<Mesh>>, Has<Mesh2dHandle>), Added<UiNodeStyle>>,
+) {
+    for (entity, style, dimension, is_3d, has_mesh2d) in &query {
+        if is_3d {
+            commands.entity(entity)
+                .remove::<Handle<StandardMaterial>>()
+                .insert(materials_3d.add(UiNodeStyleMaterial3d::new(style, dimension.size)));
+        } else {
+            let mut entity = commands.entity(entity);
+            entity.insert(materials_2d.add(UiNodeStyleMaterial2d::new(style, dimension.size)));
+            // The mesh is reconstructed from dimension once added
+            if !has_mesh2d { entity.insert(Mesh2dHandle::default()); }
+        }
+    }
+}
+
+/// This system updates the style material when [`UiNodeStyle`] or [`Dimension`] changes.
+pub fn node_style_update_material(
+    mut materials_2d: ResMut<Assets<UiNodeStyleMaterial2d>>,
+    mut materials_3d: ResMut<Assets<UiNodeStyleMaterial3d>>,
+    query: Query<(&UiNodeStyle, &Dimension, Option<&Handle<UiNodeStyleMaterial2d>>, Option<&Handle<UiNodeStyleMaterial3d>>), Or<(Changed<UiNodeStyle>, Changed<Dimension>)>>,
+) {
+    for (style, dimension, material_2d, material_3d) in &query {
+        if let Some(material) = material_2d.and_then(|handle| materials_2d.get_mut(handle)) {
+            *material = UiNodeStyleMaterial2d::new(style, dimension.size);
+        }
+        if let Some(material) = material_3d.and_then(|handle| materials_3d.get_mut(handle)) {
+            *material = UiNodeStyleMaterial3d::new(style, dimension.size);
+        }
+    }
+}
+
+
+// #===================#
+// #=== NODE SHADOW ===#
+
+/// This component renders a drop shadow behind the node. The shadow is spawned as a child entity
+/// with its own material and follows the node's [`Dimension`] and [`UiNodeStyle`] corner radius.
+///
+/// Like [`UiNodeStyle`], the shadow is rendered in 3D if the node has [`Handle<Mesh>`] and in 2D otherwise.
+/// The pixel values are multiplied by the absolute scale of the tree, like the [`Ab`] unit.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// let shadow = UiShadow::new()
+///     .offset(Vec2::new(0.0, 4.0))
+///     .blur(12.0)
+///     .color(Color::BLACK.with_alpha(0.5));
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiShadow {
+    /// Offset of the shadow in pixels. Positive y moves the shadow down.
+    pub offset: Vec2,
+    /// Blur radius in pixels.
+    pub blur: f32,
+    /// How much the shadow is expanded past the node size in pixels.
+    pub spread: f32,
+    /// The color of the shadow.
+    pub color: Color,
+}
+impl Default for UiShadow {
+    fn default() -> Self {
+        UiShadow {
+            offset: Vec2::ZERO,
+            blur: 8.0,
+            spread: 0.0,
+            color: Color::BLACK.with_alpha(0.5),
+        }
+    }
+}
+impl UiShadow {
+    /// Creates new shadow with default blur and half transparent black color.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Replaces the offset with a new value.
+    pub fn offset(mut self, offset: impl Into<Vec2>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+    /// Replaces the blur with a new value.
+    pub fn blur(mut self, blur: f32) -> Self {
+        self.blur = blur;
+        self
+    }
+    /// Replaces the spread with a new value.
+    pub fn spread(mut self, spread: f32) -> Self {
+        self.spread = spread;
+        self
+    }
+    /// Replaces the color with a new value.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = color.into();
+        self
+    }
+    /// Returns the size of the mesh the shadow needs for the given node size.
+    pub fn mesh_size(&self, size: Vec2) -> Vec2 {
+        size + 2.0 * (self.spread + self.blur)
+    }
+    /// Returns the shadow with the pixel values multiplied by the absolute scale of the tree.
+    pub fn scaled(&self, abs_scale: f32) -> Self {
+        UiShadow {
+            offset: self.offset * abs_scale,
+            blur: self.blur * abs_scale,
+            spread: self.spread * abs_scale,
+            color: self.color,
+        }
+    }
+}
+
+/// Marker for the child entity that renders [`UiShadow`] of its parent.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct UiShadowMesh;
+
+/// Internal 2D material used to render [`UiShadow`].
+#[derive(Asset, AsBindGroup, Debug, Default, Clone, PartialEq, Reflect)]
+pub struct UiShadowMaterial2d {
+    #[uniform(0)]
+    pub color: LinearRgba,
+    #[uniform(0)]
+    pub corner_radius: Vec4,
+    #[uniform(0)]
+    pub size: Vec2,
+    #[uniform(0)]
+    pub box_size: Vec2,
+    #[uniform(0)]
+    pub blur: f32,
+}
+impl UiShadowMaterial2d {
+    /// Creates new material from the shadow, the node size and the node corner radius.
+    pub fn new(shadow: &UiShadow, size: Vec2, corner_radius: Vec4) -> Self {
+        UiShadowMaterial2d {
+            color: shadow.color.into(),
+            corner_radius: (corner_radius + shadow.spread).max(Vec4::ZERO),
+            size: shadow.mesh_size(size),
+            box_size: size + 2.0 * shadow.spread,
+            blur: shadow.blur,
+        }
+    }
+}
+impl Material2d for UiShadowMaterial2d {
+    fn fragment_shader() -> ShaderRef {
+        NODE_SHADOW_2D_SHADER_HANDLE.into()
+    }
+}
+
+/// Internal 3D material used to render [`UiShadow`].
+#[derive(Asset, AsBindGroup, Debug, Default, Clone, PartialEq, Reflect)]
+pub struct UiShadowMaterial3d {
+    #[uniform(0)]
+    pub color: LinearRgba,
+    #[uniform(0)]
+    pub corner_radius: Vec4,
+    #[uniform(0)]
+    pub size: Vec2,
+    #[uniform(0)]
+    pub box_size: Vec2,
+    #[uniform(0)]
+    pub blur: f32,
+}
+impl UiShadowMaterial3d {
+    /// Creates new material from the shadow, the node size and the node corner radius.
+    pub fn new(shadow: &UiShadow, size: Vec2, corner_radius: Vec4) -> Self {
+        UiShadowMaterial3d {
+            color: shadow.color.into(),
+            corner_radius: (corner_radius + shadow.spread).max(Vec4::ZERO),
+            size: shadow.mesh_size(size),
+            box_size: size + 2.0 * shadow.spread,
+            blur: shadow.blur,
+        }
+    }
+}
+impl Material for UiShadowMaterial3d {
+    fn fragment_shader() -> ShaderRef {
+        NODE_SHADOW_3D_SHADER_HANDLE.into()
+    }
+    fn alpha_mode(&self) -> AlphaMode {
+        AlphaMode::Blend
+    }
+}
+
+/// Returns the absolute scale and the depth step of the tree, which the shadow pixels and depth are relative to.
+fn shadow_scale<T, N: Default + Component>(ui: &UiTree<T, N>) -> (f32, f32) {
+    ui.obtain_topdata().map_or((1.0, 1.0), |data| (data.abs_scale * data.scale_factor, data.depth_step.unwrap_or(data.abs_scale)))
+}
+
+/// Returns the local translation of the shadow entity relative to its node.
+fn shadow_translation(shadow: &UiShadow, size: Vec2, is_element: bool, depth_step: f32) -> Vec3 {
+    // Non-element nodes have their origin in the top-left corner
+    let center = if is_element { Vec2::ZERO } else { Vec2::new(size.x, -size.y) / 2.0 };
+    // Place it half a depth level behind the node, so it is in front of the parent node
+    (center + Vec2::new(shadow.offset.x, -shadow.offset.y)).extend(-depth_step / 2.0)
+}
+
+/// This system spawns the shadow entity for newly added [`UiShadow`] components.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn node_shadow_spawn<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials_2d: ResMut<Assets<UiShadowMaterial2d>>,
+    mut materials_3d: ResMut<Assets<UiShadowMaterial3d>>,
+    uis: Query<(&UiTree<T, N>, &Children)>,
+    query: Query<(&UiShadow, &Dimension, Option<&UiNodeStyle>, Has<Element>, Has<Handle<Mesh>>), (With<UiLink<T>>, Added<UiShadow>)>,
+) {
+    for (ui, children) in &uis {
+        let (abs_scale, depth_step) = shadow_scale(ui);
+        for child in children {
+            let Ok((shadow, dimension, style, is_element, is_3d)) = query.get(*child) else { continue };
+            let shadow = shadow.scaled(abs_scale);
+            let corner_radius = style.map(|s| s.corner_radius).unwrap_or_default();
+            let mesh = meshes.add(Rectangle::from_size(shadow.mesh_size(dimension.size)));
+            let transform = Transform::from_translation(shadow_translation(&shadow, dimension.size, is_element, depth_step));
+
+            #[cfg(feature = "verbose")]
+            info!("{} {} - Spawned shadow entity", "--".yellow(), "ENTITY".blue());
+
+            commands.entity(*child).with_children(|parent| {
+                if is_3d {
+                    parent.spawn((
+                        UiShadowMesh,
+                        MaterialMeshBundle {
+                            mesh,
+                            material: materials_3d.add(UiShadowMaterial3d::new(&shadow, dimension.size, corner_radius)),
+                            transform,
+                            ..default()
+                        },
+                    ));
+                } else {
+                    parent.spawn((
+                        UiShadowMesh,
+                        MaterialMesh2dBundle {
+                            mesh: Mesh2dHandle(mesh),
+                            material: materials_2d.add(UiShadowMaterial2d::new(&shadow, dimension.size, corner_radius)),
+                            transform,
+                            ..default()
+                        },
+                    ));
+                }
+            });
+        }
+    }
+}
+
+/// This system updates the shadow entity when [`UiShadow`], [`UiNodeStyle`], [`Dimension`] or the [`UiTree`] changes.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn node_shadow_update<T:Component, N:Default + Component>(
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials_2d: ResMut<Assets<UiShadowMaterial2d>>,
+    mut materials_3d: ResMut<Assets<UiShadowMaterial3d>>,
+    uis: Query<(Ref<UiTree<T, N>>, &Children)>,
+    query: Query<(Ref<UiShadow>, Ref<Dimension>, Option<Ref<UiNodeStyle>>, Has<Element>, &Children), With<UiLink<T>>>,
+    mut shadows: Query<(&mut Transform, Option<&mut Handle<Mesh>>, Option<&mut Mesh2dHandle>, Option<&Handle<UiShadowMaterial2d>>, Option<&Handle<UiShadowMaterial3d>>), With<UiShadowMesh>>,
+) {
+    for (ui, nodes) in &uis {
+        let (abs_scale, depth_step) = shadow_scale(&ui);
+        for node in nodes {
+            let Ok((shadow, dimension, style, is_element, children)) = query.get(*node) else { continue };
+            if !ui.is_changed() && !shadow.is_changed() && !dimension.is_changed() && !style.as_ref().is_some_and(|style| style.is_changed()) { continue }
+
+            let shadow = shadow.scaled(abs_scale);
+            let corner_radius = style.map(|s| s.corner_radius).unwrap_or_default();
+            for child in children {
+                let Ok((mut transform, mesh, mesh2d, material_2d, material_3d)) = shadows.get_mut(*child) else { continue };
+
+                let translation = shadow_translation(&shadow, dimension.size, is_element, depth_step);
+                if transform.translation != translation { transform.translation = translation }
+
+                // The tree changes often, so the mesh is resized in place only if the material changed
+                let size = shadow.mesh_size(dimension.size);
+                if let Some(handle) = material_2d {
+                    let new = UiShadowMaterial2d::new(&shadow, dimension.size, corner_radius);
+                    if materials_2d.get(handle) == Some(&new) { continue }
+                    if let Some(material) = materials_2d.get_mut(handle) { *material = new }
+                }
+                if let Some(handle) = material_3d {
+                    let new = UiShadowMaterial3d::new(&shadow, dimension.size, corner_radius);
+                    if materials_3d.get(handle) == Some(&new) { continue }
+                    if let Some(material) = materials_3d.get_mut(handle) { *material = new }
+                }
+                if let Some(mut mesh) = mesh {
+                    replace_mesh(&mut meshes, &mut mesh, Rectangle::from_size(size).into());
+                }
+                if let Some(mut mesh2d) = mesh2d {
+                    replace_mesh(&mut meshes, &mut mesh2d.0, Rectangle::from_size(size).into());
+                }
+            }
+        }
+    }
+}
+
+/// This system despawns the shadow entity when [`UiShadow`] is removed from the node.
+pub fn node_shadow_remove(
+    mut commands: Commands,
+    mut removed: RemovedComponents<UiShadow>,
+    nodes: Query<&Children, Without<UiShadow>>,
+    shadows: Query<(), With<UiShadowMesh>>,
+) {
+    for entity in removed.read() {
+        let Ok(children) = nodes.get(entity) else { continue };
+        for child in children {
+            if !shadows.contains(*child) { continue }
+
+            #[cfg(feature = "verbose")]
+            info!("{} {} - Despawned shadow entity", "--".red(), "ENTITY".blue());
+            commands.entity(*child).despawn_recursive();
+        }
+    }
+}
+
+
+// #=====================#
+// #=== BACKDROP BLUR ===#
+
+pub(crate) const NODE_BACKDROP_2D_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(0x6c75_6e65_785f_7374_796c_655f_0000_0009);
+
+/// This component marks a 2D [`UiTree`] whose background is rendered into a texture, which [`UiBackdropBlur`] nodes sample.
+/// Render the scene camera into the texture and show it as a full size node of the tree, so the texture covers the whole tree.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::render::camera::RenderTarget;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+/// let scene = images.add(Image::default());
+/// commands.spawn(Camera2dBundle { camera: Camera { target: RenderTarget::Image(scene.clone()), ..default() }, ..default() });
+///
+/// commands.spawn((
+///     UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu")),
+///     SourceFromCamera,
+///     UiBackdropSource(scene.clone()),
+/// )).with_children(|ui| {
+///     ui.spawn((
+///         UiLink::<MainUi>::path("Scene"),
+///         UiLayout::window_full().pack::<Base>(),
+///         UiImage2dBundle::from(scene),
+///         SourceFromCamera,
+///     ));
+///     ui.spawn((
+///         UiLink::<MainUi>::path("Scene/Panel"),
+///         UiLayout::window().pos(Rl(10.0)).size(Rl(30.0)).pack::<Base>(),
+///         UiBackdropBlur::new(12.0).corner_radius(16.0),
+///     ));
+/// });
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiBackdropSource(pub Handle<Image>);
+
+/// **Ui backdrop blur** - Renders the node as frosted glass, blurring the part of the [`UiBackdropSource`] texture behind it.
+/// The material is created automatically like with [`UiNodeStyle`], so the two should not be combined on one entity.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiBackdropBlur {
+    /// Blur radius in pixels of the backdrop texture.
+    pub radius: f32,
+    /// Color mixed over the blurred backdrop, its alpha is the strength.
+    pub tint: Color,
+    /// Corner radius in pixels. The order is `top-left`, `top-right`, `bottom-right`, `bottom-left`.
+    pub corner_radius: Vec4,
+}
+impl UiBackdropBlur {
+    /// Creates new blur with the radius, no tint and sharp corners.
+    pub fn new(radius: f32) -> Self {
+        UiBackdropBlur { radius, tint: Color::NONE, corner_radius: Vec4::ZERO }
+    }
+    /// Replaces the tint with a new value.
+    pub fn tint(mut self, tint: impl Into<Color>) -> Self {
+        self.tint = tint.into();
+        self
+    }
+    /// Replaces the corner radius of all corners with a new value.
+    pub fn corner_radius(mut self, radius: f32) -> Self {
+        self.corner_radius = Vec4::splat(radius);
+        self
+    }
+}
+
+/// Internal 2D material used to render [`UiBackdropBlur`].
+#[derive(Asset, AsBindGroup, Debug, Default, Clone, Reflect)]
+pub struct UiBackdropMaterial2d {
+    #[uniform(0)]
+    pub tint: LinearRgba,
+    #[uniform(0)]
+    pub corner_radius: Vec4,
+    #[uniform(0)]
+    pub area: Vec4,
+    #[uniform(0)]
+    pub size: Vec2,
+    #[uniform(0)]
+    pub texture_size: Vec2,
+    #[uniform(0)]
+    pub radius: f32,
+    #[texture(1)]
+    #[sampler(2)]
+    pub texture: Option<Handle<Image>>,
+}
+impl UiBackdropMaterial2d {
+    /// Creates new material from the blur, the texture and the area of the texture behind the node.
+    pub fn new(blur: &UiBackdropBlur, size: Vec2, texture: Handle<Image>, texture_size: Vec2, area: Rect) -> Self {
+        UiBackdropMaterial2d {
+            tint: blur.tint.into(),
+            corner_radius: blur.corner_radius,
+            area: Vec4::new(area.min.x, area.min.y, area.max.x, area.max.y),
+            size,
+            texture_size,
+            radius: blur.radius,
+            texture: Some(texture),
+        }
+    }
+}
+impl Material2d for UiBackdropMaterial2d {
+    fn fragment_shader() -> ShaderRef {
+        NODE_BACKDROP_2D_SHADER_HANDLE.into()
+    }
+}
+
+/// This system creates and updates the material of [`UiBackdropBlur`] nodes from the [`UiBackdropSource`] of their tree.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn node_backdrop_update<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    images: Res<Assets<Image>>,
+    mut materials: ResMut<Assets<UiBackdropMaterial2d>>,
+    uis: Query<(Ref<UiTree<T, N>>, Ref<UiBackdropSource>, &Dimension, &Children)>,
+    query: Query<(&UiLink<T>, Ref<UiBackdropBlur>, &Dimension, Option<&Handle<UiBackdropMaterial2d>>, Has<Mesh2dHandle>)>,
+) {
+    for (ui, source, tree_dimension, children) in &uis {
+        let Some(texture_size) = images.get(&source.0).map(|image| image.size().as_vec2()) else { continue };
+        if tree_dimension.size.cmple(Vec2::ZERO).any() { continue }
+
+        for child in children {
+            let Ok((link, blur, dimension, material, has_mesh2d)) = query.get(*child) else { continue };
+            if material.is_some() && !blur.is_changed() && !ui.is_changed() && !source.is_changed() { continue }
+            let Some(rectangle) = ui.borrow_node(link.path.as_str()).ok().and_then(|node| node.obtain_data()).map(|container| container.rectangle) else { continue };
+
+            let pos = rectangle.pos.truncate() / tree_dimension.size;
+            let area = Rect::from_corners(pos, pos + rectangle.size / tree_dimension.size);
+            let new = UiBackdropMaterial2d::new(&blur, dimension.size, source.0.clone(), texture_size, area);
+
+            match material {
+                Some(handle) => if let Some(material) = materials.get_mut(handle) { *material = new },
+                None => {
+                    #[cfg(feature = "verbose")]
+                    info!("{} {} - Created backdrop material", "--".yellow(), link.path.yellow().bold());
+                    let mut entity = commands.entity(*child);
+                    entity.insert(materials.add(new));
+                    // The mesh is reconstructed from dimension once added
+                    if !has_mesh2d { entity.insert(Mesh2dHandle::default()); }
+                },
+            }
+        }
+    }
+}
+
+
 // #==============#
 // #=== PLUGIN ===#
 
 pub struct StylePlugin;
 impl Plugin for StylePlugin {
-    fn build(&self, _app: &mut App) {
-        //app;
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(app, NODE_STYLE_SHADER_HANDLE, "../shaders/node_style.wgsl", Shader::from_wgsl);
+        load_internal_asset!(app, NODE_STYLE_2D_SHADER_HANDLE, "../shaders/node_style_2d.wgsl", Shader::from_wgsl);
+        load_internal_asset!(app, NODE_STYLE_3D_SHADER_HANDLE, "../shaders/node_style_3d.wgsl", Shader::from_wgsl);
+        load_internal_asset!(app, NODE_SHADOW_2D_SHADER_HANDLE, "../shaders/node_shadow_2d.wgsl", Shader::from_wgsl);
+        load_internal_asset!(app, NODE_SHADOW_3D_SHADER_HANDLE, "../shaders/node_shadow_3d.wgsl", Shader::from_wgsl);
+        load_internal_asset!(app, NODE_BACKDROP_2D_SHADER_HANDLE, "../shaders/node_backdrop_2d.wgsl", Shader::from_wgsl);
+        load_internal_asset!(app, MATERIAL_PARAMS_SHADER_HANDLE, "../shaders/material_params.wgsl", Shader::from_wgsl);
+
+        let schedule = UiSchedule::of(app);
+        app
+            .register_ui_material::<StandardMaterial>()
+            .register_ui_material::<ColorMaterial>()
+
+            .add_plugins(Material2dPlugin::<UiNodeStyleMaterial2d>::default())
+            .add_plugins(MaterialPlugin::<UiNodeStyleMaterial3d>::default())
+            .add_plugins(Material2dPlugin::<UiShadowMaterial2d>::default())
+            .add_plugins(MaterialPlugin::<UiShadowMaterial3d>::default())
+            .add_plugins(Material2dPlugin::<UiBackdropMaterial2d>::default())
+            .register_type::<UiBackdropSource>()
+            .register_type::<UiBackdropBlur>()
+            .register_type::<UiMaterialParams>()
+            .add_systems(schedule, material_params_update.in_set(UiSystems::Process).after(UiSystems::Fetch))
+            .add_systems(schedule, (
+                node_style_insert_material,
+                node_style_update_material,
+                node_shadow_remove,
+            ).chain().in_set(UiSystems::Process).after(UiSystems::Fetch));
     }
 }
@@ -0,0 +1,418 @@
+use crate::*;
+use bevy::sprite::Anchor;
+
+
+// #=============#
+// #=== TABLE ===#
+
+/// Width of a [`UiTable`] column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UiTableColumn {
+    /// Column of the width in layout units.
+    Fixed(f32),
+    /// Column sharing the width left by the fixed columns with the other fill columns, by the weight.
+    Fill(f32),
+}
+impl UiTableColumn {
+    /// Creates new column of the width in layout units.
+    pub fn fixed(width: f32) -> Self {
+        UiTableColumn::Fixed(width)
+    }
+    /// Creates new column filling the free width by the weight.
+    pub fn fill(weight: f32) -> Self {
+        UiTableColumn::Fill(weight)
+    }
+}
+
+/// Row of a [`UiTable`]. Rows with children can be expanded and collapsed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UiTableRow {
+    /// Value identifying the row in [`TableRowSelected`] and [`UiTable::selected`].
+    pub id: usize,
+    /// Text of the cells, one for each column.
+    pub cells: Vec<String>,
+    /// Rows nested under this row.
+    pub children: Vec<UiTableRow>,
+    /// If the children are shown.
+    pub expanded: bool,
+}
+impl UiTableRow {
+    /// Creates new row with the id and the text of the cells.
+    pub fn new(id: usize, cells: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        UiTableRow { id, cells: cells.into_iter().map(|cell| cell.into()).collect(), children: Vec::new(), expanded: false }
+    }
+    /// Adds the row as a child.
+    pub fn child(mut self, row: UiTableRow) -> Self {
+        self.children.push(row);
+        self
+    }
+    /// Replaces the expanded with a new value.
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+}
+
+/// **Ui table** - Lays out rows of text cells in columns of fixed or filling width. Rows can be nested,
+/// rows with children get an arrow expanding and collapsing them. Clicking a row selects it and sends [`TableRowSelected`].
+///
+/// Add it together with [`UiScrollArea`] to a node. The rows are virtualized through [`UiVirtualList`],
+/// so only the rows visible in the viewport are spawned, no matter how many the table holds.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Journal"))).with_children(|ui| {
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Quests"),
+///     UiLayout::window_full().pack::<Base>(),
+///     UiScrollArea::vertical(),
+///     UiTable::new([UiTableColumn::fill(1.0), UiTableColumn::fixed(120.0)])
+///         .row(UiTableRow::new(0, ["The Lost Sword", "Active"])
+///             .child(UiTableRow::new(1, ["Find the smith", "Done"]))
+///             .child(UiTableRow::new(2, ["Search the cave", "Active"]))
+///             .expanded(true)
+///         )
+///         .row(UiTableRow::new(3, ["Wolves at the Gate", "Failed"])),
+/// ));
+/// # });
+/// # }
+///
+/// fn open_quest(mut events: EventReader<TableRowSelected>) {
+///     for event in events.read() {
+///         info!("Opened quest {}", event.row);
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiTable {
+    /// Widths of the columns.
+    pub columns: Vec<UiTableColumn>,
+    /// The top level rows.
+    pub rows: Vec<UiTableRow>,
+    /// Id of the selected row.
+    pub selected: Option<usize>,
+    /// Height of one row in layout units.
+    pub row_height: f32,
+    /// Indentation of one nesting level in layout units.
+    pub indent: f32,
+    /// Color of the rows.
+    pub color: Color,
+    /// Color the rows transition to on hover.
+    pub hover_color: Color,
+    /// Color of the selected row.
+    pub selected_color: Color,
+    /// Color of the text.
+    pub text_color: Color,
+    /// Height of the text in percent of the row height.
+    pub text_size: f32,
+    /// Font of the text.
+    pub font: Handle<Font>,
+}
+impl UiTable {
+    /// Creates new empty table with the columns.
+    pub fn new(columns: impl IntoIterator<Item = UiTableColumn>) -> Self {
+        UiTable {
+            columns: columns.into_iter().collect(),
+            rows: Vec::new(),
+            selected: None,
+            row_height: 32.0,
+            indent: 20.0,
+            color: Color::NONE,
+            hover_color: Color::srgba(1.0, 1.0, 1.0, 0.08),
+            selected_color: Color::srgba(0.4, 0.7, 1.0, 0.3),
+            text_color: Color::WHITE,
+            text_size: 60.0,
+            font: Handle::default(),
+        }
+    }
+    /// Adds the row to the end of the table.
+    pub fn row(mut self, row: UiTableRow) -> Self {
+        self.rows.push(row);
+        self
+    }
+    /// Replaces the row height with a new value.
+    pub fn row_height(mut self, height: f32) -> Self {
+        self.row_height = height;
+        self
+    }
+    /// Replaces the indent with a new value.
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+    /// Replaces the color with a new value.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = color.into();
+        self
+    }
+    /// Replaces the hover color with a new value.
+    pub fn hover_color(mut self, color: impl Into<Color>) -> Self {
+        self.hover_color = color.into();
+        self
+    }
+    /// Replaces the selected color with a new value.
+    pub fn selected_color(mut self, color: impl Into<Color>) -> Self {
+        self.selected_color = color.into();
+        self
+    }
+    /// Replaces the text color with a new value.
+    pub fn text_color(mut self, color: impl Into<Color>) -> Self {
+        self.text_color = color.into();
+        self
+    }
+    /// Replaces the text size with a new value.
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = size;
+        self
+    }
+    /// Replaces the font with a new value.
+    pub fn font(mut self, font: Handle<Font>) -> Self {
+        self.font = font;
+        self
+    }
+    /// Returns the rows not hidden by a collapsed parent with their nesting level, in the order they are shown.
+    pub fn visible_rows(&self) -> Vec<(usize, &UiTableRow)> {
+        fn collect<'a>(rows: &'a [UiTableRow], level: usize, visible: &mut Vec<(usize, &'a UiTableRow)>) {
+            for row in rows {
+                visible.push((level, row));
+                if row.expanded { collect(&row.children, level + 1, visible); }
+            }
+        }
+        let mut visible = Vec::new();
+        collect(&self.rows, 0, &mut visible);
+        visible
+    }
+    /// Returns the row with the id, searching the nested rows too.
+    pub fn row_mut(&mut self, id: usize) -> Option<&mut UiTableRow> {
+        fn find(rows: &mut [UiTableRow], id: usize) -> Option<&mut UiTableRow> {
+            for row in rows {
+                if row.id == id { return Some(row) }
+                if let Some(row) = find(&mut row.children, id) { return Some(row) }
+            }
+            None
+        }
+        find(&mut self.rows, id)
+    }
+    /// Returns the layout of the column inside a row, use it to align header nodes with the table.
+    pub fn column_layout(&self, index: usize) -> UiLayout {
+        let (x, width) = self.column_span(index);
+        UiLayout::window().x(x).width(width).height(Rl(100.0)).pack::<Base>()
+    }
+    /// Returns the position and the width of the column inside a row.
+    fn column_span(&self, index: usize) -> (UiValue<f32>, UiValue<f32>) {
+        let fixed: f32 = self.columns.iter().map(|column| if let UiTableColumn::Fixed(width) = column { *width } else { 0.0 }).sum();
+        let weights: f32 = self.columns.iter().map(|column| if let UiTableColumn::Fill(weight) = column { *weight } else { 0.0 }).sum();
+        // Fill columns take their share of the row minus their share of the fixed columns
+        let width = |column: &UiTableColumn| -> UiValue<f32> {
+            match column {
+                UiTableColumn::Fixed(width) => Ab(*width).into(),
+                UiTableColumn::Fill(weight) if weights > 0.0 => Rl(100.0 * weight / weights) - Ab(fixed * weight / weights),
+                UiTableColumn::Fill(_) => Ab(0.0).into(),
+            }
+        };
+        let x = self.columns.iter().take(index).fold(UiValue::from(Ab(0.0)), |x, column| x + width(column));
+        (x, self.columns.get(index).map(width).unwrap_or(Ab(0.0).into()))
+    }
+}
+
+/// **Ui tree view** - [`UiTable`] used as a tree, usually with a single fill column and nested rows.
+pub type UiTreeView = UiTable;
+
+/// Row entity spawned by [`UiTable`], holding the entities of its cells.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiTableSlot {
+    /// The table entity.
+    pub table: Entity,
+    /// Id of the shown row.
+    pub row: Option<usize>,
+    /// The arrow expanding the row.
+    pub arrow: Entity,
+    /// The cells, one for each column.
+    pub cells: Vec<Entity>,
+}
+
+/// Arrow expanding and collapsing the row of [`UiTableSlot`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiTableArrow(pub Entity);
+
+/// This event is sent when a row of [`UiTable`] is selected by clicking it.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableRowSelected {
+    /// The table entity
+    pub target: Entity,
+    /// Id of the selected row
+    pub row: usize,
+}
+
+/// This system keeps [`UiVirtualList`] of [`UiTable`] matching the number of visible rows.
+fn table_list(mut commands: Commands, mut query: Query<(Entity, Ref<UiTable>, Option<&mut UiVirtualList>)>) {
+    for (entity, table, list) in &mut query {
+        if !table.is_changed() { continue }
+        let count = table.visible_rows().len();
+        match list {
+            Some(mut list) => {
+                if list.count() != count { list.set_count(count); }
+                if list.item_size != table.row_height { list.item_size = table.row_height; list.refresh(); }
+            },
+            None => { commands.entity(entity).insert(UiVirtualList::new(count, table.row_height, |_, _| {})); },
+        }
+    }
+}
+
+/// This system selects the clicked rows of [`UiTable`] and expands or collapses the rows of the clicked arrows.
+fn table_input(
+    mut clicks: EventReader<UiClickEvent>,
+    mut selected: EventWriter<TableRowSelected>,
+    arrows: Query<&UiTableArrow>,
+    slots: Query<&UiTableSlot, Without<UiDisabled>>,
+    mut tables: Query<&mut UiTable, Without<UiDisabled>>,
+) {
+    for event in clicks.read() {
+        let arrow = arrows.get(event.target).ok();
+        let Ok(slot) = slots.get(arrow.map_or(event.target, |arrow| arrow.0)) else { continue };
+        let (Some(row), Ok(mut table)) = (slot.row, tables.get_mut(slot.table)) else { continue };
+
+        if arrow.is_some() {
+            let Some(row) = table.row_mut(row) else { continue };
+            if row.children.is_empty() { continue }
+            row.expanded = !row.expanded;
+        } else {
+            if table.selected != Some(row) { table.selected = Some(row); }
+            selected.send(TableRowSelected { target: slot.table, row });
+        }
+    }
+}
+
+/// This system despawns the cells and arrows of [`UiTableSlot`] entities removed by [`UiVirtualList`].
+fn table_cleanup(mut commands: Commands, arrows: Query<(Entity, &UiTableArrow)>, cells: Query<(Entity, &UiTableCell)>, slots: Query<(), With<UiTableSlot>>) {
+    for (entity, slot) in arrows.iter().map(|(entity, arrow)| (entity, arrow.0)).chain(cells.iter().map(|(entity, cell)| (entity, cell.0))) {
+        if !slots.contains(slot) { commands.entity(entity).despawn_recursive(); }
+    }
+}
+
+/// Cell of the row of [`UiTableSlot`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+struct UiTableCell(Entity);
+
+/// This system turns the items of [`UiTable`] into rows and fills them with the text of the visible rows.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+pub fn table_update<T: Component>(
+    mut commands: Commands,
+    tables: Query<(Entity, Ref<UiTable>, &UiLink<T>, &Parent)>,
+    children: Query<&Children>,
+    mut items: Query<(Entity, Ref<UiVirtualItem>, &UiLink<T>, Option<&mut UiTableSlot>, Has<UiSelected>)>,
+    mut texts: Query<(&mut Text, &mut UiLayout)>,
+) {
+    for (table_entity, table, link, parent) in &tables {
+        let Ok(siblings) = children.get(parent.get()) else { continue };
+        let mut visible = None;
+
+        for sibling in siblings {
+            let Ok((entity, item, item_link, slot, is_selected)) = items.get_mut(*sibling) else { continue };
+            if item_link.path.rsplit_once('/').map(|(parent, _)| parent) != Some(link.path.as_str()) { continue }
+            if !table.is_changed() && !item.is_changed() && slot.is_some() { continue }
+
+            let visible = visible.get_or_insert_with(|| table.visible_rows());
+            let shown = visible.get(item.0).copied();
+            let row = shown.map(|(_, row)| row.id);
+
+            let style = TextStyle { font: table.font.clone(), font_size: table.text_size, color: table.text_color };
+            let contents = |column: usize| -> (String, UiLayout) {
+                let (level, row) = shown.unzip();
+                let text = row.and_then(|row| row.cells.get(column)).cloned().unwrap_or_default();
+                // The first column makes space for the arrow and the indentation
+                let (x, _) = table.column_span(column);
+                let x = if column == 0 { x + Ab(level.unwrap_or(0) as f32 * table.indent + table.row_height) } else { x + Ab(table.row_height * 0.25) };
+                (text, UiLayout::window().x(x).y(Rl(50.0)).anchor(Anchor::CenterLeft).pack::<Base>())
+            };
+            let arrow = |shown: Option<(usize, &UiTableRow)>| -> (String, UiLayout) {
+                let (level, row) = shown.unzip();
+                let text = match row { Some(row) if !row.children.is_empty() => if row.expanded { "-" } else { "+" }, _ => "" };
+                let x = Ab(level.unwrap_or(0) as f32 * table.indent + table.row_height * 0.5);
+                (text.to_string(), UiLayout::window().x(x).y(Rl(50.0)).anchor(Anchor::Center).pack::<Base>())
+            };
+
+            match slot {
+                Some(slot) if slot.cells.len() != table.columns.len() => {
+                    // The row is rebuilt with the new columns next frame
+                    for cell in std::iter::once(&slot.arrow).chain(slot.cells.iter()) {
+                        commands.entity(*cell).despawn_recursive();
+                    }
+                    commands.entity(entity).remove::<UiTableSlot>();
+                    continue;
+                },
+                Some(mut slot) => {
+                    let updates = std::iter::once((slot.arrow, arrow(shown))).chain(slot.cells.iter().enumerate().map(|(column, cell)| (*cell, contents(column))));
+                    for (entity, (value, new)) in updates {
+                        let Ok((mut text, mut layout)) = texts.get_mut(entity) else { continue };
+                        if let Some(section) = text.sections.first_mut() {
+                            if section.value != value { section.value = value; }
+                        }
+                        // The size is measured from the text, only the position is replaced
+                        let mut new = new.layout;
+                        if let (Layout::Window(new), Layout::Window(old)) = (&mut new, layout.layout) { new.size = old.size; }
+                        if layout.layout != new { layout.layout = new; }
+                    }
+                    if slot.row != row { slot.row = row; }
+                },
+                None => {
+                    #[cfg(feature = "verbose")]
+                    info!("{} {} - Turned into table row", "--".yellow(), item_link.path.yellow().bold());
+
+                    let (value, layout) = arrow(shown);
+                    let arrow = commands.spawn((
+                        item_link.add("arrow"),
+                        layout,
+                        UiText2dBundle { text: Text::from_section(value, style.clone()), ..default() },
+                        UiTableArrow(entity),
+                        UiClickEmitter::SELF,
+                    )).set_parent(parent.get()).id();
+                    let cells = (0..table.columns.len()).map(|column| {
+                        let (value, layout) = contents(column);
+                        commands.spawn((
+                            item_link.add(format!("c{column}")),
+                            layout,
+                            UiText2dBundle { text: Text::from_section(value, style.clone()), ..default() },
+                            UiTableCell(entity),
+                            Pickable::IGNORE,
+                        )).set_parent(parent.get()).id()
+                    }).collect();
+
+                    commands.entity(entity).insert((
+                        UiImage2dBundle::default(),
+                        PickableBundle::default(),
+                        UiColor::<Base>::new(table.color),
+                        UiColor::<Hover>::new(table.hover_color),
+                        UiColor::<Selected>::new(table.selected_color),
+                        UiAnimator::<Hover>::new().forward_speed(6.0).backward_speed(4.0),
+                        UiAnimator::<Selected>::new().forward_speed(8.0).backward_speed(8.0),
+                        UiClickEmitter::SELF,
+                        UiTableSlot { table: table_entity, row, arrow, cells },
+                    ));
+                },
+            }
+
+            let selected = row.is_some() && row == table.selected;
+            if selected && !is_selected { commands.entity(entity).insert(UiSelected); }
+            if !selected && is_selected { commands.entity(entity).remove::<UiSelected>(); }
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiTable`] logic.
+pub struct TablePlugin;
+impl Plugin for TablePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<TableRowSelected>()
+            .add_systems(Update, (table_input, table_list).chain().before(scroll_area_physics))
+            .add_systems(Update, table_cleanup.before(UiSystems::Send));
+    }
+}
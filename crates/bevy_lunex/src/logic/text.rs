@@ -0,0 +1,279 @@
+#[cfg(feature = "kira")]
+use bevy_kira_audio::prelude::*;
+
+use crate::*;
+use bevy::text::{update_text2d_layout, TextLayoutInfo};
+
+
+// #=====================#
+// #=== TEXT ANIMATOR ===#
+
+/// Per-character effect applied by [`UiTextAnimator`].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum UiTextEffect {
+    /// Characters move up and down in a sine wave.
+    Wave {
+        /// Height of the wave in text units.
+        amplitude: f32,
+        /// Speed of the wave in radians per second.
+        speed: f32,
+        /// Phase difference between two characters in radians.
+        spacing: f32,
+    },
+    /// Characters randomly jitter around their position.
+    Shake {
+        /// Maximum offset in text units.
+        strength: f32,
+        /// How many times per second the offset changes.
+        speed: f32,
+    },
+    /// The text color pulses between the section color and the given color.
+    /// Colors are applied per section, not per character.
+    ColorPulse {
+        /// The color to pulse into.
+        color: Color,
+        /// Speed of the pulse in radians per second.
+        speed: f32,
+    },
+}
+
+/// **Ui text animator** - Reveals the text character by character and optionally applies [`UiTextEffect`] to the glyphs.
+/// Sends [`TextRevealCharacter`] for every revealed character and [`TextRevealFinished`] once the whole text is shown.
+/// The animator controls the first text section.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let animator = UiTextAnimator::new("Hello traveler!")
+///     .rate(30.0)
+///     .effect(UiTextEffect::Wave { amplitude: 4.0, speed: 6.0, spacing: 0.5 });
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiTextAnimator {
+    /// The full text to reveal.
+    text: String,
+    /// Number of revealed characters, including fraction of the next one.
+    progress: f32,
+    /// Characters revealed per second.
+    pub rate: f32,
+    /// If the reveal is paused.
+    pub paused: bool,
+    /// Effect applied to the glyphs.
+    pub effect: Option<UiTextEffect>,
+    /// Offsets applied to glyphs last frame.
+    applied: Vec<Vec2>,
+    /// Base colors of text sections, captured on the first color pulse.
+    base_colors: Vec<Color>,
+    /// Time since the animator was created.
+    time: f32,
+    /// If the finished event was already sent.
+    finished: bool,
+}
+impl UiTextAnimator {
+    /// Creates new animator that reveals the text with 20 characters per second.
+    pub fn new(text: impl Into<String>) -> Self {
+        UiTextAnimator {
+            text: text.into(),
+            progress: 0.0,
+            rate: 20.0,
+            paused: false,
+            effect: None,
+            applied: Vec::new(),
+            base_colors: Vec::new(),
+            time: 0.0,
+            finished: false,
+        }
+    }
+    /// Replaces the reveal rate with a new value.
+    pub fn rate(mut self, rate: f32) -> Self {
+        self.rate = rate;
+        self
+    }
+    /// Replaces the effect with a new value.
+    pub fn effect(mut self, effect: UiTextEffect) -> Self {
+        self.effect = Some(effect);
+        self
+    }
+    /// Returns the full text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    /// Replaces the text and restarts the reveal.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+        self.restart();
+    }
+    /// Returns the number of characters currently revealed.
+    pub fn revealed(&self) -> usize {
+        (self.progress as usize).min(self.text.chars().count())
+    }
+    /// Returns `true` if the whole text is revealed.
+    pub fn is_finished(&self) -> bool {
+        self.revealed() == self.text.chars().count()
+    }
+    /// Pauses the reveal.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+    /// Resumes the reveal.
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+    /// Reveals the whole text immediately.
+    pub fn skip(&mut self) {
+        self.progress = self.text.chars().count() as f32;
+    }
+    /// Starts the reveal from the beginning.
+    pub fn restart(&mut self) {
+        self.progress = 0.0;
+        self.finished = false;
+    }
+}
+
+/// This event is sent for every character revealed by [`UiTextAnimator`].
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct TextRevealCharacter {
+    pub target: Entity,
+    pub character: char,
+}
+
+/// This event is sent and triggered on the entity once [`UiTextAnimator`] revealed the whole text.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct TextRevealFinished {
+    pub target: Entity,
+}
+
+/// This system progresses the reveal of [`UiTextAnimator`] and writes the revealed text.
+fn text_animator_reveal(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut UiTextAnimator, &mut Text)>,
+    mut reveal: EventWriter<TextRevealCharacter>,
+    mut finished: EventWriter<TextRevealFinished>,
+) {
+    for (entity, mut animator, mut text) in &mut query {
+        animator.time += time.delta_seconds();
+
+        let previous = animator.revealed();
+        if !animator.paused && !animator.is_finished() {
+            animator.progress += animator.rate * time.delta_seconds();
+        }
+        let revealed = animator.revealed();
+
+        if text.sections.is_empty() { continue }
+        if revealed != previous || text.sections[0].value.chars().count() != revealed {
+            for character in animator.text.chars().skip(previous).take(revealed.saturating_sub(previous)) {
+                reveal.send(TextRevealCharacter { target: entity, character });
+            }
+            text.sections[0].value = animator.text.chars().take(revealed).collect();
+        }
+
+        if animator.is_finished() && !animator.finished {
+            animator.finished = true;
+            finished.send(TextRevealFinished { target: entity });
+            commands.trigger_targets(TextRevealFinished { target: entity }, entity);
+        }
+    }
+}
+
+/// Cheap deterministic noise in range `-1.0..1.0`.
+fn noise(seed: f32) -> f32 {
+    ((seed * 12.9898).sin() * 43758.547).fract() * 2.0 - 1.0
+}
+
+/// This system applies [`UiTextEffect`] to the laid out glyphs. Runs after text layout so the offsets are not lost.
+fn text_animator_effect(mut query: Query<(&mut UiTextAnimator, Ref<Text>, &mut TextLayoutInfo)>) {
+    for (mut animator, text, mut info) in &mut query {
+        // Text was laid out again, previous offsets are gone
+        if text.is_changed() || animator.applied.len() != info.glyphs.len() {
+            animator.applied = vec![Vec2::ZERO; info.glyphs.len()];
+        }
+
+        // Glyphs move every frame, don't trigger the text size systems
+        let info = info.bypass_change_detection();
+        let t = animator.time;
+        match animator.effect {
+            Some(UiTextEffect::Wave { amplitude, speed, spacing }) => {
+                let animator = animator.as_mut();
+                for (i, (glyph, applied)) in info.glyphs.iter_mut().zip(&mut animator.applied).enumerate() {
+                    let offset = Vec2::new(0.0, (t * speed + i as f32 * spacing).sin() * amplitude);
+                    glyph.position += offset - *applied;
+                    *applied = offset;
+                }
+            },
+            Some(UiTextEffect::Shake { strength, speed }) => {
+                let step = (t * speed).floor();
+                let animator = animator.as_mut();
+                for (i, (glyph, applied)) in info.glyphs.iter_mut().zip(&mut animator.applied).enumerate() {
+                    let seed = step + i as f32 * 7.31;
+                    let offset = Vec2::new(noise(seed), noise(seed + 3.17)) * strength;
+                    glyph.position += offset - *applied;
+                    *applied = offset;
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+/// This system applies [`UiTextEffect::ColorPulse`] to the text sections.
+fn text_animator_color(mut query: Query<(&mut UiTextAnimator, &mut Text)>) {
+    for (mut animator, mut text) in &mut query {
+        let Some(UiTextEffect::ColorPulse { color, speed }) = animator.effect else { continue };
+        if animator.base_colors.len() != text.sections.len() {
+            animator.base_colors = text.sections.iter().map(|section| section.style.color).collect();
+        }
+        // Color does not affect the text layout, so skip the relayout
+        let factor = (animator.time * speed).sin() * 0.5 + 0.5;
+        for (section, base) in text.bypass_change_detection().sections.iter_mut().zip(&animator.base_colors) {
+            section.style.color = base.mix(&color, factor);
+        }
+    }
+}
+
+
+// #=============#
+// #=== SOUND ===#
+
+/// Plays the sound for every revealed non-whitespace character of [`UiTextAnimator`].
+#[cfg(feature = "kira")]
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct OnRevealPlaySound {
+    pub sound: Handle<bevy_kira_audio::AudioSource>,
+}
+#[cfg(feature = "kira")]
+impl OnRevealPlaySound {
+    /// Specify the sound to play.
+    pub fn new(sound: Handle<bevy_kira_audio::AudioSource>) -> Self {
+        OnRevealPlaySound {
+            sound,
+        }
+    }
+}
+#[cfg(feature = "kira")]
+fn on_reveal_play_sound_system(mut events: EventReader<TextRevealCharacter>, audio: Res<AudioChannel<UiSoundChannel>>, query: Query<&OnRevealPlaySound>) {
+    for event in events.read() {
+        if event.character.is_whitespace() { continue }
+        if let Ok(listener) = query.get(event.target) {
+            audio.play(listener.sound.clone());
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiTextAnimator`] logic.
+pub struct TextAnimatorPlugin;
+impl Plugin for TextAnimatorPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "kira")]
+        app.add_systems(Update, on_reveal_play_sound_system.run_if(on_event::<TextRevealCharacter>()));
+
+        app
+            .add_event::<TextRevealCharacter>()
+            .add_event::<TextRevealFinished>()
+            .add_systems(Update, (text_animator_reveal, text_animator_color).chain().before(UiSystems::Modify))
+            .add_systems(PostUpdate, text_animator_effect.after(update_text2d_layout));
+    }
+}
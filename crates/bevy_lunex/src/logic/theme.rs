@@ -0,0 +1,209 @@
+use crate::*;
+use bevy::utils::HashMap;
+
+
+// #=============#
+// #=== THEME ===#
+
+/// Theme mode that decides which color of [`ColorPair`] is used.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum ThemeMode {
+    #[default]
+    Dark,
+    Light,
+}
+impl ThemeMode {
+    /// Returns the opposite mode.
+    pub fn toggled(&self) -> Self {
+        match self {
+            ThemeMode::Dark => ThemeMode::Light,
+            ThemeMode::Light => ThemeMode::Dark,
+        }
+    }
+}
+
+/// A color defined for both dark and light [`ThemeMode`].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct ColorPair {
+    /// Color used in [`ThemeMode::Dark`].
+    pub dark: Color,
+    /// Color used in [`ThemeMode::Light`].
+    pub light: Color,
+}
+impl ColorPair {
+    /// Creates new color pair.
+    pub fn new(dark: impl Into<Color>, light: impl Into<Color>) -> Self {
+        ColorPair { dark: dark.into(), light: light.into() }
+    }
+    /// Creates new color pair with the same color for both modes.
+    pub fn splat(color: impl Into<Color>) -> Self {
+        let color = color.into();
+        ColorPair { dark: color, light: color }
+    }
+    /// Returns the color for the mode.
+    pub fn get(&self, mode: ThemeMode) -> Color {
+        match mode {
+            ThemeMode::Dark => self.dark,
+            ThemeMode::Light => self.light,
+        }
+    }
+}
+
+/// **Theme** - Resource holding the named palette of [`ColorPair`] and the active [`ThemeMode`].
+/// Entities with [`ThemeColor`] are retinted when the theme changes. Use [`SwitchTheme`] to change the mode with a transition.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// let theme = Theme::new()
+///     .with("background", ColorPair::new(Color::srgb(0.1, 0.1, 0.1), Color::srgb(0.95, 0.95, 0.95)))
+///     .with("text", ColorPair::new(Color::WHITE, Color::BLACK))
+///     .transition(0.3);
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct Theme {
+    /// The named colors.
+    pub palette: HashMap<String, ColorPair>,
+    /// The active mode.
+    pub mode: ThemeMode,
+    /// Duration of the transition between modes in seconds.
+    pub transition: f32,
+    /// The mode we are transitioning from.
+    previous: ThemeMode,
+    /// Progress of the transition from `0.0` to `1.0`.
+    progress: f32,
+}
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            palette: HashMap::new(),
+            mode: ThemeMode::default(),
+            transition: 0.0,
+            previous: ThemeMode::default(),
+            progress: 1.0,
+        }
+    }
+}
+impl Theme {
+    /// Creates new empty theme in dark mode.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Adds the color to the palette. Builder variant of [`Theme::insert`].
+    pub fn with(mut self, name: impl Into<String>, color: ColorPair) -> Self {
+        self.insert(name, color);
+        self
+    }
+    /// Replaces the mode with a new value.
+    pub fn mode(mut self, mode: ThemeMode) -> Self {
+        self.mode = mode;
+        self.previous = mode;
+        self
+    }
+    /// Replaces the transition duration with a new value.
+    pub fn transition(mut self, transition: f32) -> Self {
+        self.transition = transition;
+        self
+    }
+    /// Inserts the color into the palette, replacing the previous definition.
+    pub fn insert(&mut self, name: impl Into<String>, color: ColorPair) {
+        self.palette.insert(name.into(), color);
+    }
+    /// Starts the transition to the new mode.
+    pub fn switch(&mut self, mode: ThemeMode) {
+        if mode == self.mode { return }
+        self.previous = self.mode;
+        self.mode = mode;
+        self.progress = if self.transition > 0.0 { 0.0 } else { 1.0 };
+    }
+    /// Returns `true` if the theme is transitioning between modes.
+    pub fn is_transitioning(&self) -> bool {
+        self.progress < 1.0
+    }
+    /// Returns the current color of the named palette entry, including the transition.
+    pub fn color(&self, name: &str) -> Option<Color> {
+        let pair = self.palette.get(name)?;
+        Some(pair.get(self.previous).mix(&pair.get(self.mode), self.progress.clamp(0.0, 1.0)))
+    }
+}
+
+/// **Theme color** - Sets the color of the entity to the named color of the active [`Theme`].
+/// Overrides [`UiColor<Base>`] if present.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let color = ThemeColor::new("background");
+/// ```
+#[derive(Component, Debug, Default, Clone, PartialEq, Eq)]
+pub struct ThemeColor(pub String);
+impl ThemeColor {
+    /// Creates new component from the palette name.
+    pub fn new(name: impl Into<String>) -> Self {
+        ThemeColor(name.into())
+    }
+}
+
+/// This event will switch the [`ThemeMode`] of the [`Theme`]. If the mode is [`None`], the mode is toggled.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct SwitchTheme {
+    pub mode: Option<ThemeMode>,
+}
+impl SwitchTheme {
+    /// Switches to the mode.
+    pub fn to(mode: ThemeMode) -> Self {
+        SwitchTheme { mode: Some(mode) }
+    }
+    /// Toggles between dark and light mode.
+    pub fn toggle() -> Self {
+        SwitchTheme { mode: None }
+    }
+}
+
+/// This system handles [`SwitchTheme`] events.
+fn apply_event_switch_theme(mut events: EventReader<SwitchTheme>, mut theme: ResMut<Theme>) {
+    for event in events.read() {
+        let mode = event.mode.unwrap_or(theme.mode.toggled());
+        theme.switch(mode);
+    }
+}
+
+/// This system progresses the theme transition.
+fn theme_transition(time: Res<Time>, mut theme: ResMut<Theme>) {
+    if !theme.is_transitioning() { return }
+    theme.progress = (theme.progress + time.delta_seconds() / theme.transition).min(1.0);
+}
+
+/// This system retints entities with [`ThemeColor`] when the theme or the component changes.
+fn theme_color_update(
+    theme: Res<Theme>,
+    mut query: Query<(Entity, Ref<ThemeColor>, Option<&mut UiColor<Base>>)>,
+    mut set_color: EventWriter<actions::SetColor>,
+) {
+    for (entity, name, base) in &mut query {
+        if !theme.is_changed() && !name.is_changed() { continue }
+        let Some(color) = theme.color(&name.0) else { continue };
+        if let Some(mut base) = base {
+            base.color = color;
+        }
+        set_color.send(actions::SetColor { target: entity, color });
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`Theme`] logic.
+pub struct ThemePlugin;
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Theme>()
+            .add_event::<SwitchTheme>()
+            .add_systems(Update, (
+                apply_event_switch_theme.run_if(on_event::<SwitchTheme>()),
+                theme_transition,
+                theme_color_update,
+            ).chain().before(UiSystems::Modify));
+    }
+}
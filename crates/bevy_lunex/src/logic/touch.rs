@@ -0,0 +1,316 @@
+use crate::*;
+use bevy::utils::HashMap;
+
+
+// #=====================#
+// #=== POINTER STATE ===#
+
+/// Lists every pointer currently hovering or pressing the entity.
+/// Unlike [`UiAnimator<Hover>`] this keeps multiple touches apart, so you can tell how many fingers are on the node.
+/// Add it to nodes that need the per-pointer state, it is kept up to date automatically.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct UiPointerState {
+    /// Pointers hovering the entity.
+    pub hovered: Vec<PointerId>,
+    /// Pointers that were pressed on the entity and were not released yet.
+    pub pressed: Vec<PointerId>,
+}
+impl UiPointerState {
+    /// Creates new struct
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Returns `true` if any pointer hovers the entity.
+    pub fn is_hovered(&self) -> bool {
+        !self.hovered.is_empty()
+    }
+    /// Returns `true` if any pointer presses the entity.
+    pub fn is_pressed(&self) -> bool {
+        !self.pressed.is_empty()
+    }
+}
+
+/// This system updates [`UiPointerState`] from the picking events.
+fn update_pointer_state(
+    mut over: EventReader<Pointer<Over>>,
+    mut out: EventReader<Pointer<Out>>,
+    mut down: EventReader<Pointer<Down>>,
+    mut up: EventReader<Pointer<Up>>,
+    pointers: Query<(&PointerId, &PointerPress)>,
+    mut query: Query<&mut UiPointerState>,
+) {
+    for event in out.read() {
+        if let Ok(mut state) = query.get_mut(event.target) {
+            state.hovered.retain(|pointer| *pointer != event.pointer_id);
+        }
+    }
+    for event in over.read() {
+        if let Ok(mut state) = query.get_mut(event.target) {
+            if !state.hovered.contains(&event.pointer_id) { state.hovered.push(event.pointer_id) }
+        }
+    }
+    for event in down.read() {
+        if event.button != PointerButton::Primary { continue }
+        if let Ok(mut state) = query.get_mut(event.target) {
+            if !state.pressed.contains(&event.pointer_id) { state.pressed.push(event.pointer_id) }
+        }
+    }
+    for event in up.read() {
+        if event.button != PointerButton::Primary { continue }
+        if let Ok(mut state) = query.get_mut(event.target) {
+            state.pressed.retain(|pointer| *pointer != event.pointer_id);
+        }
+    }
+
+    // Pointers released outside of the entity don't send up events to it
+    for mut state in &mut query {
+        if state.pressed.is_empty() { continue }
+        let released = |id: &PointerId| !pointers.iter().any(|(pointer, press)| pointer == id && press.is_primary_pressed());
+        if state.pressed.iter().any(released) {
+            state.pressed.retain(|id| !released(id));
+        }
+    }
+}
+
+
+// #================#
+// #=== GESTURES ===#
+
+/// **Ui gestures** - Enables gesture recognition on the entity. Recognized gestures are sent as events
+/// and also triggered on the entity, so you can listen to them with observers.
+/// All distances are in window pixels and all times are in seconds.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn(UiGestures::new().long_press_time(0.8))
+///     .observe(|trigger: Trigger<UiSwipe>| {
+///         info!("Swiped {:?}", trigger.event().direction());
+///     });
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiGestures {
+    /// Maximum duration of a tap.
+    pub tap_time: f32,
+    /// Maximum distance the pointer can move and still count as tap or long press.
+    pub tap_distance: f32,
+    /// Duration after which a still press becomes a long press.
+    pub long_press_time: f32,
+    /// Minimum distance the pointer has to move to count as swipe.
+    pub swipe_distance: f32,
+    /// Maximum duration of a swipe.
+    pub swipe_time: f32,
+}
+impl Default for UiGestures {
+    fn default() -> Self {
+        UiGestures {
+            tap_time: 0.3,
+            tap_distance: 10.0,
+            long_press_time: 0.5,
+            swipe_distance: 50.0,
+            swipe_time: 0.5,
+        }
+    }
+}
+impl UiGestures {
+    /// Creates new struct with default thresholds.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Replaces the tap time with a new value.
+    pub fn tap_time(mut self, time: f32) -> Self {
+        self.tap_time = time;
+        self
+    }
+    /// Replaces the tap distance with a new value.
+    pub fn tap_distance(mut self, distance: f32) -> Self {
+        self.tap_distance = distance;
+        self
+    }
+    /// Replaces the long press time with a new value.
+    pub fn long_press_time(mut self, time: f32) -> Self {
+        self.long_press_time = time;
+        self
+    }
+    /// Replaces the swipe distance with a new value.
+    pub fn swipe_distance(mut self, distance: f32) -> Self {
+        self.swipe_distance = distance;
+        self
+    }
+    /// Replaces the swipe time with a new value.
+    pub fn swipe_time(mut self, time: f32) -> Self {
+        self.swipe_time = time;
+        self
+    }
+}
+
+/// This event is sent when a pointer is quickly pressed and released on the entity.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct UiTap {
+    pub target: Entity,
+    pub pointer_id: PointerId,
+    /// Position of the pointer in window pixels.
+    pub position: Vec2,
+}
+
+/// This event is sent once a pointer is held still on the entity for [`UiGestures::long_press_time`].
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct UiLongPress {
+    pub target: Entity,
+    pub pointer_id: PointerId,
+    /// Position of the pointer in window pixels.
+    pub position: Vec2,
+}
+
+/// This event is sent when a pointer pressed on the entity is quickly moved and released.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct UiSwipe {
+    pub target: Entity,
+    pub pointer_id: PointerId,
+    /// Distance travelled in window pixels, `y` points down.
+    pub delta: Vec2,
+    /// Average speed in pixels per second.
+    pub velocity: f32,
+}
+impl UiSwipe {
+    /// Returns the dominant direction of the swipe.
+    pub fn direction(&self) -> UiSwipeDirection {
+        if self.delta.x.abs() >= self.delta.y.abs() {
+            if self.delta.x < 0.0 { UiSwipeDirection::Left } else { UiSwipeDirection::Right }
+        } else if self.delta.y < 0.0 { UiSwipeDirection::Up } else { UiSwipeDirection::Down }
+    }
+}
+
+/// Dominant direction of [`UiSwipe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum UiSwipeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// This event is sent every frame two pointers pressed on the entity change their distance.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct UiPinch {
+    pub target: Entity,
+    /// Ratio between the current and the last distance of the pointers, above `1.0` means zooming in.
+    pub scale: f32,
+    /// Point between the pointers in window pixels.
+    pub center: Vec2,
+}
+
+/// Pointer pressed on an entity with [`UiGestures`].
+#[derive(Debug, Clone, Copy)]
+struct GestureTrack {
+    target: Entity,
+    start: Vec2,
+    last: Vec2,
+    time: f32,
+    long_pressed: bool,
+    pinched: bool,
+}
+
+/// This system recognizes gestures of the pressed pointers and sends them as events.
+fn recognize_gestures(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut down: EventReader<Pointer<Down>>,
+    mut tracks: Local<HashMap<PointerId, GestureTrack>>,
+    mut pinches: Local<HashMap<Entity, f32>>,
+    pointers: Query<(&PointerId, &PointerLocation, &PointerPress)>,
+    query: Query<&UiGestures>,
+    mut tap: EventWriter<UiTap>,
+    mut long_press: EventWriter<UiLongPress>,
+    mut swipe: EventWriter<UiSwipe>,
+    mut pinch: EventWriter<UiPinch>,
+) {
+    let now = time.elapsed_seconds();
+    for event in down.read() {
+        if event.button != PointerButton::Primary || !query.contains(event.target) { continue }
+        let position = event.pointer_location.position;
+        tracks.insert(event.pointer_id, GestureTrack { target: event.target, start: position, last: position, time: now, long_pressed: false, pinched: false });
+    }
+
+    let mut released = Vec::new();
+    for (pointer_id, track) in tracks.iter_mut() {
+        let Ok(gestures) = query.get(track.target) else { released.push(*pointer_id); continue };
+        let pointer = pointers.iter().find(|(id, ..)| *id == pointer_id);
+        if let Some(position) = pointer.and_then(|(_, location, _)| location.location()).map(|location| location.position) {
+            track.last = position;
+        }
+
+        let delta = track.last - track.start;
+        let duration = now - track.time;
+        let pressed = matches!(pointer, Some((_, _, press)) if press.is_primary_pressed());
+
+        if pressed {
+            if !track.long_pressed && !track.pinched && duration >= gestures.long_press_time && delta.length() <= gestures.tap_distance {
+                track.long_pressed = true;
+                let event = UiLongPress { target: track.target, pointer_id: *pointer_id, position: track.last };
+                long_press.send(event);
+                commands.trigger_targets(event, track.target);
+            }
+            continue;
+        }
+
+        released.push(*pointer_id);
+        if track.long_pressed || track.pinched { continue }
+        if duration <= gestures.tap_time && delta.length() <= gestures.tap_distance {
+            let event = UiTap { target: track.target, pointer_id: *pointer_id, position: track.last };
+            tap.send(event);
+            commands.trigger_targets(event, track.target);
+        } else if duration <= gestures.swipe_time && delta.length() >= gestures.swipe_distance {
+            let event = UiSwipe { target: track.target, pointer_id: *pointer_id, delta, velocity: delta.length() / duration.max(f32::EPSILON) };
+            swipe.send(event);
+            commands.trigger_targets(event, track.target);
+        }
+    }
+    for pointer_id in released {
+        tracks.remove(&pointer_id);
+    }
+
+    // Group the pressed pointers by target to find pinches
+    let mut groups: HashMap<Entity, Vec<PointerId>> = HashMap::new();
+    for (pointer_id, track) in tracks.iter() {
+        groups.entry(track.target).or_default().push(*pointer_id);
+    }
+    pinches.retain(|target, _| matches!(groups.get(target), Some(group) if group.len() >= 2));
+    for (target, mut group) in groups {
+        if group.len() < 2 { continue }
+        group.sort_by(|a, b| tracks[a].time.total_cmp(&tracks[b].time));
+        let (a, b) = (tracks[&group[0]].last, tracks[&group[1]].last);
+        for pointer_id in &group {
+            if let Some(track) = tracks.get_mut(pointer_id) { track.pinched = true }
+        }
+
+        let distance = a.distance(b);
+        if let Some(last) = pinches.insert(target, distance) {
+            if last > 0.0 && last != distance {
+                let event = UiPinch { target, scale: distance / last, center: (a + b) / 2.0 };
+                pinch.send(event);
+                commands.trigger_targets(event, target);
+            }
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiPointerState`] and [`UiGestures`] logic.
+pub struct TouchPlugin;
+impl Plugin for TouchPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<UiTap>()
+            .add_event::<UiLongPress>()
+            .add_event::<UiSwipe>()
+            .add_event::<UiPinch>()
+            .add_systems(Update, (update_pointer_state, recognize_gestures));
+    }
+}
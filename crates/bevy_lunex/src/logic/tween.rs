@@ -0,0 +1,158 @@
+use crate::*;
+use lunex_engine::*;
+
+
+// #==============#
+// #=== EASING ===#
+
+/// Easing curves for [`UiLayoutTween`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UiEasing {
+    /// Constant speed.
+    Linear,
+    /// Starts slow and speeds up.
+    EaseIn,
+    /// Starts fast and slows down.
+    #[default]
+    EaseOut,
+    /// Starts and ends slow.
+    EaseInOut,
+}
+impl UiEasing {
+    /// Maps the linear progress ranging from `0.0` to `1.0` onto the curve.
+    pub fn apply(&self, t: f32) -> f32 {
+        match self {
+            UiEasing::Linear => t,
+            UiEasing::EaseIn => t * t * t,
+            UiEasing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            UiEasing::EaseInOut => if t < 0.5 { 4.0 * t * t * t } else { 1.0 - (-2.0 * t + 2.0).powi(3) / 2.0 },
+        }
+    }
+}
+
+
+// #====================#
+// #=== LAYOUT TWEEN ===#
+
+/// **Ui layout tween** - Animates the node from its previous rectangle to the new one when [`UiLayout<Base>`] changes, instead of snapping.
+/// With [`UiLayoutTween::reflow`], the node also animates when it is moved by other nodes, for example when a sibling in
+/// the [`Div`](ui::Div) flow is spawned or despawned. Changes caused by resizing the tree are not animated. Subnodes are computed from
+/// the final rectangle, give them this component too if they should follow the animation.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Board"))).with_children(|ui| {
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Card"),
+///     UiLayout::window().pos(Rl((10.0, 80.0))).size(Rl((10.0, 15.0))).pack::<Base>(),
+///     UiLayoutTween::new(0.3).easing(UiEasing::EaseInOut),
+/// ));
+/// # });
+/// # }
+///
+/// // The card now slides to the board instead of teleporting
+/// fn play_card(mut query: Query<&mut UiLayout, With<UiLayoutTween>>) {
+///     for mut layout in &mut query {
+///         *layout = UiLayout::window().pos(Rl((45.0, 40.0))).size(Rl((10.0, 15.0))).pack();
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiLayoutTween {
+    /// Duration of the animation in seconds.
+    pub duration: f32,
+    /// Easing curve of the animation.
+    pub easing: UiEasing,
+    /// If the node animates when moved by other nodes, not only when its layout changes.
+    pub reflow: bool,
+    /// Rectangle the animation started from.
+    from: Rectangle3D,
+    /// Rectangle the animation ends at.
+    to: Rectangle3D,
+    /// Rectangle displayed in the last frame, `None` before the first computation.
+    current: Option<Rectangle3D>,
+    /// Seconds since the animation started.
+    elapsed: f32,
+}
+impl UiLayoutTween {
+    /// Creates new tween with the duration in seconds.
+    pub fn new(duration: f32) -> Self {
+        UiLayoutTween {
+            duration,
+            easing: UiEasing::default(),
+            reflow: false,
+            from: Rectangle3D::default(),
+            to: Rectangle3D::default(),
+            current: None,
+            elapsed: duration,
+        }
+    }
+    /// Replaces the easing with a new value.
+    pub fn easing(mut self, easing: UiEasing) -> Self {
+        self.easing = easing;
+        self
+    }
+    /// Replaces the reflow value with a new value.
+    pub fn reflow(mut self, reflow: bool) -> Self {
+        self.reflow = reflow;
+        self
+    }
+    /// Returns `true` if the node is being animated.
+    pub fn is_playing(&self) -> bool {
+        self.elapsed < self.duration
+    }
+}
+impl Default for UiLayoutTween {
+    fn default() -> Self {
+        UiLayoutTween::new(0.25)
+    }
+}
+
+/// This system animates [`UiLayoutTween`] nodes by overwriting the fetched [`Transform`] and [`Dimension`] data.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn layout_tween<T:Component, N:Default + Component>(
+    time: Res<Time>,
+    uis: Query<(&UiTree<T, N>, Ref<Dimension>, &Children)>,
+    mut query: Query<(&UiLink<T>, Ref<UiLayout>, &mut UiLayoutTween, &mut Transform, &mut Dimension, Has<Element>), Without<UiTree<T, N>>>,
+) {
+    for (ui, tree_dimension, children) in &uis {
+        for child in children {
+            let Ok((link, layout, mut tween, mut transform, mut dimension, is_element)) = query.get_mut(*child) else { continue };
+            let Some(target) = ui.borrow_node(link.path.clone()).ok().and_then(|node| node.obtain_data()).map(|container| container.rectangle) else { continue };
+
+            let Some(current) = tween.current else {
+                tween.current = Some(target);
+                tween.to = target;
+                continue;
+            };
+            let relayout = layout.is_changed() && !layout.is_added();
+            let reflow = tween.reflow && !tree_dimension.is_changed();
+            if (relayout || reflow) && target != tween.to {
+                tween.from = current;
+                tween.elapsed = 0.0;
+            }
+            tween.to = target;
+            if !tween.is_playing() {
+                tween.current = Some(target);
+                continue;
+            }
+
+            tween.elapsed += time.delta_seconds();
+            let progress = tween.easing.apply((tween.elapsed / tween.duration).min(1.0));
+            let rectangle = tween.from.lerp(target, progress);
+            tween.current = Some(rectangle);
+
+            let mut translation = rectangle.pos.invert_y();
+            if is_element {
+                translation.x += rectangle.size.x /  2.0;
+                translation.y += rectangle.size.y / -2.0;
+            }
+            if transform.translation != translation { transform.translation = translation }
+            if dimension.size != rectangle.size { dimension.size = rectangle.size }
+        }
+    }
+}
@@ -0,0 +1,489 @@
+use crate::*;
+use bevy::render::{camera::RenderTarget, render_asset::RenderAssetUsages, render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages}, view::RenderLayers};
+use bevy::transform::TransformSystem;
+
+
+// #=================#
+// #=== BILLBOARD ===#
+
+/// **Ui billboard** - Keeps the 3D [`UiTree`] or node facing the active 3D camera.
+/// The rotation is computed after the ui is fetched and before the transforms are propagated.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         UiTreeBundle::<MainUi> { tree: UiTree::new3d("Nameplate"), ..default() },
+///         UiBillboard::LockY,
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum UiBillboard {
+    /// Faces the camera on all axes, aligned with the camera up vector.
+    #[default]
+    Full,
+    /// Rotates only around the Y axis, staying upright.
+    LockY,
+}
+
+/// Returns the global transform of the active 3D camera with the highest order.
+fn active_camera(cameras: &Query<(&Camera, &GlobalTransform), With<Camera3d>>) -> Option<GlobalTransform> {
+    cameras.iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order)
+        .map(|(_, transform)| *transform)
+}
+
+/// This system rotates entities with [`UiBillboard`] to face the active camera.
+pub fn billboard_face_camera(
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    mut query: Query<(&UiBillboard, &mut Transform, &GlobalTransform)>,
+) {
+    let Some(camera) = active_camera(&cameras) else { return };
+    for (billboard, mut transform, global) in &mut query {
+        let (_, global_rotation, position) = global.to_scale_rotation_translation();
+        let mut direction = camera.translation() - position;
+
+        let up = match billboard {
+            UiBillboard::Full => camera.up().into(),
+            UiBillboard::LockY => {
+                direction.y = 0.0;
+                Vec3::Y
+            },
+        };
+        if direction.length_squared() < f32::EPSILON { continue }
+
+        // The ui plane faces +Z, so look away from the camera
+        let world_rotation = Transform::default().looking_to(-direction, up).rotation;
+
+        // Remove the rotation inherited from the parent
+        let parent_rotation = global_rotation * transform.rotation.inverse();
+        let rotation = parent_rotation.inverse() * world_rotation;
+        if transform.rotation != rotation {
+            transform.rotation = rotation;
+        }
+    }
+}
+
+
+// #======================#
+// #=== DISTANCE SCALE ===#
+
+/// **Ui distance scale** - Scales the 3D [`UiTree`] based on the distance to the active 3D camera and optionally fades it out.
+/// At the reference distance the tree has its original scale. The scale grows linearly with the distance,
+/// so the tree keeps the same size on screen until it hits the clamps.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         UiTreeBundle::<MainUi> { tree: UiTree::new3d("Nameplate"), ..default() },
+///         UiDistanceScale::new(10.0).clamp(0.5, 3.0).fade(40.0, 50.0),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiDistanceScale {
+    /// Distance at which the scale is `1.0`.
+    pub reference: f32,
+    /// Minimal scale multiplier.
+    pub min: f32,
+    /// Maximal scale multiplier.
+    pub max: f32,
+    /// Distance range `(start, end)` over which the tree fades out.
+    pub fade: Option<(f32, f32)>,
+    /// The scale of the transform before it was modified.
+    base_scale: Option<Vec3>,
+    /// The computed opacity.
+    opacity: f32,
+}
+impl UiDistanceScale {
+    /// Creates new distance scale with the reference distance and no clamps.
+    pub fn new(reference: f32) -> Self {
+        UiDistanceScale {
+            reference,
+            min: 0.0,
+            max: f32::MAX,
+            fade: None,
+            base_scale: None,
+            opacity: 1.0,
+        }
+    }
+    /// Replaces the scale clamps with new values.
+    pub fn clamp(mut self, min: f32, max: f32) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+    /// Replaces the fade distance range with new values.
+    pub fn fade(mut self, start: f32, end: f32) -> Self {
+        self.fade = Some((start, end));
+        self
+    }
+    /// Returns the scale multiplier for the distance.
+    pub fn scale_at(&self, distance: f32) -> f32 {
+        if self.reference <= 0.0 { return 1.0 }
+        (distance / self.reference).clamp(self.min, self.max)
+    }
+    /// Returns the opacity for the distance.
+    pub fn opacity_at(&self, distance: f32) -> f32 {
+        match self.fade {
+            Some((start, end)) if end > start => 1.0 - ((distance - start) / (end - start)).clamp(0.0, 1.0),
+            Some((_, end)) => if distance < end { 1.0 } else { 0.0 },
+            None => 1.0,
+        }
+    }
+    /// Returns the opacity computed this frame.
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+}
+
+/// The original alpha of a sprite or text faded by [`UiDistanceScale`].
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct UiDistanceFadeAlpha(pub f32);
+
+/// This system scales entities with [`UiDistanceScale`] based on the distance to the active camera.
+pub fn distance_scale(
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    mut query: Query<(&mut UiDistanceScale, &mut Transform, &GlobalTransform)>,
+) {
+    let Some(camera) = active_camera(&cameras) else { return };
+    for (mut distance_scale, mut transform, global) in &mut query {
+        let base_scale = *distance_scale.base_scale.get_or_insert(transform.scale);
+        let distance = camera.translation().distance(global.translation());
+
+        let scale = base_scale * distance_scale.scale_at(distance);
+        if transform.scale != scale {
+            transform.scale = scale;
+        }
+
+        let opacity = distance_scale.opacity_at(distance);
+        if distance_scale.opacity != opacity {
+            distance_scale.opacity = opacity;
+        }
+    }
+}
+
+/// This system fades sprites and texts of [`UiTree`] with [`UiDistanceScale`].
+/// It assumes the alpha of the faded entities is not changed elsewhere.
+pub fn distance_fade(
+    mut commands: Commands,
+    roots: Query<(&UiDistanceScale, &Children)>,
+    mut query: Query<(Entity, Option<&mut Sprite>, Option<&mut Text>, Option<&UiDistanceFadeAlpha>)>,
+) {
+    for (distance_scale, children) in &roots {
+        if distance_scale.fade.is_none() { continue }
+        for child in children {
+            let Ok((entity, sprite, text, origin)) = query.get_mut(*child) else { continue };
+            let origin = match origin {
+                Some(origin) => origin.0,
+                None => {
+                    let alpha = sprite.as_ref().map(|sprite| sprite.color.alpha())
+                        .or(text.as_ref().and_then(|text| text.sections.first().map(|section| section.style.color.alpha())));
+                    let Some(alpha) = alpha else { continue };
+                    commands.entity(entity).insert(UiDistanceFadeAlpha(alpha));
+                    alpha
+                },
+            };
+
+            let alpha = origin * distance_scale.opacity;
+            if let Some(mut sprite) = sprite {
+                if sprite.color.alpha() != alpha { sprite.color.set_alpha(alpha) }
+            }
+            if let Some(mut text) = text {
+                if text.sections.iter().any(|section| section.style.color.alpha() != alpha) {
+                    for section in &mut text.bypass_change_detection().sections {
+                        section.style.color.set_alpha(alpha);
+                    }
+                }
+            }
+        }
+    }
+}
+
+
+// #====================#
+// #=== WORLD ANCHOR ===#
+
+/// How [`UiWorldAnchor`] behaves when the target is not on the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum UiWorldAnchorMode {
+    /// The node and its subnodes are hidden.
+    Hide,
+    /// The node is clamped to the screen edge in the direction of the target, like an edge indicator.
+    /// The value is the margin from the edges in percent.
+    Edge(f32),
+}
+
+/// **Ui world anchor** - Moves the node to the screen position of the target world entity, for nameplates and quest markers.
+/// The target is projected every frame through the camera the [`UiTree`] is sourced from, unless a camera is set.
+/// The position of [`UiLayout::window`] is overwritten, the node must be a top-level node of a 2D tree.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::sprite::Anchor;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// let quest_giver = commands.spawn(SpatialBundle::default()).id();
+/// # commands.spawn((UiTreeBundle::<MainUi>::from(UiTree::new2d("Hud")), SourceFromCamera)).with_children(|ui| {
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Marker"),
+///     UiLayout::window().anchor(Anchor::BottomCenter).size(Ab(32.0)).pack::<Base>(),
+///     UiWorldAnchor::new(quest_giver).offset((0.0, -20.0)).mode(UiWorldAnchorMode::Edge(5.0)),
+/// ));
+/// # });
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiWorldAnchor {
+    /// The world entity followed by the node.
+    pub target: Entity,
+    /// Offset of the node in [`Ab`] units.
+    pub offset: Vec2,
+    /// Behavior when the target is not on the screen.
+    pub mode: UiWorldAnchorMode,
+    /// Camera used for the projection instead of the source camera of the tree.
+    pub camera: Option<Entity>,
+    /// If the target was off the screen in the last update.
+    off_screen: bool,
+    /// Direction from the screen center towards the target, with Y pointing down.
+    direction: Vec2,
+}
+impl UiWorldAnchor {
+    /// Creates new anchor following the target and hiding when it is off the screen.
+    pub fn new(target: Entity) -> Self {
+        UiWorldAnchor { target, offset: Vec2::ZERO, mode: UiWorldAnchorMode::Hide, camera: None, off_screen: false, direction: Vec2::ZERO }
+    }
+    /// Replaces the offset with a new value.
+    pub fn offset(mut self, offset: impl Into<Vec2>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+    /// Replaces the mode with a new value.
+    pub fn mode(mut self, mode: UiWorldAnchorMode) -> Self {
+        self.mode = mode;
+        self
+    }
+    /// Replaces the camera with a new value.
+    pub fn camera(mut self, camera: Entity) -> Self {
+        self.camera = Some(camera);
+        self
+    }
+    /// Returns `true` if the target is off the screen.
+    pub fn is_off_screen(&self) -> bool {
+        self.off_screen
+    }
+    /// Returns the direction from the screen center towards the target, with Y pointing down. Use it to rotate edge indicators.
+    pub fn direction(&self) -> Vec2 {
+        self.direction
+    }
+}
+
+/// This system moves [`UiWorldAnchor`] nodes to the screen position of their targets.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn world_anchor_update<T:Component, N:Default + Component>(
+    uis: Query<(Option<&SourceCamera>, Option<&SourceCameraSlot>, &Children), With<UiTree<T, N>>>,
+    marked: Query<Entity, (With<T>, With<Camera>)>,
+    slots: Query<(Entity, &UiSourceCamera)>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    targets: Query<&GlobalTransform>,
+    links: Query<&UiLink<T>>,
+    mut anchors: Query<(&UiLink<T>, &mut UiWorldAnchor, &mut UiLayout)>,
+    mut visibility: Query<&mut Visibility>,
+) {
+    for (bound, slot, children) in &uis {
+        let source = match (bound, slot) {
+            (Some(bound), _) => Some(bound.0),
+            (None, Some(slot)) => slots.iter().find(|(_, source)| source.slot == slot.0).map(|(entity, _)| entity),
+            (None, None) => marked.get_single().ok(),
+        };
+
+        for child in children {
+            let Ok((link, mut anchor, mut layout)) = anchors.get_mut(*child) else { continue };
+            let Some((camera, camera_transform)) = anchor.camera.or(source).and_then(|camera| cameras.get(camera).ok()) else { continue };
+            let Some(size) = camera.logical_viewport_size() else { continue };
+            let Ok(target) = targets.get(anchor.target) else { continue };
+            let target = target.translation();
+
+            // Position on the screen in the range of 0.0 to 1.0
+            let local = camera_transform.affine().inverse().transform_point3(target);
+            let projected = camera.world_to_viewport(camera_transform, target).filter(|_| local.z < 0.0);
+            let mut position = match projected {
+                Some(point) => point / size,
+                // Targets behind the camera point away from the screen center
+                None => Vec2::splat(0.5) + Vec2::new(local.x, -local.y).normalize_or_zero() * 10.0,
+            };
+            let off_screen = projected.is_none() || position.cmplt(Vec2::ZERO).any() || position.cmpgt(Vec2::ONE).any();
+
+            let direction = (position - Vec2::splat(0.5)).normalize_or_zero();
+            if let UiWorldAnchorMode::Edge(margin) = anchor.mode {
+                // Shrink the vector from the center until it fits inside the margins
+                let half = (0.5 - margin / 100.0).max(0.0);
+                let delta = position - Vec2::splat(0.5);
+                let scale = (delta.abs() / half.max(f32::EPSILON)).max_element();
+                if scale > 1.0 { position = Vec2::splat(0.5) + delta / scale; }
+            }
+            if anchor.off_screen != off_screen || anchor.direction != direction {
+                anchor.off_screen = off_screen;
+                anchor.direction = direction;
+            }
+
+            let Layout::Window(window) = layout.layout else {
+                warn!("UiWorldAnchor {} requires UiLayout::window", link.path);
+                continue;
+            };
+            let new = window.pos(Rl(position * 100.0) + Ab(anchor.offset));
+            if window != new { layout.layout = new.into(); }
+
+            let hidden = off_screen && anchor.mode == UiWorldAnchorMode::Hide;
+            let target = if hidden { Visibility::Hidden } else { Visibility::Inherited };
+            for (entity, _) in subtree_entities(children, &links, &link.path) {
+                if let Ok(mut visibility) = visibility.get_mut(entity) {
+                    if *visibility != target { *visibility = target; }
+                }
+            }
+        }
+    }
+}
+
+
+// #=========================#
+// #=== RENDER TO TEXTURE ===#
+
+/// **Ui render to texture** - Renders the 2D [`UiTree`] into an image instead of the screen.
+/// It spawns an offscreen 2D camera as a child of the tree, moves the tree and all nodes into its own [`RenderLayers`]
+/// and keeps the image resized to the tree [`Dimension`]. Use the [`image`](UiRenderToTexture::image) handle on any
+/// in-world surface and add [`UiPickingPortal`] to that surface to forward the pointer into the ui.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>, mut materials: ResMut<Assets<StandardMaterial>>) {
+///     let target = UiRenderToTexture::new(&mut images, 1);
+///     let screen = materials.add(StandardMaterial { base_color_texture: Some(target.image.clone()), ..default() });
+///
+///     commands.spawn((
+///         UiTreeBundle::<MainUi> { tree: UiTree::new2d("Screen"), dimension: Dimension::new((1280.0, 720.0)), ..default() },
+///         target,
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiRenderToTexture {
+    /// The image the ui is rendered into.
+    pub image: Handle<Image>,
+    /// The render layer used by the camera and the ui.
+    pub layer: usize,
+    /// The spawned camera.
+    camera: Option<Entity>,
+}
+impl UiRenderToTexture {
+    /// Creates new render target with a placeholder image, that will be resized to the tree [`Dimension`].
+    pub fn new(images: &mut Assets<Image>, layer: usize) -> Self {
+        let size = Extent3d { width: 1, height: 1, ..default() };
+        let mut image = Image::new_fill(size, TextureDimension::D2, &[0, 0, 0, 0], TextureFormat::Bgra8UnormSrgb, RenderAssetUsages::default());
+        image.texture_descriptor.usage = TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+        UiRenderToTexture {
+            image: images.add(image),
+            layer,
+            camera: None,
+        }
+    }
+    /// Returns the spawned camera entity.
+    pub fn camera(&self) -> Option<Entity> {
+        self.camera
+    }
+}
+
+/// This system spawns the offscreen camera for added [`UiRenderToTexture`].
+pub fn render_to_texture_setup(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut UiRenderToTexture), Added<UiRenderToTexture>>,
+) {
+    for (entity, mut target) in &mut query {
+        let camera = commands.spawn((
+            Camera2dBundle {
+                camera: Camera {
+                    order: -1 - target.layer as isize,
+                    target: RenderTarget::Image(target.image.clone()),
+                    clear_color: ClearColorConfig::Custom(Color::NONE),
+                    ..default()
+                },
+                ..default()
+            },
+            RenderLayers::layer(target.layer),
+        )).id();
+        commands.entity(entity).add_child(camera).insert(RenderLayers::layer(target.layer));
+        target.camera = Some(camera);
+    }
+}
+
+/// This system resizes the image of [`UiRenderToTexture`] and centers the camera on the tree.
+pub fn render_to_texture_resize(
+    mut images: ResMut<Assets<Image>>,
+    query: Query<(&UiRenderToTexture, &Dimension), Or<(Changed<Dimension>, Changed<UiRenderToTexture>)>>,
+    mut cameras: Query<&mut Transform, With<Camera>>,
+) {
+    for (target, dimension) in &query {
+        let size = dimension.size.max(Vec2::ONE);
+        if let Some(image) = images.get_mut(&target.image) {
+            if image.size() != size.as_uvec2() {
+                image.resize(Extent3d { width: size.x as u32, height: size.y as u32, ..default() });
+            }
+        }
+        if let Some(mut transform) = target.camera.and_then(|camera| cameras.get_mut(camera).ok()) {
+            transform.translation.x = size.x / 2.0;
+            transform.translation.y = -size.y / 2.0;
+        }
+    }
+}
+
+/// This system moves all nodes of [`UiRenderToTexture`] into its render layer.
+pub fn render_to_texture_layers(
+    mut commands: Commands,
+    roots: Query<(&UiRenderToTexture, &Children), Changed<Children>>,
+    query: Query<Option<&RenderLayers>>,
+) {
+    for (target, children) in &roots {
+        let layer = RenderLayers::layer(target.layer);
+        for child in children {
+            if let Ok(current) = query.get(*child) {
+                if current != Some(&layer) {
+                    commands.entity(*child).insert(layer.clone());
+                }
+            }
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===
+
+/// Plugin adding world-space ui logic.
+pub struct WorldPlugin;
+impl Plugin for WorldPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .register_type::<UiWorldAnchor>()
+            .add_systems(Update, distance_fade.after(UiSystems::Process))
+            .add_systems(Update, (
+                render_to_texture_setup,
+                render_to_texture_resize,
+                render_to_texture_layers,
+            ).chain().after(UiSystems::Compute))
+            .add_systems(PostUpdate, (
+                billboard_face_camera,
+                distance_scale,
+            ).before(TransformSystem::TransformPropagate));
+    }
+}
@@ -0,0 +1,257 @@
+use crate::*;
+use thiserror::Error;
+
+
+// #==============#
+// #=== ERRORS ===#
+
+/// Error returned when parsing the markup fails. Contains the byte offset where the error occurred.
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum UiMarkupError {
+    /// The markup ended before all tags were closed.
+    #[error("Unexpected end of markup, expected '{0}'")]
+    UnexpectedEnd(String),
+    /// Found a character that is not allowed here.
+    #[error("Unexpected character '{0}' at {1}")]
+    UnexpectedChar(char, usize),
+    /// Closing tag does not match the opened tag.
+    #[error("Mismatched closing tag '{0}' at {1}, expected '{2}'")]
+    MismatchedTag(String, usize, String),
+    /// The tag is not a known layout.
+    #[error("Unknown tag '{0}' at {1}")]
+    UnknownTag(String, usize),
+    /// The attribute is not supported.
+    #[error("Unknown attribute '{0}' at {1}")]
+    UnknownAttribute(String, usize),
+    /// The node is missing the `name` attribute.
+    #[error("Tag '{0}' at {1} is missing the 'name' attribute")]
+    MissingName(String, usize),
+    /// The attribute value could not be parsed.
+    #[error("Invalid value '{1}' of attribute '{0}'")]
+    InvalidValue(String, String),
+}
+
+
+// #==============#
+// #=== PARSER ===#
+
+/// Attributes that describe the layout, these can also be prefixed with `hover:`.
+const LAYOUT_ATTRIBUTES: [&str; 8] = ["pos", "pos1", "pos2", "size", "anchor", "align_x", "align_y", "scaling"];
+
+/// Simple recursive descent parser over the markup string.
+struct MarkupParser<'a> {
+    source: &'a str,
+    cursor: usize,
+}
+impl <'a> MarkupParser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.source[self.cursor..].chars().next()
+    }
+    fn starts_with(&self, pattern: &str) -> bool {
+        self.source[self.cursor..].starts_with(pattern)
+    }
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if !c.is_whitespace() { break }
+            self.cursor += c.len_utf8();
+        }
+    }
+    fn expect(&mut self, pattern: &str) -> Result<(), UiMarkupError> {
+        if self.starts_with(pattern) {
+            self.cursor += pattern.len();
+            Ok(())
+        } else {
+            match self.peek() {
+                Some(c) => Err(UiMarkupError::UnexpectedChar(c, self.cursor)),
+                None => Err(UiMarkupError::UnexpectedEnd(pattern.to_string())),
+            }
+        }
+    }
+    fn read_identifier(&mut self) -> Result<String, UiMarkupError> {
+        let start = self.cursor;
+        while let Some(c) = self.peek() {
+            if !(c.is_alphanumeric() || c == '_' || c == '-' || c == ':') { break }
+            self.cursor += c.len_utf8();
+        }
+        if start == self.cursor {
+            return match self.peek() {
+                Some(c) => Err(UiMarkupError::UnexpectedChar(c, self.cursor)),
+                None => Err(UiMarkupError::UnexpectedEnd("identifier".to_string())),
+            };
+        }
+        Ok(self.source[start..self.cursor].to_string())
+    }
+    fn read_quoted(&mut self) -> Result<String, UiMarkupError> {
+        let quote = match self.peek() {
+            Some(c @ ('"' | '\'')) => c,
+            Some(c) => return Err(UiMarkupError::UnexpectedChar(c, self.cursor)),
+            None => return Err(UiMarkupError::UnexpectedEnd("\"".to_string())),
+        };
+        self.cursor += 1;
+        let Some(length) = self.source[self.cursor..].find(quote) else {
+            return Err(UiMarkupError::UnexpectedEnd(quote.to_string()));
+        };
+        let value = self.source[self.cursor..self.cursor + length].to_string();
+        self.cursor += length + 1;
+        Ok(value)
+    }
+
+    /// Parses nodes until the closing tag is found. Returns the nodes and any text in between.
+    fn parse_content(&mut self, closing: Option<&str>) -> Result<(Vec<UiTemplateNode>, String), UiMarkupError> {
+        let mut nodes = Vec::new();
+        let mut text = String::new();
+        loop {
+            self.skip_whitespace();
+            if self.cursor >= self.source.len() {
+                return match closing {
+                    Some(tag) => Err(UiMarkupError::UnexpectedEnd(format!("</{tag}>"))),
+                    None => Ok((nodes, text)),
+                };
+            }
+            if self.starts_with("<!--") {
+                let Some(length) = self.source[self.cursor..].find("-->") else {
+                    return Err(UiMarkupError::UnexpectedEnd("-->".to_string()));
+                };
+                self.cursor += length + 3;
+            } else if self.starts_with("</") {
+                let position = self.cursor;
+                self.cursor += 2;
+                let tag = self.read_identifier()?;
+                self.skip_whitespace();
+                self.expect(">")?;
+                return match closing {
+                    Some(expected) if expected == tag => Ok((nodes, text)),
+                    Some(expected) => Err(UiMarkupError::MismatchedTag(tag, position, expected.to_string())),
+                    None => Err(UiMarkupError::MismatchedTag(tag, position, String::new())),
+                };
+            } else if self.starts_with("<") {
+                nodes.push(self.parse_element()?);
+            } else {
+                let length = self.source[self.cursor..].find('<').unwrap_or(self.source.len() - self.cursor);
+                if !text.is_empty() { text.push(' ') }
+                text.push_str(self.source[self.cursor..self.cursor + length].trim_end());
+                self.cursor += length;
+            }
+        }
+    }
+
+    /// Parses a single element including its children.
+    fn parse_element(&mut self) -> Result<UiTemplateNode, UiMarkupError> {
+        let position = self.cursor;
+        self.expect("<")?;
+        let tag = self.read_identifier()?;
+
+        let mut attributes = Vec::new();
+        let closed = loop {
+            self.skip_whitespace();
+            if self.starts_with("/>") { self.cursor += 2; break true; }
+            if self.starts_with(">") { self.cursor += 1; break false; }
+            let attribute_position = self.cursor;
+            let name = self.read_identifier()?;
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let value = self.read_quoted()?;
+            attributes.push((name, value, attribute_position));
+        };
+
+        let mut node = build_node(&tag, position, &attributes)?;
+        if !closed {
+            let (children, text) = self.parse_content(Some(&tag))?;
+            node.children = children;
+            if node.text.is_none() && !text.is_empty() { node.text = Some(text); }
+        }
+        Ok(node)
+    }
+}
+
+/// Creates the node from the tag and its attributes.
+fn build_node(tag: &str, position: usize, attributes: &[(String, String, usize)]) -> Result<UiTemplateNode, UiMarkupError> {
+    let mut node = UiTemplateNode::default();
+    let mut name = None;
+    let mut hover = UiTemplateState::default();
+    let mut has_hover_layout = false;
+
+    for (attribute, value, attribute_position) in attributes {
+        let (state, key) = match attribute.split_once(':') {
+            Some((state, key)) => (Some(state), key),
+            None => (None, attribute.as_str()),
+        };
+        match (state, key) {
+            (None, "name") => name = Some(value.clone()),
+            (None, "color") => node.color = Some(value.clone()),
+            (None, "image") => node.image = Some(value.clone()),
+            (None, "font") => node.font = Some(value.clone()),
+            (None, "text") => node.text = Some(value.clone()),
+            (None, "font_size") => node.font_size = Some(parse_number(attribute, value)?),
+            (Some("hover"), "color") => hover.color = Some(value.clone()),
+            (Some("hover"), key) if LAYOUT_ATTRIBUTES.contains(&key) => has_hover_layout = true,
+            (None, key) if LAYOUT_ATTRIBUTES.contains(&key) => {},
+            _ => return Err(UiMarkupError::UnknownAttribute(attribute.clone(), *attribute_position)),
+        }
+    }
+
+    let Some(name) = name else { return Err(UiMarkupError::MissingName(tag.to_string(), position)) };
+    node.name = name;
+
+    // Base layout
+    let get = |key: &str| attributes.iter().find(|(a, _, _)| a == key).map(|(_, v, _)| v.clone());
+    node.layout = build_layout(tag, position, &get)?;
+
+    // Hover layout inherits from the base layout
+    if has_hover_layout {
+        let get_hover = |key: &str| get(&format!("hover:{key}")).or_else(|| get(key));
+        hover.layout = build_layout(tag, position, &get_hover)?;
+    }
+    if has_hover_layout || hover.color.is_some() {
+        node.hover = Some(hover);
+    }
+    Ok(node)
+}
+
+/// Creates the layout description from the tag.
+fn build_layout(tag: &str, position: usize, get: &dyn Fn(&str) -> Option<String>) -> Result<Option<UiTemplateLayout>, UiMarkupError> {
+    let number = |key: &str| get(key).map(|value| parse_number(key, &value)).transpose();
+    Ok(match tag {
+        "node" => None,
+        "boundary" => Some(UiTemplateLayout::Boundary { pos1: get("pos1"), pos2: get("pos2") }),
+        "window" => Some(UiTemplateLayout::Window { pos: get("pos"), size: get("size"), anchor: get("anchor") }),
+        "solid" => Some(UiTemplateLayout::Solid { size: get("size"), align_x: number("align_x")?, align_y: number("align_y")?, scaling: get("scaling") }),
+        _ => return Err(UiMarkupError::UnknownTag(tag.to_string(), position)),
+    })
+}
+
+fn parse_number(attribute: &str, value: &str) -> Result<f32, UiMarkupError> {
+    value.trim().parse::<f32>().map_err(|_| UiMarkupError::InvalidValue(attribute.to_string(), value.to_string()))
+}
+
+
+// #==============#
+// #=== PUBLIC ===#
+
+impl UiTemplate {
+    /// Parses HTML-like markup into [`UiTemplate`].
+    ///
+    /// Supported tags are `<node>` (full size), `<boundary>`, `<window>` and `<solid>`.
+    /// Each tag requires a `name` attribute and accepts the layout attributes of the matching layout
+    /// (`pos`, `pos1`, `pos2`, `size`, `anchor`, `align_x`, `align_y`, `scaling`) together with
+    /// `color`, `image`, `font`, `font_size` and `text`. Text between tags is used as the node text.
+    /// Prefix `color` or any layout attribute with `hover:` to define the hover state.
+    /// ## 🛠️ Example
+    /// ```
+    /// # use bevy_lunex::prelude::*;
+    /// let template = UiTemplate::from_markup(r##"
+    ///     <window name="Menu" pos="10% 10%" size="80% 80%" color="#202020">
+    ///         <solid name="Play" size="4 1" align_y="-1" hover:color="#ffffff" hover:size="4.2 1.1">
+    ///             Play
+    ///         </solid>
+    ///     </window>
+    /// "##).unwrap();
+    /// assert_eq!(template.nodes[0].children[0].text, Some("Play".to_string()));
+    /// ```
+    pub fn from_markup(markup: &str) -> Result<UiTemplate, UiMarkupError> {
+        let mut parser = MarkupParser { source: markup, cursor: 0 };
+        let (nodes, _) = parser.parse_content(None)?;
+        Ok(UiTemplate { nodes })
+    }
+}
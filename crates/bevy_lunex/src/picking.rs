@@ -1,11 +1,13 @@
 use bevy::prelude::*;
 use bevy_mod_picking::{backend::PointerHits, prelude::*};
 use lunex_engine::YInvert;
-use std::cmp::Ordering;
+use std::{cmp::Ordering, sync::Arc};
 use bevy::window::PrimaryWindow;
+use bevy::ecs::system::SystemParam;
+use bevy::render::camera::RenderTarget;
 use bevy_mod_picking::backend::prelude::*;
 
-use crate::{Dimension, Element};
+use crate::{Dimension, Element, UiShape, UiWedge};
 
 
 // #===============#
@@ -17,7 +19,11 @@ pub struct UiLunexPickingPlugin;
 impl Plugin for UiLunexPickingPlugin {
     fn build(&self, app: &mut App) {
         app
-            .add_systems(PreUpdate, lunex_picking.in_set(PickSet::Backend))
+            .init_resource::<UiPointerTiming>()
+            .add_event::<Pointer<DoubleClick>>()
+            .add_event::<Pointer<LongPress>>()
+            .add_systems(PreUpdate, (picking_policy_to_pickable, lunex_picking).chain().in_set(PickSet::Backend))
+            .add_systems(PreUpdate, synthesize_pointer_events.in_set(PickSet::PostFocus))
             .add_systems(Update, rendered_texture_picking);
     }
 }
@@ -27,11 +33,17 @@ pub fn lunex_picking(
     pointers: Query<(&PointerId, &PointerLocation)>,
     cameras: Query<(Entity, &Camera, &GlobalTransform, &OrthographicProjection)>,
     primary_window: Query<Entity, With<PrimaryWindow>>,
+    images: Res<Assets<Image>>,
     node_query: Query<
         (
             Entity,
             &Dimension,
             Option<&Element>,
+            Option<&UiShape>,
+            Option<&UiWedge>,
+            Option<&UiHitTest>,
+            Option<&Handle<Image>>,
+            Option<&UiPickingPolicy>,
             &GlobalTransform,
             Option<&Pickable>,
             &ViewVisibility,
@@ -40,7 +52,7 @@ pub fn lunex_picking(
     mut output: EventWriter<PointerHits>,
 ) {
     let mut sorted_nodes: Vec<_> = node_query.iter().collect();
-    sorted_nodes.sort_by(|a, b| { (b.3.translation().z).partial_cmp(&a.3.translation().z).unwrap_or(Ordering::Equal) });
+    sorted_nodes.sort_by(|a, b| { (b.8.translation().z).partial_cmp(&a.8.translation().z).unwrap_or(Ordering::Equal) });
 
     for (pointer, location) in pointers.iter().filter_map(|(pointer, pointer_location)| { pointer_location.location().map(|loc| (pointer, loc)) }) {
         let mut blocked = false;
@@ -64,7 +76,7 @@ pub fn lunex_picking(
             .copied()
             .filter(|(.., visibility)| visibility.get())
             .filter_map(
-                |(entity, dimension, element, node_transform, pickable, ..)| {
+                |(entity, dimension, element, shape, wedge, hit_test, texture, policy, node_transform, pickable, ..)| {
                     if blocked {
                         return None;
                     }
@@ -83,11 +95,25 @@ pub fn lunex_picking(
                         .inverse()
                         .transform_point3((cursor_pos_world, 0.0).into());
 
-                    let is_cursor_in_sprite = rect.contains(cursor_pos_sprite.truncate());
-                    blocked = is_cursor_in_sprite && pickable.map(|p| p.should_block_lower) != Some(false);
-
+                    let local_point = cursor_pos_sprite.truncate() - rect.center();
+                    let is_cursor_in_sprite = match (wedge, shape) {
+                        (Some(wedge), _) => wedge.0.contains(local_point.invert_y()),
+                        (None, Some(shape)) => shape.contains(local_point, dimension.size),
+                        (None, None) => rect.contains(cursor_pos_sprite.truncate()),
+                    } && match hit_test {
+                        Some(hit_test) => hit_test.test(local_point, dimension, texture.and_then(|t| images.get(t))),
+                        None => true,
+                    };
                     // HitData requires a depth as calculated from the camera's near clipping plane
-                    let depth = -cam_ortho.near - node_transform.translation().z;
+                    let mut depth = -cam_ortho.near - node_transform.translation().z;
+
+                    match policy {
+                        None => blocked = is_cursor_in_sprite && pickable.map(|p| p.should_block_lower) != Some(false),
+                        Some(UiPickingPolicy::Block) => blocked = is_cursor_in_sprite,
+                        Some(UiPickingPolicy::PassThrough) => {},
+                        // Report the hit behind every other node so it blocks only other cameras
+                        Some(UiPickingPolicy::BlockChildrenOnly) => depth = f32::MAX,
+                    }
 
                     is_cursor_in_sprite.then_some((entity, HitData::new(cam_entity, depth, None, None)))
                 },
@@ -100,30 +126,409 @@ pub fn lunex_picking(
 }
 
 
+// #===============#
+// #=== RAYCAST ===#
+
+/// **Ui raycast** - System parameter converting the cursor position to local coordinates of any 2D or 3D [`UiTree`](crate::UiTree).
+/// The coordinates match the node rectangles computed by the tree, with the origin in the top-left corner and Y pointing down.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// #[derive(Component)]
+/// struct MapTree;
+///
+/// fn place_marker(raycast: UiRaycast, camera: Query<Entity, With<Camera>>, map: Query<Entity, With<MapTree>>) {
+///     let (Ok(camera), Ok(map)) = (camera.get_single(), map.get_single()) else { return };
+///     if let Some(point) = raycast.cursor_to_root(camera, map) {
+///         info!("Marker placed at {point}");
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct UiRaycast<'w, 's> {
+    windows: Query<'w, 's, &'static Window>,
+    primary_window: Query<'w, 's, Entity, With<PrimaryWindow>>,
+    cameras: Query<'w, 's, (&'static Camera, &'static GlobalTransform)>,
+    roots: Query<'w, 's, &'static GlobalTransform, With<Dimension>>,
+}
+impl UiRaycast<'_, '_> {
+    /// Returns the cursor position in the window rendered by the camera.
+    pub fn cursor(&self, camera: Entity) -> Option<Vec2> {
+        let (camera, _) = self.cameras.get(camera).ok()?;
+        let RenderTarget::Window(window) = camera.target else { return None };
+        let window = window.normalize(self.primary_window.get_single().ok())?;
+        self.windows.get(window.entity()).ok()?.cursor_position()
+    }
+    /// Returns the world ray going from the camera through the cursor.
+    pub fn cursor_ray(&self, camera: Entity) -> Option<Ray3d> {
+        let cursor = self.cursor(camera)?;
+        let (camera, camera_transform) = self.cameras.get(camera).ok()?;
+        camera.viewport_to_world(camera_transform, cursor)
+    }
+    /// Returns the cursor position in local coordinates of the root, where the cursor ray hits its plane.
+    pub fn cursor_to_root(&self, camera: Entity, root: Entity) -> Option<Vec2> {
+        self.ray_to_root(self.cursor_ray(camera)?, root)
+    }
+    /// Returns the viewport position of the camera in local coordinates of the root, for example the position of a pointer event.
+    pub fn viewport_to_root(&self, camera: Entity, position: Vec2, root: Entity) -> Option<Vec2> {
+        let (camera, camera_transform) = self.cameras.get(camera).ok()?;
+        self.ray_to_root(camera.viewport_to_world(camera_transform, position)?, root)
+    }
+    /// Returns the point where the ray hits the plane of the root in its local coordinates.
+    pub fn ray_to_root(&self, ray: Ray3d, root: Entity) -> Option<Vec2> {
+        let root = self.roots.get(root).ok()?;
+        let point = ray_to_surface(ray, root)?;
+        Some(root.affine().inverse().transform_point3(point).truncate().invert_y())
+    }
+}
+
+
+// #======================#
+// #=== PICKING POLICY ===#
+
+/// Controls how the node blocks the pointer for the nodes below it and for anything rendered by other cameras.
+/// The policy overrides [`Pickable::should_block_lower`] of the entity.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let policy = UiPickingPolicy::PassThrough;        // -> Full-screen background that lets clicks reach the world
+/// let policy = UiPickingPolicy::Block;              // -> Overlay scrim that blocks everything below
+/// let policy = UiPickingPolicy::BlockChildrenOnly;  // -> Blocks the world, but ui nodes below stay pickable
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum UiPickingPolicy {
+    /// The node is picked and blocks all nodes and world objects below it.
+    #[default]
+    Block,
+    /// The node is picked, but does not block anything below it.
+    PassThrough,
+    /// The node is picked after all other ui nodes of the same camera, so it only blocks the pointer
+    /// from reaching anything rendered by other cameras, like the game world.
+    BlockChildrenOnly,
+}
+
+/// This system overwrites [`Pickable`] to match the changed [`UiPickingPolicy`].
+pub fn picking_policy_to_pickable(
+    mut commands: Commands,
+    query: Query<(Entity, &UiPickingPolicy, Option<&Pickable>), Changed<UiPickingPolicy>>,
+) {
+    for (entity, policy, pickable) in &query {
+        let should_block_lower = *policy != UiPickingPolicy::PassThrough;
+        let is_hoverable = match pickable {
+            Some(pickable) => pickable.is_hoverable,
+            None => true,
+        };
+        commands.entity(entity).insert(Pickable { should_block_lower, is_hoverable });
+    }
+}
+
+
+// #================#
+// #=== HIT TEST ===
+
+/// Custom hit test used by the Lunex picking backend for this node. It is evaluated after [`UiShape`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let hit_test = UiHitTest::custom(|point, dimension| point.x < 0.0);  // -> Only the left half is pickable
+/// let hit_test = UiHitTest::alpha_mask(0.5);                           // -> Pixels with alpha below 0.5 are ignored
+/// ```
+#[derive(Component, Clone)]
+pub enum UiHitTest {
+    /// Closure receiving the point relative to the node center (Y is up) and the node [`Dimension`].
+    Custom(Arc<dyn Fn(Vec2, &Dimension) -> bool + Send + Sync>),
+    /// Samples the [`Handle<Image>`] of the node stretched over the [`Dimension`] and ignores pixels with lower alpha.
+    /// Only uncompressed 8-bit RGBA and BGRA textures are sampled, other formats are treated as opaque.
+    AlphaMask {
+        /// Minimal alpha in range `0.0..1.0` that can be picked.
+        threshold: f32,
+    },
+}
+impl UiHitTest {
+    /// Creates new custom hit test from the closure.
+    pub fn custom(test: impl Fn(Vec2, &Dimension) -> bool + Send + Sync + 'static) -> Self {
+        UiHitTest::Custom(Arc::new(test))
+    }
+    /// Creates new alpha mask hit test with the threshold.
+    pub fn alpha_mask(threshold: f32) -> Self {
+        UiHitTest::AlphaMask { threshold }
+    }
+    /// Returns `true` if the point relative to the node center can be picked.
+    pub fn test(&self, point: Vec2, dimension: &Dimension, image: Option<&Image>) -> bool {
+        match self {
+            UiHitTest::Custom(test) => test(point, dimension),
+            UiHitTest::AlphaMask { threshold } => {
+                let Some(image) = image else { return true };
+                let Some(alpha) = image_alpha(image, point / dimension.size + Vec2::splat(0.5)) else { return true };
+                alpha >= *threshold
+            },
+        }
+    }
+}
+impl std::fmt::Debug for UiHitTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UiHitTest::Custom(_) => f.write_str("UiHitTest::Custom"),
+            UiHitTest::AlphaMask { threshold } => write!(f, "UiHitTest::AlphaMask {{ threshold: {threshold} }}"),
+        }
+    }
+}
+
+/// Returns the alpha of the image at the normalized position with Y up, if the format is supported.
+fn image_alpha(image: &Image, position: Vec2) -> Option<f32> {
+    use bevy::render::render_resource::TextureFormat;
+    match image.texture_descriptor.format {
+        TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb | TextureFormat::Bgra8Unorm | TextureFormat::Bgra8UnormSrgb => {},
+        _ => return None,
+    }
+    let size = image.size();
+    if size.x == 0 || size.y == 0 { return None }
+    let x = ((position.x * size.x as f32) as u32).min(size.x - 1);
+    let y = (((1.0 - position.y) * size.y as f32) as u32).min(size.y - 1);
+    let index = ((y * size.x + x) * 4 + 3) as usize;
+    image.data.get(index).map(|alpha| *alpha as f32 / 255.0)
+}
+
+
 // #===============================#
-// #=== VIEWPORT PORTAL PICKING ===#
+// #=== VIEWPORT PORTAL PICKING ===
+
+/// **Ui picking portal** - Forwards the pointer into the ui rendered to the texture of this entity.
+/// The pointer position is re-mapped from the surface to the pixel coordinates of the texture, so buttons in ui rendered
+/// to a texture work when the texture is shown on a node or on an in-world surface, like an in-game computer screen.
+/// The texture is taken from [`Handle<Image>`] or from the base color texture of [`Handle<StandardMaterial>`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>, mut materials: ResMut<Assets<StandardMaterial>>) {
+/// let target = UiRenderToTexture::new(&mut images, 1);
+/// let screen = UiMaterial3dBundle::from_image(&mut materials, target.image.clone());
+/// commands.spawn((UiTreeBundle::<MainUi>::from(UiTree::new2d("Computer")), target));
+///
+/// commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new3d("Desk"))).with_children(|ui| {
+///     ui.spawn((
+///         UiLink::<MainUi>::path("Screen"),
+///         UiLayout::window().size((640.0, 360.0)).pack::<Base>(),
+///         screen,
+///         UiPickingPortal,
+///     ));
+/// });
+/// # }
+/// ```
+/// ## 📌 Note
+/// The surface must have [`Dimension`] for the re-mapping. Surfaces without it forward the pointer position unchanged.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiPickingPortal;
 
-/// This component should be attached to any entity that displays rendered texture from camera and pointers should propagate.
-#[derive(Component)]
-pub struct PickingPortal;
+/// Previous name of [`UiPickingPortal`].
+pub use UiPickingPortal as PickingPortal;
+
+/// This system forwards the pointer moving over [`UiPickingPortal`] into the texture it displays.
 pub fn rendered_texture_picking(
     mut events: EventReader<Pointer<Move>>,
-    texture_viewports: Query<&Handle<Image>, With<PickingPortal>>,
+    portals: Query<(Option<&Handle<Image>>, Option<&Handle<StandardMaterial>>, &GlobalTransform, Option<&Dimension>, Has<Element>), With<UiPickingPortal>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    images: Res<Assets<Image>>,
+    materials: Option<Res<Assets<StandardMaterial>>>,
     mut pointer_move: EventWriter<pointer::InputMove>,
 ) {
     for event in events.read() {
-        if let Ok(texture_handle) = texture_viewports.get(event.target) {
-            let position = event.pointer_location.position;
-            pointer_move.send(pointer::InputMove {
-                pointer_id: event.pointer_id,
-                location: pointer::Location {
-                    target: bevy::render::camera::NormalizedRenderTarget::Image(
-                        texture_handle.clone_weak(),
-                    ),
-                    position,
-                },
-                delta: event.delta,
-            });
+        let Ok((image, material, portal_transform, dimension, is_element)) = portals.get(event.target) else { continue };
+        let material_image = material.and_then(|material| materials.as_ref()?.get(material)?.base_color_texture.as_ref());
+        let Some(texture_handle) = image.or(material_image) else { continue };
+
+        let position = match (dimension, images.get(texture_handle)) {
+            (Some(dimension), Some(texture)) => {
+                let Some(point) = event.hit.position.or_else(|| {
+                    let (camera, camera_transform) = cameras.get(event.hit.camera).ok()?;
+                    surface_point(camera, camera_transform, portal_transform, event.pointer_location.position)
+                }) else { continue };
+                let Some(uv) = surface_uv(portal_transform, dimension, is_element, point) else { continue };
+                uv * texture.size().as_vec2()
+            },
+            _ => event.pointer_location.position,
+        };
+
+        pointer_move.send(pointer::InputMove {
+            pointer_id: event.pointer_id,
+            location: pointer::Location {
+                target: bevy::render::camera::NormalizedRenderTarget::Image(
+                    texture_handle.clone_weak(),
+                ),
+                position,
+            },
+            delta: event.delta,
+        });
+    }
+}
+
+/// Returns the world point where the cursor ray hits the plane of the surface.
+fn surface_point(camera: &Camera, camera_transform: &GlobalTransform, surface: &GlobalTransform, cursor: Vec2) -> Option<Vec3> {
+    ray_to_surface(camera.viewport_to_world(camera_transform, cursor)?, surface)
+}
+
+/// Returns the world point where the ray hits the plane of the surface.
+fn ray_to_surface(ray: Ray3d, surface: &GlobalTransform) -> Option<Vec3> {
+    let distance = ray.intersect_plane(surface.translation(), InfinitePlane3d::new(surface.back()))?;
+    Some(ray.get_point(distance))
+}
+
+/// Returns the point on the surface as texture coordinates ranging from `0.0` to `1.0`, with Y down.
+fn surface_uv(surface: &GlobalTransform, dimension: &Dimension, is_element: bool, point: Vec3) -> Option<Vec2> {
+    if dimension.size.x <= 0.0 || dimension.size.y <= 0.0 { return None }
+    let local = surface.affine().inverse().transform_point3(point).truncate();
+
+    // Elements are centered, nodes are aligned to the top-left corner
+    let top_left = if is_element { dimension.size.invert_y() / -2.0 } else { Vec2::ZERO };
+    let uv = Vec2::new(local.x - top_left.x, top_left.y - local.y) / dimension.size;
+    Some(uv.clamp(Vec2::ZERO, Vec2::ONE))
+}
+
+
+// #============================#
+// #=== SYNTHESIZED POINTERS ===#
+
+/// Timing of the pointer events synthesized from the picking events, [`DoubleClick`] and [`LongPress`].
+/// All times are in seconds and distances in window pixels.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn build(app: &mut App) {
+/// app.insert_resource(UiPointerTiming::default().long_press_time(0.8));
+/// # }
+///
+/// fn inventory_slot(mut commands: Commands) {
+///     commands.spawn(UiZoneBundle::default())
+///         .observe(|_: Trigger<Pointer<DoubleClick>>| info!("Quick move"))
+///         .observe(|_: Trigger<Pointer<LongPress>>| info!("Open item menu"));
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct UiPointerTiming {
+    /// Maximum time between two clicks to count as double click.
+    pub double_click_interval: f32,
+    /// Duration after which a still press becomes a long press.
+    pub long_press_time: f32,
+    /// Maximum distance the pointer can move before the long press is cancelled.
+    pub long_press_distance: f32,
+}
+impl Default for UiPointerTiming {
+    fn default() -> Self {
+        UiPointerTiming {
+            double_click_interval: 0.3,
+            long_press_time: 0.5,
+            long_press_distance: 10.0,
+        }
+    }
+}
+impl UiPointerTiming {
+    /// Replaces the double click interval with a new value.
+    pub fn double_click_interval(mut self, interval: f32) -> Self {
+        self.double_click_interval = interval;
+        self
+    }
+    /// Replaces the long press time with a new value.
+    pub fn long_press_time(mut self, time: f32) -> Self {
+        self.long_press_time = time;
+        self
+    }
+    /// Replaces the long press distance with a new value.
+    pub fn long_press_distance(mut self, distance: f32) -> Self {
+        self.long_press_distance = distance;
+        self
+    }
+}
+
+/// Fires when a pointer clicks the same entity twice within [`UiPointerTiming::double_click_interval`].
+/// It comes after the [`Click`] of the second click.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+pub struct DoubleClick {
+    /// Pointer button pressed to trigger this event.
+    pub button: PointerButton,
+    /// Information about the picking intersection.
+    pub hit: HitData,
+}
+
+/// Fires when a pointer is held still on an entity for [`UiPointerTiming::long_press_time`].
+/// Moving the pointer further than [`UiPointerTiming::long_press_distance`] cancels it.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+pub struct LongPress {
+    /// Pointer button pressed to trigger this event.
+    pub button: PointerButton,
+    /// Information about the picking intersection.
+    pub hit: HitData,
+}
+
+/// Pointer pressed on an entity that can still become a long press.
+#[derive(Debug, Clone)]
+struct PressTrack {
+    down: Pointer<Down>,
+    time: f32,
+}
+
+/// This system synthesizes [`DoubleClick`] and [`LongPress`] from the picking events.
+/// They are sent as events and triggered on the entity, same as the events of the picking backend.
+fn synthesize_pointer_events(
+    time: Res<Time>,
+    timing: Res<UiPointerTiming>,
+    mut commands: Commands,
+    mut clicks: EventReader<Pointer<Click>>,
+    mut downs: EventReader<Pointer<Down>>,
+    mut last_clicks: Local<bevy::utils::HashMap<PointerId, (Pointer<Click>, f32)>>,
+    mut presses: Local<Vec<PressTrack>>,
+    pointers: Query<(&PointerId, &PointerLocation, &PointerPress)>,
+    mut double_clicks: EventWriter<Pointer<DoubleClick>>,
+    mut long_presses: EventWriter<Pointer<LongPress>>,
+) {
+    let now = time.elapsed_seconds();
+
+    for click in clicks.read() {
+        let double = last_clicks.get(&click.pointer_id).is_some_and(|(last, at)| {
+            last.target == click.target && last.button == click.button && now - at <= timing.double_click_interval
+        });
+        if !double {
+            last_clicks.insert(click.pointer_id, (click.clone(), now));
+            continue;
         }
+
+        // Forget the click, so the third click starts a new double click
+        last_clicks.remove(&click.pointer_id);
+        let event = Pointer {
+            target: click.target,
+            pointer_id: click.pointer_id,
+            pointer_location: click.pointer_location.clone(),
+            event: DoubleClick { button: click.button, hit: click.hit },
+        };
+        double_clicks.send(event.clone());
+        commands.trigger_targets(event, click.target);
     }
+
+    for down in downs.read() {
+        presses.retain(|press| press.down.pointer_id != down.pointer_id || press.down.button != down.button);
+        presses.push(PressTrack { down: down.clone(), time: now });
+    }
+
+    presses.retain(|press| {
+        let Some((_, location, pointer_press)) = pointers.iter().find(|(id, ..)| **id == press.down.pointer_id) else { return false };
+        if !pointer_press.is_pressed(press.down.button) { return false }
+        let moved = location.location().map_or(0.0, |location| location.position.distance(press.down.pointer_location.position));
+        if moved > timing.long_press_distance { return false }
+        if now - press.time < timing.long_press_time { return true }
+
+        let event = Pointer {
+            target: press.down.target,
+            pointer_id: press.down.pointer_id,
+            pointer_location: location.location().cloned().unwrap_or_else(|| press.down.pointer_location.clone()),
+            event: LongPress { button: press.down.button, hit: press.down.hit },
+        };
+        long_presses.send(event.clone());
+        commands.trigger_targets(event, press.down.target);
+        false
+    });
 }
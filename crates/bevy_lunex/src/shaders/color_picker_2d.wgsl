@@ -0,0 +1,41 @@
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+struct UiColorPicker {
+    // Hue from 0 to 1, saturation, value and alpha
+    hsva: vec4<f32>,
+    size: vec2<f32>,
+    // 0 - saturation and value field, 1 - hue strip, 2 - alpha slider
+    mode: u32,
+};
+
+@group(2) @binding(0) var<uniform> picker: UiColorPicker;
+
+// Converts the hue, saturation and value into sRGB.
+fn hsv_to_srgb(h: f32, s: f32, v: f32) -> vec3<f32> {
+    let k = (vec3<f32>(5.0, 3.0, 1.0) + h * 6.0) % 6.0;
+    return v - v * s * clamp(min(k, 4.0 - k), vec3<f32>(0.0), vec3<f32>(1.0));
+}
+
+// Converts sRGB into the linear color written by the shader.
+fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+    let low = c / 12.92;
+    let high = pow((c + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(high, low, c <= vec3<f32>(0.04045));
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    // Saturation grows to the right, value grows upwards
+    if picker.mode == 0u {
+        return vec4<f32>(srgb_to_linear(hsv_to_srgb(picker.hsva.x, in.uv.x, 1.0 - in.uv.y)), 1.0);
+    }
+    // Hue goes around the circle from the top to the bottom
+    if picker.mode == 1u {
+        return vec4<f32>(srgb_to_linear(hsv_to_srgb(in.uv.y, 1.0, 1.0)), 1.0);
+    }
+    // Checkerboard behind the color fading in from the left
+    let cell = floor(in.uv * picker.size / 6.0);
+    let checker = select(0.6, 0.9, (cell.x + cell.y) % 2.0 == 0.0);
+    let color = hsv_to_srgb(picker.hsva.x, picker.hsva.y, picker.hsva.z);
+    return vec4<f32>(srgb_to_linear(mix(vec3<f32>(checker), color, in.uv.x)), 1.0);
+}
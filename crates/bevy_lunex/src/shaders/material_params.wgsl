@@ -0,0 +1,10 @@
+#define_import_path bevy_lunex::material_params
+
+// Matches the `#[uniform]` fields filled from `UiMaterialParams`, declared in the same order.
+struct UiMaterialParams {
+    size: vec2<f32>,
+    hover: f32,
+    clicked: f32,
+    selected: f32,
+    time: f32,
+};
@@ -0,0 +1,44 @@
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+#import bevy_lunex::node_style::sd_rounded_box
+
+struct UiBackdropBlur {
+    tint: vec4<f32>,
+    // Top-left, top-right, bottom-right, bottom-left
+    corner_radius: vec4<f32>,
+    // Area of the backdrop texture behind the node, in uv coordinates
+    area: vec4<f32>,
+    size: vec2<f32>,
+    texture_size: vec2<f32>,
+    radius: f32,
+};
+
+@group(2) @binding(0) var<uniform> blur: UiBackdropBlur;
+@group(2) @binding(1) var backdrop_texture: texture_2d<f32>;
+@group(2) @binding(2) var backdrop_sampler: sampler;
+
+const SAMPLES: i32 = 6;
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    let uv = mix(blur.area.xy, blur.area.zw, in.uv);
+    let step = blur.radius / f32(SAMPLES) / max(blur.texture_size, vec2<f32>(1.0));
+
+    // Gaussian weighted samples spread over the blur radius
+    var color = vec4<f32>(0.0);
+    var total = 0.0;
+    for (var x = -SAMPLES; x <= SAMPLES; x++) {
+        for (var y = -SAMPLES; y <= SAMPLES; y++) {
+            let offset = vec2<f32>(f32(x), f32(y));
+            let weight = exp(-dot(offset, offset) / f32(SAMPLES * SAMPLES) * 2.0);
+            color += textureSampleLevel(backdrop_texture, backdrop_sampler, uv + offset * step, 0.0) * weight;
+            total += weight;
+        }
+    }
+    color /= total;
+    color = vec4<f32>(mix(color.rgb, blur.tint.rgb, blur.tint.a), 1.0);
+
+    let p = (in.uv - 0.5) * blur.size;
+    let d = sd_rounded_box(p, blur.size * 0.5, blur.corner_radius);
+    let aa = max(fwidth(d), 0.0001);
+    return vec4<f32>(color.rgb, 1.0 - smoothstep(-aa, aa, d));
+}
@@ -0,0 +1,41 @@
+#define_import_path bevy_lunex::node_mask
+#import bevy_lunex::node_style::sd_rounded_box
+
+struct UiNodeMask {
+    color: vec4<f32>,
+    world_to_mask: mat4x4<f32>,
+    // Top-left, top-right, bottom-right, bottom-left
+    corner_radius: vec4<f32>,
+    size: vec2<f32>,
+    center: vec2<f32>,
+    // 0 - no shape, 1 - rounded rectangle, 2 - ellipse
+    shape: u32,
+    threshold: f32,
+    // 1 - has texture, 2 - has mask texture
+    flags: u32,
+};
+
+// Returns the point in the mask relative to its center with y pointing down.
+fn node_mask_point(mask: UiNodeMask, world_position: vec4<f32>) -> vec2<f32> {
+    let local = (mask.world_to_mask * world_position).xy - mask.center;
+    return vec2<f32>(local.x, -local.y);
+}
+
+// Returns how much of the point is covered by the mask shape.
+fn node_mask_shape_coverage(mask: UiNodeMask, p: vec2<f32>) -> f32 {
+    var d = -1.0;
+    if mask.shape == 1u {
+        d = sd_rounded_box(p, mask.size * 0.5, mask.corner_radius);
+    } else if mask.shape == 2u {
+        let half_size = max(mask.size * 0.5, vec2<f32>(0.0001));
+        d = (length(p / half_size) - 1.0) * min(half_size.x, half_size.y);
+    }
+    let aa = max(fwidth(d), 0.0001);
+    return 1.0 - smoothstep(-aa, aa, d);
+}
+
+// Returns how much of the point is covered by the mask texture alpha.
+fn node_mask_alpha_coverage(mask: UiNodeMask, alpha: f32) -> f32 {
+    if mask.threshold <= 0.0 { return alpha; }
+    return step(mask.threshold, alpha);
+}
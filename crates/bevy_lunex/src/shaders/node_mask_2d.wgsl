@@ -0,0 +1,23 @@
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+#import bevy_lunex::node_mask::{UiNodeMask, node_mask_point, node_mask_shape_coverage, node_mask_alpha_coverage}
+
+@group(2) @binding(0) var<uniform> mask: UiNodeMask;
+@group(2) @binding(1) var color_texture: texture_2d<f32>;
+@group(2) @binding(2) var color_sampler: sampler;
+@group(2) @binding(3) var mask_texture: texture_2d<f32>;
+@group(2) @binding(4) var mask_sampler: sampler;
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    let p = node_mask_point(mask, in.world_position);
+    var color = mask.color;
+    if (mask.flags & 1u) != 0u {
+        color = color * textureSample(color_texture, color_sampler, in.uv);
+    }
+    var coverage = node_mask_shape_coverage(mask, p);
+    if (mask.flags & 2u) != 0u {
+        let alpha = textureSample(mask_texture, mask_sampler, p / mask.size + 0.5).a;
+        coverage = coverage * node_mask_alpha_coverage(mask, alpha);
+    }
+    return vec4<f32>(color.rgb, color.a * coverage);
+}
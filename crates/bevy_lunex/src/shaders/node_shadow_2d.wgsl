@@ -0,0 +1,9 @@
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+#import bevy_lunex::node_style::{UiNodeShadow, node_shadow_color}
+
+@group(2) @binding(0) var<uniform> shadow: UiNodeShadow;
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    return node_shadow_color(shadow, in.uv);
+}
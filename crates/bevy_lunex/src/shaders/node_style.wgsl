@@ -0,0 +1,60 @@
+#define_import_path bevy_lunex::node_style
+
+struct UiNodeStyle {
+    color: vec4<f32>,
+    border_color: vec4<f32>,
+    // Top-left, top-right, bottom-right, bottom-left
+    corner_radius: vec4<f32>,
+    size: vec2<f32>,
+    border_width: f32,
+};
+
+// Signed distance to a rectangle with rounded corners, `p` is relative to the center with y pointing down.
+fn sd_rounded_box(p: vec2<f32>, half_size: vec2<f32>, corner_radius: vec4<f32>) -> f32 {
+    var radius = corner_radius.x;
+    if p.x >= 0.0 {
+        if p.y >= 0.0 { radius = corner_radius.z; } else { radius = corner_radius.y; }
+    } else {
+        if p.y >= 0.0 { radius = corner_radius.w; }
+    }
+    radius = min(radius, min(half_size.x, half_size.y));
+    let q = abs(p) - half_size + radius;
+    return min(max(q.x, q.y), 0.0) + length(max(q, vec2<f32>(0.0))) - radius;
+}
+
+// Returns the final color of the node at the given uv coordinate.
+fn node_style_color(style: UiNodeStyle, uv: vec2<f32>) -> vec4<f32> {
+    let p = (uv - 0.5) * style.size;
+    let d = sd_rounded_box(p, style.size * 0.5, style.corner_radius);
+    let aa = max(fwidth(d), 0.0001);
+
+    // Coverage of the whole shape
+    let coverage = 1.0 - smoothstep(-aa, aa, d);
+
+    // Blend between fill and border color
+    var color = style.color;
+    if style.border_width > 0.0 {
+        let border = smoothstep(-aa, aa, d + style.border_width);
+        color = mix(style.color, style.border_color, border);
+    }
+
+    return vec4<f32>(color.rgb, color.a * coverage);
+}
+
+struct UiNodeShadow {
+    color: vec4<f32>,
+    // Top-left, top-right, bottom-right, bottom-left
+    corner_radius: vec4<f32>,
+    size: vec2<f32>,
+    box_size: vec2<f32>,
+    blur: f32,
+};
+
+// Returns the final color of the shadow at the given uv coordinate.
+fn node_shadow_color(shadow: UiNodeShadow, uv: vec2<f32>) -> vec4<f32> {
+    let p = (uv - 0.5) * shadow.size;
+    let d = sd_rounded_box(p, shadow.box_size * 0.5, shadow.corner_radius);
+    let blur = max(shadow.blur, max(fwidth(d), 0.0001));
+    let coverage = 1.0 - smoothstep(-blur, blur, d);
+    return vec4<f32>(shadow.color.rgb, shadow.color.a * coverage);
+}
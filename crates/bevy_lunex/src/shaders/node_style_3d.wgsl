@@ -0,0 +1,9 @@
+#import bevy_pbr::forward_io::VertexOutput
+#import bevy_lunex::node_style::{UiNodeStyle, node_style_color}
+
+@group(2) @binding(0) var<uniform> style: UiNodeStyle;
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    return node_style_color(style, in.uv);
+}
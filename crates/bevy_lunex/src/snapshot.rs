@@ -0,0 +1,178 @@
+use crate::*;
+use lunex_engine::*;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+
+// #=====================#
+// #=== SNAPSHOT DATA ===#
+
+/// **Ui snapshot** - Machine readable capture of a [`UiTree`] with the layouts, states and computed rectangles of all nodes.
+/// Use it to inspect the exact state of the UI in external tools or to attach it to bug reports.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// fn save(query: Query<&UiTree<MainUi>>) {
+///     for ui in &query {
+///         let ron = UiSnapshot::from_tree(ui).to_ron().unwrap();
+///         info!("{ron}");
+///     }
+/// }
+/// ```
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct UiSnapshot {
+    /// Name of the tree.
+    pub name: String,
+    /// Absolute unit scale of the tree.
+    pub abs_scale: f32,
+    /// Default font size of the tree.
+    pub font_size: f32,
+    /// Root node of the tree.
+    pub root: UiSnapshotNode,
+}
+impl UiSnapshot {
+    /// Captures the current state of the tree.
+    pub fn from_tree<T, N: Default + Component>(ui: &UiTree<T, N>) -> Self {
+        let (abs_scale, font_size) = ui.obtain_topdata().map(|master_data| (master_data.abs_scale, master_data.font_size)).unwrap_or((1.0, 16.0));
+        UiSnapshot {
+            name: ui.get_name().clone(),
+            abs_scale,
+            font_size,
+            root: UiSnapshotNode::from_node(&ui.node),
+        }
+    }
+    /// Serializes the snapshot into pretty RON.
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+    /// Serializes the snapshot into pretty JSON.
+    #[cfg(feature = "template_json")]
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// A single node in [`UiSnapshot`].
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct UiSnapshotNode {
+    /// Name of the node.
+    pub name: String,
+    /// Full path of the node.
+    pub path: String,
+    /// Layouts of the node keyed by the state index, written in the Rust debug format.
+    pub layouts: BTreeMap<usize, String>,
+    /// State indexes the layout is blended between.
+    pub state: [usize; 2],
+    /// Blend between the two states.
+    pub tween: f32,
+    /// Display mode of the node.
+    pub display: String,
+    /// Computed position relative to the top left corner of the tree. Z is the depth.
+    pub position: [f32; 3],
+    /// Computed size.
+    pub size: [f32; 2],
+    /// Subnodes of this node.
+    pub children: Vec<UiSnapshotNode>,
+}
+impl UiSnapshotNode {
+    /// Captures the node and all its subnodes.
+    fn from_node<N: Default + Component>(node: &UiNode<N>) -> Self {
+        let mut snapshot = UiSnapshotNode {
+            name: node.get_name().clone(),
+            path: node.get_path().clone(),
+            children: node.nodes.values().map(UiSnapshotNode::from_node).collect(),
+            ..Default::default()
+        };
+        if let Some(container) = node.obtain_data() {
+            snapshot.layouts = container.layout.iter().map(|(index, layout)| (*index, format!("{:?}", layout))).collect();
+            snapshot.state = container.layout_index;
+            snapshot.tween = container.layout_tween;
+            snapshot.display = format!("{:?}", container.display);
+            snapshot.position = container.rectangle.pos.to_array();
+            snapshot.size = container.rectangle.size.to_array();
+        }
+        snapshot
+    }
+}
+
+
+// #==============#
+// #=== EVENTS ===#
+
+/// Send this event to capture a [`UiSnapshot`] of the trees. Handled by [`UiSnapshotPlugin`].
+#[derive(Event, Debug, Clone, Default, PartialEq)]
+pub struct UiSnapshotRequest {
+    /// Tree to capture, all trees if `None`.
+    pub tree: Option<Entity>,
+    /// File to write the snapshot to. Files ending with `.json` are written as JSON with the `template_json` feature, others as RON.
+    /// If `None`, the snapshot is only logged.
+    pub file: Option<String>,
+}
+
+/// This event is sent with every [`UiSnapshot`] captured by [`UiSnapshotRequest`].
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct UiSnapshotTaken {
+    /// Entity of the captured tree.
+    pub tree: Entity,
+    pub snapshot: UiSnapshot,
+}
+
+/// This system captures the trees requested by [`UiSnapshotRequest`].
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn snapshot_tree<T:Component, N:Default + Component>(
+    mut requests: EventReader<UiSnapshotRequest>,
+    mut taken: EventWriter<UiSnapshotTaken>,
+    uis: Query<(Entity, &UiTree<T, N>)>,
+) {
+    for request in requests.read() {
+        for (entity, ui) in &uis {
+            if request.tree.is_some_and(|tree| tree != entity) { continue }
+            let snapshot = UiSnapshot::from_tree(ui);
+
+            #[cfg(feature = "template_json")]
+            let serialized = match &request.file {
+                Some(file) if file.ends_with(".json") => snapshot.to_json().map_err(|error| error.to_string()),
+                _ => snapshot.to_ron().map_err(|error| error.to_string()),
+            };
+            #[cfg(not(feature = "template_json"))]
+            let serialized = snapshot.to_ron().map_err(|error| error.to_string());
+
+            match (serialized, &request.file) {
+                (Ok(text), Some(file)) => if let Err(error) = std::fs::write(file, text) {
+                    error!("Failed to write the snapshot of {} to {}: {}", ui.get_name(), file, error);
+                },
+                (Ok(text), None) => info!("Snapshot of {}:\n{}", ui.get_name(), text),
+                (Err(error), _) => error!("Failed to serialize the snapshot of {}: {}", ui.get_name(), error),
+            }
+            taken.send(UiSnapshotTaken { tree: entity, snapshot });
+        }
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin handling [`UiSnapshotRequest`] for the specified generic types.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+#[derive(Debug, Default, Clone)]
+pub struct UiSnapshotPlugin <T:Component = MainUi, N:Default + Component = NoData>(PhantomData<T>, PhantomData<N>);
+impl <T:Component, N:Default + Component> UiSnapshotPlugin<T, N> {
+    pub fn new() -> Self {
+        UiSnapshotPlugin::<T, N>(PhantomData, PhantomData)
+    }
+}
+impl <T:Component, N:Default + Component> Plugin for UiSnapshotPlugin<T, N> {
+    fn build(&self, app: &mut App) {
+        let schedule = UiSchedule::of(app);
+        app
+            .add_event::<UiSnapshotRequest>()
+            .add_event::<UiSnapshotTaken>()
+            .add_systems(schedule, snapshot_tree::<T, N>.after(UiSystems::Compute));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::*;
-use bevy::{render::primitives::Aabb, sprite::{Anchor, Material2d, Mesh2dHandle, SpriteSource}, text::{Text2dBounds, TextLayoutInfo}};
+use bevy::utils::HashMap;
+use bevy::{ecs::{entity::{EntityMapper, MapEntities}, reflect::ReflectMapEntities}, render::primitives::Aabb, sprite::{Anchor, Material2d, Mesh2dHandle, SpriteSource}, text::{Text2dBounds, TextLayoutInfo}};
 
 
 // #=====================#
@@ -12,42 +13,48 @@ pub trait UiState where Self: Send + Sync + 'static {
 
 
 /// UI state of a component, this is the normal default
-#[derive(Component, Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Component, Debug, Copy, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
 pub struct Base;
 impl UiState for Base {
     const INDEX: usize = 0;
 }
 
 /// UI state of a component, is active on hover
-#[derive(Component, Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Component, Debug, Copy, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
 pub struct Hover;
 impl UiState for Hover {
     const INDEX: usize = 1;
 }
 
 /// UI state of a component, is active when clicked
-#[derive(Component, Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Component, Debug, Copy, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
 pub struct Clicked;
 impl UiState for Clicked {
     const INDEX: usize = 2;
 }
 
 /// UI state of a component, is active when selected
-#[derive(Component, Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Component, Debug, Copy, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
 pub struct Selected;
 impl UiState for Selected {
     const INDEX: usize = 3;
 }
 
 /// UI state of a component, is active after entity is spawned
-#[derive(Component, Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Component, Debug, Copy, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
 pub struct Intro;
 impl UiState for Intro {
     const INDEX: usize = 4;
 }
 
 /// UI state of a component, is active before entity is despawned
-#[derive(Component, Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Component, Debug, Copy, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
 pub struct Outro;
 impl UiState for Outro {
     const INDEX: usize = 5;
@@ -58,15 +65,473 @@ impl UiState for Outro {
 // #=== MARKER COMPONENTS ===#
 
 /// This struct marks [`UiTree`] entity to receive piped [`Camera`] size and position to its [`Dimension`] and [`Transform`] component.
-#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
 pub struct SourceFromCamera;
+impl SourceFromCamera {
+    /// Fetches from the camera without the projection scale, so the [`UiTree`] keeps its size in world units and zooms with the world.
+    pub fn logical() -> (SourceFromCamera, SourceCameraScale) {
+        (SourceFromCamera, SourceCameraScale::Logical)
+    }
+    /// Fetches from the camera with the projection scale, so the [`UiTree`] always covers the visible area. This is the default.
+    pub fn scaled() -> (SourceFromCamera, SourceCameraScale) {
+        (SourceFromCamera, SourceCameraScale::Scaled)
+    }
+}
+
+/// This struct controls if the [`OrthographicProjection`] scale of the source camera affects [`UiTree`] with [`SourceFromCamera`].
+/// Without this component the tree behaves as [`SourceCameraScale::Scaled`].
+///
+/// The fetched [`Transform`] is local, so spawning the tree as a child of a moving game camera makes it follow the camera as a HUD.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((MainUi, Camera2dBundle::default())).with_children(|camera| {
+///     camera.spawn((
+///         UiTreeBundle::<MainUi>::from(UiTree::new2d("Hud")),
+///         SourceFromCamera::scaled(),
+///     ));
+/// });
+///
+/// // Labels placed in the world that zoom together with it
+/// commands.spawn((
+///     UiTreeBundle::<MainUi>::from(UiTree::new2d("Labels")),
+///     SourceFromCamera::logical(),
+/// ));
+/// # }
+/// ```
+/// ## 📌 Note
+/// With [`SourceCameraScale::Scaled`], zooming out enlarges the [`Dimension`] of the tree. Relative units keep their place on the screen,
+/// but absolute units get smaller with the zoom. For a HUD that is not affected by the zoom at all, render it with its own camera.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum SourceCameraScale {
+    /// The projection scale multiplies the fetched size and position.
+    #[default]
+    Scaled,
+    /// The projection scale is ignored.
+    Logical,
+}
+
+/// This struct marks [`UiTree`] entity spawned as a child of a node from another tree to receive the size and position of that node.
+/// The embedded tree is computed independently, so it can be authored as its own reusable widget, for example a minimap panel.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu")), SourceFromCamera)).with_children(|ui| {
+///     ui.spawn((
+///         UiLink::<MainUi>::path("Panel"),
+///         UiLayout::window().pos(Rl(60.0)).size(Rl(35.0)).pack::<Base>(),
+///     )).with_children(|panel| {
+///         panel.spawn((
+///             UiTreeBundle::<MainUi>::from(UiTree::new2d("Minimap")),
+///             SourceFromParent,
+///         )).with_children(|ui| {
+///             ui.spawn((UiLink::<MainUi>::path("Frame"), UiLayout::window_full().pack::<Base>()));
+///         });
+///     });
+/// });
+/// # }
+/// ```
+/// ## 📌 Note
+/// The embedded tree follows the node with one frame delay.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct SourceFromParent;
+
+/// This struct binds [`UiTree`] with [`SourceFromCamera`] to a specific camera entity, instead of the camera with the marker component.
+/// The tree then follows the viewport of that camera, which allows independent split-screen huds.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::render::{camera::Viewport, view::RenderLayers};
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// for player in 0..2 {
+///     let camera = commands.spawn((
+///         Camera2dBundle {
+///             camera: Camera {
+///                 order: player as isize,
+///                 viewport: Some(Viewport { physical_position: UVec2::new(player * 640, 0), physical_size: UVec2::new(640, 720), ..default() }),
+///                 ..default()
+///             },
+///             ..default()
+///         },
+///         RenderLayers::layer(player as usize + 1),
+///     )).id();
+///
+///     commands.spawn((
+///         UiTreeBundle::<MainUi>::from(UiTree::new2d(format!("Player {player}"))),
+///         SourceFromCamera,
+///         SourceCamera(camera),
+///         RenderLayers::layer(player as usize + 1),
+///     ));
+/// }
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct SourceCamera(pub Entity);
+impl MapEntities for SourceCamera {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0 = entity_mapper.map_entity(self.0);
+    }
+}
+
+/// This struct assigns a camera to a numbered slot. Trees with [`SourceFromCamera`] and [`SourceCameraSlot`]
+/// follow the camera in the same slot, so any number of cameras can drive their own trees,
+/// for example minimaps, portraits or picture-in-picture views.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((Camera2dBundle::default(), UiSourceCamera::new(5)));
+/// commands.spawn((
+///     UiTreeBundle::<MainUi>::from(UiTree::new2d("Minimap")),
+///     SourceFromCamera,
+///     SourceCameraSlot(5),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub struct UiSourceCamera {
+    /// The slot of the camera.
+    pub slot: u32,
+}
+impl UiSourceCamera {
+    /// Creates new struct with the slot.
+    pub fn new(slot: u32) -> Self {
+        UiSourceCamera { slot }
+    }
+}
+
+/// This struct binds [`UiTree`] with [`SourceFromCamera`] to the camera with [`UiSourceCamera`] in the same slot.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub struct SourceCameraSlot(pub u32);
 
 /// This struct is used to mark linked UI entities as elements for easier rendering.
 /// They are picked up by different systems, that ensure their piped [`Transform`] is centered,
 /// instead of being aligned in a top-left corner like the normal UI entities.
-#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
 pub struct Element;
 
+/// This struct changes the shape of the element mesh and the area that can be picked by the Lunex picking backend.
+/// The shape is always stretched to the node [`Dimension`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// let shape = UiShape::Circle;                 // -> Ellipse touching the node edges
+/// let shape = UiShape::RoundedRectangle(16.0); // -> Rectangle with 16 units of corner radius
+/// let shape = UiShape::Polygon(vec![Vec2::new(0.0, 0.5), Vec2::new(0.5, -0.5), Vec2::new(-0.5, -0.5)]); // -> Triangle
+/// ```
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub enum UiShape {
+    /// Full rectangle, the default.
+    #[default]
+    Rectangle,
+    /// Ellipse touching all node edges.
+    Circle,
+    /// Rectangle with fully rounded shorter sides.
+    Capsule,
+    /// Rectangle with rounded corners of the given radius.
+    RoundedRectangle(f32),
+    /// Custom polygon with counter-clockwise points in range `-0.5..0.5` relative to the node size, Y is up.
+    /// The mesh is triangulated as a fan from the center, so the polygon should be star-shaped around it.
+    Polygon(Vec<Vec2>),
+}
+impl UiShape {
+    /// Returns `true` if the point relative to the node center is inside the shape.
+    pub fn contains(&self, point: Vec2, size: Vec2) -> bool {
+        let half = size / 2.0;
+        match self {
+            UiShape::Rectangle => point.x.abs() <= half.x && point.y.abs() <= half.y,
+            UiShape::Circle => {
+                if half.x <= 0.0 || half.y <= 0.0 { return false }
+                (point / half).length_squared() <= 1.0
+            },
+            UiShape::Capsule => {
+                let radius = half.min_element();
+                let extent = half - radius;
+                let closest = point.clamp(-extent, extent);
+                point.distance_squared(closest) <= radius * radius
+            },
+            UiShape::RoundedRectangle(radius) => {
+                let radius = radius.clamp(0.0, half.min_element());
+                let q = point.abs() - half + radius;
+                q.max(Vec2::ZERO).length() + q.max_element().min(0.0) - radius <= 0.0
+            },
+            UiShape::Polygon(points) => {
+                let mut inside = false;
+                let mut j = points.len().wrapping_sub(1);
+                for i in 0..points.len() {
+                    let (a, b) = (points[i] * size, points[j] * size);
+                    if (a.y > point.y) != (b.y > point.y) && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x {
+                        inside = !inside;
+                    }
+                    j = i;
+                }
+                inside
+            },
+        }
+    }
+    /// Returns the outline points of the shape relative to the node center.
+    pub fn outline(&self, size: Vec2) -> Vec<Vec2> {
+        let half = size / 2.0;
+        let arc = |center: Vec2, radius: Vec2, start: f32, steps: usize| -> Vec<Vec2> {
+            (0..=steps).map(|i| {
+                let angle = start + std::f32::consts::FRAC_PI_2 * i as f32 / steps as f32;
+                center + Vec2::new(angle.cos(), angle.sin()) * radius
+            }).collect()
+        };
+        match self {
+            UiShape::Rectangle => vec![Vec2::new(half.x, half.y), Vec2::new(-half.x, half.y), Vec2::new(-half.x, -half.y), Vec2::new(half.x, -half.y)],
+            UiShape::Circle => {
+                (0..64).map(|i| {
+                    let angle = std::f32::consts::TAU * i as f32 / 64.0;
+                    Vec2::new(angle.cos(), angle.sin()) * half
+                }).collect()
+            },
+            UiShape::Capsule => UiShape::RoundedRectangle(half.min_element()).outline(size),
+            UiShape::RoundedRectangle(radius) => {
+                let radius = Vec2::splat(radius.clamp(0.0, half.min_element()));
+                let inner = half - radius;
+                let mut points = Vec::new();
+                points.extend(arc(Vec2::new(inner.x, inner.y), radius, 0.0, 8));
+                points.extend(arc(Vec2::new(-inner.x, inner.y), radius, std::f32::consts::FRAC_PI_2, 8));
+                points.extend(arc(Vec2::new(-inner.x, -inner.y), radius, std::f32::consts::PI, 8));
+                points.extend(arc(Vec2::new(inner.x, -inner.y), radius, std::f32::consts::PI * 1.5, 8));
+                points
+            },
+            UiShape::Polygon(points) => points.iter().map(|point| *point * size).collect(),
+        }
+    }
+    /// Creates the mesh of the shape, triangulated as a fan from the center.
+    pub fn mesh(&self, size: Vec2) -> Mesh {
+        if *self == UiShape::Rectangle { return Rectangle { half_size: size / 2.0 }.into() }
+
+        let outline = self.outline(size);
+        let mut positions = vec![[0.0, 0.0, 0.0]];
+        positions.extend(outline.iter().map(|point| [point.x, point.y, 0.0]));
+        let uvs: Vec<[f32; 2]> = positions.iter().map(|p| {
+            if size.x == 0.0 || size.y == 0.0 { return [0.5, 0.5] }
+            [p[0] / size.x + 0.5, 0.5 - p[1] / size.y]
+        }).collect();
+        let normals = vec![[0.0, 0.0, 1.0]; positions.len()];
+        let count = outline.len() as u32;
+        let indices: Vec<u32> = (0..count).flat_map(|i| [0, i + 1, (i + 1) % count + 1]).collect();
+
+        Mesh::new(bevy::render::mesh::PrimitiveTopology::TriangleList, bevy::render::render_asset::RenderAssetUsages::default())
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+            .with_inserted_indices(bevy::render::mesh::Indices::U32(indices))
+    }
+}
+
+/// This struct holds the wedge of the node with [`Radial`](ui::Radial) layout, fetched from the computed layout.
+/// The Lunex picking backend picks the node anywhere inside the wedge instead of its rectangle.
+/// It is inserted and removed automatically.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiWedge(pub RadialWedge);
+
+/// This struct bends all 3D elements of the [`UiTree`] into a cylindrical or spherical section.
+/// Attach it to the [`UiTree`] entity. The curve is centered in the middle of the tree [`Dimension`]
+/// and the surface is bent towards the viewer, so the meshes can still be picked by any mesh raycasting backend.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let curve = UiMeshCurved::cylinder(5.0);                    // -> Bent horizontally with the radius of 5 units
+/// let curve = UiMeshCurved::arc(std::f32::consts::FRAC_PI_2); // -> The whole tree width spans 90 degrees
+/// let curve = UiMeshCurved::sphere(5.0);                      // -> Bent on both axes
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiMeshCurved {
+    /// Radius of the curve. Ignored if the arc is set.
+    pub radius: f32,
+    /// Angle in radians that the whole tree width should span.
+    pub arc: Option<f32>,
+    /// If the surface should be bent vertically too.
+    pub spherical: bool,
+}
+impl UiMeshCurved {
+    /// Creates new cylindrical curve with the radius.
+    pub fn cylinder(radius: f32) -> Self {
+        UiMeshCurved { radius, arc: None, spherical: false }
+    }
+    /// Creates new spherical curve with the radius.
+    pub fn sphere(radius: f32) -> Self {
+        UiMeshCurved { radius, arc: None, spherical: true }
+    }
+    /// Creates new cylindrical curve where the tree width spans the angle.
+    pub fn arc(arc: f32) -> Self {
+        UiMeshCurved { radius: 0.0, arc: Some(arc), spherical: false }
+    }
+    /// Returns the radius for the tree width.
+    pub fn radius_for(&self, width: f32) -> f32 {
+        match self.arc {
+            Some(arc) if arc > 0.0 => width / arc,
+            _ => self.radius,
+        }
+    }
+    /// Bends the point from the flat tree space around the center. Returns the position and the normal.
+    pub fn bend(&self, point: Vec2, center: Vec2, radius: f32) -> (Vec3, Vec3) {
+        let theta = (point.x - center.x) / radius;
+        let phi = if self.spherical { (point.y - center.y) / radius } else { 0.0 };
+        let normal = Vec3::new(-theta.sin() * phi.cos(), -phi.sin(), theta.cos() * phi.cos());
+        let position = Vec3::new(
+            center.x + radius * theta.sin() * phi.cos(),
+            if self.spherical { center.y + radius * phi.sin() } else { point.y },
+            radius * (1.0 - theta.cos() * phi.cos()),
+        );
+        (position, normal)
+    }
+}
+
+
+/// This struct controls how the render target of an element with [`SourceFromCamera`] follows its [`Dimension`].
+/// Without it the texture is reallocated on every [`Dimension`] change, which is expensive during window resizes.
+/// If the texture ends up bigger than the element, the cameras rendering into it get a viewport covering
+/// only the used area and the [`Sprite`] is cropped to it.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy_lunex::prelude::*;
+/// let embedding = UiEmbedding::new().power_of_two(true).settle_frames(10);
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiEmbedding {
+    /// If the texture should only grow in power-of-two steps and never shrink.
+    pub power_of_two: bool,
+    /// Number of frames the [`Dimension`] has to stay the same before the texture is resized.
+    pub settle_frames: u32,
+    /// Frames since the last [`Dimension`] change.
+    pub(crate) stable: u32,
+    /// If the texture is waiting to be resized.
+    pub(crate) pending: bool,
+}
+impl UiEmbedding {
+    /// Creates new struct that resizes the texture immediately.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Replaces the power of two with a new value.
+    pub fn power_of_two(mut self, power_of_two: bool) -> Self {
+        self.power_of_two = power_of_two;
+        self
+    }
+    /// Replaces the settle frames with a new value.
+    pub fn settle_frames(mut self, frames: u32) -> Self {
+        self.settle_frames = frames;
+        self
+    }
+    /// Returns the texture size to use for the dimension, given the current texture size.
+    pub fn texture_size(&self, current: UVec2, size: UVec2) -> UVec2 {
+        if !self.power_of_two { return size }
+        if size.cmple(current).all() { return current }
+        UVec2::new(size.x.next_power_of_two(), size.y.next_power_of_two()).max(current)
+    }
+}
+
+/// This struct links an element with [`SourceFromCamera`] to the camera rendering into its texture.
+/// Every time the texture follows the [`Dimension`], the linked camera is pointed at the texture and its viewport
+/// is updated in the same system, so the camera projection never gets out of sync and the output is not stretched.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+/// let camera = commands.spawn(Camera2dBundle::default()).id();
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Embed"))).with_children(|ui| {
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Viewport"),
+///     UiLayout::window_full().pack::<Base>(),
+///     UiImage2dBundle::from(images.add(Image::default())),
+///     SourceFromCamera,
+///     UiEmbeddingCamera(camera),
+/// ));
+/// # });
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct UiEmbeddingCamera(pub Entity);
+impl MapEntities for UiEmbeddingCamera {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0 = entity_mapper.map_entity(self.0);
+    }
+}
+
+/// How the texture of [`UiDynamicTexture`] is fitted into the [`Dimension`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum UiTextureFit {
+    /// The texture is stretched to the whole element.
+    #[default]
+    Stretch,
+    /// The texture keeps its aspect ratio and covers the whole element, the overflowing part is cropped.
+    Cover,
+    /// The texture keeps its aspect ratio and fits inside the element. Only sprites are letterboxed, meshes are stretched.
+    Contain,
+}
+
+/// This struct marks an element whose texture is updated from outside every frame, for example by a video player,
+/// a webcam or a procedural canvas. Unlike elements with [`SourceFromCamera`], the texture is never resized.
+/// Instead the [`Sprite`] size and rectangle, or the UV transform of the [`StandardMaterial`], follow the [`Dimension`]
+/// and the current size of the texture.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+/// let video_frame = images.add(Image::default());
+/// # commands.spawn(UiTreeBundle::<MainUi>::from(UiTree::new2d("Player"))).with_children(|ui| {
+/// ui.spawn((
+///     UiLink::<MainUi>::path("Video"),
+///     UiLayout::window_full().pack::<Base>(),
+///     UiImage2dBundle::from(video_frame),
+///     UiDynamicTexture::new(UiTextureFit::Contain),
+/// ));
+/// # });
+/// # }
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiDynamicTexture {
+    /// How the texture is fitted into the element.
+    pub fit: UiTextureFit,
+}
+impl UiDynamicTexture {
+    /// Creates new struct with the fit.
+    pub fn new(fit: UiTextureFit) -> Self {
+        UiDynamicTexture { fit }
+    }
+    /// Returns the part of the texture to show and the size to show it at.
+    pub fn fit(&self, texture: Vec2, size: Vec2) -> (Rect, Vec2) {
+        let full = Rect::from_corners(Vec2::ZERO, texture);
+        if texture.cmple(Vec2::ZERO).any() || size.cmple(Vec2::ZERO).any() { return (full, size) }
+        match self.fit {
+            UiTextureFit::Stretch => (full, size),
+            UiTextureFit::Cover => {
+                let scale = (size / texture).max_element();
+                let crop = size / scale;
+                let min = (texture - crop) / 2.0;
+                (Rect::from_corners(min, min + crop), size)
+            },
+            UiTextureFit::Contain => (full, texture * (size / texture).min_element()),
+        }
+    }
+}
 
 // #======================#
 // #=== STD COMPONENTS ===#
@@ -74,6 +539,7 @@ pub struct Element;
 /// This struct holds rectangular data. If the component covers some kind of 2D area, it should be stored in this component.
 /// Lunex uses this component to mirror node size in & out from parent [`UiTree`].
 #[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
 pub struct Dimension {
     pub size: Vec2,
 }
@@ -85,8 +551,297 @@ impl Dimension {
     }
 }
 
-/// # WIP - used for Div layout
+/// This struct holds the final computed rectangle of the node, updated after every layout computation.
+/// Add it to any linked entity you want to read back, for example to spawn particles at a button.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// #[derive(Component)]
+/// struct BuyButton;
+///
+/// fn spawn_particles(
+///     trees: Query<&GlobalTransform, With<UiTree<MainUi>>>,
+///     buttons: Query<(&UiComputed, &Parent), With<BuyButton>>,
+///     camera: Query<(&Camera, &GlobalTransform)>,
+/// ) {
+///     let Ok((camera, camera_transform)) = camera.get_single() else { return };
+///     for (computed, tree) in &buttons {
+///         let Ok(tree) = trees.get(tree.get()) else { continue };
+///         if !computed.visible { continue }
+///         let world = computed.to_world(tree, computed.rect.center());
+///         let screen = computed.to_screen(tree, camera, camera_transform);
+///         info!("Spawning particles at {world} inside {screen:?}");
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiComputed {
+    /// Rectangle of the node in the coordinates of the tree, with the origin in the top-left corner and Y pointing down.
+    pub rect: Rect,
+    /// Depth of the node in the tree.
+    pub z: f32,
+    /// If the node and all its parent nodes are displayed.
+    pub visible: bool,
+}
+impl UiComputed {
+    /// Returns the point in the coordinates of the tree as a world position.
+    pub fn to_world(&self, tree: &GlobalTransform, point: Vec2) -> Vec3 {
+        tree.transform_point(Vec3::new(point.x, -point.y, self.z))
+    }
+    /// Returns the screen-space rectangle covering the node, as seen by the camera.
+    pub fn to_screen(&self, tree: &GlobalTransform, camera: &Camera, camera_transform: &GlobalTransform) -> Option<Rect> {
+        let corners = [self.rect.min, Vec2::new(self.rect.max.x, self.rect.min.y), self.rect.max, Vec2::new(self.rect.min.x, self.rect.max.y)];
+        let mut screen = Rect { min: Vec2::MAX, max: Vec2::MIN };
+        for corner in corners {
+            let point = camera.world_to_viewport(camera_transform, self.to_world(tree, corner))?;
+            screen = screen.union_point(point);
+        }
+        Some(screen)
+    }
+}
+
+/// This struct scales the [`Ab`] unit of the [`UiTree`] layouts. Insert it as a resource to scale all trees with [`SourceFromCamera`],
+/// or as a component on the [`UiTree`] entity to scale any tree and override the resource. The factor multiplies the absolute scale
+/// of the tree, so 3D trees keep their smaller scale, and removing it restores the original scale.
+/// Trees with [`SourceFromCamera`] are already computed in logical pixels of the window,
+/// so this is meant for user preference, not for the monitor DPI.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// App::new().insert_resource(UiScaleFactor(1.25));
+/// ```
+#[derive(Resource, Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Resource)]
+pub struct UiScaleFactor(pub f32);
+impl Default for UiScaleFactor {
+    fn default() -> Self {
+        UiScaleFactor(1.0)
+    }
+}
+
+/// How [`UiDesignResolution`] picks the scale when the viewport has a different aspect ratio than the design.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum UiScaleMode {
+    /// The whole design fits into the viewport.
+    #[default]
+    Fit,
+    /// The design covers the whole viewport.
+    Fill,
+    /// The design width matches the viewport width.
+    Width,
+    /// The design height matches the viewport height.
+    Height,
+}
+
+/// This struct makes the [`UiTree`] authored against a fixed design resolution. The [`Ab`] units are scaled by the ratio
+/// of the viewport to the design size, so `Ab(100.0)` covers the same part of the screen on every resolution.
+/// With letterbox, the tree is also shrunk to the aspect ratio of the design and centered in the viewport.
+/// It is multiplied by [`UiScaleFactor`] if present.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((
+///     UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu")),
+///     SourceFromCamera,
+///     UiDesignResolution::new((1920.0, 1080.0)).letterbox(true),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiDesignResolution {
+    /// The resolution the layouts are authored in.
+    pub size: Vec2,
+    /// How the scale is picked.
+    pub mode: UiScaleMode,
+    /// If the tree is shrunk to the aspect ratio of the design.
+    pub letterbox: bool,
+    /// The size and translation of the tree before letterboxing.
+    pub(crate) source: (Vec2, Vec2),
+    /// The size and translation of the tree after letterboxing.
+    pub(crate) output: (Vec2, Vec2),
+}
+impl UiDesignResolution {
+    /// Creates new design resolution fitting into the viewport.
+    pub fn new(size: impl Into<Vec2>) -> Self {
+        UiDesignResolution {
+            size: size.into(),
+            ..Default::default()
+        }
+    }
+    /// Replaces the scale mode with a new value.
+    pub fn mode(mut self, mode: UiScaleMode) -> Self {
+        self.mode = mode;
+        self
+    }
+    /// Replaces the letterbox with a new value.
+    pub fn letterbox(mut self, letterbox: bool) -> Self {
+        self.letterbox = letterbox;
+        self
+    }
+    /// Returns the scale of the design in the viewport.
+    pub fn scale(&self, viewport: Vec2) -> f32 {
+        let ratio = viewport / self.size;
+        match self.mode {
+            UiScaleMode::Fit => ratio.min_element(),
+            UiScaleMode::Fill => ratio.max_element(),
+            UiScaleMode::Width => ratio.x,
+            UiScaleMode::Height => ratio.y,
+        }
+    }
+}
+
+/// This struct overrides the distance between two depth levels of the [`UiTree`]. Insert it on the [`UiTree`] entity.
+/// By default the step equals the absolute scale, which can be too small to avoid z-fighting in large 3D scenes.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((
+///     UiTreeBundle::<MainUi>::from(UiTree::new3d("Panel")),
+///     UiDepthStep(0.01),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiDepthStep(pub f32);
+
+/// This struct enables culling of nodes outside of the visible area. Insert it on the [`UiTree`] entity.
+/// Nodes whose rectangle lies entirely outside of the tree, or outside of the viewport of a parent
+/// [`UiScrollArea`], are hidden together with their subnodes and marked with [`UiCulled`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((
+///     UiTreeBundle::<MainUi>::from(UiTree::new2d("Inventory")),
+///     UiCulling::new().skip_layout(true),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiCulling {
+    /// If the subnodes of culled nodes are also skipped in the layout until they are visible again.
+    pub skip_layout: bool,
+}
+impl UiCulling {
+    /// Creates new culling that only hides the nodes.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Replaces the skip layout flag with a new value.
+    pub fn skip_layout(mut self, skip_layout: bool) -> Self {
+        self.skip_layout = skip_layout;
+        self
+    }
+}
+
+/// Marker for linked entities hidden by [`UiCulling`]. It is removed once the node is visible again.
 #[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiCulled;
+
+/// This struct rounds the edges of all nodes of the [`UiTree`] to physical pixels, keeping text and thin borders sharp in 2D.
+/// Insert it on the [`UiTree`] entity. Only the fetched [`Transform`] and [`Dimension`] are rounded, the layout and its
+/// animations keep computing with the exact values, so movement stays smooth.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((
+///     UiTreeBundle::<MainUi>::from(UiTree::new2d("Hud")),
+///     SourceFromCamera,
+///     UiPixelSnap::new(),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiPixelSnap {
+    /// Physical pixels in one unit of the tree. If `None`, the scale factor of the primary window is used,
+    /// which is correct for trees with [`SourceFromCamera`].
+    pub pixels_per_unit: Option<f32>,
+}
+impl UiPixelSnap {
+    /// Creates new pixel snap using the scale factor of the primary window.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Replaces the pixels per unit with a new value.
+    pub fn pixels_per_unit(mut self, pixels_per_unit: f32) -> Self {
+        self.pixels_per_unit = Some(pixels_per_unit);
+        self
+    }
+}
+
+/// This struct sets the font size the [`Em`] unit is relative to. Insert it on the [`UiTree`] entity to change the
+/// font size of the whole tree, or on a linked entity to change it for the node and all its subnodes.
+/// The size is multiplied by the [`UiFontScale`] resource.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((
+///     UiTreeBundle::<MainUi>::from(UiTree::new2d("Menu")),
+///     UiFontSize(20.0),
+/// )).with_children(|ui| {
+///     ui.spawn((
+///         UiLink::<MainUi>::path("Title"),
+///         UiLayout::window().size(Em((10.0, 2.0))).pack::<Base>(),
+///         UiFontSize(40.0),
+///     ));
+/// });
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiFontSize(pub f32);
+impl Default for UiFontSize {
+    fn default() -> Self {
+        UiFontSize(16.0)
+    }
+}
+
+/// This resource multiplies all [`UiFontSize`] values. Use it for the font scale setting of the user.
+/// Trees without [`UiFontSize`] are scaled from the default font size.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// App::new().insert_resource(UiFontScale(1.5));
+/// ```
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct UiFontScale(pub f32);
+impl Default for UiFontScale {
+    fn default() -> Self {
+        UiFontScale(1.0)
+    }
+}
+
+/// Marker for [`UiTree`] entities that resolve the [`Vp`], [`Vw`] and [`Vh`] units against the size of the tree
+/// and the [`Rw`] and [`Rh`] units against the parent node. By default, the viewport units resolve against the primary window,
+/// so trees rendered to a texture or placed in the world still size with the screen, and [`Rw`] and [`Rh`] resolve against
+/// the size of the tree. Insert this component to get the old behavior.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiRootViewport;
+
+/// Size of the content of the node. Nodes with [`Div`](ui::Div) layout without Div subnodes are sized around it.
+/// Text elements with this component get the size of the text.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
 pub struct UiContent {
     pub size: Vec2,
 }
@@ -99,6 +854,7 @@ impl UiContent {
 
 /// This struct is used to specify size of the font in UI.
 #[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
 pub struct UiTextSize {
     /// The unit type and scale value of the text height
     pub size: UiValueType<f32>,
@@ -124,8 +880,10 @@ impl UiTextSize {
 // #=== MAIN COMPONENTS ===#
 
 #[derive(Component, Debug, Copy, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
 pub struct UiLayout<S = Base> {
     pub layout: Layout,
+    #[reflect(ignore)]
     state: PhantomData<S>,
 }
 impl UiLayout {
@@ -181,6 +939,17 @@ impl UiLayout {
     pub fn div() -> ui::Div {
         ui::Div::new()
     }
+    /// **Radial** - Declarative layout type that places the node on a circle around the parent center.
+    /// All radial subnodes of the parent are distributed along the arc one after another, each getting its own wedge.
+    /// Nodes with this layout are not included in the ui flow.
+    /// ## 🛠️ Example
+    /// ```
+    /// # use bevy_lunex::prelude::*;
+    /// let layout: UiLayout = UiLayout::radial().radius(Rl(60.0)).size(Ab(64.0)).pack();
+    /// ```
+    pub fn radial() -> ui::Radial {
+        ui::Radial::new()
+    }
 }
 impl <S> UiLayout<S> {
     /// Creates struct from layout
@@ -245,15 +1014,29 @@ impl PackageLayout for ui::Div {
         UiLayout::<S>::from(self)
     }
 }
+impl <S> From<ui::Radial> for UiLayout<S> {
+    fn from(val: ui::Radial) -> Self {
+        val.pack::<S>()
+    }
+}
+impl PackageLayout for ui::Radial {
+    fn pack<S>(self) -> UiLayout<S> {
+        UiLayout::<S>::from(self)
+    }
+}
 
 /// This struct controls what 2 layouts should be computed and lerped between.
-#[derive(Component, Debug, Clone, PartialEq)]
+/// The result is always the layout of `index[0]` lerped towards `index[1]` by the smoothed tween,
+/// no matter in which order the state layouts were added, so the blend is the same on every run.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
 pub struct UiLayoutController {
     /// Indexes of the two layouts to tween between
     pub index: [usize; 2],
     /// The transition ranging from 0.0 to 1.0
     pub tween: f32,
     /// The method called for smoothing the tween value
+    #[reflect(ignore, default = "default_method")]
     pub method: fn(f32) -> f32,
 }
 impl Default for UiLayoutController {
@@ -261,17 +1044,24 @@ impl Default for UiLayoutController {
         UiLayoutController { 
             index: [0, 0],
             tween: 0.0,
-            method: |i|{i},
+            method: default_method(),
         }
     }
 }
 
+/// Returns the default smoothing of [`UiLayoutController`], used also when the controller is loaded from a scene.
+fn default_method() -> fn(f32) -> f32 {
+    |tween| tween
+}
+
 
 /// This struct is a string reference to a specific node in a parent [`UiTree`].
 /// Lunex uses this component to locate what data this entity should be working with.
 #[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
 pub struct UiLink<T = MainUi> {
     pub path: String,
+    #[reflect(ignore)]
     marker: PhantomData<T>,
 }
 impl <T> UiLink<T> {
@@ -304,10 +1094,76 @@ impl <T> Default for UiLink<T> {
 }
 
 
+/// Reflectable copy of the [`UiTree`] settings, kept in sync with the tree automatically.
+/// [`UiTree`] itself can't be stored in a scene, so when an entity with this component and without
+/// the tree is spawned (for example from a `DynamicScene`), the tree is rebuilt from it.
+/// The nodes are then recreated from the linked children.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiTreeRoot<T = MainUi> {
+    /// Name of the tree.
+    pub name: String,
+    /// Absolute unit scale of the tree.
+    pub abs_scale: f32,
+    /// Default font size of the tree.
+    pub font_size: f32,
+    #[reflect(ignore)]
+    marker: PhantomData<T>,
+}
+impl <T> UiTreeRoot<T> {
+    /// Creates new struct with the settings.
+    pub fn new(name: impl Borrow<str>, abs_scale: f32, font_size: f32) -> Self {
+        UiTreeRoot {
+            name: name.borrow().to_string(),
+            abs_scale,
+            font_size,
+            marker: PhantomData,
+        }
+    }
+}
+impl <T> Default for UiTreeRoot<T> {
+    fn default() -> Self {
+        UiTreeRoot::new("", 1.0, 16.0)
+    }
+}
+
+
+/// Index of the entities linked to the [`UiTree`] by their path. It is added to every tree automatically
+/// and rebuilt at the start of the frame when links of the tree change, so lookups are cheap even in large trees.
+/// Use [`UiQuery`] to look up paths without querying the trees yourself.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// fn mute(trees: Query<&UiLinkIndex<MainUi>>, mut colors: Query<&mut UiColor<Base>>) {
+///     for index in &trees {
+///         let Some(entity) = index.find("Root/Settings/Audio") else { continue };
+///         if let Ok(mut color) = colors.get_mut(entity) { color.color = Color::srgb(0.5, 0.5, 0.5) }
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiLinkIndex<T = MainUi> {
+    pub(crate) entities: HashMap<String, Entity>,
+    pub(crate) marker: PhantomData<T>,
+}
+impl <T> UiLinkIndex<T> {
+    /// Returns the entity linked to the path.
+    pub fn find(&self, path: impl Borrow<str>) -> Option<Entity> {
+        self.entities.get(path.borrow()).copied()
+    }
+    /// Returns all paths with their linked entities.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Entity)> {
+        self.entities.iter().map(|(path, entity)| (path.as_str(), *entity))
+    }
+}
+
+
 /// This struct holds depth bias that will be relatively added to `depth` in the layout calculation.
 /// Nodes with higher depth bias will be placed on top of nodes with lower depth bias.
 /// It is recursive.
 #[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
 pub struct UiDepthBias (pub f32);
 
 
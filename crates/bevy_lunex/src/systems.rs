@@ -1,25 +1,83 @@
 use crate::*;
-use bevy::{math::Vec3A, render::primitives::Aabb, sprite::Mesh2dHandle, text::TextLayoutInfo, window::PrimaryWindow};
+use bevy::{diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic}, math::Vec3A, render::{mesh::{Indices, PrimitiveTopology}, primitives::Aabb, render_asset::RenderAssetUsages}, sprite::Mesh2dHandle, text::{BreakLineOn, Text2dBounds, TextLayoutInfo, TextMeasureInfo}, window::PrimaryWindow};
 use lunex_engine::*;
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::ecs::system::SystemParam;
+use bevy::utils::{Duration, HashMap, Instant};
+use colored::Colorize;
 
 
 // #===================#
 // #=== CORE SYSTEM ===#
 
 /// This system computes [`UiTree`] with data from querried [`Dimension`] component if there is a change.
+/// Only the dirty nodes are recomputed, unless the [`Dimension`] changed.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
 /// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
 pub fn compute_ui<T:Component, N:Default + Component>(
-    mut query: Query<(&Dimension, &mut UiTree<T, N>, Option<&SourceFromCamera>), (With<UiLink<T>>, Or<(Changed<UiTree<T, N>>, Changed<Dimension>)>)>,
+    mut query: Query<(Ref<Dimension>, &mut UiTree<T, N>, Option<&SourceFromCamera>), (With<UiLink<T>>, Or<(Changed<UiTree<T, N>>, Changed<Dimension>)>)>,
     window: Query<&bevy::window::Window, With<PrimaryWindow>>,
+    mut diagnostics: Option<ResMut<UiLunexDiagnostics>>,
 ) {
     let scale = if let Ok(window) = window.get_single() { window.resolution.scale_factor() } else { 1.0 };
     for (dimension, mut ui, is_camera_sourced) in &mut query {
         #[cfg(feature = "verbose")]
         info!("{} {} - {}", "<>".red(), "UiTree".purple().bold(), "Recomputed".underline().bold());
         let scale = if is_camera_sourced.is_none() { 1.0 } else { scale };
-        ui.compute(Rectangle2D::new().with_size(dimension.size / scale).into());
+        let rectangle = Rectangle2D::new().with_size(dimension.size / scale).into();
+        let triggers = ui.obtain_topdata().map(|master_data| master_data.dirty_marks).unwrap_or_default();
+        let start = diagnostics.is_some().then(Instant::now);
+        if dimension.is_changed() { ui.compute(rectangle) } else { ui.compute_dirty(rectangle) }
+
+        if let Some(diagnostics) = &mut diagnostics {
+            diagnostics.layout_time += start.map(|start| start.elapsed()).unwrap_or_default();
+            diagnostics.roots_computed += 1;
+            diagnostics.recompute_triggers += triggers;
+            diagnostics.nodes_traversed += ui.obtain_topdata().map(|master_data| master_data.nodes_traversed).unwrap_or_default();
+        }
+    }
+}
+
+
+// #==================#
+// #=== REFLECTION ===#
+
+/// This system keeps [`UiTreeRoot`] in sync with the settings of [`UiTree`].
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn sync_tree_root<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    mut query: Query<(Entity, &UiTree<T, N>, Option<&mut UiTreeRoot<T>>), Changed<UiTree<T, N>>>,
+) {
+    for (entity, ui, root) in &mut query {
+        let (abs_scale, font_size) = ui.obtain_topdata().map(|master_data| (master_data.abs_scale, master_data.font_size)).unwrap_or((1.0, 16.0));
+        let new_root = UiTreeRoot::<T>::new(ui.get_name().as_str(), abs_scale, font_size);
+        match root {
+            Some(mut root) => if root.name != new_root.name || root.abs_scale != new_root.abs_scale || root.font_size != new_root.font_size { *root = new_root },
+            None => { commands.entity(entity).insert(new_root); },
+        }
+    }
+}
+
+/// This system rebuilds [`UiTree`] on entities that have [`UiTreeRoot`] without the tree, like the ones spawned from a scene.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn rebuild_tree_from_root<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    query: Query<(Entity, &UiTreeRoot<T>), Without<UiTree<T, N>>>,
+) {
+    for (entity, root) in &query {
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Rebuilt UiTree from root", "--".yellow(), root.name.yellow().bold());
+        let mut ui = UiTree::<T, N>::new2d(root.name.as_str());
+        if let Some(master_data) = ui.obtain_topdata_mut() {
+            master_data.abs_scale = root.abs_scale;
+            master_data.font_size = root.font_size;
+        }
+        commands.entity(entity).insert(ui);
     }
 }
 
@@ -27,16 +85,58 @@ pub fn compute_ui<T:Component, N:Default + Component>(
 // #===================#
 // #=== DEBUG NODES ===#
 
+/// Node selected for inspection in the debug view. The selected entity is outlined in a highlight color
+/// by [`debug_draw_gizmo`]. It is registered for reflection, so inspector panels like `bevy-inspector-egui`
+/// can show and change it next to the components of the selected entity.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct UiInspector {
+    /// Linked entity whose node is highlighted.
+    pub selected: Option<Entity>,
+}
+impl UiInspector {
+    /// Selects the linked entity to highlight.
+    pub fn select(&mut self, entity: Entity) {
+        self.selected = Some(entity);
+    }
+    /// Clears the selection.
+    pub fn clear(&mut self) {
+        self.selected = None;
+    }
+}
+
 /// This system draws the outlines of [`UiTree`] nodes as gizmos.
+/// The node selected in [`UiInspector`] is drawn last in a highlight color.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
 /// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
 /// * Generic `(G)` - [`GizmoConfigGroup`] that will be used to draw the outlines
 pub fn debug_draw_gizmo<T:Component, N:Default + Component, G:GizmoConfigGroup>(
-    mut query: Query<(&UiTree<T, N>, &GlobalTransform)>,
+    mut query: Query<(Entity, &UiTree<T, N>, &GlobalTransform, Has<SourceFromCamera>)>,
+    links: Query<(&UiLink<T>, &Parent)>,
+    inspector: Option<Res<UiInspector>>,
+    overlay: Option<Res<UiDebugOverlay>>,
     mut gizmos: Gizmos<G>
 ) {
-    for (tree, transform) in &mut query {
+    let view_3d = overlay.is_some_and(|overlay| overlay.view_3d);
+    let draw = |gizmos: &mut Gizmos<G>, rectangle: Rectangle3D, transform: &GlobalTransform, color: Color| {
+        let mut pos = rectangle.pos.invert_y() + transform.translation();
+        pos.x += rectangle.size.x / 2.0;
+        pos.y += rectangle.size.y / -2.0;
+
+        gizmos.rect(
+            pos,
+            Quat::from_rotation_y(0.0),
+            rectangle.size,
+            color,
+        );
+    };
+
+    let selected = inspector.and_then(|inspector| inspector.selected).and_then(|entity| links.get(entity).ok());
+    for (entity, tree, transform, is_camera_sourced) in &mut query {
+        // Drawn by debug_draw_3d instead
+        if view_3d && !is_camera_sourced { continue }
+
         let list = tree.crawl();
         for node in list {
             if let Some(container) = node.obtain_data() {
@@ -45,18 +145,195 @@ pub fn debug_draw_gizmo<T:Component, N:Default + Component, G:GizmoConfigGroup>(
 
                 if let Some(Layout::Solid(_)) = container.layout.get(&container.layout_index[0]) { color = Color::linear_rgb(1.0, 1.0, 0.0) }
 
-                let mut pos = container.rectangle.pos.invert_y() + transform.translation();
-                pos.x += container.rectangle.size.x / 2.0;
-                pos.y += container.rectangle.size.y / -2.0;
+                draw(&mut gizmos, container.rectangle, transform, color);
+            }
+        }
+
+        if let Some((link, parent)) = selected {
+            if parent.get() != entity { continue }
+            if let Some(container) = tree.borrow_node(link.path.clone()).ok().and_then(|node| node.obtain_data()) {
+                draw(&mut gizmos, container.rectangle, transform, Color::linear_rgb(1.0, 0.0, 1.0));
+            }
+        }
+    }
+}
+
+/// This system draws the nodes of trees placed in the world as wireframe planes if [`UiDebugOverlay::view_3d`] is enabled.
+/// The planes follow the rotation and scale of the tree and are offset by their computed depth, each depth in its own color,
+/// so overlapping nodes sharing one depth are easy to spot. The bounds of the tree scaled by its absolute scale are drawn in white.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+/// * Generic `(G)` - [`GizmoConfigGroup`] that will be used to draw the wireframes
+pub fn debug_draw_3d<T:Component, N:Default + Component, G:GizmoConfigGroup>(
+    overlay: Res<UiDebugOverlay>,
+    query: Query<(&UiTree<T, N>, &Dimension, &GlobalTransform), Without<SourceFromCamera>>,
+    mut gizmos: Gizmos<G>
+) {
+    if !overlay.view_3d { return }
+
+    let plane = |gizmos: &mut Gizmos<G>, transform: &GlobalTransform, top_left: Vec3, size: Vec2, color: Color, diagonal: bool| {
+        let corners = [top_left, top_left + Vec3::new(size.x, 0.0, 0.0), top_left + Vec3::new(size.x, -size.y, 0.0), top_left + Vec3::new(0.0, -size.y, 0.0)]
+            .map(|corner| transform.transform_point(corner));
+        gizmos.linestrip([corners[0], corners[1], corners[2], corners[3], corners[0]], color);
+        if diagonal { gizmos.line(corners[0], corners[2], color) }
+    };
+
+    for (tree, dimension, transform) in &query {
+        let abs_scale = tree.obtain_topdata().map(|master_data| master_data.abs_scale).unwrap_or(1.0);
+        plane(&mut gizmos, transform, Vec3::ZERO, dimension.size * abs_scale, Color::WHITE, false);
+
+        for node in tree.crawl() {
+            let Some(container) = node.obtain_data() else { continue };
+            let rectangle = container.rectangle;
+            let color = Color::hsl((node.get_depth() * 47.0) % 360.0, 1.0, 0.5);
+            plane(&mut gizmos, transform, rectangle.pos.invert_y(), rectangle.size, color, true);
+        }
+    }
+}
+
+/// Settings of the on-screen debug overlay.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct UiDebugOverlay {
+    /// If every node should be labeled with its name, computed size and depth.
+    pub labels: bool,
+    /// Font size of the labels.
+    pub font_size: f32,
+    /// Key that has to be held to log the layout chain of the hovered nodes.
+    pub inspect_key: KeyCode,
+    /// If trees placed in the world instead of sourced from a camera, like the ones created with `UiTree::new3d`,
+    /// should be drawn as wireframe planes at their depth with depth labels, see [`debug_draw_3d`].
+    pub view_3d: bool,
+}
+impl Default for UiDebugOverlay {
+    fn default() -> Self {
+        UiDebugOverlay {
+            labels: false,
+            font_size: 10.0,
+            inspect_key: KeyCode::AltLeft,
+            view_3d: false,
+        }
+    }
+}
+
+/// Text label of a node spawned by [`debug_draw_labels`].
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiDebugLabel<T = MainUi> {
+    /// Entity of the tree.
+    pub tree: Entity,
+    /// Path of the labeled node.
+    pub path: String,
+    marker: PhantomData<T>,
+}
+
+/// This system spawns and updates text labels next to the gizmo outlines if [`UiDebugOverlay::labels`] is enabled.
+/// With [`UiDebugOverlay::view_3d`], trees placed in the world are labeled with the computed depth of the nodes instead.
+/// These labels are projected from the 3D camera onto the 2D camera, so they are shown only if there is one.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn debug_draw_labels<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    overlay: Res<UiDebugOverlay>,
+    uis: Query<(Entity, &UiTree<T, N>, &GlobalTransform, Has<SourceFromCamera>)>,
+    mut labels: Query<(Entity, &UiDebugLabel<T>, &mut Text, &mut Transform)>,
+    cameras_3d: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    cameras_2d: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+) {
+    let camera_3d = cameras_3d.iter().filter(|(camera, _)| camera.is_active).max_by_key(|(camera, _)| camera.order);
+    let camera_2d = cameras_2d.iter().filter(|(camera, _)| camera.is_active).max_by_key(|(camera, _)| camera.order);
+    let project = |point: Vec3| {
+        let ((camera_3d, transform_3d), (camera_2d, transform_2d)) = (camera_3d?, camera_2d?);
+        let viewport = camera_3d.world_to_viewport(transform_3d, point)?;
+        camera_2d.viewport_to_world_2d(transform_2d, viewport)
+    };
+
+    // Labels not found in the trees are left in the map and despawned
+    let mut existing: HashMap<(Entity, String), Entity> = labels.iter().map(|(label_entity, label, ..)| ((label.tree, label.path.clone()), label_entity)).collect();
+    for (entity, tree, transform, is_camera_sourced) in &uis {
+        let is_3d = overlay.view_3d && !is_camera_sourced;
+        if !overlay.labels && !is_3d { continue }
+        for node in tree.crawl() {
+            let Some(container) = node.obtain_data() else { continue };
+            let rectangle = container.rectangle;
+            let (content, translation) = if is_3d {
+                let Some(point) = project(transform.transform_point(rectangle.pos.invert_y())) else { continue };
+                (format!("{} d{} z{:.3}", node.get_name(), node.get_depth(), rectangle.pos.z), point.extend(100.0))
+            } else {
+                (format!("{} {}x{} d{}", node.get_name(), rectangle.size.x.round(), rectangle.size.y.round(), node.get_depth()), rectangle.pos.invert_y() + transform.translation() + Vec3::Z * 100.0)
+            };
+            let label = existing.remove(&(entity, node.get_path().clone())).and_then(|label| labels.get_mut(label).ok());
+            match label {
+                Some((_, _, mut text, mut label_transform)) => {
+                    if text.sections[0].value != content { text.sections[0].value = content }
+                    if label_transform.translation != translation { label_transform.translation = translation }
+                },
+                None => {
+                    commands.spawn((
+                        UiDebugLabel::<T> { tree: entity, path: node.get_path().clone(), marker: PhantomData },
+                        Text2dBundle {
+                            text: Text::from_section(content, TextStyle { font_size: overlay.font_size, color: Color::linear_rgb(0.0, 1.0, 0.0), ..default() }),
+                            text_anchor: bevy::sprite::Anchor::TopLeft,
+                            transform: Transform::from_translation(translation),
+                            ..default()
+                        },
+                    ));
+                },
+            }
+        }
+    }
+    for entity in existing.into_values() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// This system logs the layout chain of nodes hovered while [`UiDebugOverlay::inspect_key`] is held
+/// and selects the last one in [`UiInspector`].
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn debug_inspect_hovered<T:Component, N:Default + Component>(
+    overlay: Res<UiDebugOverlay>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut inspector: ResMut<UiInspector>,
+    mut over: EventReader<Pointer<Over>>,
+    mut out: EventReader<Pointer<Out>>,
+    mut hovered: Local<Vec<Entity>>,
+    uis: Query<&UiTree<T, N>>,
+    links: Query<(&UiLink<T>, &Parent, Option<&Name>)>,
+) {
+    for event in out.read() {
+        hovered.retain(|entity| *entity != event.target);
+    }
+    let mut inspect = Vec::new();
+    for event in over.read() {
+        if !links.contains(event.target) || hovered.contains(&event.target) { continue }
+        hovered.push(event.target);
+        inspect.push(event.target);
+    }
 
-                gizmos.rect(
-                    pos,
-                    Quat::from_rotation_y(0.0),
-                    container.rectangle.size,
-                    color,
-                );
+    if !keys.pressed(overlay.inspect_key) { return }
+    if keys.just_pressed(overlay.inspect_key) { inspect = hovered.clone() }
+
+    for entity in inspect {
+        let Ok((link, parent, name)) = links.get(entity) else { continue };
+        let Ok(ui) = uis.get(parent.get()) else { continue };
+
+        let mut chain = format!("{} {} - Inspecting {}", "??".cyan(), link.path.yellow().bold(), describe_entity(entity, name));
+        let mut path = String::new();
+        for segment in std::iter::once("").chain(link.path.split('/')) {
+            if !segment.is_empty() {
+                if !path.is_empty() { path.push('/') }
+                path.push_str(segment);
             }
+            let Some(container) = ui.borrow_node(path.as_str()).ok().and_then(|node| node.obtain_data()) else { continue };
+            let layout = container.layout.get(&container.layout_index[0]).map(|layout| layout.to_nicestr()).unwrap_or_default();
+            let rectangle = container.rectangle;
+            chain += &format!("\n  {} == {} -> pos: {} size: {}", if path.is_empty() { ui.get_name().as_str() } else { segment }, layout, rectangle.pos, rectangle.size);
         }
+        info!("{}", chain);
+        inspector.select(entity);
     }
 }
 
@@ -73,63 +350,426 @@ pub fn debug_print_tree<T:Component, N:Default + Component>(
 }
 
 
+/// Diagnostic with the percentage of layout states reused from the cache, see [`UiLayoutCacheStats`].
+pub const UI_LAYOUT_CACHE_HIT_RATE: DiagnosticPath = DiagnosticPath::const_new("ui_layout_cache_hit_rate");
+
+/// This system measures the layout cache hit rate of all [`UiTree`]s and resets their counters.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn debug_layout_cache<T:Component, N:Default + Component>(
+    mut uis: Query<&mut UiTree<T, N>>,
+    mut diagnostics: Diagnostics,
+) {
+    let mut total = UiLayoutCacheStats::default();
+    for mut ui in &mut uis {
+        // Resetting the counters is not a layout change
+        if let Some(master_data) = ui.bypass_change_detection().obtain_topdata_mut() {
+            total.hits += master_data.layout_cache.hits;
+            total.misses += master_data.layout_cache.misses;
+            master_data.layout_cache.reset();
+        }
+    }
+    if let Some(rate) = total.hit_rate() {
+        diagnostics.add_measurement(&UI_LAYOUT_CACHE_HIT_RATE, || rate as f64 * 100.0);
+    }
+}
+
+
+// #===================#
+// #=== DIAGNOSTICS ===#
+
+/// Statistics of what Lunex did during the last frame, added by [`UiDiagnosticsPlugin`].
+/// The values are also recorded as Bevy diagnostics, see [`UI_ROOTS_COMPUTED`] and the other paths.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// fn report(diagnostics: Res<UiLunexDiagnostics>) {
+///     info!("Traversed {} nodes in {:?}", diagnostics.nodes_traversed, diagnostics.compute_time);
+/// }
+/// ```
+#[derive(Resource, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct UiLunexDiagnostics {
+    /// Number of [`UiTree`]s computed.
+    pub roots_computed: u32,
+    /// Number of nodes visited during the computation.
+    pub nodes_traversed: u32,
+    /// Number of times a node was marked to be recomputed.
+    pub recompute_triggers: u32,
+    /// Number of text elements measured again.
+    pub text_remeasures: u32,
+    /// Time spent in [`UiSystems::Modify`].
+    pub modify_time: Duration,
+    /// Time spent in [`UiSystems::Send`].
+    pub send_time: Duration,
+    /// Time spent in [`UiSystems::Compute`].
+    pub compute_time: Duration,
+    /// Time spent in [`UiSystems::Fetch`].
+    pub fetch_time: Duration,
+    /// Time spent in [`UiSystems::Process`].
+    pub process_time: Duration,
+    /// Time spent computing the layout of the trees, without the rest of [`UiSystems::Compute`].
+    pub layout_time: Duration,
+    /// Time stamps taken at the boundaries of the system sets.
+    #[reflect(ignore)]
+    stamps: [Option<Instant>; 6],
+}
+impl UiLunexDiagnostics {
+    /// Returns the time spent in all system sets.
+    pub fn total_time(&self) -> Duration {
+        self.modify_time + self.send_time + self.compute_time + self.fetch_time + self.process_time
+    }
+}
+
+/// Diagnostic with the number of [`UiTree`]s computed per frame.
+pub const UI_ROOTS_COMPUTED: DiagnosticPath = DiagnosticPath::const_new("ui_roots_computed");
+/// Diagnostic with the number of nodes traversed per frame.
+pub const UI_NODES_TRAVERSED: DiagnosticPath = DiagnosticPath::const_new("ui_nodes_traversed");
+/// Diagnostic with the number of recompute triggers per frame.
+pub const UI_RECOMPUTE_TRIGGERS: DiagnosticPath = DiagnosticPath::const_new("ui_recompute_triggers");
+/// Diagnostic with the number of text remeasures per frame.
+pub const UI_TEXT_REMEASURES: DiagnosticPath = DiagnosticPath::const_new("ui_text_remeasures");
+/// Diagnostic with the time spent in all [`UiSystems`] per frame.
+pub const UI_SYSTEMS_TIME: DiagnosticPath = DiagnosticPath::const_new("ui_systems_time");
+/// Diagnostic with the time spent computing the layout per frame.
+pub const UI_LAYOUT_TIME: DiagnosticPath = DiagnosticPath::const_new("ui_layout_time");
+
+/// This system clears the statistics of the previous frame.
+fn diagnostics_reset(mut diagnostics: ResMut<UiLunexDiagnostics>) {
+    *diagnostics = UiLunexDiagnostics::default();
+}
+
+/// This system takes the time stamp at the boundary before the set with the index `I`.
+fn diagnostics_stamp<const I: usize>(mut diagnostics: ResMut<UiLunexDiagnostics>) {
+    diagnostics.stamps[I] = Some(Instant::now());
+}
+
+/// This system converts the time stamps into durations and records the Bevy diagnostics.
+fn diagnostics_record(mut lunex: ResMut<UiLunexDiagnostics>, mut diagnostics: Diagnostics) {
+    let stamps = lunex.stamps;
+    let span = |index: usize| match (stamps[index], stamps[index + 1]) {
+        (Some(start), Some(end)) => end.saturating_duration_since(start),
+        _ => Duration::ZERO,
+    };
+    lunex.modify_time = span(0);
+    lunex.send_time = span(1);
+    lunex.compute_time = span(2);
+    lunex.fetch_time = span(3);
+    lunex.process_time = span(4);
+
+    diagnostics.add_measurement(&UI_ROOTS_COMPUTED, || lunex.roots_computed as f64);
+    diagnostics.add_measurement(&UI_NODES_TRAVERSED, || lunex.nodes_traversed as f64);
+    diagnostics.add_measurement(&UI_RECOMPUTE_TRIGGERS, || lunex.recompute_triggers as f64);
+    diagnostics.add_measurement(&UI_TEXT_REMEASURES, || lunex.text_remeasures as f64);
+    diagnostics.add_measurement(&UI_SYSTEMS_TIME, || lunex.total_time().as_secs_f64() * 1000.0);
+    diagnostics.add_measurement(&UI_LAYOUT_TIME, || lunex.layout_time.as_secs_f64() * 1000.0);
+}
+
+
+// #==================#
+// #=== VALIDATION ===#
+
+/// Returns the name of the entity for diagnostic messages.
+fn describe_entity(entity: Entity, name: Option<&Name>) -> String {
+    match name {
+        Some(name) => format!("{} ({})", name.as_str(), entity),
+        None => format!("{}", entity),
+    }
+}
+
+/// This system looks for common setup mistakes that otherwise fail silently and logs a warning for each of them.
+/// * Linked entities that are not direct children of a matching [`UiTree`] are never computed.
+/// * Text elements measure themselves only into [`Window`] and [`Solid`] layouts.
+/// * [`Solid`] size is an aspect ratio, relative units make it change with the parent.
+///
+/// Entities are checked when the inspected components are added or changed.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn debug_validate_layout<T:Component, N:Default + Component>(
+    uis: Query<(), With<UiTree<T, N>>>,
+    links: Query<(Entity, &UiLink<T>, Option<&Parent>, Option<&Name>), (Without<UiTree<T, N>>, Or<(Added<UiLink<T>>, Changed<Parent>)>)>,
+    texts: Query<(Entity, &UiLink<T>, &UiLayout, Option<&Name>), (With<Text>, With<Element>, Changed<UiLayout>)>,
+    layouts: Query<(Entity, &UiLink<T>, &UiLayout, Option<&Name>), Changed<UiLayout>>,
+) {
+    for (entity, link, parent, name) in &links {
+        if parent.is_some_and(|parent| uis.contains(parent.get())) { continue }
+        warn!("{} {} - {} is linked to a node, but its parent is not a UiTree of the same type. It will never be computed, spawn it as a child of the tree entity.",
+            "!!".yellow(), link.path.yellow().bold(), describe_entity(entity, name));
+    }
+    for (entity, link, layout, name) in &texts {
+        if matches!(layout.layout, Layout::Window(_) | Layout::Solid(_) | Layout::Radial(_)) { continue }
+        warn!("{} {} - {} is a text element with {} layout. Text size is only applied to Window, Solid and Radial layouts.",
+            "!!".yellow(), link.path.yellow().bold(), describe_entity(entity, name), layout.layout.to_nicestr());
+    }
+    for (entity, link, layout, name) in &layouts {
+        let Layout::Solid(solid) = layout.layout else { continue };
+        let small = solid.size.evaluate(Vec2::ONE, Vec2::splat(100.0), Vec2::splat(100.0), Vec2::splat(100.0), Vec2::splat(16.0));
+        let large = solid.size.evaluate(Vec2::ONE, Vec2::new(200.0, 300.0), Vec2::new(200.0, 300.0), Vec2::new(400.0, 500.0), Vec2::splat(32.0));
+        if (small.x * large.y - small.y * large.x).abs() <= f32::EPSILON * small.length() * large.length() { continue }
+        warn!("{} {} - {} has Solid layout with relative size units. Solid size is an aspect ratio, use plain numbers or Ab to keep it stable.",
+            "!!".yellow(), link.path.yellow().bold(), describe_entity(entity, name));
+    }
+}
+
+
 // #=========================#
 // #=== PIPING FOR UITREE ===#
 
 /// This system takes [`Camera`] data and overwrites querried [`Dimension`] + [`SourceFromCamera`].
 /// It is mainly used to pipe [`Camera`] data into [`UiTree`] for root node computation.
+/// Trees with [`SourceCamera`] or [`SourceCameraSlot`] use the bound camera instead of the one with the marker.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
 /// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
 pub fn fetch_dimension_from_camera<T:Component, N:Default + Component>(
-    source: Query<(&Camera, Option<&OrthographicProjection>), (With<T>, Changed<Camera>)>,
-    mut destination: Query<&mut Dimension, (With<UiTree<T, N>>, With<SourceFromCamera>)>
+    source: Query<(Ref<Camera>, Option<&OrthographicProjection>), With<T>>,
+    cameras: Query<(Ref<Camera>, Option<&OrthographicProjection>, Option<Ref<UiSourceCamera>>)>,
+    mut destination: Query<(&mut Dimension, Option<Ref<SourceCamera>>, Option<Ref<SourceCameraSlot>>, Option<Ref<SourceCameraScale>>), (With<UiTree<T, N>>, With<SourceFromCamera>)>
 ) {
-    if source.is_empty() { return; }
-    let Ok((cam, o_projection)) = source.get_single() else {
-        warn!("Multiple D cameras with UI marker component. Only a single camera can be used as source!");
-        return;
-    };
+    for (mut dimension, bound, slot, mode) in &mut destination {
+        let Some((cam, o_projection)) = source_camera(&source, &cameras, bound, slot, mode.as_ref().is_some_and(|mode| mode.is_changed())) else { continue };
 
-    for mut dimension in &mut destination {
         // Extract camera size
         if let Some(size) = cam.physical_viewport_size() {
             #[cfg(feature = "verbose")]
             info!("{} {} - Fetched Dimension data from Camera", "->".blue(), "UiTree".purple().bold());
-            dimension.size = Vec2::from((size.x as f32, size.y as f32)) * if let Some(p) = o_projection { p.scale } else { 1.0 };
+            let size = Vec2::from((size.x as f32, size.y as f32)) * projection_scale(o_projection, mode.as_deref());
+            if dimension.size != size { dimension.size = size }
         }
     }
 }
 
 /// This system takes [`Camera`] data and overwrites querried [`Transform`] + [`SourceFromCamera`].
 /// It is mainly used to pipe [`Camera`] data into [`UiTree`] for root node computation.
+/// Trees with [`SourceCamera`] or [`SourceCameraSlot`] use the bound camera instead of the one with the marker.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
 /// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
 pub fn fetch_transform_from_camera<T:Component, N:Default + Component>(
-    source: Query<(&Camera, Option<&OrthographicProjection>), (With<T>, Changed<Camera>)>,
-    mut destination: Query<&mut Transform, (With<UiTree<T, N>>, With<SourceFromCamera>)>,
+    source: Query<(Ref<Camera>, Option<&OrthographicProjection>), With<T>>,
+    cameras: Query<(Ref<Camera>, Option<&OrthographicProjection>, Option<Ref<UiSourceCamera>>)>,
+    mut destination: Query<(&mut Transform, Option<Ref<SourceCamera>>, Option<Ref<SourceCameraSlot>>, Option<Ref<SourceCameraScale>>), (With<UiTree<T, N>>, With<SourceFromCamera>)>,
     window: Query<&bevy::window::Window, With<PrimaryWindow>>,
 ) {
-    if source.is_empty() { return; }
-    let Ok((cam, o_projection)) = source.get_single() else {
-        warn!("Multiple cameras with UI marker component. Only a single camera can be used as source!");
-        return;
-    };
-
     let scale = if let Ok(window) = window.get_single() { window.resolution.scale_factor() } else { 1.0 };
-    for mut transform in &mut destination {
+    for (mut transform, bound, slot, mode) in &mut destination {
+        let Some((cam, o_projection)) = source_camera(&source, &cameras, bound, slot, mode.as_ref().is_some_and(|mode| mode.is_changed())) else { continue };
+
         // Extract camera size
         if let Some(size) = cam.physical_viewport_size() {
             #[cfg(feature = "verbose")]
             info!("{} {} - Fetched Transform data from Camera", "->".blue(), "UiTree".purple().bold());
-            let s = if let Some(p) = o_projection { p.scale } else { 1.0 };
-            transform.translation.x = (size.x as f32 /-2.0 / scale) * s;
-            transform.translation.y = (size.y as f32 / 2.0 / scale) * s;
+            let s = projection_scale(o_projection, mode.as_deref());
+            let translation = Vec3::new((size.x as f32 /-2.0 / scale) * s, (size.y as f32 / 2.0 / scale) * s, transform.translation.z);
+            if transform.translation != translation { transform.translation = translation }
         }
     }
 }
 
+/// This system takes the [`Dimension`] of the parent node and overwrites querried [`Dimension`] + [`Transform`] + [`SourceFromParent`].
+/// It is used to embed [`UiTree`] into a node of another tree.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn fetch_from_parent_node<T:Component, N:Default + Component>(
+    parents: Query<(Ref<Dimension>, Has<Element>), Without<UiTree<T, N>>>,
+    mut destination: Query<(&mut Dimension, &mut Transform, Ref<Parent>), (With<UiTree<T, N>>, With<SourceFromParent>)>,
+) {
+    for (mut dimension, mut transform, parent) in &mut destination {
+        let Ok((source, is_element)) = parents.get(parent.get()) else { continue };
+        if !source.is_changed() && !parent.is_changed() { continue }
+
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Fetched Dimension and Transform data from parent node", "->".blue(), "UiTree".purple().bold());
+        if dimension.size != source.size { dimension.size = source.size }
+
+        // Elements are centered, align the tree to their top-left corner
+        let offset = if is_element { source.size.invert_y() / -2.0 } else { Vec2::ZERO };
+        if transform.translation.truncate() != offset {
+            transform.translation.x = offset.x;
+            transform.translation.y = offset.y;
+        }
+    }
+}
+
+/// Returns the changed camera the [`UiTree`] should fetch its data from.
+/// That is the camera bound by [`SourceCamera`], the camera in the [`SourceCameraSlot`], or the only camera with the marker component.
+fn source_camera<'a, T: Component>(
+    source: &'a Query<(Ref<Camera>, Option<&OrthographicProjection>), With<T>>,
+    cameras: &'a Query<(Ref<Camera>, Option<&OrthographicProjection>, Option<Ref<UiSourceCamera>>)>,
+    bound: Option<Ref<SourceCamera>>,
+    slot: Option<Ref<SourceCameraSlot>>,
+    forced: bool,
+) -> Option<(&'a Camera, Option<&'a OrthographicProjection>)> {
+    match (bound, slot) {
+        (Some(bound), _) => {
+            let (cam, o_projection, _) = cameras.get(bound.0).ok()?;
+            if !cam.is_changed() && !bound.is_changed() && !forced { return None }
+            Some((cam.into_inner(), o_projection))
+        },
+        (None, Some(slot)) => {
+            let (cam, o_projection, source) = cameras.iter().find(|(_, _, source)| matches!(source, Some(source) if source.slot == slot.0))?;
+            if !cam.is_changed() && !slot.is_changed() && !source.is_some_and(|source| source.is_changed()) && !forced { return None }
+            Some((cam.into_inner(), o_projection))
+        },
+        (None, None) => {
+            if !forced && !source.iter().any(|(cam, _)| cam.is_changed()) { return None }
+            let Ok((cam, o_projection)) = source.get_single() else {
+                if !source.is_empty() { warn!("Multiple cameras with UI marker component. Only a single camera can be used as source, use SourceCamera to bind trees to specific cameras!"); }
+                return None;
+            };
+            Some((cam.into_inner(), o_projection))
+        },
+    }
+}
+/// Returns the scale of the projection applied to the fetched data.
+fn projection_scale(projection: Option<&OrthographicProjection>, mode: Option<&SourceCameraScale>) -> f32 {
+    match (projection, mode) {
+        (Some(projection), None | Some(SourceCameraScale::Scaled)) => projection.scale,
+        _ => 1.0,
+    }
+}
+
+/// Returns the [`UiScaleFactor`] of the tree. The resource only applies to trees with [`SourceFromCamera`].
+fn scale_factor(local: Option<&UiScaleFactor>, global: Option<&UiScaleFactor>, from_camera: bool) -> f32 {
+    match (local, global) {
+        (Some(local), _) => local.0,
+        (None, Some(global)) if from_camera => global.0,
+        _ => 1.0,
+    }
+}
+
+/// This system takes [`UiScaleFactor`] from the [`UiTree`] entity or the resource and overwrites the scale factor of the tree.
+/// Removing the component or the resource restores the original scale.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn fetch_scale_factor<T:Component, N:Default + Component>(
+    global: Option<Res<UiScaleFactor>>,
+    mut had_global: Local<bool>,
+    mut removed: RemovedComponents<UiScaleFactor>,
+    mut removed_design: RemovedComponents<UiDesignResolution>,
+    mut query: Query<(Entity, &mut UiTree<T, N>, Option<Ref<UiScaleFactor>>, Has<SourceFromCamera>), Without<UiDesignResolution>>,
+) {
+    let removed: Vec<Entity> = removed.read().chain(removed_design.read()).collect();
+    let global_changed = global.as_ref().is_some_and(|global| global.is_changed()) || (*had_global && global.is_none());
+    *had_global = global.is_some();
+
+    for (entity, mut ui, local, from_camera) in &mut query {
+        if !ui.is_added() && !global_changed && !local.as_ref().is_some_and(|local| local.is_changed()) && !removed.contains(&entity) { continue }
+        let scale = scale_factor(local.as_deref(), global.as_deref(), from_camera);
+        if ui.obtain_topdata().map(|data| data.scale_factor) == Some(scale) { continue }
+
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Fetched scale factor", "->".blue(), "UiTree".purple().bold());
+        if let Some(data) = ui.obtain_topdata_mut() { data.scale_factor = scale }
+        ui.mark_dirty("");
+    }
+}
+
+/// This system scales the [`UiTree`] with [`UiDesignResolution`] to its viewport, letterboxing the [`Dimension`] and [`Transform`] if enabled.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn fetch_design_resolution<T:Component, N:Default + Component>(
+    global: Option<Res<UiScaleFactor>>,
+    mut query: Query<(&mut UiTree<T, N>, &mut UiDesignResolution, &mut Dimension, &mut Transform, Option<&UiScaleFactor>, Has<SourceFromCamera>)>,
+) {
+    for (mut ui, mut design, mut dimension, mut transform, local, from_camera) in &mut query {
+        // Anything else than our output is a new viewport from the source
+        let current = (dimension.size, transform.translation.truncate());
+        if current.0 != design.output.0 { design.source.0 = current.0; }
+        if current.1 != design.output.1 { design.source.1 = current.1; }
+        let (viewport, origin) = design.source;
+        if viewport.cmple(Vec2::ZERO).any() || design.size.cmple(Vec2::ZERO).any() { continue }
+
+        // Letterboxed tree is centered around the same point as the viewport
+        let fit = design.scale(viewport);
+        let size = if design.letterbox { (design.size * fit).min(viewport) } else { viewport };
+        let output = (size, origin * size / viewport);
+        if dimension.size != output.0 { dimension.size = output.0; }
+        if transform.translation.truncate() != output.1 { transform.translation = output.1.extend(transform.translation.z); }
+        if design.output != output { design.output = output; }
+
+        let scale = fit * scale_factor(local, global.as_deref(), from_camera);
+        if ui.obtain_topdata().map(|data| data.scale_factor) == Some(scale) { continue }
+
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Fetched design resolution scale", "->".blue(), "UiTree".purple().bold());
+        if let Some(data) = ui.obtain_topdata_mut() { data.scale_factor = scale }
+        ui.mark_dirty("");
+    }
+}
+
+/// This system takes [`UiFontSize`] data from the [`UiTree`] entity multiplied by [`UiFontScale`] and overwrites the tree settings.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn fetch_font_size<T:Component, N:Default + Component>(
+    scale: Option<Res<UiFontScale>>,
+    mut query: Query<(&mut UiTree<T, N>, Option<&UiFontSize>)>,
+) {
+    for (mut ui, local) in &mut query {
+        if local.is_none() && scale.is_none() { continue }
+        let font_size = local.copied().unwrap_or_default().0 * scale.as_deref().copied().unwrap_or_default().0;
+        if ui.obtain_topdata().map(|data| data.font_size) == Some(font_size) { continue }
+
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Fetched font size", "->".blue(), "UiTree".purple().bold());
+        if let Some(data) = ui.obtain_topdata_mut() { data.font_size = font_size }
+        ui.mark_dirty("");
+    }
+}
+
+/// This system sets the size of the primary window as the viewport of the [`UiTree`], so the viewport units resolve against it.
+/// Trees with [`UiRootViewport`] keep using their own size and resolve the [`Rw`] and [`Rh`] units against the parent node.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn fetch_viewport_from_window<T:Component, N:Default + Component>(
+    window: Query<&bevy::window::Window, With<PrimaryWindow>>,
+    mut query: Query<(&mut UiTree<T, N>, Has<UiRootViewport>)>,
+) {
+    let window_size = window.get_single().ok().map(|window| Vec2::new(window.width(), window.height()));
+    for (mut ui, root_viewport) in &mut query {
+        let viewport_size = if root_viewport { None } else { window_size };
+        if ui.obtain_topdata().map(|data| (data.viewport_size, data.relative_to_root)) == Some((viewport_size, !root_viewport)) { continue }
+
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Fetched viewport size", "->".blue(), "UiTree".purple().bold());
+        if let Some(data) = ui.obtain_topdata_mut() {
+            data.viewport_size = viewport_size;
+            data.relative_to_root = !root_viewport;
+        }
+        ui.mark_dirty("");
+    }
+}
+
+/// This system takes [`UiDepthStep`] data from the [`UiTree`] entity and overwrites the tree settings.
+/// Removing the component restores the default depth step.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn fetch_depth_step<T:Component, N:Default + Component>(
+    mut removed: RemovedComponents<UiDepthStep>,
+    mut query: Query<(Entity, &mut UiTree<T, N>, Option<Ref<UiDepthStep>>)>,
+) {
+    let removed: Vec<Entity> = removed.read().collect();
+    for (entity, mut ui, step) in &mut query {
+        if !ui.is_added() && !step.as_ref().is_some_and(|step| step.is_changed()) && !removed.contains(&entity) { continue }
+        let step = step.map(|step| step.0);
+        if ui.obtain_topdata().map(|data| data.depth_step) == Some(step) { continue }
+
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Fetched depth step", "->".blue(), "UiTree".purple().bold());
+        if let Some(data) = ui.obtain_topdata_mut() { data.depth_step = step }
+        ui.mark_dirty("");
+    }
+}
+
 /// This system listens for added [`UiTree`] components and if it finds one, mutable accesses all [`Camera`]s without changing them.
 /// This way [`UiTree`]s that are spawned independently get the correct size immidietly piped into them.
 /// ## 📦 Types
@@ -148,6 +788,66 @@ pub fn touch_camera_if_uitree_added<T:Component, N:Default + Component>(
     }
 }
 
+// #==================#
+// #=== LINK INDEX ===#
+
+/// This system builds [`UiLinkIndex`] of new trees and rebuilds it for trees whose linked entities changed.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn link_index_update<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    mut trees: Query<(Entity, Option<&Children>, Option<&mut UiLinkIndex<T>>), With<UiTree<T, N>>>,
+    changed_trees: Query<(), (With<UiTree<T, N>>, Changed<Children>)>,
+    changed_links: Query<&Parent, (With<UiLink<T>>, Or<(Changed<UiLink<T>>, Changed<Parent>)>)>,
+    mut removed: RemovedComponents<UiLink<T>>,
+    parents: Query<&Parent>,
+    links: Query<&UiLink<T>>,
+) {
+    let mut dirty: Vec<Entity> = changed_links.iter().map(Parent::get).collect();
+    dirty.extend(removed.read().filter_map(|entity| parents.get(entity).ok().map(Parent::get)));
+
+    for (entity, children, index) in &mut trees {
+        if index.is_some() && !changed_trees.contains(entity) && !dirty.contains(&entity) { continue }
+        let entities = children.into_iter().flatten().filter_map(|child| Some((links.get(*child).ok()?.path.clone(), *child))).collect();
+        match index {
+            Some(mut index) => index.entities = entities,
+            None => { commands.entity(entity).insert(UiLinkIndex::<T> { entities, marker: PhantomData }); },
+        }
+    }
+}
+
+/// **Ui query** - System parameter looking up entities linked to [`UiTree`]s by their path, using [`UiLinkIndex`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// fn open_audio(ui: UiQuery, mut query: Query<&mut Visibility>) {
+///     if let Some(entity) = ui.find("Root/Settings/Audio") {
+///         if let Ok(mut visibility) = query.get_mut(entity) { *visibility = Visibility::Inherited }
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct UiQuery<'w, 's, T: Component = MainUi> {
+    trees: Query<'w, 's, (Entity, &'static UiLinkIndex<T>, Option<&'static UiTreeRoot<T>>)>,
+}
+impl <T: Component> UiQuery<'_, '_, T> {
+    /// Returns the entity linked to the path in any tree.
+    pub fn find(&self, path: impl Borrow<str>) -> Option<Entity> {
+        self.trees.iter().find_map(|(_, index, _)| index.find(path.borrow()))
+    }
+    /// Returns the entity linked to the path in the tree entity.
+    pub fn find_in(&self, tree: Entity, path: impl Borrow<str>) -> Option<Entity> {
+        self.trees.get(tree).ok()?.1.find(path)
+    }
+    /// Returns the entity of the tree with the name.
+    pub fn tree(&self, name: impl Borrow<str>) -> Option<Entity> {
+        self.trees.iter().find(|(_, _, root)| root.is_some_and(|root| root.name == name.borrow())).map(|(entity, ..)| entity)
+    }
+}
+
+
 // #========================#
 // #=== PIPING FOR NODES ===#
 
@@ -158,107 +858,289 @@ pub fn touch_camera_if_uitree_added<T:Component, N:Default + Component>(
 /// * Generic `(S)` - A state generic for the given layout, as entities can have multiple layouts
 pub fn send_layout_to_node<T:Component, N:Default + Component, S: UiState>(
     mut uis: Query<(&mut UiTree<T, N>, &Children)>,
-    query: Query<(&UiLink<T>, &UiLayout<S>), (Changed<UiLayout<S>>, Without<UiTree<T, N>>)>,
+    query: Query<(&UiLink<T>, &UiLayout<S>), (Changed<UiLayout<S>>, Without<UiTree<T, N>>)>,
+) {
+    for (mut ui, children) in &mut uis {
+        for child in children {
+            // If child matches
+            if let Ok((link, layout)) = query.get(*child) {
+                // If node exists
+                if let Ok(node) = ui.borrow_or_create_ui_node_mut(link.path.clone()) {
+                    //Should always be Some but just in case
+                    if let Some(container) = node.obtain_data_mut() {
+                        #[cfg(feature = "verbose")]
+                        info!("{} {} - Received Layout data", "->".blue(), link.path.yellow().bold());
+                        container.layout.insert(S::INDEX, layout.layout);
+                    }
+                }
+                ui.mark_dirty(link.path.as_str());
+            }
+        }
+    }
+}
+
+/// This observer removes the node of an entity whose [`UiLink`] is removed or despawned, so the surrounding nodes in
+/// the flow take its place. Nodes that still have subnodes or are linked to other entities are kept.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn remove_node_on_unlink<T:Component, N:Default + Component>(
+    trigger: Trigger<OnRemove, UiLink<T>>,
+    links: Query<(&UiLink<T>, &Parent)>,
+    mut uis: Query<(&mut UiTree<T, N>, &Children)>,
+) {
+    let Ok((link, parent)) = links.get(trigger.entity()) else { return };
+    let Ok((mut ui, children)) = uis.get_mut(parent.get()) else { return };
+    if !ui.borrow_node(link.path.as_str()).is_ok_and(|node| node.nodes.is_empty()) { return }
+
+    // Elements and their backgrounds are often linked to the same node
+    if children.iter().any(|child| *child != trigger.entity() && links.get(*child).is_ok_and(|(other, _)| other.path == link.path)) { return }
+
+    #[cfg(feature = "verbose")]
+    info!("{} {} - Removed node of unlinked entity", "--".yellow(), link.path.yellow().bold());
+    let _ = ui.remove_node(link.path.as_str());
+    let parent_path = link.path.rsplit_once('/').map(|(parent_path, _)| parent_path).unwrap_or_default();
+    ui.mark_dirty(parent_path);
+}
+
+/// This system takes [`UiLayoutController`] data and overwrites coresponding [`UiTree`] data.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn send_layout_control_to_node<T:Component, N:Default + Component>(
+    mut uis: Query<(&mut UiTree<T, N>, &Children)>,
+    query: Query<(&UiLink<T>, &UiLayoutController), Changed<UiLayoutController>>,
+) {
+    for (mut ui, children) in &mut uis {
+        for child in children {
+            // If child matches
+            if let Ok((link, control)) = query.get(*child) {
+                // If node exists
+                if let Ok(node) = ui.borrow_node_mut(link.path.clone()) {
+                    //Should always be Some but just in case
+                    if let Some(container) = node.obtain_data_mut() {
+                        #[cfg(feature = "verbose")]
+                        info!("{} {} - Tweening between [{}] [{}] - {}", "->".blue(), link.path.yellow().bold(), control.index[0], control.index[1], control.tween);
+                        container.layout_index = control.index;
+                        container.layout_tween = (control.method)(control.tween);
+                    }
+                }
+                ui.mark_dirty(link.path.as_str());
+            }
+        }
+    }
+}
+
+/// This system takes [`UiStack`] data and overwrites coresponding [`UiTree`] data.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn send_stack_to_node<T:Component, N:Default + Component>(
+    mut uis: Query<(&mut UiTree<T, N>, &Children)>,
+    query: Query<(&UiLink<T>, &UiStack), Changed<UiStack>>,
+) {
+    for (mut ui, children) in &mut uis {
+        for child in children {
+            // If child matches
+            if let Ok((link, stack)) = query.get(*child) {
+                // If node exists
+                if let Ok(node) = ui.borrow_node_mut(link.path.clone()) {
+                    //Should always be Some but just in case
+                    if let Some(container) = node.obtain_data_mut() {
+                        #[cfg(feature = "verbose")]
+                        info!("{} {} - Received Stack data", "->".blue(), link.path.yellow().bold());
+                        container.stack = stack.clone();
+                    }
+                }
+                ui.mark_dirty(link.path.as_str());
+            }
+        }
+    }
+}
+
+/// This system takes [`UiDepthBias`] data and overwrites coresponding [`UiTree`] data.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn send_depth_bias_to_node<T:Component, N:Default + Component>(
+    mut uis: Query<(&mut UiTree<T, N>, &Children)>,
+    query: Query<(&UiLink<T>, &UiDepthBias), Changed<UiDepthBias>>,
+) {
+    for (mut ui, children) in &mut uis {
+        for child in children {
+            // If child matches
+            if let Ok((link, bias)) = query.get(*child) {
+                // If node exists
+                if let Ok(node) = ui.borrow_node_mut(link.path.clone()) {
+                    //Should always be Some but just in case
+                    if let Some(container) = node.obtain_data_mut() {
+                        #[cfg(feature = "verbose")]
+                        info!("{} {} - Received Depth bias data", "->".blue(), link.path.yellow().bold());
+                        container.depth_bias = bias.0;
+                    }
+                }
+                ui.mark_dirty(link.path.as_str());
+            }
+        }
+    }
+}
+
+/// This system takes [`UiZIndex`] data and overwrites coresponding [`UiTree`] data.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn send_z_index_to_node<T:Component, N:Default + Component>(
+    mut uis: Query<(&mut UiTree<T, N>, &Children)>,
+    query: Query<(&UiLink<T>, &UiZIndex), Changed<UiZIndex>>,
+) {
+    for (mut ui, children) in &mut uis {
+        for child in children {
+            // If child matches
+            if let Ok((link, z_index)) = query.get(*child) {
+                // If node exists
+                if let Ok(node) = ui.borrow_node_mut(link.path.clone()) {
+                    //Should always be Some but just in case
+                    if let Some(container) = node.obtain_data_mut() {
+                        #[cfg(feature = "verbose")]
+                        info!("{} {} - Received Z-index data", "->".blue(), link.path.yellow().bold());
+                        container.z_index = Some(*z_index);
+                    }
+                }
+                ui.mark_dirty(link.path.as_str());
+            }
+        }
+    }
+}
+
+/// This system takes [`UiFontSize`] data multiplied by [`UiFontScale`] and overwrites coresponding [`UiTree`] data.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn send_font_size_to_node<T:Component, N:Default + Component>(
+    scale: Option<Res<UiFontScale>>,
+    mut uis: Query<(&mut UiTree<T, N>, &Children)>,
+    query: Query<(&UiLink<T>, Ref<UiFontSize>)>,
 ) {
+    let rescaled = scale.as_ref().is_some_and(|scale| scale.is_changed());
+    let scale = scale.as_deref().copied().unwrap_or_default().0;
     for (mut ui, children) in &mut uis {
         for child in children {
             // If child matches
-            if let Ok((link, layout)) = query.get(*child) {
+            if let Ok((link, font_size)) = query.get(*child) {
+                if !rescaled && !font_size.is_changed() { continue }
                 // If node exists
                 if let Ok(node) = ui.borrow_or_create_ui_node_mut(link.path.clone()) {
                     //Should always be Some but just in case
                     if let Some(container) = node.obtain_data_mut() {
                         #[cfg(feature = "verbose")]
-                        info!("{} {} - Received Layout data", "->".blue(), link.path.yellow().bold());
-                        container.layout.insert(S::INDEX, layout.layout);
+                        info!("{} {} - Received Font size data", "->".blue(), link.path.yellow().bold());
+                        container.font_size = Some(font_size.0 * scale);
                     }
                 }
+                ui.mark_dirty(link.path.as_str());
             }
         }
     }
 }
 
-/// This system takes [`UiLayoutController`] data and overwrites coresponding [`UiTree`] data.
+/// This system takes [`UiDepthLayer`] data and overwrites coresponding [`UiTree`] data.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
 /// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
-pub fn send_layout_control_to_node<T:Component, N:Default + Component>(
+pub fn send_depth_layer_to_node<T:Component, N:Default + Component>(
     mut uis: Query<(&mut UiTree<T, N>, &Children)>,
-    query: Query<(&UiLink<T>, &UiLayoutController), Changed<UiLayoutController>>,
+    query: Query<(&UiLink<T>, &UiDepthLayer), Changed<UiDepthLayer>>,
 ) {
     for (mut ui, children) in &mut uis {
         for child in children {
             // If child matches
-            if let Ok((link, control)) = query.get(*child) {
+            if let Ok((link, depth_layer)) = query.get(*child) {
                 // If node exists
                 if let Ok(node) = ui.borrow_node_mut(link.path.clone()) {
                     //Should always be Some but just in case
                     if let Some(container) = node.obtain_data_mut() {
                         #[cfg(feature = "verbose")]
-                        info!("{} {} - Tweening between [{}] [{}] - {}", "->".blue(), link.path.yellow().bold(), control.index[0], control.index[1], control.tween);
-                        container.layout_index = control.index;
-                        container.layout_tween = (control.method)(control.tween);
+                        info!("{} {} - Received Depth layer data", "->".blue(), link.path.yellow().bold());
+                        container.depth_layer = Some(*depth_layer);
                     }
                 }
+                ui.mark_dirty(link.path.as_str());
             }
         }
     }
 }
 
-/// # WORK IN PROGRESS!!! DOES NOTHING CURRENTLY.
-/// This system takes [`UiStack`] data and overwrites coresponding [`UiTree`] data.
+/// This system takes [`UiDisplay`] data and overwrites coresponding [`UiTree`] data.
+/// Removing the component shows the node again.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
 /// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
-pub fn send_stack_to_node<T:Component, N:Default + Component>(
+pub fn send_display_to_node<T:Component, N:Default + Component>(
+    mut removed: RemovedComponents<UiDisplay>,
     mut uis: Query<(&mut UiTree<T, N>, &Children)>,
-    query: Query<(&UiLink<T>, &UiStack), Changed<UiStack>>,
+    query: Query<(&UiLink<T>, &UiDisplay), Changed<UiDisplay>>,
+    links: Query<&UiLink<T>, Without<UiDisplay>>,
 ) {
+    let removed: Vec<Entity> = removed.read().collect();
     for (mut ui, children) in &mut uis {
         for child in children {
+            // If the component was removed
+            if removed.contains(child) {
+                let Ok(link) = links.get(*child) else { continue };
+                let Some(container) = ui.borrow_node_mut(link.path.clone()).ok().and_then(|node| node.obtain_data_mut()) else { continue };
+                if container.display == UiDisplay::default() { continue }
+
+                #[cfg(feature = "verbose")]
+                info!("{} {} - Reset Display data", "->".blue(), link.path.yellow().bold());
+                container.display = UiDisplay::default();
+                ui.mark_dirty(link.path.as_str());
+                continue;
+            }
+
             // If child matches
-            if let Ok((link, stack)) = query.get(*child) {
+            if let Ok((link, display)) = query.get(*child) {
                 // If node exists
                 if let Ok(node) = ui.borrow_node_mut(link.path.clone()) {
                     //Should always be Some but just in case
                     if let Some(container) = node.obtain_data_mut() {
                         #[cfg(feature = "verbose")]
-                        info!("{} {} - Received Stack data", "->".blue(), link.path.yellow().bold());
-                        container.stack = stack.clone();
+                        info!("{} {} - Received Display data", "->".blue(), link.path.yellow().bold());
+                        container.display = *display;
                     }
                 }
+                ui.mark_dirty(link.path.as_str());
             }
         }
     }
 }
 
-/// This system takes [`UiDepthBias`] data and overwrites coresponding [`UiTree`] data.
+/// This system takes [`UiFit`] data and overwrites coresponding [`UiTree`] data.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
 /// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
-pub fn send_depth_bias_to_node<T:Component, N:Default + Component>(
+pub fn send_fit_to_node<T:Component, N:Default + Component>(
     mut uis: Query<(&mut UiTree<T, N>, &Children)>,
-    query: Query<(&UiLink<T>, &UiDepthBias), Changed<UiDepthBias>>,
+    query: Query<(&UiLink<T>, &UiFit), Changed<UiFit>>,
 ) {
     for (mut ui, children) in &mut uis {
         for child in children {
             // If child matches
-            if let Ok((link, bias)) = query.get(*child) {
+            if let Ok((link, fit)) = query.get(*child) {
                 // If node exists
                 if let Ok(node) = ui.borrow_node_mut(link.path.clone()) {
                     //Should always be Some but just in case
                     if let Some(container) = node.obtain_data_mut() {
                         #[cfg(feature = "verbose")]
-                        info!("{} {} - Received Depth bias data", "->".blue(), link.path.yellow().bold());
-                        container.depth_bias = bias.0;
+                        info!("{} {} - Received Fit data", "->".blue(), link.path.yellow().bold());
+                        container.fit = Some(*fit);
                     }
                 }
+                ui.mark_dirty(link.path.as_str());
             }
         }
     }
 }
 
-/// # WORK IN PROGRESS!!! DOES NOTHING CURRENTLY.
 /// This system takes [`UiContent`] data and overwrites coresponding [`UiTree`] data.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
@@ -280,11 +1162,36 @@ pub fn send_content_size_to_node<T:Component, N:Default + Component>(
                         container.content_size = content.size;
                     }
                 }
+                ui.mark_dirty(link.path.as_str());
             }
         }
     }
 }
 
+/// This system marks the nodes of text elements as relative to the parent, so the [`Rw`] and [`Rh`] units of the text size
+/// resolve against the parent node even if the tree resolves them against its own size.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn send_text_relative_to_node<T:Component, N:Default + Component>(
+    mut uis: Query<(&mut UiTree<T, N>, &Children)>,
+    query: Query<&UiLink<T>, (With<Element>, With<Text>)>,
+) {
+    for (mut ui, children) in &mut uis {
+        for child in children {
+            let Ok(link) = query.get(*child) else { continue };
+            let Ok(node) = ui.borrow_node_mut(link.path.as_str()) else { continue };
+            let Some(container) = node.obtain_data_mut() else { continue };
+            if container.relative_to_parent { continue }
+
+            #[cfg(feature = "verbose")]
+            info!("{} {} - Received text units", "->".blue(), link.path.yellow().bold());
+            container.relative_to_parent = true;
+            ui.mark_dirty(link.path.as_str());
+        }
+    }
+}
+
 /// This system fetches computed [`UiTree`] data and overwrites querried [`Transform`] data.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
@@ -303,7 +1210,8 @@ pub fn fetch_transform_from_node<T:Component, N:Default + Component>(
                     if let Some(container) = node.obtain_data() {
                         #[cfg(feature = "verbose")]
                         info!("{} {} - Linked {} fetched Transform data from node", "<-".bright_green(), link.path.yellow().bold(), "ENTITY".blue());
-                        transform.translation = container.rectangle.pos.invert_y();
+                        let translation = container.rectangle.pos.invert_y();
+                        if transform.translation != translation { transform.translation = translation }
                     }
                 }
             }
@@ -339,6 +1247,34 @@ pub fn fetch_dimension_from_node<T:Component, N:Default + Component>(
     }
 }
 
+/// This system rounds the edges of the nodes in trees with [`UiPixelSnap`] to physical pixels.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn pixel_snap<T:Component, N:Default + Component>(
+    windows: Query<&bevy::window::Window, With<PrimaryWindow>>,
+    uis: Query<(&UiPixelSnap, &Children), (With<UiTree<T, N>>, Or<(Changed<UiTree<T, N>>, Changed<UiPixelSnap>)>)>,
+    mut query: Query<(&mut Transform, &mut Dimension, Has<Element>), (With<UiLink<T>>, Without<UiTree<T, N>>)>,
+) {
+    for (snap, children) in &uis {
+        let Some(pixels) = snap.pixels_per_unit.or_else(|| windows.get_single().ok().map(|window| window.resolution.scale_factor())) else { continue };
+        let round = |value: Vec2| (value * pixels).round() / pixels;
+        for child in children {
+            let Ok((mut transform, mut dimension, is_element)) = query.get_mut(*child) else { continue };
+
+            // Elements are centered, other nodes start at the top left corner
+            let size = Vec2::new(dimension.size.x, -dimension.size.y);
+            let start = if is_element { transform.translation.truncate() - size / 2.0 } else { transform.translation.truncate() };
+            let (start, end) = (round(start), round(start + size));
+            let position = if is_element { (start + end) / 2.0 } else { start };
+            let size = Vec2::new(end.x - start.x, start.y - end.y);
+
+            if transform.translation.truncate() != position { transform.translation = position.extend(transform.translation.z) }
+            if dimension.size != size { dimension.size = size }
+        }
+    }
+}
+
 /// This system takes computed [`UiTree`] data and overwrites querried [`Transform`] + [`Element`] data in specific way.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
@@ -357,9 +1293,10 @@ pub fn element_fetch_transform_from_node<T:Component, N:Default + Component>(
                     if let Some(container) = node.obtain_data() {
                         #[cfg(feature = "verbose")]
                         info!("{} {} - Linked {} fetched Transform data", "<-".bright_green(), link.path.yellow().bold(), "ELEMENT".red());
-                        transform.translation = container.rectangle.pos.invert_y();
-                        transform.translation.x += container.rectangle.size.x /  2.0;
-                        transform.translation.y += container.rectangle.size.y / -2.0;
+                        let mut translation = container.rectangle.pos.invert_y();
+                        translation.x += container.rectangle.size.x /  2.0;
+                        translation.y += container.rectangle.size.y / -2.0;
+                        if transform.translation != translation { transform.translation = translation }
                     }
                 }
             }
@@ -367,6 +1304,185 @@ pub fn element_fetch_transform_from_node<T:Component, N:Default + Component>(
     }
 }
 
+/// This system fetches computed [`UiTree`] data and overwrites querried [`UiComputed`] data.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn fetch_computed_from_node<T:Component, N:Default + Component>(
+    uis: Query<(&UiTree<T, N>, &Children), Changed<UiTree<T, N>>>,
+    mut query: Query<(&UiLink<T>, &mut UiComputed)>,
+) {
+    for (ui, children) in &uis {
+        for child in children {
+            let Ok((link, mut computed)) = query.get_mut(*child) else { continue };
+            let Some(rectangle) = ui.borrow_node(link.path.clone()).ok().and_then(|node| node.obtain_data()).map(|container| container.rectangle) else { continue };
+
+            // Hidden parent nodes hide the node too
+            let mut visible = true;
+            let mut path = String::new();
+            for name in link.path.split('/') {
+                if !path.is_empty() { path.push('/') }
+                path.push_str(name);
+                if ui.borrow_node(path.as_str()).ok().and_then(|node| node.obtain_data()).is_some_and(|container| !container.display.is_visible()) {
+                    visible = false;
+                    break;
+                }
+            }
+
+            let pos = rectangle.pos.truncate();
+            let new = UiComputed { rect: Rect::from_corners(pos, pos + rectangle.size), z: rectangle.pos.z, visible };
+            if *computed != new {
+                #[cfg(feature = "verbose")]
+                info!("{} {} - Linked {} fetched Computed data from node", "<-".bright_green(), link.path.yellow().bold(), "ENTITY".blue());
+                *computed = new;
+            }
+        }
+    }
+}
+
+/// This system fetches the wedges of nodes with radial layout from [`UiTree`] and inserts or removes [`UiWedge`].
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn fetch_wedge_from_node<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    uis: Query<(&UiTree<T, N>, &Children), Changed<UiTree<T, N>>>,
+    mut query: Query<(Entity, &UiLink<T>, Option<&mut UiWedge>)>,
+) {
+    for (ui, children) in &uis {
+        for child in children {
+            let Ok((entity, link, wedge)) = query.get_mut(*child) else { continue };
+            let new = ui.borrow_node(link.path.clone()).ok().and_then(|node| node.obtain_data()).and_then(|container| container.wedge);
+            match (wedge, new) {
+                (Some(mut wedge), Some(new)) => if wedge.0 != new { wedge.0 = new },
+                (None, Some(new)) => { commands.entity(entity).insert(UiWedge(new)); },
+                (Some(_), None) => { commands.entity(entity).remove::<UiWedge>(); },
+                (None, None) => {},
+            }
+        }
+    }
+}
+
+/// This system takes updated [`UiDisplay`] data and overwrites querried [`Visibility`] data.
+/// Hidden and collapsed nodes are also excluded from picking, because it respects [`ViewVisibility`].
+/// Removing the component makes the entity visible again.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+pub fn display_to_visibility<T: Component>(
+    mut removed: RemovedComponents<UiDisplay>,
+    mut query: Query<(&UiDisplay, &mut Visibility), (With<UiLink<T>>, Changed<UiDisplay>)>,
+    mut shown: Query<&mut Visibility, (With<UiLink<T>>, Without<UiDisplay>)>,
+) {
+    for (display, mut visibility) in &mut query {
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Piped Display into visibility", "--".yellow(), "ENTITY".blue());
+        *visibility = if display.is_visible() { Visibility::Inherited } else { Visibility::Hidden };
+    }
+    for entity in removed.read() {
+        let Ok(mut visibility) = shown.get_mut(entity) else { continue };
+        if *visibility != Visibility::Inherited { *visibility = Visibility::Inherited }
+    }
+}
+
+/// This system hides nodes outside of the visible area of [`UiTree`] with [`UiCulling`] and marks them with [`UiCulled`].
+/// The visible area is the tree itself, narrowed down by every [`UiScrollArea`] the node is inside of.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn cull_nodes<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    mut uis: Query<(&mut UiTree<T, N>, &UiCulling, &Dimension, &Children), Or<(Changed<UiTree<T, N>>, Changed<UiCulling>, Changed<Dimension>)>>,
+    mut query: Query<(&UiLink<T>, &mut Visibility, Option<&UiDisplay>, Has<UiCulled>, Has<UiScrollArea>), Without<UiTree<T, N>>>,
+) {
+    for (mut ui, culling, dimension, children) in &mut uis {
+
+        // Parents are resolved before their subnodes
+        let mut nodes: Vec<(Entity, String, Rect, bool)> = children.iter().filter_map(|child| {
+            let (link, _, _, _, is_area) = query.get(*child).ok()?;
+            let rectangle = ui.borrow_node(link.path.clone()).ok()?.obtain_data()?.rectangle;
+            let pos = rectangle.pos.truncate();
+            Some((*child, link.path.clone(), Rect::from_corners(pos, pos + rectangle.size), is_area))
+        }).collect();
+        nodes.sort_by_key(|(_, path, _, _)| path.matches('/').count());
+
+        let tree_rect = Rect::from_corners(Vec2::ZERO, dimension.size);
+        let mut areas: Vec<(String, Rect)> = Vec::new();
+        let mut culled_paths: Vec<String> = Vec::new();
+        let is_inside = |path: &str, parent: &str| path.len() > parent.len() && path.starts_with(parent) && path.as_bytes()[parent.len()] == b'/';
+
+        for (entity, path, rect, is_area) in nodes {
+            let bounds = areas.iter().filter(|(area, _)| is_inside(&path, area)).fold(tree_rect, |bounds, (_, area)| bounds.intersect(*area));
+            let outside = rect.max.x < bounds.min.x || rect.min.x > bounds.max.x || rect.max.y < bounds.min.y || rect.min.y > bounds.max.y;
+            let parent_culled = culled_paths.iter().any(|parent| is_inside(&path, parent));
+            let culled = outside || parent_culled;
+            if is_area { areas.push((path.clone(), rect)); }
+            if culled { culled_paths.push(path.clone()); }
+
+            let Ok((_, mut visibility, display, was_culled, _)) = query.get_mut(entity) else { continue };
+            if culled {
+                if *visibility != Visibility::Hidden { *visibility = Visibility::Hidden; }
+                if !was_culled { commands.entity(entity).insert(UiCulled); }
+            } else if was_culled {
+                #[cfg(feature = "verbose")]
+                info!("{} {} - Node is visible again", "--".yellow(), path.yellow().bold());
+                *visibility = if display.is_none_or(|display| display.is_visible()) { Visibility::Inherited } else { Visibility::Hidden };
+                commands.entity(entity).remove::<UiCulled>();
+            }
+
+            // Skip the subnodes in the layout, the node is recomputed with them once it is visible again.
+            // Subnodes of culled nodes keep their flag, because their rectangles are not up to date.
+            if parent_culled { continue }
+            let skip = culling.skip_layout && outside;
+            let Ok(node) = ui.borrow_node(path.as_str()) else { continue };
+            if node.obtain_data().is_some_and(|data| data.culled != skip) {
+                if let Some(data) = ui.borrow_node_mut(path.as_str()).ok().and_then(|node| node.obtain_data_mut()) {
+                    data.culled = skip;
+                }
+                if !skip { ui.mark_dirty(path.as_str()); }
+            }
+        }
+    }
+}
+
+/// This system sets the depth bias of [`StandardMaterial`] on the nodes to their [`UiDepthLayer`], including the layers inherited from
+/// the parent nodes. Coplanar 3D panels in different layers are then ordered by the GPU too, so they never flicker.
+/// Layered nodes should use [`UiUniqueMaterial`] if their material is shared with nodes from other layers.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn element_depth_layer_to_material<T:Component, N:Default + Component>(
+    uis: Query<&Children, With<UiTree<T, N>>>,
+    changed: Query<(), Or<(Changed<UiDepthLayer>, Changed<Handle<StandardMaterial>>)>>,
+    layers: Query<(&UiLink<T>, &UiDepthLayer)>,
+    nodes: Query<(&UiLink<T>, &Handle<StandardMaterial>)>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    let Some(mut materials) = materials else { return };
+    for children in &uis {
+        if !children.iter().any(|child| changed.contains(*child)) { continue }
+        let layers: bevy::utils::HashMap<&str, u32> = children.iter().filter_map(|child| layers.get(*child).ok()).map(|(link, layer)| (link.path.as_str(), layer.0)).collect();
+        if layers.is_empty() { continue }
+
+        for child in children {
+            let Ok((link, handle)) = nodes.get(*child) else { continue };
+            // Sum the layers of the node and all its ancestors
+            let mut layer = layers.get(link.path.as_str()).copied().unwrap_or_default();
+            let mut path = link.path.as_str();
+            while let Some((parent, _)) = path.rsplit_once('/') {
+                layer += layers.get(parent).copied().unwrap_or_default();
+                path = parent;
+            }
+
+            let bias = layer as f32;
+            if materials.get(handle).is_some_and(|material| material.depth_bias != bias) {
+                #[cfg(feature = "verbose")]
+                info!("{} {} - Set material depth bias to layer {}", "--".yellow(), link.path.yellow().bold(), layer);
+                if let Some(material) = materials.get_mut(handle) { material.depth_bias = bias }
+            }
+        }
+    }
+}
+
 /// This system takes updated [`Dimension`] data and overwrites querried [`Sprite`] data to fit.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
@@ -376,23 +1492,115 @@ pub fn element_sprite_size_from_dimension<T: Component>(
     for (mut sprite, dimension) in &mut query {
         #[cfg(feature = "verbose")]
         info!("{} {} - Piped Dimension into sprite size", "--".yellow(), "ELEMENT".red());
-        sprite.custom_size = Some(dimension.size)
+        if sprite.custom_size != Some(dimension.size) { sprite.custom_size = Some(dimension.size) }
     }
 }
 
 /// This system takes updated [`Dimension`] data and overwrites querried [`Handle<Image>`] data to fit.
 /// This is used to resize manually created render targets for secondary cameras, not textures.
+/// The resizing can be delayed and rounded up with [`UiEmbedding`] and the camera can be linked with [`UiEmbeddingCamera`].
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
 pub fn element_image_size_from_dimension<T: Component>(
-    query: Query<(&Handle<Image>, &Dimension), (With<UiLink<T>>, With<Element>, With<SourceFromCamera>, Changed<Dimension>)>,
+    mut query: Query<(Ref<Dimension>, &Handle<Image>, Option<&mut UiEmbedding>, Option<&mut Sprite>, Option<Ref<UiEmbeddingCamera>>), (With<UiLink<T>>, With<Element>, With<SourceFromCamera>)>,
     mut images: ResMut<Assets<Image>>,
+    mut cameras: Query<(Entity, &mut Camera)>,
+) {
+    for (dimension, handle, embedding, sprite, link) in &mut query {
+        let relink = link.as_ref().is_some_and(|link| link.is_changed());
+        let size = dimension.size.max(Vec2::ONE).as_uvec2();
+        let Some(current) = images.get(handle).map(|image| image.size()) else { continue };
+        let embedded = embedding.is_some();
+
+        let texture = match embedding {
+            Some(mut embedding) => {
+                let embedding = embedding.bypass_change_detection();
+                if dimension.is_changed() {
+                    embedding.stable = 0;
+                    embedding.pending = true;
+                } else {
+                    embedding.stable = embedding.stable.saturating_add(1);
+                }
+                if relink { embedding.pending = true }
+                if !embedding.pending || embedding.stable < embedding.settle_frames { continue }
+                embedding.pending = false;
+                embedding.texture_size(current, size)
+            },
+            None => {
+                if !dimension.is_changed() && !relink { continue }
+                size
+            },
+        };
+
+        if texture != current {
+            #[cfg(feature = "verbose")]
+            info!("{} {} - Resizing texture based on Dimension", "--".yellow(), "ELEMENT".red());
+            if let Some(image) = images.get_mut(handle) {
+                image.resize(bevy::render::render_resource::Extent3d { width: texture.x, height: texture.y, ..default() });
+            }
+        }
+
+        // Render and show only the used part of a bigger texture
+        let used = size.min(texture);
+        let cropped = used != texture;
+        if let Some(mut sprite) = sprite {
+            let rect = if cropped { Some(Rect::new(0.0, 0.0, used.x as f32, used.y as f32)) } else { None };
+            if sprite.rect != rect { sprite.rect = rect }
+        }
+        for (entity, mut camera) in &mut cameras {
+            let linked = link.as_ref().map(|link| link.0) == Some(entity);
+            let rendering = matches!(&camera.target, bevy::render::camera::RenderTarget::Image(target) if target == handle);
+            if !rendering {
+                if !linked { continue }
+                camera.target = bevy::render::camera::RenderTarget::Image(handle.clone());
+            }
+            // Viewports of cameras set up by the user are left alone unless the texture is managed by UiEmbedding
+            if !embedded && !linked { continue }
+            let synced = match &camera.viewport {
+                Some(viewport) => cropped && viewport.physical_position == UVec2::ZERO && viewport.physical_size == used,
+                None => !cropped,
+            };
+            if !synced { camera.viewport = cropped.then(|| bevy::render::camera::Viewport { physical_size: used, ..default() }) }
+        }
+    }
+}
+
+/// This system fits the texture of elements with [`UiDynamicTexture`] into their [`Dimension`] without resizing it.
+/// It runs when the [`Dimension`] changes and when the texture is modified.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+pub fn element_dynamic_texture<T: Component>(
+    mut events: EventReader<AssetEvent<Image>>,
+    images: Res<Assets<Image>>,
+    mut materials: Option<ResMut<Assets<StandardMaterial>>>,
+    mut query: Query<(Ref<Dimension>, Ref<UiDynamicTexture>, Ref<Handle<Image>>, Option<&mut Sprite>, Option<&Handle<StandardMaterial>>), (With<UiLink<T>>, With<Element>)>,
 ) {
-    for (handle, dimension) in &query {
+    let modified: bevy::utils::HashSet<AssetId<Image>> = events.read().filter_map(|event| match event {
+        AssetEvent::Added { id } | AssetEvent::Modified { id } | AssetEvent::LoadedWithDependencies { id } => Some(*id),
+        _ => None,
+    }).collect();
+
+    for (dimension, texture, handle, sprite, material) in &mut query {
+        if !dimension.is_changed() && !texture.is_changed() && !handle.is_changed() && !modified.contains(&handle.id()) { continue }
+        let Some(image) = images.get(handle.id()) else { continue };
+        let image_size = image.size().as_vec2();
+        let (rect, size) = texture.fit(image_size, dimension.size);
+
         #[cfg(feature = "verbose")]
-        info!("{} {} - Resizing texture based on Dimension", "--".yellow(), "ELEMENT".red());
-        if let Some(image) = images.get_mut(handle) {
-            image.resize(bevy::render::render_resource::Extent3d { width: dimension.size.x as u32, height: dimension.size.y as u32, ..default() });
+        info!("{} {} - Fitted dynamic texture into Dimension", "--".yellow(), "ELEMENT".red());
+
+        if let Some(mut sprite) = sprite {
+            let rect = (rect.size() != image_size).then_some(rect);
+            if sprite.rect != rect { sprite.rect = rect }
+            if sprite.custom_size != Some(size) { sprite.custom_size = Some(size) }
+        }
+
+        if let (Some(material), Some(materials)) = (material, materials.as_mut()) {
+            if image_size.cmple(Vec2::ZERO).any() { continue }
+            let uv_transform = bevy::math::Affine2::from_scale_angle_translation(rect.size() / image_size, 0.0, rect.min / image_size);
+            if materials.get(material).is_some_and(|material| material.uv_transform != uv_transform) {
+                if let Some(material) = materials.get_mut(material) { material.uv_transform = uv_transform }
+            }
         }
     }
 }
@@ -402,9 +1610,10 @@ pub fn element_image_size_from_dimension<T: Component>(
 /// * Generic `(T)` - Marker component grouping entities into one widget type
 pub fn element_reconstruct_mesh<T: Component>(
     mut msh: ResMut<Assets<Mesh>>,
-    mut query: Query<(&Dimension, Option<&mut Handle<Mesh>>, Option<&mut Mesh2dHandle>, Option<&mut Aabb>), (With<UiLink<T>>, With<Element>, Or<(Changed<Dimension>, Added<Mesh2dHandle>)>)>,
+    mut query: Query<(&Dimension, Option<&UiShape>, Option<&mut Handle<Mesh>>, Option<&mut Mesh2dHandle>, Option<&mut Aabb>), (With<UiLink<T>>, With<Element>, Or<(Changed<Dimension>, Added<Mesh2dHandle>, Changed<UiShape>)>)>,
 ) {
-    for (dimension, mut mesh_option, mut mesh2d_option, mut aabb_option) in &mut query {
+    for (dimension, shape_option, mut mesh_option, mut mesh2d_option, mut aabb_option) in &mut query {
+        let shape = shape_option.cloned().unwrap_or_default();
 
         #[cfg(feature = "verbose")]
         info!("{} {} - Reconstructed mesh size", "--".yellow(), "ELEMENT".red());
@@ -418,23 +1627,114 @@ pub fn element_reconstruct_mesh<T: Component>(
         }
 
         if let Some(mesh) = mesh_option.as_mut() {
-            // Unload old mesh
-            let _ = msh.remove(mesh.id());
-
-            // Create new mesh
-            **mesh = msh.add(Rectangle {half_size: dimension.size / 2.0});
+            replace_mesh(&mut msh, mesh, shape.mesh(dimension.size));
         }
 
         if let Some(mesh2d) = mesh2d_option.as_mut() {
-            // Unload old mesh
-            let _ = msh.remove(mesh2d.0.id());
+            replace_mesh(&mut msh, &mut mesh2d.0, shape.mesh(dimension.size));
+        }
+    }
+}
+
+/// Overwrites the mesh asset behind the handle in place, so resizing doesn't allocate a new asset every frame.
+/// A new asset is added only if the handle doesn't point to one yet.
+pub(crate) fn replace_mesh(msh: &mut Assets<Mesh>, handle: &mut Handle<Mesh>, mesh: Mesh) {
+    match msh.get_mut(handle.id()) {
+        Some(old) => *old = mesh,
+        None => *handle = msh.add(mesh),
+    }
+}
+
+/// This system bends the meshes of elements in [`UiTree`] with [`UiMeshCurved`].
+/// It runs after [`element_reconstruct_mesh`] and replaces the flat mesh.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+pub fn element_curve_mesh<T: Component>(
+    mut msh: ResMut<Assets<Mesh>>,
+    roots: Query<(Ref<UiMeshCurved>, Ref<Dimension>, &Children), With<UiLink<T>>>,
+    mut query: Query<(Ref<Dimension>, Ref<Transform>, &mut Handle<Mesh>, Option<&mut Aabb>), (With<UiLink<T>>, With<Element>)>,
+) {
+    for (curve, root_dimension, children) in &roots {
+        let root_changed = curve.is_changed() || root_dimension.is_changed();
+        let radius = curve.radius_for(root_dimension.size.x);
+        if radius <= 0.0 { continue }
+        let center = Vec2::new(root_dimension.size.x / 2.0, -root_dimension.size.y / 2.0);
+
+        for child in children {
+            let Ok((dimension, transform, mut mesh, aabb)) = query.get_mut(*child) else { continue };
+            if !root_changed && !dimension.is_changed() && !transform.is_changed() { continue }
+
+            #[cfg(feature = "verbose")]
+            info!("{} {} - Bent the mesh", "--".yellow(), "ELEMENT".red());
+
+            // Subdivide so every segment spans at most ~3 degrees
+            let step = std::f32::consts::PI / 64.0;
+            let segments_x = ((dimension.size.x / radius / step).ceil() as u32).max(1);
+            let segments_y = if curve.spherical { ((dimension.size.y / radius / step).ceil() as u32).max(1) } else { 1 };
+
+            let origin = transform.translation.truncate();
+            let mut positions = Vec::new();
+            let mut normals = Vec::new();
+            let mut uvs = Vec::new();
+            for j in 0..=segments_y {
+                for i in 0..=segments_x {
+                    let uv = Vec2::new(i as f32 / segments_x as f32, j as f32 / segments_y as f32);
+                    let local = Vec2::new((uv.x - 0.5) * dimension.size.x, (0.5 - uv.y) * dimension.size.y);
+                    let (position, normal) = curve.bend(origin + local, center, radius);
+                    positions.push((position - origin.extend(0.0)).to_array());
+                    normals.push(normal.to_array());
+                    uvs.push(uv.to_array());
+                }
+            }
+            let mut indices = Vec::new();
+            let row = segments_x + 1;
+            for j in 0..segments_y {
+                for i in 0..segments_x {
+                    let a = j * row + i;
+                    indices.extend_from_slice(&[a, a + row, a + 1, a + 1, a + row, a + row + 1]);
+                }
+            }
+
+            let new_mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default())
+                .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+                .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+                .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+                .with_inserted_indices(Indices::U32(indices));
+
+            if let Some(mut aabb) = aabb {
+                if let Some(new_aabb) = new_mesh.compute_aabb() { *aabb = new_aabb }
+            }
 
-            // Create new mesh
-            **mesh2d = Mesh2dHandle(msh.add(Rectangle {half_size: dimension.size / 2.0}));
+            replace_mesh(&mut msh, &mut mesh, new_mesh);
         }
     }
 }
 
+/// This system measures changed [`Text`] before the layout is computed and writes the size into [`TextLayoutInfo`].
+/// Text sized nodes then get the correct size in the same frame the text spawns, instead of waiting a frame for the text pipeline.
+/// If the font is not loaded yet, the measurement is skipped and the size arrives from the text pipeline later.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+pub fn element_text_measure<T: Component>(
+    fonts: Res<Assets<Font>>,
+    window: Query<&bevy::window::Window, With<PrimaryWindow>>,
+    mut query: Query<(&Text, Option<&Text2dBounds>, &mut TextLayoutInfo), (With<UiLink<T>>, With<Element>, Changed<Text>)>,
+    mut diagnostics: Option<ResMut<UiLunexDiagnostics>>,
+) {
+    let scale_factor = window.get_single().map(|window| window.resolution.scale_factor()).unwrap_or(1.0);
+    for (text, bounds, mut text_info) in &mut query {
+        let Ok(measure) = TextMeasureInfo::from_text(text, &fonts, scale_factor) else { continue };
+        let bounds = bounds.map(|bounds| bounds.size).unwrap_or(Vec2::INFINITY) * scale_factor;
+        let bounds = if text.linebreak_behavior == BreakLineOn::NoWrap { Vec2::new(f32::INFINITY, bounds.y) } else { bounds };
+
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Measured text size", "--".yellow(), "ELEMENT".red());
+        let size = measure.compute_size(bounds) / scale_factor;
+        if text_info.logical_size != size { text_info.logical_size = size }
+        if let Some(diagnostics) = &mut diagnostics { diagnostics.text_remeasures += 1 }
+    }
+}
+
 /// This system takes updated [`TextLayoutInfo`] data and overwrites coresponding [`Layout`] data to match the text size.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
@@ -442,12 +1742,16 @@ pub fn element_text_size_to_layout<T: Component>(
     mut query: Query<(&mut UiLayout, &TextLayoutInfo, &Text, Option<&UiTextSize>), (With<UiLink<T>>, With<Element>, Changed<TextLayoutInfo>)>,
 ) {
     for (mut layout, text_info, text, optional_text_size) in &mut query {
+        // Measure the whole block relative to the largest section
+        let font_size = text.sections.iter().fold(0.0, |size: f32, section| size.max(section.style.font_size));
+        if font_size <= 0.0 { continue }
+
         #[cfg(feature = "verbose")]
         info!("{} {} - Converted text size into Layout", "--".yellow(), "ELEMENT".red());
-        match &mut layout.layout {
-            Layout::Window(window) => {
-                let font_size = text.sections[0].style.font_size;
-                window.size = if let Some(text_size) = optional_text_size {
+        let mut new_layout = layout.layout;
+        match &mut new_layout {
+            Layout::Window(ui::Window { size, .. }) | Layout::Radial(ui::Radial { size, .. }) => {
+                *size = if let Some(text_size) = optional_text_size {
                     match text_size.size {
                         UiValueType::Ab(t) => Ab(text_info.logical_size/font_size * t.0).into(),
                         UiValueType::Rl(t) => Rl(text_info.logical_size/font_size * t.0).into(),
@@ -464,10 +1768,10 @@ pub fn element_text_size_to_layout<T: Component>(
             Layout::Solid(solid) => {solid.size = Ab(text_info.logical_size).into()},
             _ => {},
         }
+        if layout.layout != new_layout { layout.layout = new_layout }
     }
 }
 
-/// # WORK IN PROGRESS!!! DOES NOTHING CURRENTLY.
 /// This system takes updated [`TextLayoutInfo`] data and overwrites coresponding [`UiContent`] data to match the text size.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
@@ -477,7 +1781,7 @@ pub fn element_text_size_to_content<T: Component>(
     for (mut content, text_info) in &mut query {
         #[cfg(feature = "verbose")]
         info!("{} {} - Piped text size into content", "--".yellow(), "ELEMENT".red());
-        content.size = text_info.logical_size;
+        if content.size != text_info.logical_size { content.size = text_info.logical_size }
     }
 }
 
@@ -490,9 +1794,8 @@ pub fn element_text_size_scale_fit_to_dimension<T: Component>(
     for (mut transform, dimension, text_info) in &mut query {
         #[cfg(feature = "verbose")]
         info!("{} {} - Scaled Transform for text size to fit into Dimension", "--".yellow(), "ELEMENT".red());
-        let scale = dimension.size / text_info.logical_size;
-        transform.scale.x = scale.x;
-        transform.scale.y = scale.y;
+        let scale = (dimension.size / text_info.logical_size).extend(transform.scale.z);
+        if transform.scale != scale { transform.scale = scale }
     }
 }
 
@@ -515,10 +1818,61 @@ pub enum UiSystems {
     Process,
 }
 
+/// When the layout of [`UiTree`]s is computed. Insert it as a resource to change the mode, it can be changed at any time.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub enum UiUpdateMode {
+    /// The layout systems run every frame. Nodes are still recomputed only if something changed.
+    #[default]
+    Continuous,
+    /// The layout systems run only on frames when [`RecomputeUiLayout`] is sent or when [`Dimension`] changes,
+    /// which happens when trees are spawned or resized. Use it for static menus that barely change.
+    OnDemand,
+}
+
+/// Send this event to run the layout systems in [`UiUpdateMode::OnDemand`].
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// fn open_settings(mut query: Query<&mut UiLayout>, mut recompute: EventWriter<RecomputeUiLayout>) {
+///     for mut layout in &mut query {
+///         *layout = UiLayout::window().size(Rl(50.0)).pack::<Base>();
+///     }
+///     recompute.send(RecomputeUiLayout);
+/// }
+/// ```
+#[derive(Event, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RecomputeUiLayout;
+
+/// Run condition of the [`UiSystems::Send`], [`UiSystems::Compute`], [`UiSystems::Fetch`] and [`UiSystems::Process`] sets.
+/// It is always true in [`UiUpdateMode::Continuous`].
+pub fn ui_layout_requested(mode: Res<UiUpdateMode>, mut requests: EventReader<RecomputeUiLayout>, resized: Query<(), Changed<Dimension>>) -> bool {
+    let requested = requests.read().count() > 0;
+    *mode == UiUpdateMode::Continuous || requested || !resized.is_empty()
+}
+
+/// The schedule the [`UiSystems::Modify`], [`UiSystems::Send`], [`UiSystems::Compute`], [`UiSystems::Fetch`] and [`UiSystems::Process`] sets run in.
+/// It is inserted by [`UiCorePlugin`] and changed with [`UiCorePlugin::schedule`]. Plugins adding systems to or ordered against
+/// these sets read it when they are built, so they need to be added after [`UiCorePlugin`].
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiSchedule(pub InternedScheduleLabel);
+impl UiSchedule {
+    /// Returns the schedule of the layout sets, or [`Update`] if [`UiCorePlugin`] was not added yet.
+    pub fn of(app: &App) -> InternedScheduleLabel {
+        app.world().get_resource::<UiSchedule>().map_or(Update.intern(), |schedule| schedule.0)
+    }
+}
+
 /// Plugin implementing all ui logic for the specified generic types.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
 /// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+///
+/// The layout runs in [`Update`] by default. Use [`UiCorePlugin::schedule`] to run it in another schedule,
+/// like [`FixedUpdate`] for deterministic games. Lunex plugins added after this one put their systems in or ordered against
+/// the layout sets into the same schedule, see [`UiSchedule`]. Their input systems keep running in [`Update`].
+/// To compute the layout only on demand, insert [`UiUpdateMode::OnDemand`].
 /// 
 /// ## 🛠️ Example
 /// *1. Define the types used*
@@ -544,52 +1898,204 @@ pub enum UiSystems {
 ///   ));
 ///#  }
 /// ```
-#[derive(Debug, Default, Clone)]
-pub struct UiCorePlugin <T:Component = MainUi, N:Default + Component = NoData>(PhantomData<T>, PhantomData<N>);
+#[derive(Debug, Clone)]
+pub struct UiCorePlugin <T:Component = MainUi, N:Default + Component = NoData> {
+    schedule: InternedScheduleLabel,
+    marker: PhantomData<(T, N)>,
+}
 impl <T:Component, N:Default + Component> UiCorePlugin<T, N> {
     pub fn new() -> Self {
-        UiCorePlugin::<T, N>(PhantomData, PhantomData)
+        UiCorePlugin::<T, N> {
+            schedule: Update.intern(),
+            marker: PhantomData,
+        }
+    }
+    /// Replaces the schedule the layout systems run in with a new value.
+    /// ## 🛠️ Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_lunex::prelude::*;
+    /// use bevy_lunex::UiCorePlugin;
+    ///
+    /// App::new()
+    ///     .add_plugins(MinimalPlugins)
+    ///     .add_plugins(UiMinimalPlugins.set(UiCorePlugin::<MainUi>::new().schedule(FixedUpdate)));
+    /// ```
+    pub fn schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+}
+impl <T:Component, N:Default + Component> Default for UiCorePlugin<T, N> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 impl <T:Component, N:Default + Component> Plugin for UiCorePlugin<T, N> {
     fn build(&self, app: &mut App) {
+        app.insert_resource(UiSchedule(self.schedule));
+        if !app.is_plugin_added::<UiDiagnosticsPlugin>() { app.add_plugins(UiDiagnosticsPlugin); }
+        if !app.is_plugin_added::<UiReflectPlugin>() { app.add_plugins(UiReflectPlugin); }
         app
-            .add_systems(Update, (
+            // Also added by AssetPlugin, required by UiDynamicTexture in headless apps
+            .add_event::<AssetEvent<Image>>()
+            .add_event::<RecomputeUiLayout>()
+            .init_resource::<UiUpdateMode>()
+            .configure_sets(self.schedule, UiSystems::Modify.before(UiSystems::Send))
+            .configure_sets(self.schedule, (UiSystems::Send, UiSystems::Compute, UiSystems::Fetch, UiSystems::Process).run_if(ui_layout_requested))
+            .observe(remove_node_on_unlink::<T, N>)
+            .add_systems(PreUpdate, link_index_update::<T, N>)
+            .add_systems(self.schedule, (
+                element_text_measure::<T>.before(element_text_size_to_layout::<T>).before(element_text_size_to_content::<T>),
                 element_text_size_to_layout::<T>,
                 element_text_size_to_content::<T>,
                 touch_camera_if_uitree_added::<T, N>,
+                fetch_scale_factor::<T, N>,
+                fetch_depth_step::<T, N>,
+                fetch_font_size::<T, N>,
+                fetch_viewport_from_window::<T, N>,
+                rebuild_tree_from_root::<T, N>,
+                sync_tree_root::<T, N>.after(rebuild_tree_from_root::<T, N>),
                 fetch_dimension_from_camera::<T, N>.after(touch_camera_if_uitree_added::<T, N>),
                 fetch_transform_from_camera::<T, N>.after(touch_camera_if_uitree_added::<T, N>),
+                fetch_from_parent_node::<T, N>,
+                fetch_design_resolution::<T, N>
+                    .after(fetch_scale_factor::<T, N>)
+                    .after(fetch_dimension_from_camera::<T, N>)
+                    .after(fetch_transform_from_camera::<T, N>)
+                    .after(fetch_from_parent_node::<T, N>),
             ).in_set(UiSystems::Modify).before(UiSystems::Send))
 
-            .add_systems(Update, (
+            .add_systems(self.schedule, (
                 send_layout_to_node::<T, N, Base>,
                 send_content_size_to_node::<T, N>,
                 send_stack_to_node::<T, N>,
                 send_layout_control_to_node::<T, N>,
-                send_depth_bias_to_node::<T, N>
+                send_depth_bias_to_node::<T, N>,
+                send_z_index_to_node::<T, N>,
+                send_depth_layer_to_node::<T, N>,
+                send_font_size_to_node::<T, N>,
+                send_display_to_node::<T, N>,
+                send_fit_to_node::<T, N>,
+                send_text_relative_to_node::<T, N>,
             ).chain().in_set(UiSystems::Send).before(UiSystems::Compute))
 
-            .add_systems(Update, (
+            .add_systems(self.schedule, (
                 compute_ui::<T, N>.in_set(UiSystems::Compute)
             ).in_set(UiSystems::Compute))
 
-            .add_systems(Update, (
+            .add_systems(self.schedule, (
                 fetch_transform_from_node::<T, N>,
                 fetch_dimension_from_node::<T, N>,
                 element_fetch_transform_from_node::<T, N>,
+                fetch_computed_from_node::<T, N>,
+                fetch_wedge_from_node::<T, N>,
+                pixel_snap::<T, N>.after(fetch_transform_from_node::<T, N>).after(fetch_dimension_from_node::<T, N>).after(element_fetch_transform_from_node::<T, N>),
             ).in_set(UiSystems::Fetch).after(UiSystems::Compute))
 
-            .add_systems(Update, (
+            .add_systems(self.schedule, (
+                display_to_visibility::<T>,
+                cull_nodes::<T, N>.after(display_to_visibility::<T>),
                 element_sprite_size_from_dimension::<T>,
                 element_image_size_from_dimension::<T>,
+                element_dynamic_texture::<T>.after(element_sprite_size_from_dimension::<T>),
                 element_text_size_scale_fit_to_dimension::<T>,
                 element_reconstruct_mesh::<T>,
+                element_curve_mesh::<T>.after(element_reconstruct_mesh::<T>),
+                element_depth_layer_to_material::<T, N>,
             ).in_set(UiSystems::Process).after(UiSystems::Fetch))
             ;
     }
 }
 
+/// Plugin registering the Lunex components for reflection, so they can be saved and spawned with `DynamicScene`.
+/// Generic components are registered for the built-in states and [`MainUi`]. It is added automatically by [`UiCorePlugin`].
+pub struct UiReflectPlugin;
+impl Plugin for UiReflectPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .register_type::<UiLink<MainUi>>()
+            .register_type::<UiTreeRoot<MainUi>>()
+            .register_type::<UiLayout<Base>>()
+            .register_type::<UiLayout<Hover>>()
+            .register_type::<UiLayout<Clicked>>()
+            .register_type::<UiLayout<Selected>>()
+            .register_type::<UiLayout<Intro>>()
+            .register_type::<UiLayout<Outro>>()
+            .register_type::<UiLayoutController>()
+            .register_type::<UiUpdateMode>()
+            .register_type::<UiDepthBias>()
+            .register_type::<UiDepthLayer>()
+            .register_type::<UiDepthStep>()
+            .register_type::<UiFontSize>()
+            .register_type::<UiFontScale>()
+            .register_type::<UiRootViewport>()
+            .register_type::<UiContent>()
+            .register_type::<UiTextSize>()
+            .register_type::<UiScaleFactor>()
+            .register_type::<UiShape>()
+            .register_type::<UiMeshCurved>()
+            .register_type::<UiEmbedding>()
+            .register_type::<UiEmbeddingCamera>()
+            .register_type::<UiDynamicTexture>()
+            .register_type::<Dimension>()
+            .register_type::<Element>()
+            .register_type::<SourceFromCamera>()
+            .register_type::<SourceFromParent>()
+            .register_type::<UiComputed>()
+            .register_type::<UiCulling>()
+            .register_type::<UiCulled>()
+            .register_type::<UiPixelSnap>()
+            .register_type::<UiDesignResolution>()
+            .register_type::<SourceCamera>()
+            .register_type::<SourceCameraSlot>()
+            .register_type::<SourceCameraScale>()
+            .register_type::<UiSourceCamera>()
+            .register_type::<UiBillboard>()
+            .register_type::<UiDistanceScale>()
+            .register_type::<UiUniqueMaterial>()
+            .register_type::<UiNodeStyle>()
+            .register_type::<UiShadow>()
+            .register_type::<UiGestures>()
+            .register_type::<UiHaptics>()
+            .register_type::<UiPickingPolicy>()
+            .register_type::<UiPickingPortal>()
+            .register_type::<UiPointerTiming>()
+            .register_type::<UiDisabled>()
+            .register_type::<UiColorPickerValue>()
+            .register_type::<UiMinimap>()
+            .register_type::<UiWedge>();
+    }
+}
+
+/// Plugin collecting [`UiLunexDiagnostics`] and recording them as Bevy diagnostics.
+/// It is added automatically by [`UiCorePlugin`].
+pub struct UiDiagnosticsPlugin;
+impl Plugin for UiDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        let schedule = UiSchedule::of(app);
+        app
+            .register_type::<UiLunexDiagnostics>()
+            .init_resource::<UiLunexDiagnostics>()
+            .register_diagnostic(Diagnostic::new(UI_ROOTS_COMPUTED))
+            .register_diagnostic(Diagnostic::new(UI_NODES_TRAVERSED))
+            .register_diagnostic(Diagnostic::new(UI_RECOMPUTE_TRIGGERS))
+            .register_diagnostic(Diagnostic::new(UI_TEXT_REMEASURES))
+            .register_diagnostic(Diagnostic::new(UI_SYSTEMS_TIME).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(UI_LAYOUT_TIME).with_suffix("ms"))
+            .add_systems(First, diagnostics_reset)
+            .add_systems(schedule, (
+                diagnostics_stamp::<0>.before(UiSystems::Modify),
+                diagnostics_stamp::<1>.after(UiSystems::Modify).before(UiSystems::Send),
+                diagnostics_stamp::<2>.after(UiSystems::Send).before(UiSystems::Compute),
+                diagnostics_stamp::<3>.after(UiSystems::Compute).before(UiSystems::Fetch),
+                diagnostics_stamp::<4>.after(UiSystems::Fetch).before(UiSystems::Process),
+                diagnostics_stamp::<5>.after(UiSystems::Process),
+            ))
+            .add_systems(Last, diagnostics_record);
+    }
+}
+
 /// Plugin implementing all debug ui logic for the specified generic types.
 /// ## 📦 Types
 /// * Generic `(T)` - Marker component grouping entities into one widget type
@@ -630,8 +2136,329 @@ impl <T:Component, N:Default + Component, G:GizmoConfigGroup> UiDebugPlugin<T, N
 }
 impl <T:Component, N:Default + Component, G:GizmoConfigGroup> Plugin for UiDebugPlugin<T, N, G> {
     fn build(&self, app: &mut App) {
+        let schedule = UiSchedule::of(app);
         app
+            .register_diagnostic(Diagnostic::new(UI_LAYOUT_CACHE_HIT_RATE).with_suffix("%"))
+            .register_type::<UiInspector>()
+            .init_resource::<UiInspector>()
+            .register_type::<UiDebugOverlay>()
+            .init_resource::<UiDebugOverlay>()
             .add_systems(Update, debug_draw_gizmo::<T, N, G>)
-            .add_systems(Update, debug_print_tree::<T, N>.after(UiSystems::Compute));
+            .add_systems(Update, debug_draw_3d::<T, N, G>)
+            .add_systems(schedule, (debug_draw_labels::<T, N>, debug_inspect_hovered::<T, N>).after(UiSystems::Compute))
+            .add_systems(schedule, debug_print_tree::<T, N>.after(UiSystems::Compute))
+            .add_systems(schedule, debug_layout_cache::<T, N>.after(UiSystems::Compute))
+            .add_systems(schedule, debug_validate_layout::<T, N>.after(UiSystems::Compute));
+
+        #[cfg(feature = "template")]
+        if !app.is_plugin_added::<UiSnapshotPlugin<T, N>>() { app.add_plugins(UiSnapshotPlugin::<T, N>::new()); }
+    }
+}
+
+
+// #=============#
+// #=== TESTS ===#
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use lunex_engine::*;
+
+    #[derive(Resource, Default)]
+    struct Changes(usize);
+
+    fn count_changes(query: Query<(), Or<(Changed<Transform>, Changed<Dimension>)>>, mut changes: ResMut<Changes>) {
+        changes.0 = query.iter().count();
+    }
+
+    #[test]
+    fn static_ui_is_not_changed() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<Assets<Image>>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<Font>>()
+            .init_resource::<Changes>()
+            .add_plugins(UiCorePlugin::<MainUi>::new())
+            .add_systems(Update, count_changes.after(UiSystems::Process));
+
+        app.world_mut().spawn(UiTreeBundle::<MainUi> {
+            dimension: Dimension::new((800.0, 600.0)),
+            ..UiTree::new2d("Static").into()
+        }).with_children(|ui| {
+            ui.spawn((
+                UiLink::<MainUi>::path("Root"),
+                UiLayout::window().pos(Rl(10.0)).size(Rl(50.0)).pack::<Base>(),
+                Transform::default(),
+                Dimension::default(),
+            ));
+        });
+
+        app.update();
+        assert!(app.world().resource::<Changes>().0 > 0);
+        for _ in 0..3 { app.update() }
+        assert_eq!(app.world().resource::<Changes>().0, 0);
+    }
+
+    #[test]
+    fn resized_mesh_is_reused() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<Assets<Image>>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<Font>>()
+            .add_plugins(UiCorePlugin::<MainUi>::new())
+            .add_systems(Update, (|mut query: Query<&mut UiLayout>, mut frame: Local<f32>| {
+                *frame += 1.0;
+                for mut layout in &mut query {
+                    *layout = UiLayout::window().size(Ab(10.0 + *frame)).pack::<Base>();
+                }
+            }).before(UiSystems::Send));
+
+        let handle = app.world_mut().resource_mut::<Assets<Mesh>>().add(Rectangle::default());
+        let element = app.world_mut().spawn(UiTreeBundle::<MainUi> {
+            dimension: Dimension::new((800.0, 600.0)),
+            ..UiTree::new2d("Animated").into()
+        }).with_children(|ui| {
+            ui.spawn((
+                UiLink::<MainUi>::path("Root"),
+                UiLayout::window().pack::<Base>(),
+                Element,
+                Transform::default(),
+                Dimension::default(),
+                handle.clone(),
+            ));
+        }).id();
+
+        for _ in 0..1000 { app.update() }
+        let world = app.world_mut();
+        let child = world.entity(element).get::<Children>().unwrap()[0];
+        assert_eq!(world.entity(child).get::<Dimension>().unwrap().size, Vec2::splat(1010.0));
+        assert_eq!(world.entity(child).get::<Handle<Mesh>>(), Some(&handle));
+        assert_eq!(world.resource::<Assets<Mesh>>().len(), 1);
+    }
+
+    #[test]
+    fn tree_is_rebuilt_from_root() {
+        let mut harness = crate::test_utils::UiTestHarness::new((800.0, 600.0));
+        let layout = UiLayout::window().pos(Rl(10.0)).size(Rl(50.0)).pack::<Base>();
+        let panel = harness.spawn("Panel", layout);
+        harness.update();
+
+        // Scenes spawn the entities without the tree and with freshly added components
+        let root = harness.root();
+        harness.app().world_mut().entity_mut(root).remove::<UiTree<MainUi>>();
+        harness.app().world_mut().entity_mut(panel).insert(layout);
+        harness.update().resize((400.0, 400.0)).update();
+
+        assert_eq!(harness.app().world().get::<UiTree<MainUi>>(root).map(|ui| ui.get_name().clone()), Some("Test".to_string()));
+        assert_eq!(harness.dimension(panel), Vec2::new(200.0, 200.0));
+    }
+
+    #[test]
+    fn scrollbar_thumb_is_dragged() {
+        let mut harness = crate::test_utils::UiTestHarness::new((800.0, 600.0));
+        harness.app()
+            .add_event::<Pointer<Drag>>()
+            .add_event::<Pointer<Click>>()
+            .add_systems(Update, (scrollbar_input::<MainUi, NoData>, scrollbar_update::<MainUi, NoData>).chain().before(UiSystems::Send))
+            .add_systems(Update, fetch_scroll_from_node::<MainUi, NoData>.in_set(UiSystems::Fetch).after(UiSystems::Compute));
+
+        let area = harness.spawn("List", (UiLayout::window().size(Rl((90.0, 100.0))).pack::<Base>(), UiScrollArea::vertical()));
+        harness.spawn("List/Content", UiLayout::window().size(Rl((100.0, 400.0))).pack::<Base>());
+        harness.spawn("Track", UiLayout::window().x(Rl(90.0)).size(Rl((10.0, 100.0))).pack::<Base>());
+        let thumb = harness.spawn("Track/Thumb", UiScrollbar::vertical(area));
+        for _ in 0..3 { harness.update(); }
+        assert_eq!(harness.app().world().get::<UiScrollArea>(area).unwrap().offset, Vec2::ZERO);
+
+        harness.app().world_mut().send_event(Pointer {
+            target: thumb,
+            pointer_id: PointerId::Mouse,
+            pointer_location: pointer::Location { target: bevy::render::camera::NormalizedRenderTarget::Image(Handle::default()), position: Vec2::ZERO },
+            event: Drag { button: PointerButton::Primary, distance: Vec2::new(0.0, 50.0), delta: Vec2::new(0.0, 50.0) },
+        });
+        harness.update();
+
+        // The thumb is a quarter of the track, so the free 450 pixels of the track scroll over the hidden 1800 pixels
+        let offset = harness.app().world().get::<UiScrollArea>(area).unwrap().offset;
+        assert_eq!(offset, Vec2::new(0.0, 200.0));
+    }
+
+    #[test]
+    fn commands_apply_before_fixed_compute() {
+        let mut harness = crate::test_utils::UiTestHarness::with_schedule((800.0, 600.0), FixedUpdate);
+        harness.app().add_plugins(crate::actions::ActionsPlugin);
+        let panel = harness.spawn("Panel", UiLayout::window().size(Ab((200.0, 100.0))).pack::<Base>());
+        harness.update().update();
+        assert_eq!(harness.dimension(panel), Vec2::new(200.0, 100.0));
+
+        // The command is applied in the same fixed step as the layout compute
+        harness.app().world_mut().send_event(crate::actions::UiCommand::SetLayout(panel, UiLayout::window().size(Ab((50.0, 25.0))).pack()));
+        harness.update();
+        assert_eq!(harness.dimension(panel), Vec2::new(50.0, 25.0));
+    }
+    #[test]
+    fn removed_depth_step_is_reset() {
+        let mut harness = crate::test_utils::UiTestHarness::new((800.0, 600.0));
+        let root = harness.root();
+        harness.app().world_mut().entity_mut(root).insert(UiDepthStep(0.5));
+        harness.update();
+        let depth_step = |harness: &mut crate::test_utils::UiTestHarness| harness.app().world().get::<UiTree<MainUi>>(root).and_then(|ui| ui.obtain_topdata().map(|data| data.depth_step));
+        assert_eq!(depth_step(&mut harness), Some(Some(0.5)));
+
+        harness.app().world_mut().entity_mut(root).remove::<UiDepthStep>();
+        harness.update();
+        assert_eq!(depth_step(&mut harness), Some(None));
+    }
+    #[test]
+    fn removed_display_is_reset() {
+        let mut harness = crate::test_utils::UiTestHarness::new((800.0, 600.0));
+        harness.spawn("List", (UiLayout::window().size(Rl(100.0)).pack::<Base>(), UiStack::new()));
+        let card = || (UiLayout::div().pack::<Base>(), UiContent::new((50.0, 40.0)));
+        let first = harness.spawn("List/A", (card(), Visibility::default(), UiDisplay::Collapsed));
+        harness.spawn("List/B", card());
+        harness.update();
+        assert_eq!(harness.rectangle("List/B").map(|rect| rect.pos.x), Some(0.0));
+        assert_eq!(harness.app().world().get::<Visibility>(first), Some(&Visibility::Hidden));
+
+        harness.app().world_mut().entity_mut(first).remove::<UiDisplay>();
+        harness.update();
+        assert_eq!(harness.rectangle("List/B").map(|rect| rect.pos.x), Some(50.0));
+        assert_eq!(harness.app().world().get::<Visibility>(first), Some(&Visibility::Inherited));
+    }
+    #[test]
+    fn removed_scale_factor_is_reset() {
+        let mut harness = crate::test_utils::UiTestHarness::new((800.0, 600.0));
+        let root = harness.root();
+        let panel = harness.spawn("Panel", UiLayout::window().size(Ab((200.0, 100.0))).pack::<Base>());
+        harness.app().insert_resource(UiScaleFactor(2.0));
+        harness.update();
+
+        // The resource only scales trees sourced from a camera
+        assert_eq!(harness.dimension(panel), Vec2::new(200.0, 100.0));
+
+        harness.app().world_mut().entity_mut(root).insert(UiScaleFactor(1.5));
+        harness.update();
+        assert_eq!(harness.dimension(panel), Vec2::new(300.0, 150.0));
+
+        harness.app().world_mut().entity_mut(root).remove::<UiScaleFactor>();
+        harness.update();
+        assert_eq!(harness.dimension(panel), Vec2::new(200.0, 100.0));
+    }
+    #[test]
+    fn shadow_is_scaled_and_removed() {
+        let mut harness = crate::test_utils::UiTestHarness::new((800.0, 600.0));
+        harness.app()
+            .init_resource::<Assets<UiShadowMaterial2d>>()
+            .init_resource::<Assets<UiShadowMaterial3d>>()
+            .add_systems(Update, (node_shadow_remove, node_shadow_spawn::<MainUi, NoData>, node_shadow_update::<MainUi, NoData>).chain().after(UiSystems::Fetch));
+        let root = harness.root();
+        harness.app().world_mut().entity_mut(root).insert(UiScaleFactor(2.0));
+        let panel = harness.spawn("Panel", (UiLayout::window().size(Ab((100.0, 50.0))).pack::<Base>(), UiShadow::new().blur(8.0).offset((0.0, 4.0))));
+        harness.update().update();
+
+        let shadow = |harness: &mut crate::test_utils::UiTestHarness| {
+            let world = harness.app().world_mut();
+            let children = world.get::<Children>(panel).map(|children| children.to_vec()).unwrap_or_default();
+            children.into_iter().find(|child| world.get::<UiShadowMesh>(*child).is_some())
+        };
+        let entity = shadow(&mut harness).expect("shadow entity");
+        let world = harness.app().world();
+        let material = world.get::<Handle<UiShadowMaterial2d>>(entity).and_then(|handle| world.resource::<Assets<UiShadowMaterial2d>>().get(handle)).cloned();
+
+        // The pixel values are scaled like the node, the shadow is half a depth step behind it
+        assert_eq!(material.map(|material| (material.size, material.blur)), Some((Vec2::new(232.0, 132.0), 16.0)));
+        assert_eq!(world.get::<Transform>(entity).map(|transform| transform.translation), Some(Vec3::new(100.0, -58.0, -0.5)));
+
+        harness.app().world_mut().entity_mut(panel).remove::<UiShadow>();
+        harness.update();
+        assert_eq!(shadow(&mut harness), None);
+    }
+    #[test]
+    fn text_size_is_relative_to_parent() {
+        let mut harness = crate::test_utils::UiTestHarness::new((800.0, 600.0));
+        harness.spawn("Panel", UiLayout::window().size(Ab((200.0, 100.0))).pack::<Base>());
+        let text = harness.spawn("Panel/Text", (
+            UiLayout::window().pack::<Base>(),
+            Element,
+            Text::from_section("Text", TextStyle { font_size: 10.0, ..default() }),
+            bevy::text::TextLayoutInfo { logical_size: Vec2::new(40.0, 10.0), ..default() },
+        ));
+        harness.update();
+
+        // The tree resolves Rh against its own height, the text against the 100 pixels of the panel
+        assert_eq!(harness.dimension(text), Vec2::new(40.0, 10.0));
+    }
+    #[test]
+    fn div_flow_gap_and_padding() {
+        let mut harness = crate::test_utils::UiTestHarness::new((800.0, 600.0));
+        harness.spawn("List", (UiLayout::window().size(Rl(100.0)).pack::<Base>(), UiStack::new().gap(Ab(Vec2::new(10.0, 0.0)))));
+        harness.spawn("List/First", (UiLayout::div().pad(Ab(5.0)).pack::<Base>(), UiContent::new((100.0, 40.0))));
+        harness.spawn("List/Second", UiLayout::div().pad(Ab(5.0)).pack::<Base>());
+        harness.spawn("List/Second/Label", (UiLayout::div().pack::<Base>(), UiContent::new((20.0, 10.0))));
+        harness.update();
+
+        let rectangle = |harness: &crate::test_utils::UiTestHarness, path: &str| harness.rectangle(path).map(|rect| (rect.pos.truncate(), rect.size));
+        assert_eq!(rectangle(&harness, "List/First"), Some((Vec2::new(0.0, 0.0), Vec2::new(110.0, 50.0))));
+        // The div without content is sized around its div subnodes, which are placed inside the padding
+        assert_eq!(rectangle(&harness, "List/Second"), Some((Vec2::new(120.0, 0.0), Vec2::new(30.0, 20.0))));
+        assert_eq!(rectangle(&harness, "List/Second/Label"), Some((Vec2::new(125.0, 5.0), Vec2::new(20.0, 10.0))));
+    }
+    #[test]
+    fn div_flow_wraps_on_br() {
+        let mut harness = crate::test_utils::UiTestHarness::new((800.0, 600.0));
+        harness.spawn("List", (UiLayout::window().size(Rl(100.0)).pack::<Base>(), UiStack::new().gap(Ab(Vec2::new(10.0, 20.0)))));
+        harness.spawn("List/A", (UiLayout::div().br().pack::<Base>(), UiContent::new((50.0, 40.0))));
+        harness.spawn("List/B", (UiLayout::div().pack::<Base>(), UiContent::new((30.0, 40.0))));
+        harness.spawn("List/C", (UiLayout::div().pack::<Base>(), UiContent::new((30.0, 40.0))));
+        harness.update();
+
+        let position = |harness: &crate::test_utils::UiTestHarness, path: &str| harness.rectangle(path).map(|rect| rect.pos.truncate());
+        assert_eq!(position(&harness, "List/A"), Some(Vec2::new(0.0, 0.0)));
+        assert_eq!(position(&harness, "List/B"), Some(Vec2::new(0.0, 60.0)));
+        assert_eq!(position(&harness, "List/C"), Some(Vec2::new(40.0, 60.0)));
+    }
+    #[test]
+    fn div_flow_skips_collapsed_siblings() {
+        let mut harness = crate::test_utils::UiTestHarness::new((800.0, 600.0));
+        harness.spawn("List", (UiLayout::window().size(Rl(100.0)).pack::<Base>(), UiStack::new().gap(Ab(Vec2::new(10.0, 0.0)))));
+        let card = || (UiLayout::div().pack::<Base>(), UiContent::new((50.0, 40.0)));
+        harness.spawn("List/A", card());
+        let middle = harness.spawn("List/B", card());
+        harness.spawn("List/C", card());
+        harness.update();
+        assert_eq!(harness.rectangle("List/C").map(|rect| rect.pos.x), Some(120.0));
+
+        harness.app().world_mut().entity_mut(middle).insert(UiDisplay::Collapsed);
+        harness.update();
+        assert_eq!(harness.rectangle("List/C").map(|rect| rect.pos.x), Some(60.0));
+    }
+
+    #[test]
+    fn div_siblings_reflow_on_despawn() {
+        let mut harness = crate::test_utils::UiTestHarness::new((800.0, 600.0));
+        harness.app().add_systems(Update, layout_tween::<MainUi, NoData>.in_set(UiSystems::Fetch).after(fetch_transform_from_node::<MainUi, NoData>).after(fetch_dimension_from_node::<MainUi, NoData>));
+        harness.spawn("List", (UiLayout::window().size(Rl(100.0)).pack::<Base>(), UiStack::new().gap(Ab(Vec2::new(10.0, 0.0)))));
+        let card = |width: f32| (UiLayout::div().pad(Ab(5.0)).pack::<Base>(), UiContent::new((width, 40.0)));
+        let first = harness.spawn("List/First", card(100.0));
+        let second = harness.spawn("List/Second", (card(50.0), UiLayoutTween::new(0.25).reflow(true)));
+        let background = harness.spawn("List/Second", Transform::default());
+        harness.update();
+        assert_eq!(harness.rectangle("List/Second").map(|rect| (rect.pos.truncate(), rect.size)), Some((Vec2::new(120.0, 0.0), Vec2::new(60.0, 50.0))));
+
+        // The node is still linked to the background, so it stays in place
+        harness.app().world_mut().despawn(background);
+        harness.update();
+        assert!(harness.rectangle("List/Second").is_some());
+
+        harness.app().world_mut().despawn(first);
+        harness.update();
+        assert!(harness.rectangle("List/First").is_none());
+        assert_eq!(harness.rectangle("List/Second").map(|rect| rect.pos.truncate()), Some(Vec2::ZERO));
+        assert_eq!(harness.dimension(second), Vec2::new(60.0, 50.0));
+
+        // The node is moved by the despawn, so the tween slides it from the old slot to the new one
+        let x = harness.translation(second).x;
+        assert!(x > 0.0 && x < 120.0, "{x}");
     }
 }
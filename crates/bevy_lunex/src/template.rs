@@ -0,0 +1,390 @@
+use crate::*;
+use bevy::asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext};
+use bevy::utils::HashSet;
+use serde::Deserialize;
+use thiserror::Error;
+
+
+// #=====================#
+// #=== TEMPLATE DATA ===#
+
+/// **Ui template** - Asset containing a description of a node tree. It is loaded from `.ui.ron` files
+/// (or `.ui.json` files with the `template_json` feature and `.ui.html` files with the `markup` feature) and spawned under a [`UiTree`] entity with [`UiTemplateRoot`].
+/// Enable the `file_watcher` feature of Bevy to hot-reload the template when the file changes.
+/// ## 📌 Note
+/// Values are strings that are parsed into ui units, so `"50% - 10ab"` is a valid size.
+/// Colors are hex strings like `"#ff0000"` or `"#ff000080"`.
+/// ## 🛠️ Example
+/// ```ron
+/// (
+///     nodes: [
+///         (
+///             name: "Menu",
+///             layout: Window(pos: "10% 10%", size: "80% 80%"),
+///             color: "#202020",
+///             children: [
+///                 (
+///                     name: "Play",
+///                     layout: Solid(size: "4 1", align_y: -1.0),
+///                     image: "images/button.png",
+///                     hover: (color: "#ffffff"),
+///                 ),
+///             ],
+///         ),
+///     ],
+/// )
+/// ```
+#[derive(Asset, TypePath, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct UiTemplate {
+    /// Top level nodes of the template.
+    #[serde(default)]
+    pub nodes: Vec<UiTemplateNode>,
+}
+
+/// A single node in [`UiTemplate`].
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct UiTemplateNode {
+    /// Name of the node, used for constructing the path.
+    pub name: String,
+    /// Layout of the node. Defaults to a full window.
+    #[serde(default)]
+    pub layout: Option<UiTemplateLayout>,
+    /// Color of the sprite or the text.
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Path to the image asset to display.
+    #[serde(default)]
+    pub image: Option<String>,
+    /// Text to display.
+    #[serde(default)]
+    pub text: Option<String>,
+    /// Path to the font asset used for the text.
+    #[serde(default)]
+    pub font: Option<String>,
+    /// Font size used for the text.
+    #[serde(default)]
+    pub font_size: Option<f32>,
+    /// Layout and color the node should transition to when hovered.
+    #[serde(default)]
+    pub hover: Option<UiTemplateState>,
+    /// Subnodes of this node.
+    #[serde(default)]
+    pub children: Vec<UiTemplateNode>,
+}
+
+/// State variant of [`UiTemplateNode`].
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct UiTemplateState {
+    /// Layout to transition to.
+    #[serde(default)]
+    pub layout: Option<UiTemplateLayout>,
+    /// Color to transition to.
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+/// Layout description of [`UiTemplateNode`]. All values are parsed from strings.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub enum UiTemplateLayout {
+    /// Parses into [`ui::Boundary`].
+    Boundary {
+        #[serde(default)]
+        pos1: Option<String>,
+        #[serde(default)]
+        pos2: Option<String>,
+    },
+    /// Parses into [`ui::Window`].
+    Window {
+        #[serde(default)]
+        pos: Option<String>,
+        #[serde(default)]
+        size: Option<String>,
+        #[serde(default)]
+        anchor: Option<String>,
+    },
+    /// Parses into [`ui::Solid`].
+    Solid {
+        #[serde(default)]
+        size: Option<String>,
+        #[serde(default)]
+        align_x: Option<f32>,
+        #[serde(default)]
+        align_y: Option<f32>,
+        #[serde(default)]
+        scaling: Option<String>,
+    },
+}
+impl UiTemplateLayout {
+    /// Parses the description into [`Layout`].
+    pub fn parse(&self) -> Result<Layout, UiError> {
+        Ok(match self {
+            UiTemplateLayout::Boundary { pos1, pos2 } => {
+                let mut layout = ui::Boundary::new();
+                if let Some(pos) = pos1 { layout.set_pos1(pos.parse::<UiValue<Vec2>>()?) }
+                if let Some(pos) = pos2 { layout.set_pos2(pos.parse::<UiValue<Vec2>>()?) }
+                layout.package()
+            },
+            UiTemplateLayout::Window { pos, size, anchor } => {
+                let mut layout = ui::Window::full();
+                if let Some(pos) = pos { layout.set_pos(pos.parse::<UiValue<Vec2>>()?) }
+                if let Some(size) = size { layout.set_size(size.parse::<UiValue<Vec2>>()?) }
+                if let Some(anchor) = anchor { layout.set_anchor(anchor.parse::<lunex_engine::Anchor>()?) }
+                layout.package()
+            },
+            UiTemplateLayout::Solid { size, align_x, align_y, scaling } => {
+                let mut layout = ui::Solid::new();
+                if let Some(size) = size { layout.set_size(size.parse::<UiValue<Vec2>>()?) }
+                if let Some(align) = align_x { layout.set_align_x(*align) }
+                if let Some(align) = align_y { layout.set_align_y(*align) }
+                if let Some(scaling) = scaling { layout.set_scaling(scaling.parse::<Scaling>()?) }
+                layout.package()
+            },
+        })
+    }
+}
+
+/// Parses a hex color string.
+fn parse_color(color: &str) -> Result<Color, UiError> {
+    Srgba::hex(color).map(Color::from).map_err(|_| UiError::ParseError(color.to_string()))
+}
+
+
+// #=======================#
+// #=== TEMPLATE LOADER ===#
+
+/// Error returned when [`UiTemplate`] fails to load.
+#[derive(Debug, Error)]
+pub enum UiTemplateError {
+    /// The file could not be read.
+    #[error("Could not read the template: {0}")]
+    Io(#[from] std::io::Error),
+    /// The file is not valid RON.
+    #[error("Could not parse RON template: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    /// The file is not valid JSON.
+    #[cfg(feature = "template_json")]
+    #[error("Could not parse JSON template: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The file is not valid markup.
+    #[cfg(feature = "markup")]
+    #[error("Could not parse markup template: {0}")]
+    Markup(#[from] UiMarkupError),
+}
+
+/// Asset loader for [`UiTemplate`].
+#[derive(Debug, Default)]
+pub struct UiTemplateLoader;
+impl AssetLoader for UiTemplateLoader {
+    type Asset = UiTemplate;
+    type Settings = ();
+    type Error = UiTemplateError;
+    async fn load<'a>(&'a self, reader: &'a mut Reader<'_>, _settings: &'a (), load_context: &'a mut LoadContext<'_>) -> Result<UiTemplate, UiTemplateError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        #[cfg(feature = "template_json")]
+        if load_context.path().to_string_lossy().ends_with(".json") {
+            return Ok(serde_json::from_slice(&bytes)?);
+        }
+        #[cfg(feature = "markup")]
+        if load_context.path().to_string_lossy().ends_with(".html") {
+            return Ok(UiTemplate::from_markup(&String::from_utf8_lossy(&bytes))?);
+        }
+
+        #[cfg(not(any(feature = "template_json", feature = "markup")))]
+        let _ = load_context;
+
+        let options = ron::Options::default().with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME);
+        Ok(options.from_bytes(&bytes)?)
+    }
+    fn extensions(&self) -> &[&str] {
+        &[
+            "ui.ron",
+            #[cfg(feature = "template_json")]
+            "ui.json",
+            #[cfg(feature = "markup")]
+            "ui.html",
+        ]
+    }
+}
+
+
+// #=========================#
+// #=== TEMPLATE SPAWNING ===#
+
+/// Add this component to a [`UiTree`] entity to spawn the [`UiTemplate`] under it.
+/// When the template asset changes, previously spawned entities are despawned and the template is spawned again.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct UiTemplateRoot {
+    /// The template to spawn.
+    pub template: Handle<UiTemplate>,
+    /// If the template needs to be spawned again.
+    pub(crate) dirty: bool,
+}
+impl UiTemplateRoot {
+    /// Creates new template root from the handle.
+    pub fn new(template: Handle<UiTemplate>) -> Self {
+        UiTemplateRoot { template, dirty: true }
+    }
+}
+
+/// Marker for entities spawned from [`UiTemplate`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct UiTemplateSpawned;
+
+impl UiTemplate {
+    /// Spawns all nodes of this template as children of the [`UiTree`] entity.
+    /// Nodes that fail to parse are logged and skipped together with their subnodes.
+    pub fn spawn<T: Component>(&self, ui: &mut ChildBuilder, asset_server: &AssetServer) {
+        for node in &self.nodes {
+            node.spawn::<T>(ui, asset_server, node.name.clone());
+        }
+    }
+}
+impl UiTemplateNode {
+    /// Spawns this node and its subnodes at the given path.
+    fn spawn<T: Component>(&self, ui: &mut ChildBuilder, asset_server: &AssetServer, path: String) {
+        if let Err(error) = self.spawn_entity::<T>(ui, asset_server, &path) {
+            warn!("Failed to spawn template node '{}': {}", path, error);
+            return;
+        }
+        for child in &self.children {
+            child.spawn::<T>(ui, asset_server, format!("{}/{}", path, child.name));
+        }
+    }
+    /// Spawns the entity of this node only.
+    fn spawn_entity<T: Component>(&self, ui: &mut ChildBuilder, asset_server: &AssetServer, path: &str) -> Result<(), UiError> {
+        let layout = match &self.layout {
+            Some(layout) => layout.parse()?,
+            None => ui::Window::full().package(),
+        };
+        let color = self.color.as_deref().map(parse_color).transpose()?;
+
+        let mut entity = ui.spawn((
+            UiLink::<T>::path(path),
+            UiLayout::<Base>::from(layout),
+            UiTemplateSpawned,
+        ));
+
+        // Visual element
+        if let Some(text) = &self.text {
+            entity.insert(UiText2dBundle {
+                text: Text::from_section(text, TextStyle {
+                    font: self.font.as_ref().map(|path| asset_server.load(path)).unwrap_or_default(),
+                    font_size: self.font_size.unwrap_or(60.0),
+                    color: color.unwrap_or(Color::WHITE),
+                }),
+                ..default()
+            });
+        } else if self.image.is_some() || color.is_some() {
+            entity.insert(UiImage2dBundle {
+                texture: self.image.as_ref().map(|path| asset_server.load(path)).unwrap_or_default(),
+                sprite: Sprite { color: color.unwrap_or(Color::WHITE), ..default() },
+                ..default()
+            });
+        }
+
+        // Hover state
+        if let Some(hover) = &self.hover {
+            entity.insert((
+                UiLayoutController::default(),
+                UiAnimator::<Hover>::new(),
+                UiZoneBundle::default(),
+            ));
+            if let Some(layout) = &hover.layout {
+                entity.insert(UiLayout::<Hover>::from(layout.parse()?));
+            }
+            if let Some(hover_color) = hover.color.as_deref().map(parse_color).transpose()? {
+                entity.insert((
+                    UiColor::<Base>::new(color.unwrap_or(Color::WHITE)),
+                    UiColor::<Hover>::new(hover_color),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// This system marks [`UiTemplateRoot`] components for respawn when their template is loaded or modified.
+pub fn template_mark_dirty(
+    mut events: EventReader<AssetEvent<UiTemplate>>,
+    mut query: Query<&mut UiTemplateRoot>,
+) {
+    let changed: HashSet<AssetId<UiTemplate>> = events.read().filter_map(|event| match event {
+        AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(*id),
+        _ => None,
+    }).collect();
+    for mut root in &mut query {
+        if changed.contains(&root.template.id()) {
+            root.dirty = true;
+        }
+    }
+}
+
+/// This system despawns previously spawned nodes and spawns the template again for dirty [`UiTemplateRoot`] components.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+pub fn template_spawn<T:Component, N:Default + Component>(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    templates: Res<Assets<UiTemplate>>,
+    mut roots: Query<(Entity, &mut UiTemplateRoot, &mut UiTree<T, N>, Option<&Children>)>,
+    spawned: Query<&UiLink<T>, With<UiTemplateSpawned>>,
+) {
+    for (entity, mut root, mut ui, children) in &mut roots {
+        if !root.dirty { continue }
+        let Some(template) = templates.get(&root.template) else { continue };
+        root.dirty = false;
+
+        #[cfg(feature = "verbose")]
+        info!("{} {} - Spawning template", "--".yellow(), "UiTree".purple().bold());
+
+        // Remove the old nodes
+        for child in children.into_iter().flatten() {
+            if let Ok(link) = spawned.get(*child) {
+                let _ = ui.remove_node(link.path.clone());
+                commands.entity(*child).despawn_recursive();
+            }
+        }
+
+        commands.entity(entity).with_children(|ui| {
+            template.spawn::<T>(ui, &asset_server);
+        });
+    }
+}
+
+
+// #==============#
+// #=== PLUGIN ===#
+
+/// Plugin adding [`UiTemplate`] asset loading and spawning for the specified generic types.
+/// ## 📦 Types
+/// * Generic `(T)` - Marker component grouping entities into one widget type
+/// * Generic `(N)` - Node data schema struct defining what can be stored in [`UiNode`]
+#[derive(Debug, Default, Clone)]
+pub struct UiTemplatePlugin <T:Component = MainUi, N:Default + Component = NoData>(PhantomData<T>, PhantomData<N>);
+impl <T:Component, N:Default + Component> UiTemplatePlugin<T, N> {
+    pub fn new() -> Self {
+        UiTemplatePlugin::<T, N>(PhantomData, PhantomData)
+    }
+}
+impl <T:Component, N:Default + Component> Plugin for UiTemplatePlugin<T, N> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<UiTemplateAssetPlugin>() {
+            app.add_plugins(UiTemplateAssetPlugin);
+        }
+        app.add_systems(Update, template_spawn::<T, N>.after(template_mark_dirty).before(UiSystems::Modify));
+    }
+}
+
+/// Plugin registering the [`UiTemplate`] asset. Added automatically by [`UiTemplatePlugin`].
+pub struct UiTemplateAssetPlugin;
+impl Plugin for UiTemplateAssetPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_asset::<UiTemplate>()
+            .init_asset_loader::<UiTemplateLoader>()
+            .add_systems(Update, template_mark_dirty);
+    }
+}
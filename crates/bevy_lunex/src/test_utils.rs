@@ -0,0 +1,95 @@
+use crate::*;
+use lunex_engine::*;
+use std::borrow::Borrow;
+
+
+// #====================#
+// #=== TEST HARNESS ===#
+
+/// **Ui test harness** - Headless app for testing layouts without a window or a renderer.
+/// It spawns a [`MainUi`] tree with a fixed viewport size, so you can spawn nodes, run the
+/// layout systems and assert the computed [`Transform`] and [`Dimension`] of the entities.
+/// ## 🛠️ Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_lunex::prelude::*;
+/// # use bevy_lunex::test_utils::UiTestHarness;
+/// let mut harness = UiTestHarness::new((800.0, 600.0));
+/// let panel = harness.spawn("Panel", UiLayout::window().pos(Rl(10.0)).size(Rl(50.0)).pack::<Base>());
+/// harness.update();
+/// assert_eq!(harness.dimension(panel), Vec2::new(400.0, 300.0));
+///
+/// harness.resize((400.0, 400.0)).update();
+/// assert_eq!(harness.dimension(panel), Vec2::new(200.0, 200.0));
+/// ```
+pub struct UiTestHarness {
+    app: App,
+    root: Entity,
+}
+impl UiTestHarness {
+    /// Creates new harness with the tree computed in the viewport of the given size.
+    pub fn new(size: impl Into<Vec2>) -> Self {
+        Self::with_schedule(size, Update)
+    }
+    /// Creates new harness with the layout running in the given schedule, see [`UiCorePlugin::schedule`].
+    /// Each update advances the time by one fixed timestep, so [`FixedUpdate`] runs once per update after the first one.
+    pub fn with_schedule(size: impl Into<Vec2>, schedule: impl bevy::ecs::schedule::ScheduleLabel) -> Self {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Time::<Fixed>::default().timestep()))
+            .init_resource::<Assets<Image>>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<Font>>()
+            .add_plugins(UiCorePlugin::<MainUi>::new().schedule(schedule));
+
+        let root = app.world_mut().spawn(UiTreeBundle::<MainUi> {
+            dimension: Dimension::new(size),
+            ..UiTree::new2d("Test").into()
+        }).id();
+
+        UiTestHarness { app, root }
+    }
+    /// Returns the app, use it to add plugins, systems or resources.
+    pub fn app(&mut self) -> &mut App {
+        &mut self.app
+    }
+    /// Returns the entity of the tree.
+    pub fn root(&self) -> Entity {
+        self.root
+    }
+    /// Spawns a linked entity with the path and the bundle as a child of the tree.
+    /// [`Transform`] and [`Dimension`] are added unless the bundle contains them.
+    pub fn spawn(&mut self, path: impl Borrow<str>, bundle: impl Bundle) -> Entity {
+        let root = self.root;
+        let world = self.app.world_mut();
+        let mut entity = world.spawn((UiLink::<MainUi>::path(path), Transform::default(), Dimension::default()));
+        entity.insert(bundle).set_parent(root);
+        entity.id()
+    }
+    /// Changes the size of the viewport, the layout is recomputed on the next update.
+    pub fn resize(&mut self, size: impl Into<Vec2>) -> &mut Self {
+        let root = self.root;
+        if let Some(mut dimension) = self.app.world_mut().get_mut::<Dimension>(root) {
+            dimension.size = size.into();
+        }
+        self
+    }
+    /// Runs all systems once, including the layout computation.
+    pub fn update(&mut self) -> &mut Self {
+        self.app.update();
+        self
+    }
+    /// Returns the computed size of the entity.
+    pub fn dimension(&self, entity: Entity) -> Vec2 {
+        self.app.world().get::<Dimension>(entity).map(|dimension| dimension.size).unwrap_or_default()
+    }
+    /// Returns the computed translation of the entity.
+    pub fn translation(&self, entity: Entity) -> Vec3 {
+        self.app.world().get::<Transform>(entity).map(|transform| transform.translation).unwrap_or_default()
+    }
+    /// Returns the computed rectangle of the node, the position is relative to the top left corner of the tree.
+    pub fn rectangle(&self, path: impl Borrow<str>) -> Option<Rectangle3D> {
+        let ui = self.app.world().get::<UiTree<MainUi>>(self.root)?;
+        ui.borrow_node(path).ok()?.obtain_data().map(|container| container.rectangle)
+    }
+}
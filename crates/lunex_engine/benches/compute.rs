@@ -0,0 +1,82 @@
+//! Compares the full layout compute against the incremental one on trees with different node counts and depths.
+//! Run with `cargo bench -p lunex_engine`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use lunex_engine::*;
+
+/// Node counts of the benchmarked trees, each is benchmarked with all depths.
+const NODES: [usize; 2] = [1_000, 10_000];
+/// Depths of the benchmarked trees.
+const DEPTHS: [u32; 3] = [1, 2, 4];
+/// Percentage of nodes animated every frame.
+const ANIMATED: usize = 1;
+
+/// Builds a tree with about `nodes` nodes spread evenly over `depth` levels and returns the paths of the leaves.
+fn build_tree(nodes: usize, depth: u32) -> (UiTree, Vec<String>) {
+    let branching = (nodes as f32).powf(1.0 / depth as f32).round() as usize;
+    let mut tree: UiTree = UiTree::new2d("Bench");
+    let mut level = vec![String::new()];
+    for _ in 0..depth {
+        let mut next = Vec::new();
+        for parent in &level {
+            for i in 0..branching {
+                let path = if parent.is_empty() { format!("Node{i}") } else { format!("{parent}/Node{i}") };
+                let step = 100.0 / branching as f32;
+                tree.borrow_or_create_ui_node_mut(path.as_str()).unwrap().obtain_data_mut().unwrap()
+                    .layout.insert(0, Layout::window().x(Rl(step * i as f32)).size(Rl((step, 100.0))).package());
+                next.push(path);
+            }
+        }
+        level = next;
+    }
+    (tree, level)
+}
+
+/// Simulates hover animations tweening the layout of the animated nodes.
+fn animate(tree: &mut UiTree, animated: &[&String], frame: u32) {
+    for path in animated {
+        tree.borrow_node_mut(path.as_str()).unwrap().obtain_data_mut().unwrap().layout_tween = (frame % 10) as f32 / 10.0;
+        tree.mark_dirty(path.as_str());
+    }
+}
+
+fn compute(c: &mut Criterion) {
+    let viewport: Rectangle3D = Rectangle2D::new().with_size((1920.0, 1080.0)).into();
+    let mut group = c.benchmark_group("compute");
+    for (nodes, depth) in NODES.into_iter().flat_map(|nodes| DEPTHS.map(|depth| (nodes, depth))) {
+        let (mut full_tree, leaves) = build_tree(nodes, depth);
+        let mut dirty_tree = full_tree.clone();
+        let animated: Vec<&String> = leaves.iter().step_by(100 / ANIMATED).collect();
+        full_tree.compute(viewport);
+        dirty_tree.compute(viewport);
+
+        // Both computes have to produce the same layout
+        for frame in 0..10 {
+            animate(&mut full_tree, &animated, frame);
+            full_tree.compute(viewport);
+            animate(&mut dirty_tree, &animated, frame);
+            dirty_tree.compute_dirty(viewport);
+        }
+        for (a, b) in full_tree.crawl().into_iter().zip(dirty_tree.crawl()) {
+            assert_eq!(a.obtain_data().map(|d| d.rectangle), b.obtain_data().map(|d| d.rectangle), "{}", a.get_path());
+        }
+
+        let id = format!("{nodes}x{depth}");
+        let mut frame = 0;
+        group.bench_function(BenchmarkId::new("full", &id), |b| b.iter(|| {
+            frame += 1;
+            animate(&mut full_tree, &animated, frame);
+            full_tree.compute(viewport);
+        }));
+        let mut frame = 0;
+        group.bench_function(BenchmarkId::new("incremental", &id), |b| b.iter(|| {
+            frame += 1;
+            animate(&mut dirty_tree, &animated, frame);
+            dirty_tree.compute_dirty(viewport);
+        }));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, compute);
+criterion_main!(benches);
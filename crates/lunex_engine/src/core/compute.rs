@@ -1,3 +1,5 @@
+use std::borrow::Borrow;
+
 use bevy::ecs::component::Component;
 
 use crate::import::*;
@@ -5,330 +7,336 @@ use crate::NodeGeneralTrait;
 use crate::NodeTopDataTrait;
 use crate::UiNode;
 use crate::UiTree;
+use crate::Rectangle2D;
 use crate::Rectangle3D;
 use crate::Layout;
+use crate::Div;
+use crate::FlowItem;
+use crate::NodeData;
+use crate::Radial;
+use crate::LayoutCache;
+use crate::UiFit;
+use crate::UiLayoutCacheStats;
 
 /// Trait with [`UiTree`] layout computation methods.
 pub trait UiNodeTreeComputeTrait {
-    /// Compute the layout of the [`UiTree`].
+    /// Compute the layout of the whole [`UiTree`].
     fn compute(&mut self, parent: Rectangle3D);
+    /// Compute only the nodes marked by [`UiNodeTreeComputeTrait::mark_dirty`] and their subnodes.
+    /// Subtrees without any marked node are not traversed.
+    fn compute_dirty(&mut self, parent: Rectangle3D);
+    /// ## 🚸 Recursive
+    /// Marks the node to be recomputed on the next [`UiNodeTreeComputeTrait::compute_dirty`].
+    /// Call this every time you modify the node data directly.
+    fn mark_dirty(&mut self, path: impl Borrow<str>);
 }
 impl <T, N: Default + Component> UiNodeTreeComputeTrait for UiTree<T, N> {
     fn compute(&mut self, parent: Rectangle3D) {
+        let (mut context, font_size) = self.compute_context(parent);
+        self.node.compute_all(parent, &mut context, font_size, 0.0, true);
+        self.add_stats(&context);
+    }
+    fn compute_dirty(&mut self, parent: Rectangle3D) {
+        let (mut context, font_size) = self.compute_context(parent);
+        self.node.compute_all(parent, &mut context, font_size, 0.0, false);
+        self.add_stats(&context);
+    }
+    fn mark_dirty(&mut self, path: impl Borrow<str>) {
+        self.node.mark_dirty(path.borrow());
+        if let Some(master_data) = self.obtain_topdata_mut() { master_data.dirty_marks += 1 }
+    }
+}
 
-        let mut abs_scale = 1.0;
-        let mut font_size = 16.0;
+/// Trait with private [`UiTree`] compute helpers.
+trait UiTreeSettingsTrait {
+    /// Returns the compute context and the font size built from the master data.
+    fn compute_context(&self, parent: Rectangle3D) -> (ComputeContext, f32);
+    /// Adds the statistics of the last compute to the master data.
+    fn add_stats(&mut self, context: &ComputeContext);
+}
+impl <T, N: Default + Component> UiTreeSettingsTrait for UiTree<T, N> {
+    fn compute_context(&self, parent: Rectangle3D) -> (ComputeContext, f32) {
+        let mut context = ComputeContext { absolute_scale: 1.0, depth_step: 1.0, root_size: Some(parent.size), viewport_size: parent.size, stats: Default::default(), traversed: 0 };
+        let Some(master_data) = self.obtain_topdata() else { return (context, 16.0) };
+        context.absolute_scale = master_data.abs_scale * master_data.scale_factor;
+        context.depth_step = master_data.depth_step.unwrap_or(master_data.abs_scale);
+        if let Some(viewport_size) = master_data.viewport_size { context.viewport_size = viewport_size }
+        if !master_data.relative_to_root { context.root_size = None }
+        (context, master_data.font_size)
+    }
+    fn add_stats(&mut self, context: &ComputeContext) {
+        if let Some(master_data) = self.obtain_topdata_mut() {
+            master_data.layout_cache.hits += context.stats.hits;
+            master_data.layout_cache.misses += context.stats.misses;
+            master_data.nodes_traversed = context.traversed;
+            master_data.dirty_marks = 0;
+        }
+    }
+}
 
-        if let Some(master_data) = self.obtain_topdata() {
-            abs_scale = master_data.abs_scale;
-            font_size = master_data.font_size;
+/// Values shared by the whole compute pass.
+struct ComputeContext {
+    absolute_scale: f32,
+    depth_step: f32,
+    /// Size of the tree or `None` if the [`Rw`](crate::Rw) and [`Rh`](crate::Rh) units are relative to the parent.
+    root_size: Option<Vec2>,
+    viewport_size: Vec2,
+    stats: UiLayoutCacheStats,
+    traversed: u32,
+}
+
+/// Computes the layout rectangle or returns the cached one if none of the inputs changed.
+/// During state animations only the blend changes, so both states are reused and just lerped.
+fn compute_layout(cache: &mut Option<LayoutCache>, layout: &Layout, parent: Rectangle2D, root_size: Vec2, wedge: Vec2, flow: Rectangle2D, context: &mut ComputeContext, font_size: f32) -> Option<Rectangle3D> {
+    let (absolute_scale, viewport_size) = (context.absolute_scale, context.viewport_size);
+    if let Some(cached) = cache {
+        if cached.layout == *layout && cached.parent == parent && cached.root_size == root_size && cached.wedge == wedge && cached.flow == flow && cached.absolute_scale == absolute_scale && cached.viewport_size == viewport_size && cached.font_size == font_size {
+            context.stats.hits += 1;
+            return cached.rectangle;
         }
+    }
+    context.stats.misses += 1;
+
+    let rectangle = match layout {
+        Layout::Div(_) => Some(Rectangle2D { pos: parent.pos + flow.pos, size: flow.size }.into()),
+        Layout::Boundary(l) => Some(l.compute(parent, root_size, absolute_scale, viewport_size, font_size).into()),
+        Layout::Window(l) => Some(l.compute(parent, root_size, absolute_scale, viewport_size, font_size).into()),
+        Layout::Solid(l) => Some(l.compute(parent, root_size, absolute_scale, viewport_size, font_size).into()),
+        Layout::Radial(l) => Some(l.compute(parent, root_size, wedge, absolute_scale, viewport_size, font_size).into()),
+    };
+    *cache = Some(LayoutCache { layout: *layout, parent, root_size, wedge, flow, absolute_scale, viewport_size, font_size, rectangle });
+    rectangle
+}
 
-        self.node.compute_all(parent, abs_scale, parent.size, font_size);
+/// Returns the [`Div`] layout of the node, if it has one.
+fn div_layout<N: Default + Component>(node_data: &NodeData<N>) -> Option<Div> {
+    match node_data.layout.get(&node_data.layout_index[0]).unwrap_or(node_data.layout.get(&0).unwrap()) {
+        Layout::Div(layout) => Some(*layout),
+        _ => None,
     }
 }
 
 
 /// Trait with [`UiNode`] layout computation methods. Includes private methods.
 trait UiNodeComputeTrait {
-    fn compute_all(&mut self, parent: Rectangle3D, absolute_scale: f32, viewport_size: Vec2, font_size: f32);
-    //fn compute_content(&mut self, ancestor_size: Vec2, ancestor_padding: Vec4, abs_scale: f32, font_size: f32) -> Vec2;
-    //fn compute_stack(&mut self, ancestor_size: Vec2, ancestor_padding: Vec4, abs_scale: f32, font_size: f32, horizontal: bool) -> Vec2;
-    //fn align_stack(&mut self, ancestor_position: Vec2);
+    fn compute_all(&mut self, parent: Rectangle3D, context: &mut ComputeContext, font_size: f32, z_offset: f32, force: bool);
+    fn mark_dirty(&mut self, path: &str);
+    fn collapse_all(&mut self, position: Vec3);
+    fn fit_to_subnodes(&mut self, fit: UiFit, root_size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32);
+    fn flow_subnodes(&self, available: Vec2, context: &ComputeContext, font_size: f32) -> (Vec<(usize, Rectangle2D)>, Vec2);
 }
 impl <N:Default + Component> UiNodeComputeTrait for UiNode<N> { 
     /// Triggers the recursion in the right manner.
-    fn compute_all(&mut self, parent: Rectangle3D, absolute_scale: f32, viewport_size: Vec2, mut font_size: f32) {
+    /// Unless forced, only dirty nodes are recomputed and only subtrees with dirty nodes are traversed.
+    fn compute_all(&mut self, parent: Rectangle3D, context: &mut ComputeContext, mut font_size: f32, mut z_offset: f32, force: bool) {
+
+        let (absolute_scale, viewport_size) = (context.absolute_scale, context.viewport_size);
+        let mut root_size = context.root_size.unwrap_or(parent.size);
+        context.traversed += 1;
 
         // Get depth before mutating self
         let depth = self.get_depth();
-        
-        let skip = true;
-        let is_parametric = false;
 
         // Check here if computation is required for partial recalculation
+        let recompute = if let Some(node_data) = &mut self.data {
+            if !force && !node_data.dirty && !node_data.dirty_subnodes { return }
+            // Fitted nodes depend on their subnodes, so their rectangle has to be rebuilt from layout
+            let recompute = force || node_data.dirty || (node_data.fit.is_some() && node_data.dirty_subnodes);
+            node_data.dirty = false;
+            node_data.dirty_subnodes = false;
+            recompute
+        } else { return; };
 
         // Compute my layout and return computed rectangle for recursion
         let my_rectangle = if let Some(node_data) = &mut self.data {
 
             // Overwrite passed style with font size
             if let Some(fnt) = node_data.font_size { font_size = fnt }
+            if node_data.relative_to_parent { root_size = parent.size }
+
+            // Clean nodes keep their rectangle, only the inherited values are passed down
+            if !recompute {
+                if let Some(z_index) = node_data.z_index { z_offset = z_index.offset(z_offset, depth) }
+                if let Some(depth_layer) = node_data.depth_layer { z_offset = depth_layer.offset(z_offset) }
+                if node_data.display.is_collapsed() { return }
+                node_data.rectangle
+            } else {
 
-            // Compute node layout
-
-            let layout_0 = node_data.layout.get(&node_data.layout_index[0]).unwrap_or(node_data.layout.get(&0).unwrap());
-            let layout_0: Option<Rectangle3D> = match layout_0 {
-                Layout::Div(_) => {
-                    None
-                },
-                Layout::Boundary(l) => {
-                    Some(l.compute(parent.into(), absolute_scale, viewport_size, font_size).into())
-                },
-                Layout::Window(l) => {
-                    Some(l.compute(parent.into(), absolute_scale, viewport_size, font_size).into())
-                },
-                Layout::Solid(l)  => {
-                    Some(l.compute(parent.into(), absolute_scale, viewport_size, font_size).into())
-                },
-            };
-
-            let layout_1 = node_data.layout.get(&node_data.layout_index[1]).unwrap_or(node_data.layout.get(&0).unwrap());
-            let layout_1: Option<Rectangle3D> = match layout_1 {
-                Layout::Div(_) => {
-                    None
-                },
-                Layout::Boundary(l) => {
-                    Some(l.compute(parent.into(), absolute_scale, viewport_size, font_size).into())
-                },
-                Layout::Window(l) => {
-                    Some(l.compute(parent.into(), absolute_scale, viewport_size, font_size).into())
-                },
-                Layout::Solid(l)  => {
-                    Some(l.compute(parent.into(), absolute_scale, viewport_size, font_size).into())
-                },
-            };
-
-            /* match &node_data.layout {
-                Layout::Div(_) => {
-                    is_parametric = true;
-                },
-                Layout::Boundary(l) => {
-                    skip = false;
-                },
-                Layout::Window(l) => {
-                    skip = false;
-                },
-                Layout::Solid(l)  => {
-                    skip = false;
-                },
-            } */
-
-            if let Some(l0) = layout_0 {
-                if let Some(l1) = layout_1 {
-                    node_data.rectangle = l0.lerp(l1, node_data.layout_tween);
-                };
-            };
+                // Compute node layout
 
-            // Adding depth
-            node_data.rectangle.pos.z = (depth + node_data.depth_bias)*absolute_scale;
-            node_data.rectangle
+                let layout_0 = node_data.layout.get(&node_data.layout_index[0]).unwrap_or(node_data.layout.get(&0).unwrap());
+                let layout_0 = compute_layout(&mut node_data.layout_cache[0], layout_0, parent.into(), root_size, node_data.radial_slot, node_data.flow_slot, context, font_size);
 
-        } else { return; };
+                let layout_1 = node_data.layout.get(&node_data.layout_index[1]).unwrap_or(node_data.layout.get(&0).unwrap());
+                let layout_1 = compute_layout(&mut node_data.layout_cache[1], layout_1, parent.into(), root_size, node_data.radial_slot, node_data.flow_slot, context, font_size);
 
-        if !skip {
-            if is_parametric {
-                //compute divs with inherited scale
-                //self.compute_content(parent.size, Vec4::ZERO, absolute_scale, font_size);
-            } else {
-                //compute divs with my rectangle scale
-                //self.compute_content(my_rectangle.size, Vec4::ZERO, absolute_scale, font_size);
-            }
-        }
+                if let Some(l0) = layout_0 {
+                    if let Some(l1) = layout_1 {
+                        node_data.rectangle = l0.lerp(l1, node_data.layout_tween);
+                    };
+                };
 
-        // Enter recursion
-        for (_, subnode) in &mut self.nodes {
-            subnode.compute_all(my_rectangle, absolute_scale, viewport_size, font_size);
-        }
-    }
-    /* /// Computes the content only.
-    fn compute_content(&mut self, ancestor_size: Vec2, ancestor_padding: Vec4, abs_scale: f32, font_size: f32) -> Vec2 {
+                // Radial nodes are picked inside their wedge
+                node_data.wedge = match node_data.layout.get(&node_data.layout_index[0]).unwrap_or(node_data.layout.get(&0).unwrap()) {
+                    Layout::Radial(l) => Some(l.compute_wedge(parent.into(), root_size, node_data.rectangle.into(), node_data.radial_slot, absolute_scale, viewport_size, font_size)),
+                    _ => None,
+                };
 
-        let stack_options = self.data.as_ref().unwrap().stack;
+                // Collapsed nodes are removed from the layout
+                if node_data.display.is_collapsed() {
+                    node_data.rectangle = Rectangle3D { pos: parent.pos, ..Default::default() };
+                }
 
-        match stack_options.direction {
-            StackDirection::Horizontal => self.compute_stack(ancestor_size, ancestor_padding, abs_scale, font_size, true),
-            StackDirection::Vertical => self.compute_stack(ancestor_size, ancestor_padding, abs_scale, font_size, false),
-        }
-    }
-    /// This will compute the stack and position nodes ONLY locally as if every matrix starts at 0,0.
-    /// Secondary pass after alignment of parent nodes is required.
-    fn compute_stack(&mut self, ancestor_size: Vec2, ancestor_padding: Vec4, abs_scale: f32, font_size: f32, horizontal: bool) -> Vec2 {
+                // Overwrite inherited depth offset with z-index
+                if let Some(z_index) = node_data.z_index { z_offset = z_index.offset(z_offset, depth) }
+                if let Some(depth_layer) = node_data.depth_layer { z_offset = depth_layer.offset(z_offset) }
 
-        let mut matrix: Vec<Vec<&mut Node<NodeData<N>>>> = Vec::new();
-        let mut content_size = Vec2::ZERO;
+                // Adding depth
+                node_data.rectangle.pos.z = (depth + z_offset + node_data.depth_bias)*context.depth_step;
 
-        // Sort mutable pointers into matrix
-        let mut i = 0;
-        matrix.push(Vec::new());
-        for (_, subnode) in &mut self.nodes {
-            if let Some(subnode_data) = &subnode.data {
-                if let Layout::Div(layout) = &subnode_data.layout {
-                    let br = layout.force_break;
-                    matrix[i].push(subnode);
-                    if br {
-                        i += 1;
-                        matrix.push(Vec::new());
+                // Collapse the whole subtree and skip the recursion
+                if node_data.display.is_collapsed() {
+                    let position = node_data.rectangle.pos;
+                    for (_, subnode) in &mut self.nodes {
+                        subnode.collapse_all(position);
                     }
+                    return;
                 }
-            }
-        }
-
-
-        // INSIDE MATRIX =================================================================
-
-        let gap = self.data.as_ref().unwrap().stack.gap.evaluate(abs_scale, ancestor_size, font_size);
-        let align = self.data.as_ref().unwrap().stack.node_alignment.0;
-
-
-        let mut line_cursor = if horizontal { ancestor_padding.y } else { ancestor_padding.x };
-
-        //--------------------------//
-        let mut _i = 0;             //
-        let _i_max = matrix.len();  //
-        for line in &mut matrix {   //
-            // INSIDE LINE =================================================================
-
-            // Register that is shared between the two passes
-            let mut comline = ComputedLine {
-                divs: Vec::new(),
-                line_length: 0.0,
-            };
 
-            // First pass to compute sizes--//
-            let mut _ii = 0;                //
-            let _ii_max = line.len();       //
-            for subnode in &mut *line {     //
-                // INSIDE SUBNODE =================================================================
-
-                // Fetch data
-                let subnode_data = subnode.data.as_ref().unwrap();
-                let layout = if let Layout::Div(layout) = subnode_data.layout { layout } else { unreachable!() };
-
-                // Get padding & margin => compute range of motion
-                let padding = layout.compute_padding(ancestor_size, abs_scale, font_size);
-                let margin = layout.compute_margin(ancestor_size, abs_scale, font_size);
-                let border = layout.compute_border(ancestor_size, abs_scale, font_size);
-
-                // Enter recursion to get the right content size
-                let potential_content = subnode.compute_content(ancestor_size, padding, abs_scale, font_size);
-
-                // Fetch data again, because they were modified
-                let subnode_data = subnode.data.as_mut().unwrap();
-                let mut subnode_content = subnode_data.content_size;
-
-                // Overwrite subnode content if div contains no subdivs
-                if potential_content != Vec2::ZERO { subnode_content = potential_content }
-
-                // Compute size and (line_length)
-                let size = layout.compute_size(subnode_content, padding, border);
-                let line_length = if horizontal { margin.y + size.y + margin.w } else { margin.x + size.x + margin.z };
-
-                // Push into register
-                comline.line_length = f32::max(comline.line_length, line_length);
-                comline.divs.push(ComputedDiv { size, margin });
-
-
-                // END OF INSIDE SUBNODE =================================================================
-                _ii += 1;
+                node_data.rectangle
             }
 
-            if _i != 0 { line_cursor += if horizontal { gap.x } else { gap.y } }
-            let mut cursor = if horizontal { ancestor_padding.x } else { ancestor_padding.y };
-
-            // Second pass to align them----//
-            let mut _ii = 0;                //
-            let _ii_max = line.len();       //
-            for subnode in &mut *line {     //
-                // INSIDE SUBNODE =================================================================
-
-                // Fetch data
-                let subnode_data = subnode.data.as_ref().unwrap();
-                let layout = if let Layout::Div(layout) = subnode_data.layout { layout } else { unreachable!() };
-                
-                let margin = comline.divs[_ii].margin;
-                let size = comline.divs[_ii].size;
-
-                let possible_size = if horizontal {comline.line_length - margin.y - margin.w } else {comline.line_length - margin.x - margin.z };
-
-                let mut my_align = align;
-                let my_offset;
-                
-                if horizontal {
-                    if let Some(align) = layout.align_y { my_align = align.0 }
-
-                    if _ii != 0 { cursor += gap.x }
-                    cursor += margin.x;
-                    let off = margin.y + possible_size/2.0 - size.y/2.0;
-                    my_offset = Vec2::new(cursor, off + (off - margin.x) * my_align);
-                    cursor += size.x;
-                    cursor += margin.z;
-
-                } else {
-                    if let Some(align) = layout.align_x { my_align = align.0 }
-
-                    if _ii != 0 { cursor += gap.y }
-                    cursor += margin.y;
-                    let off = margin.x + possible_size/2.0 - size.x/2.0;
-                    my_offset = Vec2::new(off + (off - margin.x) * my_align, cursor);
-                    cursor += size.y;
-                    cursor += margin.w;
-                };
-                
-
-                // Fetch data again, because they were modified
-                let subnode_data = subnode.data.as_mut().unwrap();
-
-                let _xxx = my_offset + if horizontal { Vec2::new(0.0, line_cursor) } else { Vec2::new(line_cursor, 0.0) };
-                subnode_data.rectangle.pos.x = _xxx.x;
-                subnode_data.rectangle.pos.y = _xxx.y;
-                subnode_data.rectangle.size = size;
-
-
-                let subnode_data = subnode.data.as_ref().unwrap();
-                subnode.align_stack(subnode_data.rectangle.pos.xy());
-
+        } else { return; };
 
-                // END OF INSIDE SUBNODE =================================================================
-                _ii += 1;
+        // Culled nodes keep their subnodes as they are until they are visible again
+        if self.data.as_ref().is_some_and(|data| data.culled) { return }
+
+        // Radial subnodes share the arc in the order they were added
+        let radials: Vec<(usize, Radial)> = self.nodes.values().enumerate().filter_map(|(index, subnode)| {
+            let subnode_data = subnode.data.as_ref()?;
+            if subnode_data.display.is_collapsed() { return None }
+            match subnode_data.layout.get(&0) { Some(Layout::Radial(layout)) => Some((index, *layout)), _ => None }
+        }).collect();
+        let wedges = Radial::distribute(radials.iter().map(|(_, layout)| layout));
+        for ((index, _), wedge) in radials.iter().zip(wedges) {
+            let Some((_, subnode)) = self.nodes.get_index_mut(*index) else { continue };
+            let Some(subnode_data) = &mut subnode.data else { continue };
+            if subnode_data.radial_slot != wedge {
+                subnode_data.radial_slot = wedge;
+                subnode_data.dirty = true;
             }
+        }
 
-            // Set content size
-            if horizontal {
-                content_size.x = f32::max(content_size.x, cursor - ancestor_padding.x)
-            } else {
-                content_size.y = f32::max(content_size.y, cursor - ancestor_padding.y)
+        // Subnodes are computed from the scrolled rectangle, without the padding and border of Div nodes
+        let mut content_rectangle = my_rectangle;
+        if let Some(node_data) = &self.data {
+            if let Some(div) = div_layout(node_data) {
+                let boxed = div.compute_box(parent.size, root_size, absolute_scale, viewport_size, font_size);
+                content_rectangle.pos += boxed.offset().extend(0.0);
+                content_rectangle.size = (content_rectangle.size - boxed.inset()).max(Vec2::ZERO);
             }
+            content_rectangle.pos -= node_data.scroll.extend(0.0);
+        }
 
-            line_cursor += comline.line_length;
+        // Div subnodes are placed one after another by the stack
+        for (index, slot) in self.flow_subnodes(content_rectangle.size, context, font_size).0 {
+            let Some((_, subnode)) = self.nodes.get_index_mut(index) else { continue };
+            let Some(subnode_data) = &mut subnode.data else { continue };
+            if subnode_data.flow_slot != slot {
+                subnode_data.flow_slot = slot;
+                subnode_data.dirty = true;
+            }
+        }
 
-            // END OF INSIDE LINE =================================================================
-            _i += 1;
+        // Enter recursion
+        for (_, subnode) in &mut self.nodes {
+            subnode.compute_all(content_rectangle, context, font_size, z_offset, recompute);
         }
 
-        // Set content size
-        if horizontal {
-            content_size.y = line_cursor - ancestor_padding.y
-        } else {
-            content_size.x = line_cursor - ancestor_padding.x
+        // Shrink-wrap the computed subnodes
+        if let Some(fit) = self.data.as_ref().and_then(|data| data.fit) {
+            self.fit_to_subnodes(fit, root_size, absolute_scale, viewport_size, font_size);
         }
-        
-        // END OF INSIDE MATRIX =========================================================
-        content_size
     }
-    /// This is the secondary pass to align the nodes.
-    fn align_stack(&mut self, ancestor_position: Vec2) {
-
+    /// Marks the node at the path as dirty and all nodes on the way as having dirty subnodes.
+    fn mark_dirty(&mut self, path: &str) {
+        let Some(node_data) = &mut self.data else { return };
+        let (name, rempath) = match path.split_once('/') {
+            None => (path, None),
+            Some((name, rempath)) => (name, Some(rempath)),
+        };
+        if name.is_empty() || name == "." {
+            node_data.dirty = true;
+            return;
+        }
+        node_data.dirty_subnodes = true;
+        if let Ok(subnode) = self.obtain_node_mut(name) {
+            match rempath {
+                None => if let Some(subnode_data) = &mut subnode.data { subnode_data.dirty = true },
+                Some(rempath) => subnode.mark_dirty(rempath),
+            }
+        }
+    }
+    /// Sets the rectangle of this node and all subnodes to zero size at the given position.
+    fn collapse_all(&mut self, position: Vec3) {
+        if let Some(node_data) = &mut self.data {
+            node_data.rectangle = Rectangle3D { pos: position, ..Default::default() };
+        }
         for (_, subnode) in &mut self.nodes {
-            if let Some(subnode_data) = &mut subnode.data {
-                if let Layout::Div(_) = &subnode_data.layout {
+            subnode.collapse_all(position);
+        }
+    }
+    /// Resizes this node to the bounding box of all non-collapsed subnodes.
+    fn fit_to_subnodes(&mut self, fit: UiFit, root_size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) {
+        let mut min = Vec2::MAX;
+        let mut max = Vec2::MIN;
+        for (_, subnode) in &self.nodes {
+            if let Some(subnode_data) = &subnode.data {
+                if subnode_data.display.is_collapsed() { continue }
+                min = min.min(subnode_data.rectangle.pos.truncate());
+                max = max.max(subnode_data.rectangle.pos.truncate() + subnode_data.rectangle.size);
+            }
+        }
 
-                    subnode_data.rectangle.pos.x += ancestor_position.x;
-                    subnode_data.rectangle.pos.y += ancestor_position.y;
+        // No subnodes to fit around
+        if min.x > max.x { return }
 
-                }
-                subnode.align_stack(ancestor_position);
-            }
+        if let Some(node_data) = &mut self.data {
+            node_data.rectangle = fit.fit(node_data.rectangle, min, max, root_size, absolute_scale, viewport_size, font_size);
         }
+    }
+    /// ## 🚸 Recursive
+    /// Measures the [`Div`] subnodes and places them inside the available size. Returns the rectangles
+    /// by subnode index together with the size of the content. Div subnodes are sized by their own Div subnodes
+    /// or by [`NodeData::content_size`] if they have none.
+    fn flow_subnodes(&self, available: Vec2, context: &ComputeContext, font_size: f32) -> (Vec<(usize, Rectangle2D)>, Vec2) {
+        let (absolute_scale, viewport_size) = (context.absolute_scale, context.viewport_size);
+        let root_size = context.root_size.unwrap_or(available);
+        let Some(node_data) = &self.data else { return Default::default() };
+
+        let mut indices = Vec::new();
+        let mut items = Vec::new();
+        for (index, subnode) in self.nodes.values().enumerate() {
+            let Some(subnode_data) = &subnode.data else { continue };
+            if subnode_data.display.is_collapsed() { continue }
+            let Some(div) = div_layout(subnode_data) else { continue };
+            let font_size = subnode_data.font_size.unwrap_or(font_size);
+
+            let root_size = if subnode_data.relative_to_parent { available } else { root_size };
+
+            let boxed = div.compute_box(available, root_size, absolute_scale, viewport_size, font_size);
+            let inner = (available - Vec2::new(boxed.margin.x + boxed.margin.z, boxed.margin.y + boxed.margin.w) - boxed.inset()).max(Vec2::ZERO);
+            let (slots, content) = subnode.flow_subnodes(inner, context, font_size);
+            let content = if slots.is_empty() { subnode_data.content_size } else { content };
+
+            let size = div.compute_size(content, &boxed, available, root_size, absolute_scale, viewport_size, font_size);
+            items.push(FlowItem { size, margin: boxed.margin, space: boxed.space, br: div.br });
+            indices.push(index);
+        }
+        if items.is_empty() { return Default::default() }
 
-    } */
-}
-/* 
-struct ComputedDiv {
-    size: Vec2,
-    margin: Vec4,
-}
-struct ComputedLine {
-    divs: Vec<ComputedDiv>,
-    line_length: f32,
+        let (positions, content) = node_data.stack.flow(&items, available, root_size, absolute_scale, viewport_size, font_size);
+        let slots = indices.into_iter().zip(positions).zip(&items).map(|((index, pos), item)| (index, Rectangle2D { pos, size: item.size })).collect();
+        (slots, content)
+    }
 }
- */
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use crate::{import::*, NiceDisplay, UiStack};
+use crate::{import::*, NiceDisplay, UiDisplay, UiFit, UiStack, UiZIndex, UiDepthLayer, RadialWedge};
 use bevy::ecs::component::Component;
 use bevy::math::FloatExt;
 use colored::Colorize;
@@ -18,6 +18,10 @@ pub enum UiError {
     /// Error that occurs when something went wrong with NodeTree.
     #[error("NodeTree error: {0}")]
     NodeError(NodeError),
+
+    /// Error that occurs when a string can't be parsed into ui type.
+    #[error("Unable to parse '{0}'")]
+    ParseError(String),
 }
 impl From<NodeError> for UiError {
     fn from(value: NodeError) -> Self {
@@ -176,11 +180,11 @@ pub type UiNode<N = NoData> = Node<NodeData<N>>;
 // #====================================#
 // #=== DIFFERENT DATA TYPE GENERICS ===#
 
-#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
 pub struct MainUi;
 
 /// Empty type to tell the compiler that there is no data stored in the node.
-#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
 pub struct NoData;
 
 
@@ -192,15 +196,37 @@ pub struct MasterData<T> {
     pub marker: PhantomData<T>,
     /// Scale of the [`crate::Abs`] unit.
     pub abs_scale: f32,
+    /// Multiplier of the absolute scale, used for user preference and design resolution scaling.
+    /// Unlike the absolute scale, it doesn't affect the default depth step.
+    pub scale_factor: f32,
     /// Default font size for all subnodes to use (Rem unit scaling).
     pub font_size: f32,
+    /// Distance between two depth levels. If `None`, the absolute scale is used.
+    pub depth_step: Option<f32>,
+    /// Size the viewport units ([`crate::Vp`], [`crate::Vw`], [`crate::Vh`]) are relative to. If `None`, the size of the tree is used.
+    pub viewport_size: Option<Vec2>,
+    /// If the [`crate::Rw`] and [`crate::Rh`] units are relative to the size of the tree. If `false`, they are relative to the parent node.
+    pub relative_to_root: bool,
+    /// Hits and misses of the layout cache since the last reset.
+    pub layout_cache: UiLayoutCacheStats,
+    /// Number of nodes visited by the last computation.
+    pub nodes_traversed: u32,
+    /// Number of times a node was marked dirty since the last computation.
+    pub dirty_marks: u32,
 }
 impl <T> Default for MasterData<T> {
     fn default() -> Self {
         MasterData {
             marker: PhantomData,
             abs_scale: 1.0,
+            scale_factor: 1.0,
             font_size: 16.0,
+            depth_step: None,
+            viewport_size: None,
+            relative_to_root: true,
+            layout_cache: Default::default(),
+            nodes_traversed: 0,
+            dirty_marks: 0,
         }
     }
 }
@@ -211,6 +237,40 @@ impl <T> NiceDisplay for MasterData<T> {
 }
 
 
+/// Statistics of the per-state layout cache, counted during [`UiTree`] computation.
+/// Every computed layout state is either a hit (reused) or a miss (computed again).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct UiLayoutCacheStats {
+    pub hits: u32,
+    pub misses: u32,
+}
+impl UiLayoutCacheStats {
+    /// Returns the ratio of hits to all lookups or `None` if nothing was computed.
+    pub fn hit_rate(&self) -> Option<f32> {
+        let total = self.hits + self.misses;
+        if total == 0 { None } else { Some(self.hits as f32 / total as f32) }
+    }
+    /// Sets both counters to zero.
+    pub fn reset(&mut self) {
+        *self = Default::default();
+    }
+}
+
+/// Computed rectangle of a layout state together with the inputs it was computed from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct LayoutCache {
+    pub(crate) layout: Layout,
+    pub(crate) parent: Rectangle2D,
+    pub(crate) root_size: Vec2,
+    pub(crate) absolute_scale: f32,
+    pub(crate) viewport_size: Vec2,
+    pub(crate) font_size: f32,
+    pub(crate) wedge: Vec2,
+    pub(crate) flow: Rectangle2D,
+    pub(crate) rectangle: Option<Rectangle3D>,
+}
+
+
 /// A struct holding all data appended to [`UiNode`]. Responsible for storing layout, custom data, cache, etc.
 /// Every [`UiNode`] needs to have this to work properly.
 #[derive(Component, Debug, Clone, PartialEq)]
@@ -219,8 +279,9 @@ pub struct NodeData<N:Default + Component> {
     pub data: Option<N>,
     /// Calculated rectangle from layout.
     pub rectangle: Rectangle3D,
-    /// Layouts of this node.
-    pub layout: HashMap<usize, Layout>,
+    /// Layouts of this node keyed by the state index. They are kept sorted by the index,
+    /// so iterating them visits the states in the same order on every run.
+    pub layout: BTreeMap<usize, Layout>,
     pub layout_index: [usize; 2],
     pub layout_tween: f32,
 
@@ -230,21 +291,61 @@ pub struct NodeData<N:Default + Component> {
     pub font_size: Option<f32>,
     /// Value that will be relatively applied to Z after layout compute.
     pub depth_bias: f32,
+    /// Optional depth override that is inherited by all subnodes.
+    pub z_index: Option<UiZIndex>,
+    /// Optional depth layer that is inherited by all subnodes.
+    pub depth_layer: Option<UiDepthLayer>,
+    /// Whether this node is shown, hidden or collapsed out of the layout.
+    pub display: UiDisplay,
+    /// Optional shrink-wrapping of this node around its subnodes.
+    pub fit: Option<UiFit>,
+    /// Offset applied to all subnodes, used for scrolling the content.
+    pub scroll: Vec2,
     /// Size of the content to wrap around. Affects this node's size only if the layout is parametric (Div).
     pub content_size: Vec2,
+    /// If the [`crate::Rw`] and [`crate::Rh`] units of this node are relative to the parent node, even if [`MasterData::relative_to_root`] is set.
+    /// Text elements use it, so their size follows the parent node.
+    pub relative_to_parent: bool,
+    /// If the subnodes are skipped in the layout, because this node is outside of the visible area.
+    pub culled: bool,
+    /// Computed wedge of the node with [`Radial`](crate::Radial) layout.
+    pub wedge: Option<RadialWedge>,
+    /// Start and end angle of the wedge given to this node by the parent.
+    pub(crate) radial_slot: Vec2,
+    /// Rectangle given to this node with [`Div`](crate::Div) layout by the stack of the parent, relative to the parent content.
+    pub(crate) flow_slot: Rectangle2D,
+    /// If this node has to be recomputed.
+    pub(crate) dirty: bool,
+    /// If any of the subnodes has to be recomputed.
+    pub(crate) dirty_subnodes: bool,
+    /// Computed rectangles of the two blended layout states.
+    pub(crate) layout_cache: [Option<LayoutCache>; 2],
 }
 impl <N:Default + Component> Default for NodeData<N> {
     fn default() -> Self {
         NodeData {
             data: Default::default(),
             rectangle: Default::default(),
-            layout: HashMap::from([(0, Layout::default())]),
+            layout: BTreeMap::from([(0, Layout::default())]),
             layout_index: Default::default(),
             layout_tween: Default::default(),
             stack: Default::default(),
             font_size: Default::default(),
             depth_bias: Default::default(),
+            z_index: Default::default(),
+            depth_layer: Default::default(),
+            display: Default::default(),
+            fit: Default::default(),
+            scroll: Default::default(),
             content_size: Default::default(),
+            relative_to_parent: false,
+            culled: false,
+            wedge: None,
+            radial_slot: Vec2::ZERO,
+            flow_slot: Rectangle2D::default(),
+            dirty: true,
+            dirty_subnodes: true,
+            layout_cache: Default::default(),
         }
     }
 }
@@ -5,10 +5,12 @@ use std::ops::Sub;
 use std::ops::SubAssign;
 use std::ops::Mul;
 use std::ops::MulAssign;
+use std::str::FromStr;
 
 use crate::import::*;
 
 use super::NiceDisplay;
+use super::UiError;
 
 
 // #==========================#
@@ -620,7 +622,8 @@ pub struct Em<T>(pub T);
 #[derive(Debug, Default, Clone, Copy, PartialEq, Deref, DerefMut, Reflect)]
 pub struct Sp<T>(pub T);
 
-/// **Viewport** - Represents scalable unit `0% to 100%` of the root container. `120%` is allowed.
+/// **Viewport** - Represents scalable unit `0% to 100%` of the viewport. `120%` is allowed.
+/// The viewport is the root container, unless [`crate::MasterData::viewport_size`] is set.
 /// ## 🛠️ Example
 /// ```
 /// # use lunex_engine::Vp;
@@ -630,7 +633,8 @@ pub struct Sp<T>(pub T);
 #[derive(Debug, Default, Clone, Copy, PartialEq, Deref, DerefMut, Reflect)]
 pub struct Vp<T>(pub T);
 
-/// **Viewport width** - Represents scalable unit `0% to 100%` of the root container. `120%` is allowed.
+/// **Viewport width** - Represents scalable unit `0% to 100%` of the viewport. `120%` is allowed.
+/// The viewport is the root container, unless [`crate::MasterData::viewport_size`] is set.
 /// Proportional to a width measure even when used in a height field.
 /// ## 🛠️ Example
 /// ```
@@ -641,7 +645,8 @@ pub struct Vp<T>(pub T);
 #[derive(Debug, Default, Clone, Copy, PartialEq, Deref, DerefMut, Reflect)]
 pub struct Vw<T>(pub T);
 
-/// **Viewport Height** - Represents scalable unit `0% to 100%` of the root container. `120%` is allowed.
+/// **Viewport Height** - Represents scalable unit `0% to 100%` of the viewport. `120%` is allowed.
+/// The viewport is the root container, unless [`crate::MasterData::viewport_size`] is set.
 /// Proportional to a height measure even when used in a width field.
 /// ## 🛠️ Example
 /// ```
@@ -966,64 +971,75 @@ impl Ab<Vec4> {
 // #=====================#
 // #=== FUNCTIONALITY ===#
 
+impl <T: Copy + Default> UiValue<T> {
+    /// Returns the [`Sp`] part of the value, which is not included in [`UiValueEvaluate::evaluate`].
+    pub(crate) fn space(&self) -> T {
+        self.sp.unwrap_or_default()
+    }
+}
+
 /// ## UiValue Evaluate
 /// Trait for implementing evaluation logic for `(TT)`.
 /// `(T)` should be 1 vector unit version of `(TT)`.
 /// ## 📦 Types
 pub trait UiValueEvaluate<T> {
-    /// Evaluates the NodeSize for `(T)`
-    fn evaluate(&self, absolute_scale: T, parent_size: T, viewport_size: T, font_size: T) -> T;
+    /// Evaluates the NodeSize for `(T)`. The [`Rw`] and [`Rh`] units are relative to the root size.
+    fn evaluate(&self, absolute_scale: T, parent_size: T, root_size: T, viewport_size: T, font_size: T) -> T;
 }
 
 // # Impl evaluate
 impl UiValueEvaluate<f32> for UiValue<f32> {
-    fn evaluate(&self, absolute_scale: f32, parent_size: f32, viewport_size: f32, font_size: f32) -> f32 {
+    fn evaluate(&self, absolute_scale: f32, parent_size: f32, root_size: f32, viewport_size: f32, font_size: f32) -> f32 {
         let mut out = 0.0;
         if let Some(v) = self.ab { out += v * absolute_scale }
         if let Some(v) = self.rl { out += (v/100.0) * parent_size }
-        if let Some(v) = self.rw { out += (v/100.0) * parent_size }
-        if let Some(v) = self.rh { out += (v/100.0) * parent_size }
+        if let Some(v) = self.rw { out += (v/100.0) * root_size }
+        if let Some(v) = self.rh { out += (v/100.0) * root_size }
         if let Some(v) = self.em { out += v * font_size }
         if let Some(v) = self.vp { out += (v/100.0) * viewport_size }
+        if let Some(v) = self.vw { out += (v/100.0) * viewport_size }
         if let Some(v) = self.vh { out += (v/100.0) * viewport_size }
         out
     }
 }
 impl UiValueEvaluate<Vec2> for UiValue<Vec2> {
-    fn evaluate(&self, absolute_scale: Vec2, parent_size: Vec2, viewport_size: Vec2, font_size: Vec2) -> Vec2 {
+    fn evaluate(&self, absolute_scale: Vec2, parent_size: Vec2, root_size: Vec2, viewport_size: Vec2, font_size: Vec2) -> Vec2 {
         let mut out = Vec2::ZERO;
         if let Some(v) = self.ab { out += v * absolute_scale }
         if let Some(v) = self.rl { out += (v/100.0) * parent_size }
-        if let Some(v) = self.rw { out += (v/100.0) * parent_size.x }
-        if let Some(v) = self.rh { out += (v/100.0) * parent_size.y }
+        if let Some(v) = self.rw { out += (v/100.0) * root_size.x }
+        if let Some(v) = self.rh { out += (v/100.0) * root_size.y }
         if let Some(v) = self.em { out += v * font_size }
-        if let Some(v) = self.vp { out += (v/100.0) * viewport_size.x }
+        if let Some(v) = self.vp { out += (v/100.0) * viewport_size }
+        if let Some(v) = self.vw { out += (v/100.0) * viewport_size.x }
         if let Some(v) = self.vh { out += (v/100.0) * viewport_size.y }
         out
     }
 }
 impl UiValueEvaluate<Vec3> for UiValue<Vec3> {
-    fn evaluate(&self, absolute_scale: Vec3, parent_size: Vec3, viewport_size: Vec3, font_size: Vec3) -> Vec3 {
+    fn evaluate(&self, absolute_scale: Vec3, parent_size: Vec3, root_size: Vec3, viewport_size: Vec3, font_size: Vec3) -> Vec3 {
         let mut out = Vec3::ZERO;
         if let Some(v) = self.ab { out += v * absolute_scale }
         if let Some(v) = self.rl { out += (v/100.0) * parent_size }
-        if let Some(v) = self.rw { out += (v/100.0) * parent_size.x }
-        if let Some(v) = self.rh { out += (v/100.0) * parent_size.y }
+        if let Some(v) = self.rw { out += (v/100.0) * root_size.x }
+        if let Some(v) = self.rh { out += (v/100.0) * root_size.y }
         if let Some(v) = self.em { out += v * font_size }
-        if let Some(v) = self.vp { out += (v/100.0) * viewport_size.x }
+        if let Some(v) = self.vp { out += (v/100.0) * viewport_size }
+        if let Some(v) = self.vw { out += (v/100.0) * viewport_size.x }
         if let Some(v) = self.vh { out += (v/100.0) * viewport_size.y }
         out
     }
 }
 impl UiValueEvaluate<Vec4> for UiValue<Vec4> {
-    fn evaluate(&self, absolute_scale: Vec4, parent_size: Vec4, viewport_size: Vec4, font_size: Vec4) -> Vec4 {
+    fn evaluate(&self, absolute_scale: Vec4, parent_size: Vec4, root_size: Vec4, viewport_size: Vec4, font_size: Vec4) -> Vec4 {
         let mut out = Vec4::ZERO;
         if let Some(v) = self.ab { out += v * absolute_scale }
         if let Some(v) = self.rl { out += (v/100.0) * parent_size }
-        if let Some(v) = self.rw { out += (v/100.0) * parent_size.x }
-        if let Some(v) = self.rh { out += (v/100.0) * parent_size.y }
+        if let Some(v) = self.rw { out += (v/100.0) * root_size.x }
+        if let Some(v) = self.rh { out += (v/100.0) * root_size.y }
         if let Some(v) = self.em { out += v * font_size }
-        if let Some(v) = self.vp { out += (v/100.0) * viewport_size.x }
+        if let Some(v) = self.vp { out += (v/100.0) * viewport_size }
+        if let Some(v) = self.vw { out += (v/100.0) * viewport_size.x }
         if let Some(v) = self.vh { out += (v/100.0) * viewport_size.y }
         out
     }
@@ -1493,6 +1509,74 @@ impl NiceDisplay for UiValue<Vec4> {
     }
 }
 
+// #===============#
+// #=== PARSING ===#
+
+/// Parses a single term like `10ab`, `50%` or `-2em` and adds it to the value.
+fn parse_unit_term(term: &str, value: UiValue<f32>) -> Result<UiValue<f32>, UiError> {
+    let split = term.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+')).unwrap_or(term.len());
+    let (number, unit) = term.split_at(split);
+    let number: f32 = number.parse().map_err(|_| UiError::ParseError(term.to_string()))?;
+    Ok(match unit.trim().to_lowercase().as_str() {
+        "" | "ab" | "px" => value + Ab(number),
+        "%" | "rl" => value + Rl(number),
+        "rw" => value + Rw(number),
+        "rh" => value + Rh(number),
+        "em" => value + Em(number),
+        "sp" => value + Sp(number),
+        "vp" => value + Vp(number),
+        "vw" => value + Vw(number),
+        "vh" => value + Vh(number),
+        _ => return Err(UiError::ParseError(term.to_string())),
+    })
+}
+
+/// Parses a sum of units like `50% - 10ab + 1em`. A number without unit is [`Ab`].
+/// ## 🛠️ Example
+/// ```
+/// # use lunex_engine::{UiValue, Ab, Rl};
+/// let value: UiValue<f32> = "50% - 10ab".parse().unwrap();
+/// assert_eq!(value, Rl(50.0) + Ab(-10.0));
+/// ```
+impl FromStr for UiValue<f32> {
+    type Err = UiError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut value = UiValue::new();
+        let mut term = String::new();
+        for c in s.chars().filter(|c| !c.is_whitespace()) {
+            // Sign starts a new term, unless it is the sign of the first number
+            if (c == '+' || c == '-') && !term.is_empty() && term != "-" && term != "+" {
+                value = parse_unit_term(&term, value)?;
+                term.clear();
+            }
+            term.push(c);
+        }
+        if term.is_empty() { return Err(UiError::ParseError(s.to_string())) }
+        parse_unit_term(&term, value)
+    }
+}
+
+/// Parses two values separated by a comma, or by whitespace if there is no comma.
+/// ## 🛠️ Example
+/// ```
+/// # use lunex_engine::{UiValue, Ab, Rl};
+/// # use bevy::prelude::Vec2;
+/// let a: UiValue<Vec2> = "50% 10ab".parse().unwrap();
+/// let b: UiValue<Vec2> = "50% - 10ab, 20ab".parse().unwrap();
+/// ```
+impl FromStr for UiValue<Vec2> {
+    type Err = UiError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = if s.contains(',') { s.split(',').collect() } else { s.split_whitespace().collect() };
+        match parts.as_slice() {
+            [x, y] => Ok(UiValue::<Vec2>::new().with_x(x.parse::<UiValue<f32>>()?).with_y(y.parse::<UiValue<f32>>()?)),
+            [xy] => Ok(xy.parse::<UiValue<f32>>()?.into()),
+            _ => Err(UiError::ParseError(s.to_string())),
+        }
+    }
+}
+
+
 // #=============#
 // #=== TESTS ===#
 
@@ -1500,7 +1584,7 @@ impl NiceDisplay for UiValue<Vec4> {
 mod test {
     use crate::NiceDisplay;
 
-    use super::{Ab, Rl, Rw, Rh, Em, Sp, UiValue, Vec2};
+    use super::{Ab, Rl, Rw, Rh, Em, Sp, Vp, Vw, UiValue, UiValueEvaluate, Vec2};
     #[test]
     fn all () {
         let _: UiValue<f32> = Ab(5.0) + Rl(5.0);
@@ -1510,5 +1594,28 @@ mod test {
         let size: UiValue<Vec2> = Ab(Vec2::splat(5.0)) + Rl(Vec2::splat(5.0));
         println!("{}", size.to_nicestr());
     }
+    #[test]
+    fn parse () {
+        assert_eq!("10ab".parse::<UiValue<f32>>(), Ok(Ab(10.0).into()));
+        assert_eq!("-50% + 2em".parse::<UiValue<f32>>(), Ok(Rl(-50.0) + Em(2.0)));
+        assert_eq!("100% - 4.5".parse::<UiValue<f32>>(), Ok(Rl(100.0) + Ab(-4.5)));
+        assert!("10xx".parse::<UiValue<f32>>().is_err());
+        assert!("".parse::<UiValue<f32>>().is_err());
+
+        let pos: UiValue<Vec2> = "50% - 10ab, 2em".parse().unwrap();
+        assert_eq!(pos.evaluate(Vec2::ONE, Vec2::splat(100.0), Vec2::splat(100.0), Vec2::ZERO, Vec2::splat(16.0)), Vec2::new(40.0, 32.0));
+    }
+    #[test]
+    fn evaluate () {
+        let parent = Vec2::new(100.0, 50.0);
+        let root = Vec2::new(800.0, 600.0);
+        let viewport = Vec2::new(1920.0, 1080.0);
+        let size: UiValue<Vec2> = Rw(Vec2::splat(10.0)).into();
+        assert_eq!(size.evaluate(Vec2::ONE, parent, root, viewport, Vec2::ZERO), Vec2::new(80.0, 80.0));
+        let size: UiValue<Vec2> = (Rh(10.0), Rl(10.0)).into();
+        assert_eq!(size.evaluate(Vec2::ONE, parent, root, viewport, Vec2::ZERO), Vec2::new(60.0, 5.0));
+        let size: UiValue<Vec2> = (Vw(10.0), Vp(10.0)).into();
+        assert_eq!(size.evaluate(Vec2::ONE, parent, root, viewport, Vec2::ZERO), Vec2::new(192.0, 108.0));
+    }
 }
 
@@ -0,0 +1,62 @@
+use crate::import::*;
+
+
+// #===============#
+// #=== Z INDEX ===#
+
+/// **Ui z-index** - A type used to override the depth the node would get from its position in the hierarchy.
+/// The offset is inherited by all subnodes, so the whole subtree is moved together.
+/// ## 🛠️ Example
+/// ```
+/// # use lunex_engine::UiZIndex;
+/// let z = UiZIndex::Local(2);   // -> Node is placed 2 levels above its siblings
+/// let z = UiZIndex::Global(50); // -> Node is placed at depth 50 regardless of the hierarchy
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+pub enum UiZIndex {
+    /// Depth offset relative to the depth computed from the hierarchy.
+    Local(i32),
+    /// Absolute depth in the tree, independent of the hierarchy.
+    Global(i32),
+}
+impl Default for UiZIndex {
+    fn default() -> Self {
+        UiZIndex::Local(0)
+    }
+}
+impl UiZIndex {
+    /// Returns the depth offset this node and its subnodes should use.
+    /// * `inherited` - Offset inherited from the parent node
+    /// * `depth` - Depth of this node in the hierarchy
+    pub fn offset(&self, inherited: f32, depth: f32) -> f32 {
+        match self {
+            UiZIndex::Local(index) => inherited + *index as f32,
+            UiZIndex::Global(index) => *index as f32 - depth,
+        }
+    }
+}
+
+
+// #===================#
+// #=== DEPTH LAYER ===#
+
+/// **Ui depth layer** - A type used to group the node and all its subnodes into a layer placed above the lower layers.
+/// Every layer adds [`UiDepthLayer::LEVELS`] depth levels to the inherited depth, so nodes in different layers
+/// never end up on the same plane, no matter how deep the hierarchy is. Nested layers add up.
+/// ## 🛠️ Example
+/// ```
+/// # use lunex_engine::UiDepthLayer;
+/// let popup = UiDepthLayer(1);   // -> Node is placed 100 levels above its layer
+/// let tooltip = UiDepthLayer(2); // -> Node is placed 200 levels above its layer
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Component, Reflect)]
+pub struct UiDepthLayer(pub u32);
+impl UiDepthLayer {
+    /// Number of depth levels in one layer.
+    pub const LEVELS: f32 = 100.0;
+    /// Returns the depth offset this node and its subnodes should use.
+    /// * `inherited` - Offset inherited from the parent node
+    pub fn offset(&self, inherited: f32) -> f32 {
+        inherited + self.0 as f32 * Self::LEVELS
+    }
+}
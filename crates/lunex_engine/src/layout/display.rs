@@ -0,0 +1,35 @@
+use crate::import::*;
+
+
+// #===============#
+// #=== DISPLAY ===#
+
+/// **Ui display** - A type used to define if the node should be shown and if it should take part in the layout.
+/// Unlike `Visibility`, this type can also remove the node from the layout.
+/// ## 🛠️ Example
+/// ```
+/// # use lunex_engine::UiDisplay;
+/// let display = UiDisplay::Shown;     // -> Default, node is rendered and occupies space
+/// let display = UiDisplay::Hidden;    // -> Node is invisible, but still occupies space
+/// let display = UiDisplay::Collapsed; // -> Node is invisible and has zero size, including subnodes
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+pub enum UiDisplay {
+    /// Default, the node is rendered and occupies space.
+    #[default]
+    Shown,
+    /// The node is invisible and can't be picked, but still occupies space.
+    Hidden,
+    /// The node is invisible and removed from the layout flow. It and all subnodes are computed with zero size.
+    Collapsed,
+}
+impl UiDisplay {
+    /// Returns `true` if the node should be rendered.
+    pub fn is_visible(&self) -> bool {
+        matches!(self, UiDisplay::Shown)
+    }
+    /// Returns `true` if the node is removed from the layout.
+    pub fn is_collapsed(&self) -> bool {
+        matches!(self, UiDisplay::Collapsed)
+    }
+}
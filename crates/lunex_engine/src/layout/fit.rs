@@ -0,0 +1,75 @@
+use crate::import::*;
+use crate::{UiValue, UiValueEvaluate, Rectangle3D};
+
+
+// #===========#
+// #=== FIT ===#
+
+/// **Ui fit** - Makes the node shrink-wrap the bounding box of its subnodes after they are computed.
+/// The node layout is still used to compute the space available to the subnodes.
+/// Useful for tooltips, context menus or speech bubbles whose size depends on their content.
+/// ## 🛠️ Example
+/// ```
+/// # use lunex_engine::{UiFit, Ab};
+/// let fit = UiFit::new().padding(Ab(10.0)); // -> Fits both axes with 10 units of padding
+/// let fit = UiFit::height();                // -> Fits only the height, width is kept from layout
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct UiFit {
+    /// Padding around the subnodes. Order is left, top, right, bottom.
+    pub padding: UiValue<Vec4>,
+    /// If the width should be fitted.
+    pub width: bool,
+    /// If the height should be fitted.
+    pub height: bool,
+}
+impl Default for UiFit {
+    fn default() -> Self {
+        UiFit {
+            padding: UiValue::new(),
+            width: true,
+            height: true,
+        }
+    }
+}
+impl UiFit {
+    /// Creates new fit on both axes.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Creates new fit on the horizontal axis only.
+    pub fn width() -> Self {
+        UiFit { height: false, ..Default::default() }
+    }
+    /// Creates new fit on the vertical axis only.
+    pub fn height() -> Self {
+        UiFit { width: false, ..Default::default() }
+    }
+    /// Replaces the padding with a new value.
+    pub fn padding(mut self, padding: impl Into<UiValue<Vec4>>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+    /// Returns the rectangle fitted around the content bounds `(min, max)`.
+    /// Relative padding is computed from the original rectangle size.
+    pub fn fit(&self, rectangle: Rectangle3D, min: Vec2, max: Vec2, root_size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Rectangle3D {
+        let size = rectangle.size;
+        let pad = self.padding.evaluate(
+            Vec4::splat(absolute_scale),
+            Vec4::new(size.x, size.y, size.x, size.y),
+            Vec4::new(root_size.x, root_size.y, root_size.x, root_size.y),
+            Vec4::new(viewport_size.x, viewport_size.y, viewport_size.x, viewport_size.y),
+            Vec4::splat(font_size),
+        );
+        let mut output = rectangle;
+        if self.width {
+            output.pos.x = min.x - pad.x;
+            output.size.x = max.x - min.x + pad.x + pad.z;
+        }
+        if self.height {
+            output.pos.y = min.y - pad.y;
+            output.size.y = max.y - min.y + pad.y + pad.w;
+        }
+        output
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{import::*, YInvert};
-use crate::{NiceDisplay, Rectangle2D, UiValue, UiValueEvaluate, Ab, Rl};
+use crate::{NiceDisplay, Rectangle2D, UiError, UiValue, UiValueEvaluate, Ab, Rl};
+use std::str::FromStr;
 
 
 // #===================#
@@ -12,6 +13,7 @@ use crate::{NiceDisplay, Rectangle2D, UiValue, UiValueEvaluate, Ab, Rl};
 /// let layout: Layout = Layout::boundary().pos1(Rl(50.0)).package();
 /// let layout: Layout = Layout::window().size(Rl(50.0)).package();
 /// let layout: Layout = Layout::solid().size(Rl(50.0)).package();
+/// let layout: Layout = Layout::radial().radius(Rl(50.0)).package();
 /// ```
 /// The expected range is `-1.0` to `1.0`, but you can extrapolate.
 #[allow(clippy::large_enum_variant)]
@@ -21,6 +23,7 @@ pub enum Layout {
     Window(Window),
     Solid(Solid),
     Div(Div),
+    Radial(Radial),
 }
 impl Layout {
 
@@ -81,6 +84,18 @@ impl Layout {
         Div::new()
     }
 
+    /// **Radial** - Declarative layout type that places the node on a circle around the parent center.
+    /// All radial subnodes of the parent are distributed along the arc one after another, each getting its own wedge.
+    /// Nodes with this layout are not included in the ui flow.
+    /// ## 🛠️ Example
+    /// ```
+    /// # use lunex_engine::{Layout, Ab, Rl};
+    /// let layout: Layout = Layout::radial().radius(Rl(60.0)).size(Ab(64.0)).package();
+    /// ```
+    pub fn radial() -> Radial {
+        Radial::new()
+    }
+
     /// Unwrap the type, panic if not Boundary variant
     pub fn expect_boundary(&self) -> &Boundary {
         match self {
@@ -137,6 +152,20 @@ impl Layout {
             _ => panic!("A different layout type than expected! Got {}, expected Div", self.to_nicestr())
         }
     }
+    /// Unwrap the type, panic if not Radial variant
+    pub fn expect_radial(&self) -> &Radial {
+        match self {
+            Layout::Radial(r) => r,
+            _ => panic!("A different layout type than expected! Got {}, expected Radial", self.to_nicestr())
+        }
+    }
+    /// Unwrap the type, panic if not Radial variant
+    pub fn expect_radial_mut(&mut self) -> &mut Radial {
+        match self {
+            Layout::Radial(r) => r,
+            _ => panic!("A different layout type than expected! Got {}, expected Radial", self.to_nicestr())
+        }
+    }
 }
 impl Default for Layout {
     fn default() -> Self {
@@ -150,6 +179,7 @@ impl NiceDisplay for Layout {
             Layout::Solid(layout) => format!("{} {}", "Solid".bold().bright_cyan(), layout.to_nicestr()),
             Layout::Window(layout) => format!("{} {}", "Window".bold().bright_cyan(), layout.to_nicestr()),
             Layout::Div(layout) => format!("{} {}", "Div".bold().bright_cyan(), layout.to_nicestr()),
+            Layout::Radial(layout) => format!("{} {}", "Radial".bold().bright_cyan(), layout.to_nicestr()),
         }
     }
 }
@@ -205,6 +235,39 @@ impl Anchor {
             Anchor::Custom(point) => *point,
         }
     }
+    /// Returns the named anchor matching the point, or [`Anchor::Custom`] if there is none.
+    pub fn from_vec(point: Vec2) -> Self {
+        match (point.x, point.y) {
+            (0.0, 1.0) => Anchor::BottomLeft,
+            (0.5, 1.0) => Anchor::BottomCenter,
+            (1.0, 1.0) => Anchor::BottomRight,
+            (0.0, 0.5) => Anchor::CenterLeft,
+            (0.5, 0.5) => Anchor::Center,
+            (1.0, 0.5) => Anchor::CenterRight,
+            (0.0, 0.0) => Anchor::TopLeft,
+            (0.5, 0.0) => Anchor::TopCenter,
+            (1.0, 0.0) => Anchor::TopRight,
+            _ => Anchor::Custom(point),
+        }
+    }
+}
+impl FromStr for Anchor {
+    type Err = UiError;
+    /// Parses the anchor name, case and separator insensitive. For example `"top-left"` or `"TopLeft"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['-', '_', ' '], "").as_str() {
+            "center" => Ok(Anchor::Center),
+            "bottomleft" => Ok(Anchor::BottomLeft),
+            "bottomcenter" => Ok(Anchor::BottomCenter),
+            "bottomright" => Ok(Anchor::BottomRight),
+            "centerleft" => Ok(Anchor::CenterLeft),
+            "centerright" => Ok(Anchor::CenterRight),
+            "topleft" => Ok(Anchor::TopLeft),
+            "topcenter" => Ok(Anchor::TopCenter),
+            "topright" => Ok(Anchor::TopRight),
+            _ => Err(UiError::ParseError(s.to_string())),
+        }
+    }
 }
 impl From<bevy::sprite::Anchor> for Anchor {
     fn from(val: bevy::sprite::Anchor) -> Self {
@@ -275,6 +338,19 @@ pub enum Scaling {
     /// Node layout should always cover all of the parent node.
     Fill,
 }
+impl FromStr for Scaling {
+    type Err = UiError;
+    /// Parses the scaling name, case and separator insensitive. For example `"fit"` or `"hor-fill"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['-', '_', ' '], "").as_str() {
+            "horfill" => Ok(Scaling::HorFill),
+            "verfill" => Ok(Scaling::VerFill),
+            "fit" => Ok(Scaling::Fit),
+            "fill" => Ok(Scaling::Fill),
+            _ => Err(UiError::ParseError(s.to_string())),
+        }
+    }
+}
 impl NiceDisplay for Scaling {
     fn to_nicestr(&self) -> String {
         match self {
@@ -396,9 +472,9 @@ impl Boundary {
     }
 
     /// Computes the layout based on given parameters.
-    pub(crate) fn compute(&self, parent: Rectangle2D, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Rectangle2D {
-        let pos1 = self.pos1.evaluate(Vec2::splat(absolute_scale), parent.size, viewport_size, Vec2::splat(font_size));
-        let pos2 = self.pos2.evaluate(Vec2::splat(absolute_scale), parent.size, viewport_size, Vec2::splat(font_size));
+    pub(crate) fn compute(&self, parent: Rectangle2D, root_size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Rectangle2D {
+        let pos1 = self.pos1.evaluate(Vec2::splat(absolute_scale), parent.size, root_size, viewport_size, Vec2::splat(font_size));
+        let pos2 = self.pos2.evaluate(Vec2::splat(absolute_scale), parent.size, root_size, viewport_size, Vec2::splat(font_size));
         Rectangle2D {
             pos: parent.pos + pos1,
             size: pos2 - pos1,
@@ -429,6 +505,12 @@ impl NiceDisplay for Boundary {
 /// # use lunex_engine::{Layout, Window, Ab, Rl};
 /// let layout: Layout = Window::new().pos(Ab(100.0)).size(Rl(50.0)).package();
 /// ```
+/// Anchor presets set the position and the anchor of an axis in one call.
+/// ```
+/// # use lunex_engine::{Layout, Window, Ab, Rl};
+/// let sidebar: Layout = Window::new().anchor_left(Ab(20.0)).center_y().size(Rl(30.0)).package();
+/// let button: Layout = Window::new().top_right(Ab(10.0)).size(Ab(40.0)).package();
+/// ```
 #[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
 pub struct Window {
     /// Position of the node.
@@ -519,10 +601,81 @@ impl Window {
         self.anchor = anchor.into();
     }
 
+    /// Places the left edge of the node at the offset from the left edge of the parent.
+    pub fn anchor_left(mut self, offset: impl Into<UiValue<f32>>) -> Self {
+        self.place_x(offset.into(), 0.0);
+        self
+    }
+    /// Places the right edge of the node at the offset from the right edge of the parent.
+    pub fn anchor_right(mut self, offset: impl Into<UiValue<f32>>) -> Self {
+        self.place_x(UiValue::from(Rl(100.0)) - offset.into(), 1.0);
+        self
+    }
+    /// Places the top edge of the node at the offset from the top edge of the parent.
+    pub fn anchor_top(mut self, offset: impl Into<UiValue<f32>>) -> Self {
+        self.place_y(offset.into(), 0.0);
+        self
+    }
+    /// Places the bottom edge of the node at the offset from the bottom edge of the parent.
+    pub fn anchor_bottom(mut self, offset: impl Into<UiValue<f32>>) -> Self {
+        self.place_y(UiValue::from(Rl(100.0)) - offset.into(), 1.0);
+        self
+    }
+    /// Centers the node horizontally within the parent.
+    pub fn center_x(mut self) -> Self {
+        self.place_x(Rl(50.0).into(), 0.5);
+        self
+    }
+    /// Centers the node vertically within the parent.
+    pub fn center_y(mut self) -> Self {
+        self.place_y(Rl(50.0).into(), 0.5);
+        self
+    }
+    /// Centers the node within the parent.
+    pub fn center(self) -> Self {
+        self.center_x().center_y()
+    }
+    /// Places the node into the top left corner of the parent with the margin.
+    pub fn top_left(self, margin: impl Into<UiValue<Vec2>>) -> Self {
+        let margin = margin.into();
+        self.anchor_left(margin.get_x()).anchor_top(margin.get_y())
+    }
+    /// Places the node into the top right corner of the parent with the margin.
+    pub fn top_right(self, margin: impl Into<UiValue<Vec2>>) -> Self {
+        let margin = margin.into();
+        self.anchor_right(margin.get_x()).anchor_top(margin.get_y())
+    }
+    /// Places the node into the bottom left corner of the parent with the margin.
+    pub fn bottom_left(self, margin: impl Into<UiValue<Vec2>>) -> Self {
+        let margin = margin.into();
+        self.anchor_left(margin.get_x()).anchor_bottom(margin.get_y())
+    }
+    /// Places the node into the bottom right corner of the parent with the margin.
+    pub fn bottom_right(self, margin: impl Into<UiValue<Vec2>>) -> Self {
+        let margin = margin.into();
+        self.anchor_right(margin.get_x()).anchor_bottom(margin.get_y())
+    }
+    /// Replaces the whole x position and the horizontal anchor.
+    fn place_x(&mut self, x: UiValue<f32>, anchor: f32) {
+        let y = self.pos.get_y();
+        self.pos = UiValue::new();
+        self.pos.set_x(x);
+        self.pos.set_y(y);
+        self.anchor = Anchor::from_vec(Vec2::new(anchor, self.anchor.as_vec().y));
+    }
+    /// Replaces the whole y position and the vertical anchor.
+    fn place_y(&mut self, y: UiValue<f32>, anchor: f32) {
+        let x = self.pos.get_x();
+        self.pos = UiValue::new();
+        self.pos.set_x(x);
+        self.pos.set_y(y);
+        self.anchor = Anchor::from_vec(Vec2::new(self.anchor.as_vec().x, anchor));
+    }
+
     /// Computes the layout based on given parameters.
-    pub(crate) fn compute(&self, parent: Rectangle2D, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Rectangle2D {
-        let pos = self.pos.evaluate(Vec2::splat(absolute_scale), parent.size, viewport_size, Vec2::splat(font_size));
-        let size = self.size.evaluate(Vec2::splat(absolute_scale), parent.size, viewport_size, Vec2::splat(font_size));
+    pub(crate) fn compute(&self, parent: Rectangle2D, root_size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Rectangle2D {
+        let pos = self.pos.evaluate(Vec2::splat(absolute_scale), parent.size, root_size, viewport_size, Vec2::splat(font_size));
+        let size = self.size.evaluate(Vec2::splat(absolute_scale), parent.size, root_size, viewport_size, Vec2::splat(font_size));
         Rectangle2D {
             pos: parent.pos + pos - size * self.anchor.as_vec(),
             size,
@@ -631,9 +784,9 @@ impl Solid {
     }
 
     /// Computes the layout based on given parameters.
-    pub(crate) fn compute(&self, parent: Rectangle2D, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Rectangle2D {
+    pub(crate) fn compute(&self, parent: Rectangle2D, root_size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Rectangle2D {
         
-        let size = self.size.evaluate(Vec2::splat(absolute_scale), parent.size, viewport_size, Vec2::splat(font_size));
+        let size = self.size.evaluate(Vec2::splat(absolute_scale), parent.size, root_size, viewport_size, Vec2::splat(font_size));
 
         let scale = match self.scaling {
             Scaling::HorFill => parent.size.x / size.x,
@@ -674,9 +827,262 @@ impl NiceDisplay for Solid {
 }
 
 
+/// **Radial** - Declarative layout type that places the node on a circle around the parent center.
+/// All radial subnodes of the parent are distributed along the arc one after another, each getting its own wedge.
+/// The node is centered in the middle of its wedge. Nodes with this layout are not included in the ui flow.
+///
+/// Angles are in degrees, `0.0` is at the top and they grow clockwise. The arc is taken from the first radial subnode,
+/// so give all of them the same one. The radius uses [`Rl`] relative to the largest circle fitting in the parent,
+/// so `Rl(100.0)` touches its edge.
+/// ## 🛠️ Example
+/// ```
+/// # use lunex_engine::{Layout, Radial, Ab, Rl};
+/// // Weapon wheel of 8 slots, the first one centered at the top
+/// let layout: Layout = Radial::new().radius(Rl(65.0)).size(Ab(64.0)).arc(-22.5, 337.5).package();
+///
+/// // Bigger wedge for one special slot, the rest share what is left
+/// let layout: Layout = Radial::new().radius(Rl(65.0)).size(Ab(96.0)).angle(90.0).package();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct Radial {
+    /// Distance of the node center from the parent center.
+    pub radius: UiValue<f32>,
+    /// Size of the node layout.
+    pub size: UiValue<Vec2>,
+    /// Angle where the arc starts.
+    pub start: f32,
+    /// Angle where the arc ends.
+    pub end: f32,
+    /// Angular size of the wedge. If `None`, the wedge gets an equal share of the arc left by the other wedges.
+    pub angle: Option<f32>,
+    /// Distance of the inner edge of the wedge from the parent center.
+    pub inner: UiValue<f32>,
+    /// Distance of the outer edge of the wedge from the parent center.
+    pub outer: UiValue<f32>,
+}
+impl Default for Radial {
+    fn default() -> Self {
+        Radial::new()
+    }
+}
+impl Radial {
+    /// Creates new full circle Radial node layout.
+    pub fn new() -> Self {
+        Radial {
+            radius: Rl(70.0).into(),
+            size: Rl(25.0).into(),
+            start: 0.0,
+            end: 360.0,
+            angle: None,
+            inner: UiValue::new(),
+            outer: Rl(100.0).into(),
+        }
+    }
+    /// Replaces the radius with a new value.
+    pub fn radius(mut self, radius: impl Into<UiValue<f32>>) -> Self {
+        self.radius = radius.into();
+        self
+    }
+    /// Replaces the size with a new value.
+    pub fn size(mut self, size: impl Into<UiValue<Vec2>>) -> Self {
+        self.size = size.into();
+        self
+    }
+    /// Replaces the width with a new value.
+    pub fn width(mut self, width: impl Into<UiValue<f32>>) -> Self {
+        self.size.set_x(width);
+        self
+    }
+    /// Replaces the height with a new value.
+    pub fn height(mut self, height: impl Into<UiValue<f32>>) -> Self {
+        self.size.set_y(height);
+        self
+    }
+    /// Replaces the start angle with a new value.
+    pub fn start(mut self, angle: f32) -> Self {
+        self.start = angle;
+        self
+    }
+    /// Replaces the end angle with a new value.
+    pub fn end(mut self, angle: f32) -> Self {
+        self.end = angle;
+        self
+    }
+    /// Replaces the start and end angle with new values.
+    pub fn arc(mut self, start: f32, end: f32) -> Self {
+        self.start = start;
+        self.end = end;
+        self
+    }
+    /// Replaces the angular size of the wedge with a new value.
+    pub fn angle(mut self, angle: f32) -> Self {
+        self.angle = Some(angle);
+        self
+    }
+    /// Replaces the inner edge of the wedge with a new value.
+    pub fn inner(mut self, inner: impl Into<UiValue<f32>>) -> Self {
+        self.inner = inner.into();
+        self
+    }
+    /// Replaces the outer edge of the wedge with a new value.
+    pub fn outer(mut self, outer: impl Into<UiValue<f32>>) -> Self {
+        self.outer = outer.into();
+        self
+    }
+    /// Sets the radius to a new value.
+    pub fn set_radius(&mut self, radius: impl Into<UiValue<f32>>) {
+        self.radius = radius.into();
+    }
+    /// Sets the size to a new value.
+    pub fn set_size(&mut self, size: impl Into<UiValue<Vec2>>) {
+        self.size = size.into();
+    }
+    /// Sets the width to a new value.
+    pub fn set_width(&mut self, width: impl Into<UiValue<f32>>) {
+        self.size.set_x(width);
+    }
+    /// Sets the height to a new value.
+    pub fn set_height(&mut self, height: impl Into<UiValue<f32>>) {
+        self.size.set_y(height);
+    }
+    /// Sets the start and end angle to new values.
+    pub fn set_arc(&mut self, start: f32, end: f32) {
+        self.start = start;
+        self.end = end;
+    }
+    /// Sets the angular size of the wedge to a new value.
+    pub fn set_angle(&mut self, angle: Option<f32>) {
+        self.angle = angle;
+    }
+    /// Sets the inner edge of the wedge to a new value.
+    pub fn set_inner(&mut self, inner: impl Into<UiValue<f32>>) {
+        self.inner = inner.into();
+    }
+    /// Sets the outer edge of the wedge to a new value.
+    pub fn set_outer(&mut self, outer: impl Into<UiValue<f32>>) {
+        self.outer = outer.into();
+    }
+
+    /// Returns the start and end angle of the wedge of each layout, in the order they are given.
+    /// The arc is taken from the first layout.
+    pub fn distribute<'a>(layouts: impl IntoIterator<Item = &'a Radial>) -> Vec<Vec2> {
+        let layouts: Vec<&Radial> = layouts.into_iter().collect();
+        let Some(first) = layouts.first() else { return Vec::new() };
+        let fixed: f32 = layouts.iter().filter_map(|layout| layout.angle).sum();
+        let shared = layouts.iter().filter(|layout| layout.angle.is_none()).count();
+        let share = if shared > 0 { ((first.end - first.start).abs() - fixed).max(0.0) / shared as f32 } else { 0.0 };
+        let sign = if first.end < first.start { -1.0 } else { 1.0 };
+
+        let mut angle = first.start;
+        layouts.iter().map(|layout| {
+            let from = angle;
+            angle += layout.angle.unwrap_or(share) * sign;
+            Vec2::new(from, angle)
+        }).collect()
+    }
+    /// Computes the layout based on given parameters.
+    pub(crate) fn compute(&self, parent: Rectangle2D, root_size: Vec2, wedge: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Rectangle2D {
+        let radius = self.radius.evaluate(absolute_scale, parent.size.min_element() / 2.0, root_size.min_element() / 2.0, viewport_size.min_element(), font_size);
+        let size = self.size.evaluate(Vec2::splat(absolute_scale), parent.size, root_size, viewport_size, Vec2::splat(font_size));
+        let center = parent.pos + parent.size / 2.0 + radial_direction((wedge.x + wedge.y) / 2.0) * radius;
+        Rectangle2D {
+            pos: center - size / 2.0,
+            size,
+        }
+    }
+    /// Computes the wedge of the node relative to the computed node rectangle.
+    pub(crate) fn compute_wedge(&self, parent: Rectangle2D, root_size: Vec2, rectangle: Rectangle2D, wedge: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> RadialWedge {
+        let (scale, root_scale) = (parent.size.min_element() / 2.0, root_size.min_element() / 2.0);
+        let inner = self.inner.evaluate(absolute_scale, scale, root_scale, viewport_size.min_element(), font_size);
+        let outer = self.outer.evaluate(absolute_scale, scale, root_scale, viewport_size.min_element(), font_size);
+        RadialWedge {
+            center: parent.pos + parent.size / 2.0 - rectangle.pos - rectangle.size / 2.0,
+            inner,
+            outer,
+            start: wedge.x.min(wedge.y),
+            end: wedge.x.max(wedge.y),
+        }
+    }
+    /// Packs the struct into Layout.
+    pub fn package(self) -> Layout {
+        self.into()
+    }
+}
+impl From<Radial> for Layout {
+    fn from(val: Radial) -> Self {
+        Layout::Radial(val)
+    }
+}
+impl NiceDisplay for Radial {
+    fn to_nicestr(&self) -> String {
+        let t = format!("[radius: ({}) size: ({}) arc: {} {}]", self.radius.to_nicestr(), self.size.to_nicestr(), self.start.to_string().bold(), self.end.to_string().bold());
+        format!("{}", t.black())
+    }
+}
+
+/// Returns the direction of the angle in degrees, `0.0` points up and the angle grows clockwise. Y is down.
+fn radial_direction(angle: f32) -> Vec2 {
+    let (sin, cos) = angle.to_radians().sin_cos();
+    Vec2::new(sin, -cos)
+}
+
+/// **Radial wedge** - The part of the ring given to a node with [`Radial`] layout, used as its picking region.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+pub struct RadialWedge {
+    /// Center of the ring relative to the node center. Y is down.
+    pub center: Vec2,
+    /// Distance of the inner edge from the center of the ring.
+    pub inner: f32,
+    /// Distance of the outer edge from the center of the ring.
+    pub outer: f32,
+    /// Angle where the wedge starts.
+    pub start: f32,
+    /// Angle where the wedge ends.
+    pub end: f32,
+}
+impl RadialWedge {
+    /// Returns `true` if the point relative to the node center is inside the wedge. Y is down.
+    pub fn contains(&self, point: Vec2) -> bool {
+        let offset = point - self.center;
+        let distance = offset.length();
+        if distance < self.inner || distance > self.outer { return false }
+        if self.end - self.start >= 360.0 { return true }
+        let angle = offset.x.atan2(-offset.y).to_degrees();
+        (angle - self.start).rem_euclid(360.0) <= self.end - self.start
+    }
+}
+
+
+/// Computed padding, border and margin of [`Div`]. `x-left`, `y-top`, `z-right`, `w-bottom`
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct DivBox {
+    pub(crate) padding: Vec4,
+    pub(crate) border: Vec4,
+    pub(crate) margin: Vec4,
+    /// The [`Sp`] part of the margin.
+    pub(crate) space: Vec4,
+}
+impl DivBox {
+    /// Returns the size taken by the padding and the border.
+    pub(crate) fn inset(&self) -> Vec2 {
+        let inset = self.padding + self.border;
+        Vec2::new(inset.x + inset.z, inset.y + inset.w)
+    }
+    /// Returns the offset of the content from the top-left corner.
+    pub(crate) fn offset(&self) -> Vec2 {
+        let inset = self.padding + self.border;
+        Vec2::new(inset.x, inset.y)
+    }
+}
+
 /// **Div** - Parametric layout type that is defined by margin, border and padding. Its location and size
 /// is based on the surrounding nodes, like HTML. It is also the only node layout that uses the [`Sp`] unit.
 /// You can use this unit for alignment and justification.
+///
+/// Div nodes are placed one after another in the direction of the [`UiStack`](crate::UiStack) of the parent node,
+/// starting a new line after nodes with [`Div::br`]. The size is the content size, given by the Div subnodes or by
+/// [`NodeData::content_size`](crate::NodeData::content_size), with the padding and the border added. The [`Sp`] parts
+/// of the margins share the space left in the line, and the space left in the line height on the other axis.
 /// ## 🛠️ Example
 /// ```
 /// # use lunex_engine::{Div, Sp, Layout};
@@ -1050,6 +1456,38 @@ impl Div {
         self.margin.set_w(margin);
     }
 
+    /// Computes the padding, border and margin. Relative units are relative to the parent size.
+    pub(crate) fn compute_box(&self, parent_size: Vec2, root_size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> DivBox {
+        let evaluate = |value: &UiValue<Vec4>| value.evaluate(Vec4::splat(absolute_scale), Vec4::new(parent_size.x, parent_size.y, parent_size.x, parent_size.y), Vec4::new(root_size.x, root_size.y, root_size.x, root_size.y), Vec4::new(viewport_size.x, viewport_size.y, viewport_size.x, viewport_size.y), Vec4::splat(font_size));
+        DivBox {
+            padding: evaluate(&self.padding),
+            border: evaluate(&self.border),
+            margin: evaluate(&self.margin),
+            space: self.margin.space(),
+        }
+    }
+    /// Computes the size of the node from the size of its content, the padding and the border are added to it.
+    /// The available size is used by [`Sizing::Max`] without maximal size and relative units are relative to it.
+    pub(crate) fn compute_size(&self, content: Vec2, boxed: &DivBox, available: Vec2, root_size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> Vec2 {
+        let evaluate = |value: &UiValue<Vec2>| value.evaluate(Vec2::splat(absolute_scale), available, root_size, viewport_size, Vec2::splat(font_size));
+        let min = self.min_size.as_ref().map(evaluate);
+        let max = self.max_size.as_ref().map(evaluate);
+        let content = content + boxed.inset();
+        let available = available - Vec2::new(boxed.margin.x + boxed.margin.z, boxed.margin.y + boxed.margin.w);
+        let pick = |sizing: Sizing, content: f32, available: f32, min: Option<f32>, max: Option<f32>| {
+            let size = match sizing {
+                Sizing::Min => min.unwrap_or(content),
+                Sizing::Basic => content,
+                Sizing::Max => max.unwrap_or(available),
+            };
+            let size = min.map_or(size, |min| size.max(min));
+            max.map_or(size, |max| size.min(max)).max(0.0)
+        };
+        Vec2::new(
+            pick(self.width, content.x, available.x, min.map(|min| min.x), max.map(|max| max.x)),
+            pick(self.height, content.y, available.y, min.map(|min| min.y), max.map(|max| max.y)),
+        )
+    }
     /// Packs the struct into Layout
     pub fn package(self) -> Layout {
         self.into()
@@ -5,18 +5,29 @@ pub use layout::*;
 mod stack;
 pub use stack::*;
 
+mod display;
+pub use display::*;
+
+mod depth;
+pub use depth::*;
+
+mod fit;
+pub use fit::*;
+
 // #======================#
 // #=== PRELUDE EXPORT ===#
 
 pub mod prelude {
-    pub use super::Layout;
+    pub use super::{Layout, RadialWedge};
     pub use super::{Align, Scaling, Sizing};
 
     pub use super::UiStack;
     pub use super::{StackDirection, StackMargin};
 
+    pub use super::{UiDisplay, UiZIndex, UiDepthLayer, UiFit};
+
     #[allow(non_snake_case)]
     pub mod ui {
-        pub use super::super::{Boundary, Window, Solid, Div};
+        pub use super::super::{Boundary, Window, Solid, Div, Radial};
     }
 }
\ No newline at end of file
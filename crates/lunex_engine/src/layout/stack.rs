@@ -1,5 +1,5 @@
 use crate::import::*;
-use crate::UiValue;
+use crate::{UiValue, UiValueEvaluate};
 
 
 // #========================#
@@ -43,6 +43,21 @@ pub enum StackMargin {
     /// Manually set margin for all subnodes.
     Manual(Box<UiValue<Vec4>>),
 }
+impl StackMargin {
+    /// Returns the [`Sp`](crate::Sp) weights of the spaces around the subnodes in a line, from before the first to after the last.
+    fn spaces(&self, count: usize) -> Vec<f32> {
+        let mut spaces = vec![0.0; count + 1];
+        match self {
+            StackMargin::Start | StackMargin::Manual(_) => {},
+            StackMargin::Center => { spaces[0] = 1.0; spaces[count] = 1.0 },
+            StackMargin::End => spaces[0] = 1.0,
+            StackMargin::Between => spaces[1..count].fill(1.0),
+            StackMargin::Evenly => spaces.fill(1.0),
+            StackMargin::Around => { spaces.fill(2.0); spaces[0] = 1.0; spaces[count] = 1.0 },
+        }
+        spaces
+    }
+}
 
 
 // #=================#
@@ -135,4 +150,91 @@ impl UiStack {
     pub fn set_margin(&mut self, margin: StackMargin) {
         self.margin = margin;
     }
+
+    /// Places the items one after another in lines and returns their positions with the size of the content.
+    /// The content size does not include the space shared by the [`Sp`](crate::Sp) margins.
+    pub(crate) fn flow(&self, items: &[FlowItem], available: Vec2, root_size: Vec2, absolute_scale: f32, viewport_size: Vec2, font_size: f32) -> (Vec<Vec2>, Vec2) {
+        // Everything is computed as horizontal, vertical stacks swap the axes
+        let horizontal = self.direction == StackDirection::Horizontal;
+        let swap2 = |v: Vec2| if horizontal { v } else { Vec2::new(v.y, v.x) };
+        let swap4 = |v: Vec4| if horizontal { v } else { Vec4::new(v.y, v.x, v.w, v.z) };
+
+        let gap = swap2(self.gap.evaluate(Vec2::splat(absolute_scale), available, root_size, viewport_size, Vec2::splat(font_size)));
+        let (stack_margin, stack_space) = match &self.margin {
+            StackMargin::Manual(margin) => (margin.evaluate(Vec4::splat(absolute_scale), Vec4::new(available.x, available.y, available.x, available.y), Vec4::new(root_size.x, root_size.y, root_size.x, root_size.y), Vec4::new(viewport_size.x, viewport_size.y, viewport_size.x, viewport_size.y), Vec4::splat(font_size)), margin.space()),
+            _ => (Vec4::ZERO, Vec4::ZERO),
+        };
+        let items: Vec<FlowItem> = items.iter().map(|item| FlowItem {
+            size: swap2(item.size),
+            margin: swap4(item.margin + stack_margin),
+            space: swap4(item.space + stack_space),
+            br: item.br,
+        }).collect();
+        let available = swap2(available);
+
+        // Split the items into lines
+        let mut lines = Vec::new();
+        let mut start = 0;
+        for (index, item) in items.iter().enumerate() {
+            if item.br || index + 1 == items.len() {
+                lines.push(&items[start..=index]);
+                start = index + 1;
+            }
+        }
+
+        let mut positions = Vec::with_capacity(items.len());
+        let mut content = Vec2::ZERO;
+        let mut line_cursor = 0.0;
+        for (i, line) in lines.into_iter().enumerate() {
+            if i != 0 { line_cursor += gap.y }
+
+            // Share the space left in the line between the Sp margins
+            let used = line.iter().map(|item| item.margin.x + item.size.x + item.margin.z).sum::<f32>() + gap.x * (line.len() - 1) as f32;
+            let spaces = self.margin.spaces(line.len());
+            let weights = spaces.iter().sum::<f32>() + line.iter().map(|item| item.space.x + item.space.z).sum::<f32>();
+            let unit = if weights > 0.0 { (available.x - used).max(0.0) / weights } else { 0.0 };
+            let line_length = line.iter().map(|item| item.margin.y + item.size.y + item.margin.w).fold(0.0, f32::max);
+
+            let mut cursor = spaces[0] * unit;
+            for (ii, item) in line.iter().enumerate() {
+                if ii != 0 { cursor += gap.x + spaces[ii] * unit }
+                cursor += item.space.x * unit + item.margin.x;
+                let x = cursor;
+                cursor += item.size.x + item.margin.z + item.space.z * unit;
+
+                // Align inside the line with the Sp margins on the other axis
+                let left = line_length - (item.margin.y + item.size.y + item.margin.w);
+                let weights = item.space.y + item.space.w;
+                let offset = if weights > 0.0 { left * item.space.y / weights } else { 0.0 };
+                positions.push(Vec2::new(x, line_cursor + offset + item.margin.y));
+            }
+
+            content.x = content.x.max(used);
+            line_cursor += line_length;
+        }
+        content.y = line_cursor;
+
+        // Swap the axes back and mirror the directions
+        let available = swap2(available);
+        let positions = positions.into_iter().zip(&items).map(|(position, item)| {
+            let (mut position, size) = (swap2(position), swap2(item.size));
+            if self.inverted { position.x = available.x - position.x - size.x }
+            if self.flipped { position.y = available.y - position.y - size.y }
+            position
+        }).collect();
+        (positions, swap2(content))
+    }
+}
+
+/// A node placed by [`UiStack::flow`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct FlowItem {
+    /// Size of the node without the margin.
+    pub(crate) size: Vec2,
+    /// Computed margin. `x-left`, `y-top`, `z-right`, `w-bottom`
+    pub(crate) margin: Vec4,
+    /// The [`Sp`](crate::Sp) part of the margin.
+    pub(crate) space: Vec4,
+    /// If the next node starts on a new line.
+    pub(crate) br: bool,
 }
\ No newline at end of file
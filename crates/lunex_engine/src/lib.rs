@@ -1,4 +1,5 @@
 #![doc = include_str!("../README.md")]
+#![allow(clippy::too_many_arguments)]
 
 // #======================#
 // #=== PRELUDE EXPORT ===#
@@ -27,7 +28,7 @@ pub(crate) mod import {
     pub(crate) use bevy::prelude::{Component, Deref, DerefMut};
 
     pub(crate) use indexmap::IndexMap;
-    pub(crate) use bevy::utils::HashMap;
+    pub(crate) use std::collections::BTreeMap;
     pub(crate) use colored::Colorize;
 
     pub(crate) use bevy::math::{Vec2, Vec3, Vec4};
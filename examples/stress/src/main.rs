@@ -0,0 +1,106 @@
+//! Spawns a large tree and animates a part of it to measure the layout performance.
+//! Configure it with environment variables, for example `NODES=10000 DEPTH=4 ANIMATED=5 cargo run -p stress --release`.
+
+use bevy::{diagnostic::DiagnosticsStore, prelude::*};
+use bevy_lunex::prelude::*;
+use bevy_lunex::{UI_LAYOUT_TIME, UI_NODES_TRAVERSED, UI_SYSTEMS_TIME};
+
+
+/// Settings of the stress test.
+#[derive(Resource, Debug, Clone, Copy)]
+struct Stress {
+    /// Number of nodes to spawn, rounded up to fill every level.
+    nodes: usize,
+    /// Number of levels the nodes are spread over.
+    depth: u32,
+    /// Percentage of nodes animated every frame.
+    animated: usize,
+}
+impl Stress {
+    fn from_env() -> Self {
+        let var = |name: &str, default: usize| std::env::var(name).ok().and_then(|value| value.parse().ok()).unwrap_or(default);
+        Stress {
+            nodes: var("NODES", 10_000),
+            depth: var("DEPTH", 3).max(1) as u32,
+            animated: var("ANIMATED", 5).min(100),
+        }
+    }
+}
+
+/// Animated node with its phase and width in percent.
+#[derive(Component)]
+struct Animated {
+    phase: f32,
+    width: f32,
+}
+
+fn main() {
+    App::new()
+        .insert_resource(Stress::from_env())
+        .add_plugins((DefaultPlugins, UiMinimalPlugins))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (animate.before(UiSystems::Send), report))
+        .run();
+}
+
+fn setup(mut commands: Commands, stress: Res<Stress>) {
+    commands.spawn((MainUi, Camera2dBundle::default()));
+
+    let branching = (stress.nodes as f32).powf(1.0 / stress.depth as f32).ceil() as usize;
+    let step = 100.0 / branching as f32;
+    let every = 100usize.checked_div(stress.animated).unwrap_or(usize::MAX);
+
+    commands.spawn((
+        UiTreeBundle::<MainUi>::from(UiTree::new2d("Stress")),
+        SourceFromCamera,
+    )).with_children(|ui| {
+        let mut level = vec![UiLink::<MainUi>::path("Root")];
+        ui.spawn((level[0].clone(), UiLayout::window_full().pack::<Base>()));
+
+        let mut count = 0;
+        for depth in 0..stress.depth {
+            let mut next = Vec::new();
+            for parent in &level {
+                for i in 0..branching {
+                    let link = parent.add(format!("Node{i}"));
+                    let color = Color::hsl(depth as f32 * 60.0 + i as f32 * step, 0.7, 0.3);
+                    let mut node = ui.spawn((
+                        link.clone(),
+                        UiLayout::window().x(Rl(step * i as f32)).size(Rl((step, 100.0))).pack::<Base>(),
+                    ));
+                    // Only the leaves are drawn, the rest of the nodes is layout only
+                    if depth + 1 == stress.depth {
+                        node.insert((UiColor::<Base>::new(color), UiImage2dBundle::default()));
+                    }
+                    if count % every == 0 { node.insert(Animated { phase: count as f32, width: step }); }
+                    count += 1;
+                    next.push(link);
+                }
+            }
+            level = next;
+        }
+        info!("Spawned {count} nodes, {branching} per parent over {} levels", stress.depth);
+    });
+}
+
+/// Resizes the animated nodes every frame, which marks them and their subnodes to be recomputed.
+fn animate(time: Res<Time>, mut query: Query<(&mut UiLayout, &Animated)>) {
+    for (mut layout, animated) in &mut query {
+        let Layout::Window(window) = &mut layout.layout else { continue };
+        let scale = 0.75 + (time.elapsed_seconds() * 2.0 + animated.phase).sin() * 0.25;
+        window.size = Rl((animated.width, 100.0 * scale)).into();
+    }
+}
+
+/// Logs the smoothed layout statistics once per second.
+fn report(time: Res<Time>, mut timer: Local<f32>, store: Res<DiagnosticsStore>) {
+    *timer += time.delta_seconds();
+    if *timer < 1.0 { return }
+    *timer = 0.0;
+
+    let value = |path| store.get(path).and_then(|diagnostic| diagnostic.smoothed()).unwrap_or_default();
+    info!(
+        "layout {:.3} ms | all systems {:.3} ms | {:.0} nodes traversed",
+        value(&UI_LAYOUT_TIME), value(&UI_SYSTEMS_TIME), value(&UI_NODES_TRAVERSED),
+    );
+}